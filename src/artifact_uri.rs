@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use serde_sarif::sarif::{Artifact, ArtifactLocation, Location, Result as SarifResult, Run};
+
+/// Default `uriBaseId` for `--relative-to`, matching the SARIF convention for the source root.
+pub(crate) const DEFAULT_URI_BASE_ID: &str = "SRCROOT";
+
+/// Rewrites `file://` artifact URIs under a base directory as references relative to it, so
+/// reports from checkouts in different directories compare equal.
+///
+/// Plain file URIs become relative references resolved through `uri_base_id`. JAR entry URIs
+/// keep their `jar:<jar uri>!/<entry>` form with only the JAR URI relativized, because a `jar:`
+/// URI is absolute and cannot carry a base ID.
+#[derive(Clone, Debug)]
+pub(crate) struct UriRelativizer {
+    /// `file://` URI of the base directory, ending in `/`.
+    base_uri: String,
+    uri_base_id: String,
+}
+
+impl UriRelativizer {
+    pub(crate) fn new(base_dir: &Path, uri_base_id: &str) -> Self {
+        let absolute = if base_dir.is_absolute() {
+            base_dir.to_path_buf()
+        } else {
+            std::env::current_dir()
+                .unwrap_or_else(|_| Path::new(".").to_path_buf())
+                .join(base_dir)
+        };
+        let mut base_uri = format!("file://{}", absolute.to_string_lossy());
+        if !base_uri.ends_with('/') {
+            base_uri.push('/');
+        }
+        Self {
+            base_uri,
+            uri_base_id: uri_base_id.to_string(),
+        }
+    }
+
+    /// Rewrites the artifact locations of `artifacts`.
+    pub(crate) fn relativize_artifacts(&self, artifacts: &mut [Artifact]) {
+        for artifact in artifacts {
+            if let Some(location) = artifact.location.as_mut() {
+                self.relativize_artifact_location(location);
+            }
+        }
+    }
+
+    /// Rewrites the primary, related, and code flow locations of `result`.
+    pub(crate) fn relativize_result(&self, result: &mut SarifResult) {
+        let locations = result.locations.iter_mut().flatten();
+        let related = result.related_locations.iter_mut().flatten();
+        let code_flow_steps = result
+            .code_flows
+            .iter_mut()
+            .flatten()
+            .flat_map(|code_flow| code_flow.thread_flows.iter_mut())
+            .flat_map(|thread_flow| thread_flow.locations.iter_mut())
+            .filter_map(|step| step.location.as_mut());
+        for location in locations.chain(related).chain(code_flow_steps) {
+            self.relativize_location(location);
+        }
+    }
+
+    /// Declares the base directory as `originalUriBaseIds` of `run`.
+    pub(crate) fn declare_base(&self, run: &mut Run) {
+        let base = ArtifactLocation::builder()
+            .uri(self.base_uri.clone())
+            .build();
+        run.original_uri_base_ids = Some([(self.uri_base_id.clone(), base)].into_iter().collect());
+    }
+
+    fn relativize_location(&self, location: &mut Location) {
+        if let Some(artifact_location) = location
+            .physical_location
+            .as_mut()
+            .and_then(|physical| physical.artifact_location.as_mut())
+        {
+            self.relativize_artifact_location(artifact_location);
+        }
+    }
+
+    fn relativize_artifact_location(&self, location: &mut ArtifactLocation) {
+        let Some(uri) = location.uri.as_deref() else {
+            return;
+        };
+        if let Some(relative) = uri.strip_prefix(&self.base_uri) {
+            location.uri = Some(relative.to_string());
+            location.uri_base_id = Some(self.uri_base_id.clone());
+            return;
+        }
+        if let Some(jar_uri) = self.relativize_jar_uri(uri) {
+            location.uri = Some(jar_uri);
+        }
+    }
+
+    /// Relativizes the outermost JAR of a `jar:file:///...!/entry` URI.
+    fn relativize_jar_uri(&self, uri: &str) -> Option<String> {
+        let rest = uri.strip_prefix("jar:")?;
+        let (jar, entry) = rest.split_once("!/")?;
+        let relative_jar = jar.strip_prefix(&self.base_uri)?;
+        Some(format!("jar:{relative_jar}!/{entry}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_sarif::sarif::{PhysicalLocation, Tool, ToolComponent};
+
+    fn artifact(uri: &str) -> Artifact {
+        Artifact::builder()
+            .location(ArtifactLocation::builder().uri(uri.to_string()).build())
+            .build()
+    }
+
+    fn location_uri(artifact: &Artifact) -> (Option<&str>, Option<&str>) {
+        let location = artifact.location.as_ref().expect("location");
+        (location.uri.as_deref(), location.uri_base_id.as_deref())
+    }
+
+    #[test]
+    fn relativize_artifacts_rewrites_files_under_base() {
+        let relativizer = UriRelativizer::new(Path::new("/work/repo"), DEFAULT_URI_BASE_ID);
+        let mut artifacts = vec![
+            artifact("file:///work/repo/build/classes/com/example/ClassA.class"),
+            artifact("jar:file:///work/repo/lib/app.jar!/com/example/ClassB.class"),
+            artifact("file:///opt/lib/other.jar"),
+            artifact("file:///work/repository/app.jar"),
+        ];
+
+        relativizer.relativize_artifacts(&mut artifacts);
+
+        assert_eq!(
+            location_uri(&artifacts[0]),
+            (
+                Some("build/classes/com/example/ClassA.class"),
+                Some("SRCROOT")
+            )
+        );
+        assert_eq!(
+            location_uri(&artifacts[1]),
+            (Some("jar:lib/app.jar!/com/example/ClassB.class"), None)
+        );
+        assert_eq!(
+            location_uri(&artifacts[2]),
+            (Some("file:///opt/lib/other.jar"), None)
+        );
+        assert_eq!(
+            location_uri(&artifacts[3]),
+            (Some("file:///work/repository/app.jar"), None)
+        );
+    }
+
+    #[test]
+    fn relativize_result_rewrites_primary_locations_and_declares_base() {
+        let relativizer = UriRelativizer::new(Path::new("/work/repo/"), "PROJECT");
+        let location = Location::builder()
+            .physical_location(
+                PhysicalLocation::builder()
+                    .artifact_location(
+                        ArtifactLocation::builder()
+                            .uri("file:///work/repo/src/main/java/ClassA.java".to_string())
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+        let mut result = SarifResult::builder()
+            .message(crate::rules::result_message("finding"))
+            .locations(vec![location])
+            .build();
+        let mut run = Run::builder()
+            .tool(Tool {
+                driver: ToolComponent::builder().name("inspequte").build(),
+                extensions: None,
+                properties: None,
+            })
+            .build();
+
+        relativizer.relativize_result(&mut result);
+        relativizer.declare_base(&mut run);
+
+        let artifact_location = result.locations.as_ref().expect("locations")[0]
+            .physical_location
+            .as_ref()
+            .and_then(|physical| physical.artifact_location.as_ref())
+            .expect("artifact location");
+        assert_eq!(
+            artifact_location.uri.as_deref(),
+            Some("src/main/java/ClassA.java")
+        );
+        assert_eq!(artifact_location.uri_base_id.as_deref(), Some("PROJECT"));
+        let base_ids = run.original_uri_base_ids.expect("base ids");
+        assert_eq!(
+            base_ids.get("PROJECT").and_then(|base| base.uri.as_deref()),
+            Some("file:///work/repo/")
+        );
+    }
+}
@@ -45,16 +45,41 @@ impl Baseline {
     pub(crate) fn filter(&self, results: Vec<SarifResult>) -> Vec<SarifResult> {
         results
             .into_iter()
-            .filter(|result| {
-                let entry = BaselineEntry::from(result);
-                self.findings.binary_search(&entry).is_err()
-            })
+            .filter(|result| !self.contains(result))
             .collect()
     }
+
+    /// Returns a baseline keeping only the entries that still match one of `results`.
+    pub(crate) fn pruned(&self, results: &[SarifResult]) -> Self {
+        let current: BTreeSet<BaselineEntry> = results.iter().map(BaselineEntry::from).collect();
+        Self {
+            version: self.version,
+            findings: self
+                .findings
+                .iter()
+                .filter(|entry| current.contains(*entry))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Number of distinct findings recorded in this baseline.
+    pub(crate) fn finding_count(&self) -> usize {
+        self.findings.len()
+    }
+
+    /// Returns whether `result` is a known finding recorded in this baseline.
+    pub(crate) fn contains(&self, result: &SarifResult) -> bool {
+        let entry = BaselineEntry::from(result);
+        self.findings.binary_search(&entry).is_ok()
+    }
 }
 
 pub(crate) fn write_baseline(path: &Path, results: &[SarifResult]) -> Result<()> {
-    let baseline = Baseline::capture(results);
+    write_baseline_file(path, &Baseline::capture(results))
+}
+
+pub(crate) fn write_baseline_file(path: &Path, baseline: &Baseline) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
             .with_context(|| format!("failed to create baseline directory {}", parent.display()))?;
@@ -200,6 +225,19 @@ mod tests {
         assert_eq!(new_findings, filtered);
     }
 
+    #[test]
+    fn baseline_contains_only_recorded_results() {
+        let recorded = sample_result("RULE_A", "com/example/App.run()V", "something");
+        let baseline = Baseline::capture(std::slice::from_ref(&recorded));
+
+        assert!(baseline.contains(&recorded));
+        assert!(!baseline.contains(&sample_result(
+            "RULE_A",
+            "com/example/App.run()V",
+            "something else",
+        )));
+    }
+
     #[test]
     fn baseline_round_trips_through_json() {
         let findings = vec![
@@ -232,6 +270,29 @@ mod tests {
         assert!(filtered.is_empty());
     }
 
+    #[test]
+    fn pruned_baseline_drops_entries_for_fixed_findings() {
+        let fixed = sample_result("RULE_A", "com/example/App.run()V", "fixed");
+        let remaining = sample_result("RULE_B", "com/example/App.run()V", "remaining");
+        let baseline = Baseline::capture(&[fixed.clone(), remaining.clone()]);
+        let dir = tempdir().expect("baseline temp dir");
+        let path = dir.path().join("baseline.json");
+
+        let pruned = baseline.pruned(&[
+            remaining.clone(),
+            sample_result("RULE_C", "com/example/App.run()V", "new"),
+        ]);
+        write_baseline_file(&path, &pruned).expect("write baseline");
+        let loaded = load_baseline(&path)
+            .expect("load baseline")
+            .expect("baseline present");
+
+        assert_eq!(baseline.finding_count() - pruned.finding_count(), 1);
+        assert_eq!(loaded.finding_count(), 1);
+        assert!(loaded.contains(&remaining));
+        assert!(!loaded.contains(&fixed));
+    }
+
     #[test]
     fn baseline_load_missing_file_returns_none() {
         let dir = tempdir().expect("baseline temp dir");
@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_sarif::sarif::Result as SarifResult;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::engine::{AnalysisContext, RuleOptions};
+use crate::ir::Class;
+
+/// Default directory for the incremental result cache, relative to the working directory.
+pub(crate) const DEFAULT_CACHE_DIR: &str = ".inspequte/cache";
+
+const CACHE_FILE_NAME: &str = "results.json";
+
+/// Rule results from a previous run, keyed by class name.
+///
+/// A cache only applies to runs with an equal `CacheKey`; loading it for any other run yields
+/// an empty cache. Within a cache, each class entry is reused only while its dependency digest
+/// and artifact are unchanged.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct ResultCache {
+    #[serde(flatten)]
+    key: CacheKey,
+    classes: BTreeMap<String, CachedClass>,
+}
+
+/// What a result cache was computed for: the inspequte version, the selected rules, and their
+/// options.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct CacheKey {
+    version: String,
+    rule_ids: Vec<String>,
+    /// SHA-256 of the serialized `RuleOptions`.
+    rule_options_hash: String,
+}
+
+impl CacheKey {
+    pub(crate) fn new(rule_ids: Vec<String>, rule_options: &RuleOptions) -> Result<Self> {
+        let rule_options =
+            serde_json::to_string(rule_options).context("failed to serialize rule options")?;
+        Ok(Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            rule_ids,
+            rule_options_hash: format!("{:x}", Sha256::digest(rule_options.as_bytes())),
+        })
+    }
+}
+
+/// Results produced for one class, valid while its dependencies and artifact stay the same.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct CachedClass {
+    /// `class_dependency_digest` of the class when its results were produced.
+    pub(crate) dependency_digest: String,
+    pub(crate) artifact_uri: Option<String>,
+    pub(crate) results: Vec<SarifResult>,
+}
+
+/// Digest of the classes that rule results for `class` depend on: the class itself, the
+/// classes it references, and the supertypes of both, transitively.
+///
+/// Each dependency contributes its name and content hash, or only its name when it is not
+/// loaded, so adding, removing, or changing any of them yields a new digest.
+pub(crate) fn class_dependency_digest(context: &AnalysisContext, class: &Class) -> String {
+    let mut dependencies: BTreeMap<&str, Option<&str>> = BTreeMap::new();
+    dependencies.insert(class.name.as_str(), Some(class.content_hash.as_str()));
+    let mut pending: Vec<&str> = class
+        .super_name
+        .iter()
+        .chain(&class.interfaces)
+        .chain(&class.referenced_classes)
+        .map(String::as_str)
+        .collect();
+    while let Some(name) = pending.pop() {
+        if dependencies.contains_key(name) {
+            continue;
+        }
+        let loaded = context.class_by_name(name);
+        dependencies.insert(name, loaded.map(|loaded| loaded.content_hash.as_str()));
+        if let Some(loaded) = loaded {
+            pending.extend(loaded.super_name.as_deref());
+            pending.extend(loaded.interfaces.iter().map(String::as_str));
+        }
+    }
+    let mut hasher = Sha256::new();
+    for (name, content_hash) in dependencies {
+        hasher.update(name.as_bytes());
+        hasher.update([0]);
+        hasher.update(content_hash.unwrap_or_default().as_bytes());
+        hasher.update([b'\n']);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+impl ResultCache {
+    pub(crate) fn new(key: CacheKey) -> Self {
+        Self {
+            key,
+            classes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the cached results for `class_name` if its dependencies and artifact are
+    /// unchanged.
+    pub(crate) fn get(
+        &self,
+        class_name: &str,
+        dependency_digest: &str,
+        artifact_uri: Option<&str>,
+    ) -> Option<&[SarifResult]> {
+        self.classes
+            .get(class_name)
+            .filter(|cached| {
+                cached.dependency_digest == dependency_digest
+                    && cached.artifact_uri.as_deref() == artifact_uri
+            })
+            .map(|cached| cached.results.as_slice())
+    }
+
+    pub(crate) fn insert(&mut self, class_name: String, cached: CachedClass) {
+        self.classes.insert(class_name, cached);
+    }
+
+    /// Returns an empty cache for the same key.
+    pub(crate) fn emptied(&self) -> Self {
+        Self::new(self.key.clone())
+    }
+}
+
+/// Loads the cache in `dir`, falling back to an empty cache for `key` when the file is
+/// missing, unreadable, or written for another key.
+pub(crate) fn load_result_cache(dir: &Path, key: CacheKey) -> Result<ResultCache> {
+    let empty = ResultCache::new(key);
+    let path = cache_file_path(dir);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(empty),
+        Err(err) => {
+            return Err(err)
+                .with_context(|| format!("failed to read result cache {}", path.display()));
+        }
+    };
+    let cache: ResultCache = match serde_json::from_str(&content) {
+        Ok(cache) => cache,
+        Err(err) => {
+            warn!("ignoring unreadable result cache {}: {err}", path.display());
+            return Ok(empty);
+        }
+    };
+    if cache.key != empty.key {
+        return Ok(empty);
+    }
+    Ok(cache)
+}
+
+/// Replaces the cache in `dir` with `cache`.
+pub(crate) fn write_result_cache(dir: &Path, cache: &ResultCache) -> Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create cache directory {}", dir.display()))?;
+    let path = cache_file_path(dir);
+    let file = File::create(&path)
+        .with_context(|| format!("failed to create result cache {}", path.display()))?;
+    serde_json::to_writer(BufWriter::new(file), cache)
+        .with_context(|| format!("failed to write result cache {}", path.display()))
+}
+
+fn cache_file_path(dir: &Path) -> PathBuf {
+    dir.join(CACHE_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_sarif::sarif::Message;
+    use tempfile::tempdir;
+
+    fn key(rule_ids: &[&str]) -> CacheKey {
+        key_with_options(rule_ids, &RuleOptions::default())
+    }
+
+    fn key_with_options(rule_ids: &[&str], rule_options: &RuleOptions) -> CacheKey {
+        let rule_ids = rule_ids.iter().map(ToString::to_string).collect();
+        CacheKey::new(rule_ids, rule_options).expect("cache key")
+    }
+
+    fn cached_class(dependency_digest: &str) -> CachedClass {
+        CachedClass {
+            dependency_digest: dependency_digest.to_string(),
+            artifact_uri: Some("file:///tmp/app.jar".to_string()),
+            results: vec![
+                SarifResult::builder()
+                    .message(Message::builder().text("finding".to_string()).build())
+                    .rule_id("SYSTEM_EXIT")
+                    .build(),
+            ],
+        }
+    }
+
+    #[test]
+    fn get_requires_matching_dependency_digest_and_artifact() {
+        let mut cache = ResultCache::new(key(&["SYSTEM_EXIT"]));
+        cache.insert("com/example/ClassA".to_string(), cached_class("abc"));
+
+        let hit = cache.get("com/example/ClassA", "abc", Some("file:///tmp/app.jar"));
+        assert_eq!(hit.map(<[SarifResult]>::len), Some(1));
+        assert!(
+            cache
+                .get("com/example/ClassA", "def", Some("file:///tmp/app.jar"))
+                .is_none()
+        );
+        assert!(
+            cache
+                .get("com/example/ClassA", "abc", Some("file:///tmp/other.jar"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn load_result_cache_round_trips_for_same_rule_ids() {
+        let dir = tempdir().expect("temp dir");
+        let mut cache = ResultCache::new(key(&["SYSTEM_EXIT"]));
+        cache.insert("com/example/ClassA".to_string(), cached_class("abc"));
+        write_result_cache(dir.path(), &cache).expect("write cache");
+
+        let loaded = load_result_cache(dir.path(), key(&["SYSTEM_EXIT"])).expect("load cache");
+
+        assert!(
+            loaded
+                .get("com/example/ClassA", "abc", Some("file:///tmp/app.jar"))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn load_result_cache_discards_entries_for_other_rule_ids() {
+        let dir = tempdir().expect("temp dir");
+        let mut cache = ResultCache::new(key(&["SYSTEM_EXIT"]));
+        cache.insert("com/example/ClassA".to_string(), cached_class("abc"));
+        write_result_cache(dir.path(), &cache).expect("write cache");
+
+        let loaded = load_result_cache(dir.path(), key(&["SYSTEM_EXIT", "RETURN_IN_FINALLY"]))
+            .expect("load cache");
+
+        assert!(
+            loaded
+                .get("com/example/ClassA", "abc", Some("file:///tmp/app.jar"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn load_result_cache_discards_entries_from_other_versions() {
+        let dir = tempdir().expect("temp dir");
+        let mut cache = ResultCache::new(key(&["SYSTEM_EXIT"]));
+        cache.key.version = "0.0.0-old".to_string();
+        cache.insert("com/example/ClassA".to_string(), cached_class("abc"));
+        write_result_cache(dir.path(), &cache).expect("write cache");
+
+        let loaded = load_result_cache(dir.path(), key(&["SYSTEM_EXIT"])).expect("load cache");
+
+        assert!(
+            loaded
+                .get("com/example/ClassA", "abc", Some("file:///tmp/app.jar"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn load_result_cache_discards_entries_for_other_rule_options() {
+        let dir = tempdir().expect("temp dir");
+        let mut cache = ResultCache::new(key(&["MAGIC_NUMBER"]));
+        cache.insert("com/example/ClassA".to_string(), cached_class("abc"));
+        write_result_cache(dir.path(), &cache).expect("write cache");
+        let mut rule_options = RuleOptions::default();
+        rule_options.magic_number.allowlist = vec![42];
+
+        let loaded = load_result_cache(
+            dir.path(),
+            key_with_options(&["MAGIC_NUMBER"], &rule_options),
+        )
+        .expect("load cache");
+
+        assert!(
+            loaded
+                .get("com/example/ClassA", "abc", Some("file:///tmp/app.jar"))
+                .is_none()
+        );
+    }
+}
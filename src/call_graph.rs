@@ -0,0 +1,148 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, Class};
+
+const CALL_GRAPH_VERSION: u32 = 1;
+
+/// Call graph written by `inspequte dump callgraph`, for diagnosing hierarchy-based rules.
+///
+/// Nodes are the methods of the analysis target classes. Each call site becomes one edge per
+/// resolved target method, or a single unresolved edge to the referenced method when no loaded
+/// class declares it.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub(crate) struct CallGraphDump {
+    version: u32,
+    nodes: Vec<CallGraphNode>,
+    edges: Vec<CallGraphEdge>,
+}
+
+/// Method node identified by `Class.nameDescriptor`, such as `A.foo()V`.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+struct CallGraphNode {
+    id: String,
+    class: String,
+    name: String,
+    descriptor: String,
+}
+
+/// Call from `from` to `to` at bytecode `offset` of the calling method.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+struct CallGraphEdge {
+    from: String,
+    to: String,
+    kind: &'static str,
+    offset: u32,
+    resolved: bool,
+}
+
+/// Builds the call graph of the analysis target classes, resolving virtual and interface calls
+/// with class hierarchy analysis over every loaded class.
+pub(crate) fn build_call_graph(context: &AnalysisContext) -> CallGraphDump {
+    let classes: BTreeMap<&str, &Class> = context
+        .all_classes()
+        .map(|class| (class.name.as_str(), class))
+        .collect();
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    for class in context.analysis_target_classes() {
+        for method in &class.methods {
+            let from = method_id(&class.name, &method.name, &method.descriptor);
+            for call in &method.calls {
+                let targets = resolve_call(context, &classes, call);
+                let resolved = !targets.is_empty();
+                let targets = if resolved {
+                    targets
+                } else {
+                    BTreeSet::from([call.owner.as_str()])
+                };
+                edges.extend(targets.into_iter().map(|owner| CallGraphEdge {
+                    from: from.clone(),
+                    to: method_id(owner, &call.name, &call.descriptor),
+                    kind: call_kind_name(call.kind),
+                    offset: call.offset,
+                    resolved,
+                }));
+            }
+            nodes.push(CallGraphNode {
+                id: from,
+                class: class.name.clone(),
+                name: method.name.clone(),
+                descriptor: method.descriptor.clone(),
+            });
+        }
+    }
+    nodes.sort_by(|left, right| left.id.cmp(&right.id));
+    edges.sort_by(|left, right| {
+        (&left.from, left.offset, &left.to).cmp(&(&right.from, right.offset, &right.to))
+    });
+    CallGraphDump {
+        version: CALL_GRAPH_VERSION,
+        nodes,
+        edges,
+    }
+}
+
+/// Returns the classes declaring a method that `call` may dispatch to.
+///
+/// Static and special calls bind to the nearest declaration in the owner's superclass chain.
+/// Virtual and interface calls also include every loaded subtype of the owner with a concrete
+/// declaration of the method.
+fn resolve_call<'a>(
+    context: &AnalysisContext,
+    classes: &BTreeMap<&str, &'a Class>,
+    call: &CallSite,
+) -> BTreeSet<&'a str> {
+    let mut targets = BTreeSet::new();
+    let mut current = classes.get(call.owner.as_str()).copied();
+    while let Some(class) = current {
+        if declares(class, call) {
+            targets.insert(class.name.as_str());
+            break;
+        }
+        current = class
+            .super_name
+            .as_deref()
+            .and_then(|name| classes.get(name).copied());
+    }
+    if matches!(call.kind, CallKind::Virtual | CallKind::Interface) {
+        targets.extend(
+            classes
+                .values()
+                .filter(|class| declares_concrete(class, call))
+                .filter(|class| context.is_subtype_of(&class.name, &call.owner))
+                .map(|class| class.name.as_str()),
+        );
+    }
+    targets
+}
+
+fn declares(class: &Class, call: &CallSite) -> bool {
+    class
+        .methods
+        .iter()
+        .any(|method| method.name == call.name && method.descriptor == call.descriptor)
+}
+
+fn declares_concrete(class: &Class, call: &CallSite) -> bool {
+    class.methods.iter().any(|method| {
+        method.name == call.name
+            && method.descriptor == call.descriptor
+            && !method.access.is_abstract
+    })
+}
+
+fn method_id(class: &str, name: &str, descriptor: &str) -> String {
+    format!("{class}.{name}{descriptor}")
+}
+
+fn call_kind_name(kind: CallKind) -> &'static str {
+    match kind {
+        CallKind::Virtual => "virtual",
+        CallKind::Interface => "interface",
+        CallKind::Special => "special",
+        CallKind::Static => "static",
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use serde_sarif::sarif::{MultiformatMessageString, ReportingDescriptor, ToolComponent};
+
+use crate::engine::rule_descriptor;
+
+/// Descriptions of the rule tags, published as SARIF taxa.
+const TAG_DESCRIPTIONS: &[(&str, &str)] = &[
+    (
+        "concurrency",
+        "Thread safety, locking, and concurrent execution issues",
+    ),
+    (
+        "correctness",
+        "Code that likely behaves differently from what was intended",
+    ),
+    (
+        "logging",
+        "Misuse of logging APIs such as SLF4J and Log4j 2",
+    ),
+    (
+        "maintainability",
+        "Code that is harder to read, change, or evolve than necessary",
+    ),
+    ("performance", "Avoidable runtime or memory overhead"),
+    (
+        "resource",
+        "Streams, locks, executors, and other resources that are not released",
+    ),
+    (
+        "security",
+        "Code that weakens security or exposes mutable global state",
+    ),
+];
+
+/// Returns every registered rule, including opt-in rules, as a SARIF tool component with
+/// `rules` sorted by ID and one taxon per rule tag.
+pub(crate) fn rule_catalog() -> ToolComponent {
+    let mut rules = crate::rules::all_rules()
+        .iter()
+        .map(|rule| rule_descriptor(&rule.metadata()))
+        .collect::<Vec<_>>();
+    rules.sort_by(|left, right| left.id.cmp(&right.id));
+    let mut catalog = ToolComponent::builder()
+        .name("inspequte")
+        .information_uri("https://github.com/KengoTODA/inspequte")
+        .semantic_version(env!("CARGO_PKG_VERSION").to_string())
+        .build();
+    catalog.taxa = tag_taxa(&rules);
+    catalog.rules = Some(rules);
+    catalog
+}
+
+/// Returns the IDs of every registered rule, including opt-in rules, tagged `tag`.
+pub(crate) fn rule_ids_with_tag(tag: &str) -> Result<BTreeSet<String>> {
+    let ids: BTreeSet<String> = crate::rules::all_rules()
+        .iter()
+        .map(|rule| rule.metadata())
+        .filter(|metadata| metadata.tags.contains(&tag))
+        .map(|metadata| metadata.id.to_string())
+        .collect();
+    if ids.is_empty() {
+        anyhow::bail!("no rules are tagged {tag}");
+    }
+    Ok(ids)
+}
+
+/// Returns one taxon per distinct tag of `rules`, sorted by tag, or `None` without tags.
+pub(crate) fn tag_taxa(rules: &[ReportingDescriptor]) -> Option<Vec<ReportingDescriptor>> {
+    let tags = rules
+        .iter()
+        .filter_map(|rule| rule.properties.as_ref()?.tags.as_ref())
+        .flatten()
+        .collect::<BTreeSet<_>>();
+    if tags.is_empty() {
+        return None;
+    }
+    let taxa = tags
+        .into_iter()
+        .map(|tag| {
+            let mut taxon = ReportingDescriptor::builder().id(tag.clone()).build();
+            taxon.short_description = TAG_DESCRIPTIONS
+                .iter()
+                .find(|(name, _)| *name == tag.as_str())
+                .map(|(_, text)| MultiformatMessageString::builder().text(*text).build());
+            taxon
+        })
+        .collect();
+    Some(taxa)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_catalog_lists_every_registered_rule() {
+        let mut registered = crate::rules::all_rules()
+            .iter()
+            .map(|rule| rule.metadata().id.to_string())
+            .collect::<Vec<_>>();
+        registered.sort();
+
+        let catalog = rule_catalog();
+
+        let exported = catalog
+            .rules
+            .expect("rules")
+            .into_iter()
+            .map(|rule| rule.id)
+            .collect::<Vec<_>>();
+        assert_eq!(exported, registered);
+        assert!(exported.contains(&"MODULE_INFO_REQUIRES_TRANSITIVE".to_string()));
+    }
+
+    #[test]
+    fn rule_catalog_publishes_a_described_taxon_per_tag() {
+        let catalog = rule_catalog();
+
+        let taxa = catalog.taxa.expect("taxa");
+        let ids = taxa
+            .iter()
+            .map(|taxon| taxon.id.as_str())
+            .collect::<Vec<_>>();
+        let mut expected = TAG_DESCRIPTIONS
+            .iter()
+            .map(|(tag, _)| *tag)
+            .collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert!(taxa.iter().all(|taxon| taxon.short_description.is_some()));
+    }
+
+    #[test]
+    fn rule_ids_with_tag_selects_exactly_the_tagged_rules() {
+        let expected = crate::rules::all_rules()
+            .iter()
+            .map(|rule| rule.metadata())
+            .filter(|metadata| metadata.tags.contains(&"concurrency"))
+            .map(|metadata| metadata.id.to_string())
+            .collect::<BTreeSet<_>>();
+
+        let ids = rule_ids_with_tag("concurrency").expect("tagged rules");
+
+        assert_eq!(ids, expected);
+        assert!(ids.contains("LOCK_NOT_RELEASED_ON_EXCEPTION_PATH"));
+        assert!(!ids.contains("SYSTEM_EXIT"));
+        let error = rule_ids_with_tag("no-such-tag").expect_err("unknown tag");
+        assert_eq!(error.to_string(), "no rules are tagged no-such-tag");
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::BTreeSet;
+use std::ops::Range;
 
 use anyhow::{Context, Result};
 
@@ -99,6 +100,27 @@ pub(crate) fn build_cfg(
     Ok(ControlFlowGraph { blocks, edges })
 }
 
+/// Bytecode offset ranges of loops, found from backward branch edges.
+///
+/// A branch to a block that starts at or before the branching block closes a loop that spans
+/// from the target block to the end of the branching block. Ranges are sorted and deduplicated.
+pub(crate) fn loop_ranges(cfg: &ControlFlowGraph) -> Vec<Range<u32>> {
+    let mut ranges = BTreeSet::new();
+    for edge in &cfg.edges {
+        if edge.kind != EdgeKind::Branch || edge.to > edge.from {
+            continue;
+        }
+        if let Some(block) = cfg
+            .blocks
+            .iter()
+            .find(|block| block.start_offset == edge.from)
+        {
+            ranges.insert((edge.to, block.end_offset));
+        }
+    }
+    ranges.into_iter().map(|(start, end)| start..end).collect()
+}
+
 fn next_block_start(blocks: &[BasicBlock], offset: u32) -> Option<u32> {
     blocks
         .iter()
@@ -233,4 +255,26 @@ mod tests {
 
         assert_eq!(targets, vec![16, 4, 12]);
     }
+
+    #[test]
+    fn loop_ranges_span_from_backward_branch_target_to_branching_block_end() {
+        let block = |start_offset, end_offset| BasicBlock {
+            start_offset,
+            end_offset,
+            instructions: Vec::new(),
+        };
+        let edge = |from, to, kind| FlowEdge { from, to, kind };
+        let cfg = ControlFlowGraph {
+            blocks: vec![block(0, 2), block(2, 7), block(7, 15), block(15, 16)],
+            edges: vec![
+                edge(0, 2, EdgeKind::FallThrough),
+                edge(2, 15, EdgeKind::Branch),
+                edge(2, 7, EdgeKind::FallThrough),
+                edge(7, 2, EdgeKind::Branch),
+                edge(7, 7, EdgeKind::Exception),
+            ],
+        };
+
+        assert_eq!(loop_ranges(&cfg), vec![2..15]);
+    }
 }
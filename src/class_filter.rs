@@ -0,0 +1,338 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::ir::Class;
+
+/// Name of the ignore file read from the working directory unless `--no-ignore-file` is set.
+pub(crate) const IGNORE_FILE_NAME: &str = ".inspequteignore";
+
+/// Include/exclude glob patterns that narrow which classes are analysis targets.
+///
+/// Patterns match internal class names such as `com/example/Foo`:
+/// `*` and `?` stay within one path segment, `**` also crosses `/`, and a leading
+/// `**/` matches zero or more whole segments.
+///
+/// With `--changed-files`, only classes compiled from one of the listed source files match.
+/// Classes ignored by `.inspequteignore` patterns never match.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClassFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+    ignore: Vec<IgnorePattern>,
+    /// Slash-separated source paths, e.g. `src/main/java/com/example/Foo.java`.
+    changed_files: Option<Vec<String>>,
+}
+
+impl ClassFilter {
+    pub(crate) fn new(include: Vec<String>, exclude: Vec<String>) -> Self {
+        Self {
+            include,
+            exclude,
+            ignore: Vec::new(),
+            changed_files: None,
+        }
+    }
+
+    /// Skips classes ignored by `patterns`, as read by [`load_ignore_file`].
+    pub(crate) fn with_ignore_patterns(mut self, patterns: Vec<IgnorePattern>) -> Self {
+        self.ignore = patterns;
+        self
+    }
+
+    /// Restricts matches to classes whose source file is one of `changed_files`.
+    pub(crate) fn with_changed_files(mut self, changed_files: Option<Vec<String>>) -> Self {
+        self.changed_files = changed_files.map(|paths| {
+            paths
+                .iter()
+                .map(|path| {
+                    let path = path.replace('\\', "/");
+                    path.trim_start_matches("./").to_string()
+                })
+                .collect()
+        });
+        self
+    }
+
+    /// Returns whether `class` matches the name patterns and, with changed files, was
+    /// compiled from one of them.
+    pub(crate) fn matches_class(&self, class: &Class) -> bool {
+        self.matches(&class.name) && self.is_changed(class)
+    }
+
+    /// Returns whether `class_name` matches an include pattern (or none are set),
+    /// no exclude pattern, and is not ignored.
+    pub(crate) fn matches(&self, class_name: &str) -> bool {
+        let included = self.include.is_empty()
+            || self
+                .include
+                .iter()
+                .any(|pattern| glob_matches(pattern.as_bytes(), class_name.as_bytes()));
+        included
+            && !self
+                .exclude
+                .iter()
+                .any(|pattern| glob_matches(pattern.as_bytes(), class_name.as_bytes()))
+            && !self.is_ignored(class_name)
+    }
+
+    /// Applies the ignore patterns in order; the last one matching `class_name` decides.
+    fn is_ignored(&self, class_name: &str) -> bool {
+        self.ignore
+            .iter()
+            .rev()
+            .find(|pattern| pattern.matches(class_name))
+            .is_some_and(|pattern| !pattern.negated)
+    }
+
+    /// Maps `class` to the path suffix of its source, `<package>/<SourceFile>`, and looks
+    /// for a changed file ending with it. Classes without a `SourceFile` attribute never match.
+    fn is_changed(&self, class: &Class) -> bool {
+        let Some(changed_files) = &self.changed_files else {
+            return true;
+        };
+        let Some(source_file) = class.source_file.as_deref() else {
+            return false;
+        };
+        let source_path = match class.name.rsplit_once('/') {
+            Some((package, _)) => format!("{package}/{source_file}"),
+            None => source_file.to_string(),
+        };
+        changed_files.iter().any(|path| {
+            path.strip_suffix(source_path.as_str())
+                .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with('/'))
+        })
+    }
+}
+
+/// One line of an `.inspequteignore` file, matched against internal class names with
+/// gitignore semantics.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct IgnorePattern {
+    glob: String,
+    /// `!pattern`: re-includes classes ignored by an earlier pattern.
+    negated: bool,
+    /// `pattern/`: matches packages only, never a class name itself.
+    packages_only: bool,
+}
+
+impl IgnorePattern {
+    /// Parses one line; returns `None` for blank lines and `#` comments.
+    ///
+    /// A pattern without a `/` other than a trailing one matches at any depth, like
+    /// `**/pattern`; any other pattern is anchored at the root package.
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (line, negated) = match line.strip_prefix('!') {
+            Some(rest) => (rest, true),
+            None => (line, false),
+        };
+        // `\#` and `\!` escape a literal leading character.
+        let line = line.strip_prefix('\\').unwrap_or(line);
+        let (line, packages_only) = match line.strip_suffix('/') {
+            Some(rest) => (rest, true),
+            None => (line, false),
+        };
+        let glob = match line.strip_prefix('/') {
+            Some(rest) => rest.to_string(),
+            None if line.contains('/') => line.to_string(),
+            None => format!("**/{line}"),
+        };
+        if glob.is_empty() {
+            return None;
+        }
+        Some(Self {
+            glob,
+            negated,
+            packages_only,
+        })
+    }
+
+    /// Returns whether the pattern matches `class_name` or one of its packages, so ignoring a
+    /// package ignores every class below it.
+    fn matches(&self, class_name: &str) -> bool {
+        let glob = self.glob.as_bytes();
+        (!self.packages_only && glob_matches(glob, class_name.as_bytes()))
+            || class_name
+                .match_indices('/')
+                .any(|(index, _)| glob_matches(glob, class_name[..index].as_bytes()))
+    }
+}
+
+/// Parses the contents of an `.inspequteignore` file.
+pub(crate) fn parse_ignore_file(contents: &str) -> Vec<IgnorePattern> {
+    contents.lines().filter_map(IgnorePattern::parse).collect()
+}
+
+/// Reads the ignore patterns in `path`. A missing file yields no patterns.
+pub(crate) fn load_ignore_file(path: &Path) -> Result<Vec<IgnorePattern>> {
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read ignore file {}", path.display()))?;
+    Ok(parse_ignore_file(&contents))
+}
+
+fn glob_matches(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern {
+        [] => name.is_empty(),
+        [b'*', b'*', b'/', rest @ ..] => {
+            glob_matches(rest, name)
+                || name
+                    .iter()
+                    .enumerate()
+                    .any(|(index, byte)| *byte == b'/' && glob_matches(rest, &name[index + 1..]))
+        }
+        [b'*', b'*', rest @ ..] => (0..=name.len()).any(|index| glob_matches(rest, &name[index..])),
+        [b'*', rest @ ..] => (0..=name.len())
+            .take_while(|&index| index == 0 || name[index - 1] != b'/')
+            .any(|index| glob_matches(rest, &name[index..])),
+        [b'?', rest @ ..] => {
+            matches!(name, [byte, tail @ ..] if *byte != b'/' && glob_matches(rest, tail))
+        }
+        [expected, rest @ ..] => {
+            matches!(name, [byte, tail @ ..] if byte == expected && glob_matches(rest, tail))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> ClassFilter {
+        ClassFilter::new(
+            include.iter().map(|pattern| pattern.to_string()).collect(),
+            exclude.iter().map(|pattern| pattern.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn empty_filter_matches_every_class() {
+        assert!(filter(&[], &[]).matches("com/example/Foo"));
+    }
+
+    #[test]
+    fn include_only_matches_classes_under_package() {
+        let filter = filter(&["com/myapp/**"], &[]);
+
+        assert!(filter.matches("com/myapp/Foo"));
+        assert!(filter.matches("com/myapp/service/Bar$Inner"));
+        assert!(!filter.matches("com/other/Foo"));
+        assert!(!filter.matches("com/myapplication/Foo"));
+    }
+
+    #[test]
+    fn exclude_only_skips_generated_packages_at_any_depth() {
+        let filter = filter(&[], &["**/generated/**"]);
+
+        assert!(filter.matches("com/myapp/Foo"));
+        assert!(!filter.matches("com/myapp/generated/Foo"));
+        assert!(!filter.matches("generated/Foo"));
+        assert!(filter.matches("com/myapp/generatedstuff/Foo"));
+    }
+
+    #[test]
+    fn include_and_exclude_combine() {
+        let filter = filter(
+            &["com/myapp/**", "org/lib/Api"],
+            &["**/generated/**", "**/*Test"],
+        );
+
+        assert!(filter.matches("com/myapp/Foo"));
+        assert!(filter.matches("org/lib/Api"));
+        assert!(!filter.matches("org/lib/Other"));
+        assert!(!filter.matches("com/myapp/generated/Foo"));
+        assert!(!filter.matches("com/myapp/FooTest"));
+    }
+
+    fn class_from_source(name: &str, source_file: Option<&str>) -> Class {
+        Class {
+            name: name.to_string(),
+            source_file: source_file.map(str::to_string),
+            super_name: None,
+            interfaces: Vec::new(),
+            type_parameters: Vec::new(),
+            referenced_classes: Vec::new(),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            annotation_defaults: Vec::new(),
+            artifact_index: 0,
+            is_record: false,
+            is_final: false,
+            content_hash: String::new(),
+        }
+    }
+
+    #[test]
+    fn changed_files_match_classes_by_package_and_source_file() {
+        let filter = ClassFilter::default().with_changed_files(Some(vec![
+            "./src/main/java/com/myapp/Foo.java".to_string(),
+            "src\\main\\kotlin\\com\\myapp\\Util.kt".to_string(),
+        ]));
+
+        assert!(filter.matches_class(&class_from_source("com/myapp/Foo", Some("Foo.java"))));
+        assert!(filter.matches_class(&class_from_source("com/myapp/Foo$Inner", Some("Foo.java"))));
+        assert!(filter.matches_class(&class_from_source("com/myapp/UtilKt", Some("Util.kt"))));
+        assert!(!filter.matches_class(&class_from_source("com/other/Foo", Some("Foo.java"))));
+        assert!(!filter.matches_class(&class_from_source("com/myapp/Bar", Some("Bar.java"))));
+        assert!(!filter.matches_class(&class_from_source("com/myapp/Foo", None)));
+    }
+
+    #[test]
+    fn empty_changed_files_match_no_class() {
+        let filter = ClassFilter::default().with_changed_files(Some(Vec::new()));
+
+        assert!(!filter.matches_class(&class_from_source("com/myapp/Foo", Some("Foo.java"))));
+    }
+
+    #[test]
+    fn single_star_and_question_mark_stay_within_a_segment() {
+        let filter = filter(&["com/*/Foo?"], &[]);
+
+        assert!(filter.matches("com/myapp/Foo1"));
+        assert!(!filter.matches("com/myapp/service/Foo1"));
+        assert!(!filter.matches("com/myapp/Foo"));
+    }
+
+    fn ignore_filter(contents: &str) -> ClassFilter {
+        ClassFilter::default().with_ignore_patterns(parse_ignore_file(contents))
+    }
+
+    #[test]
+    fn ignore_file_excludes_packages_and_unanchored_names() {
+        let filter = ignore_filter("# generated sources\n\ncom/myapp/generated/\n*Test\n/Main\n");
+
+        assert!(filter.matches("com/myapp/Foo"));
+        assert!(!filter.matches("com/myapp/generated/Foo"));
+        assert!(!filter.matches("com/myapp/generated/nested/Bar$Inner"));
+        assert!(filter.matches("org/myapp/generated/Foo"));
+        assert!(!filter.matches("com/myapp/FooTest"));
+        assert!(!filter.matches("FooTest"));
+        assert!(!filter.matches("Main"));
+        assert!(filter.matches("com/myapp/Main"));
+    }
+
+    #[test]
+    fn ignore_file_negation_reincludes_subpackage() {
+        let filter = ignore_filter("internal/\n!com/myapp/internal/api/\n");
+
+        assert!(!filter.matches("com/myapp/internal/Impl"));
+        assert!(!filter.matches("org/lib/internal/Impl"));
+        assert!(filter.matches("com/myapp/internal/api/Api"));
+        assert!(filter.matches("com/myapp/internal/api/v2/Api"));
+        assert!(filter.matches("com/myapp/Foo"));
+    }
+
+    #[test]
+    fn missing_ignore_file_yields_no_patterns() {
+        let path = std::env::temp_dir().join("inspequte-missing-ignore-file");
+
+        assert!(load_ignore_file(&path).expect("load").is_empty());
+    }
+}
@@ -6,9 +6,15 @@ use tracing::warn;
 
 use crate::ir::Class;
 
+/// Maximum number of duplicate classes listed in the duplicate-class error.
+const MAX_DUPLICATE_EXAMPLES: usize = 5;
+
 /// Resolved classpath index keyed by class name.
 pub(crate) struct ClasspathIndex {
     pub(crate) classes: BTreeMap<String, i64>,
+    /// Classes defined by more than one artifact, mapped to the competing artifact URIs.
+    /// The first URI is the artifact whose class is used.
+    pub(crate) duplicate_classes: BTreeMap<String, Vec<String>>,
 }
 
 /// Resolves the classpath index from the given classes and artifacts.
@@ -32,31 +38,32 @@ pub(crate) fn resolve_classpath(
             .push(class.artifact_index);
     }
 
-    let mut error_duplicates = Vec::new();
+    let mut duplicate_classes = BTreeMap::new();
     for (name, indices) in &mut class_map {
         if indices.len() <= 1 {
             continue;
         }
-        if allow_duplicate_classes {
-            // Sort by artifact URI for a deterministic, reproducible selection.
-            indices.sort_by(|&a, &b| artifact_uri(artifacts, a).cmp(&artifact_uri(artifacts, b)));
+        // Sort by artifact URI for a deterministic, reproducible selection.
+        indices.sort_by_cached_key(|&index| artifact_uri(artifacts, index));
+        duplicate_classes.insert(
+            name.clone(),
+            indices
+                .iter()
+                .map(|&index| artifact_uri(artifacts, index))
+                .collect::<Vec<_>>(),
+        );
+    }
+    if !duplicate_classes.is_empty() {
+        if !allow_duplicate_classes {
+            anyhow::bail!(duplicate_classes_error(&duplicate_classes));
+        }
+        for (name, uris) in &duplicate_classes {
             warn!(
-                "duplicate class {} found in multiple artifacts; using {}",
-                name,
-                artifact_uri(artifacts, indices[0])
+                "duplicate class {name} found in multiple artifacts; using {}",
+                uris[0]
             );
-        } else {
-            let duplicate_artifacts = indices
-                .iter()
-                .map(|index| format!("{index} ({})", artifact_uri(artifacts, *index)))
-                .collect::<Vec<_>>()
-                .join(", ");
-            error_duplicates.push(format!("{name}: [{duplicate_artifacts}]"));
         }
     }
-    if !error_duplicates.is_empty() {
-        anyhow::bail!("duplicate classes found: {}", error_duplicates.join(", "));
-    }
 
     let class_names: BTreeSet<String> = class_map.keys().cloned().collect();
     let mut missing = BTreeSet::new();
@@ -82,7 +89,29 @@ pub(crate) fn resolve_classpath(
         })
         .collect();
 
-    Ok(ClasspathIndex { classes })
+    Ok(ClasspathIndex {
+        classes,
+        duplicate_classes,
+    })
+}
+
+fn duplicate_classes_error(duplicate_classes: &BTreeMap<String, Vec<String>>) -> String {
+    let mut examples = duplicate_classes
+        .iter()
+        .take(MAX_DUPLICATE_EXAMPLES)
+        .map(|(name, uris)| format!("{name}: [{}]", uris.join(", ")))
+        .collect::<Vec<_>>();
+    if duplicate_classes.len() > MAX_DUPLICATE_EXAMPLES {
+        examples.push(format!(
+            "and {} more",
+            duplicate_classes.len() - MAX_DUPLICATE_EXAMPLES
+        ));
+    }
+    format!(
+        "{} duplicate classes found: {}; pass --allow-duplicate-classes to use the class from the lexicographically first artifact",
+        duplicate_classes.len(),
+        examples.join(", ")
+    )
 }
 
 /// Returns the URI of the artifact at the given index, or an empty string if unavailable.
@@ -127,6 +156,8 @@ mod tests {
                 annotation_defaults: Vec::new(),
                 artifact_index: 0,
                 is_record: false,
+                is_final: false,
+                content_hash: String::new(),
             },
             Class {
                 name: "com/example/Bar".to_string(),
@@ -140,6 +171,8 @@ mod tests {
                 annotation_defaults: Vec::new(),
                 artifact_index: 1,
                 is_record: false,
+                is_final: false,
+                content_hash: String::new(),
             },
         ];
 
@@ -162,6 +195,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
 
         let result = resolve_classpath(&classes, &[], false);
@@ -188,6 +223,8 @@ mod tests {
                 annotation_defaults: Vec::new(),
                 artifact_index: 0,
                 is_record: false,
+                is_final: false,
+                content_hash: String::new(),
             },
             Class {
                 name: "com/example/Foo".to_string(),
@@ -201,6 +238,8 @@ mod tests {
                 annotation_defaults: Vec::new(),
                 artifact_index: 1,
                 is_record: false,
+                is_final: false,
+                content_hash: String::new(),
             },
         ];
 
@@ -229,6 +268,8 @@ mod tests {
                 annotation_defaults: Vec::new(),
                 artifact_index: 0,
                 is_record: false,
+                is_final: false,
+                content_hash: String::new(),
             },
             Class {
                 name: "com/example/Foo".to_string(),
@@ -242,6 +283,8 @@ mod tests {
                 annotation_defaults: Vec::new(),
                 artifact_index: 1,
                 is_record: false,
+                is_final: false,
+                content_hash: String::new(),
             },
         ];
 
@@ -278,6 +321,8 @@ mod tests {
                 annotation_defaults: Vec::new(),
                 artifact_index: 0,
                 is_record: false,
+                is_final: false,
+                content_hash: String::new(),
             },
             Class {
                 name: "com/example/Foo".to_string(),
@@ -291,6 +336,8 @@ mod tests {
                 annotation_defaults: Vec::new(),
                 artifact_index: 1,
                 is_record: false,
+                is_final: false,
+                content_hash: String::new(),
             },
         ];
 
@@ -303,4 +350,70 @@ mod tests {
             "should pick artifact 1 (aaa.jar) over artifact 0 (zzz.jar)"
         );
     }
+
+    fn class_in_artifact(name: &str, artifact_index: i64) -> Class {
+        Class {
+            name: name.to_string(),
+            source_file: None,
+            super_name: None,
+            interfaces: Vec::new(),
+            type_parameters: Vec::new(),
+            referenced_classes: Vec::new(),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            annotation_defaults: Vec::new(),
+            artifact_index,
+            is_record: false,
+            is_final: false,
+            content_hash: String::new(),
+        }
+    }
+
+    #[test]
+    fn resolve_classpath_collects_duplicate_classes_with_competing_artifacts() {
+        let artifacts = vec![
+            make_artifact("file:///zzz.jar"),
+            make_artifact("file:///aaa.jar"),
+        ];
+        let classes = vec![
+            class_in_artifact("com/example/Foo", 0),
+            class_in_artifact("com/example/Foo", 1),
+            class_in_artifact("com/example/Bar", 0),
+        ];
+
+        let index = resolve_classpath(&classes, &artifacts, true).expect("resolve classpath");
+
+        assert_eq!(
+            index.duplicate_classes,
+            BTreeMap::from([(
+                "com/example/Foo".to_string(),
+                vec!["file:///aaa.jar".to_string(), "file:///zzz.jar".to_string()]
+            )])
+        );
+    }
+
+    #[test]
+    fn resolve_classpath_error_lists_limited_duplicate_examples() {
+        let artifacts = vec![
+            make_artifact("file:///first.jar"),
+            make_artifact("file:///second.jar"),
+        ];
+        let classes = (0..MAX_DUPLICATE_EXAMPLES + 2)
+            .flat_map(|number| {
+                let name = format!("com/example/Class{number}");
+                [class_in_artifact(&name, 0), class_in_artifact(&name, 1)]
+            })
+            .collect::<Vec<_>>();
+
+        let error = resolve_classpath(&classes, &artifacts, false)
+            .err()
+            .expect("duplicate class error");
+        let error_text = format!("{error:#}");
+
+        assert!(error_text.starts_with("7 duplicate classes found: "));
+        assert!(error_text.contains("com/example/Class0: [file:///first.jar, file:///second.jar]"));
+        assert!(error_text.contains("com/example/Class4"));
+        assert!(!error_text.contains("com/example/Class5"));
+        assert!(error_text.contains("and 2 more"));
+    }
 }
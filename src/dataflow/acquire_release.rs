@@ -0,0 +1,317 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use anyhow::Result;
+
+use crate::dataflow::worklist::{
+    BlockEndStep, InstructionStep, WorklistConfig, WorklistSemantics, WorklistState,
+    analyze_method_with_config,
+};
+use crate::ir::{Instruction, Method};
+
+/// Instruction that acquires a resource which must be released on every exit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub(crate) struct AcquireSite {
+    pub(crate) block_start: u32,
+    pub(crate) instruction_index: usize,
+    pub(crate) offset: u32,
+}
+
+/// Exploration state for CFG traversal after an acquisition.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct ExplorationState {
+    block_start: u32,
+    instruction_index: usize,
+    release_seen: bool,
+}
+
+impl WorklistState for ExplorationState {
+    fn block_start(&self) -> u32 {
+        self.block_start
+    }
+
+    fn instruction_index(&self) -> usize {
+        self.instruction_index
+    }
+
+    fn set_position(&mut self, block_start: u32, instruction_index: usize) {
+        self.block_start = block_start;
+        self.instruction_index = instruction_index;
+    }
+}
+
+/// Dataflow callbacks that look for an exit reached without passing a release instruction.
+struct ReleasePathSemantics<'a> {
+    start_block: u32,
+    start_index: usize,
+    /// Offsets of instructions that release the resource.
+    release_offsets: &'a BTreeSet<u32>,
+}
+
+impl WorklistSemantics for ReleasePathSemantics<'_> {
+    type State = ExplorationState;
+    /// Start offset of a block that exits the method without a release.
+    type Finding = u32;
+
+    fn initial_states(&self, _method: &Method) -> Vec<Self::State> {
+        vec![ExplorationState {
+            block_start: self.start_block,
+            instruction_index: self.start_index,
+            release_seen: false,
+        }]
+    }
+
+    fn transfer_instruction(
+        &self,
+        _method: &Method,
+        instruction: &Instruction,
+        state: &mut Self::State,
+    ) -> Result<InstructionStep<Self::Finding>> {
+        if self.release_offsets.contains(&instruction.offset) {
+            state.release_seen = true;
+        }
+        Ok(InstructionStep::continue_path())
+    }
+
+    fn on_block_end(
+        &self,
+        _method: &Method,
+        state: &Self::State,
+        successors: &[u32],
+    ) -> Result<BlockEndStep<Self::State, Self::Finding>> {
+        if successors.is_empty() {
+            if state.release_seen {
+                return Ok(BlockEndStep::terminal());
+            }
+            return Ok(BlockEndStep::terminal().with_finding(state.block_start));
+        }
+        Ok(BlockEndStep::follow_all_successors(state, successors))
+    }
+}
+
+/// Dataflow callbacks that collect target instructions reached before a release instruction.
+struct ReachBeforeReleaseSemantics<'a> {
+    start_block: u32,
+    start_index: usize,
+    /// Offsets of instructions that release the resource and end the path.
+    release_offsets: &'a BTreeSet<u32>,
+    /// Offsets of instructions to report when reached while the resource is held.
+    target_offsets: &'a BTreeSet<u32>,
+}
+
+impl WorklistSemantics for ReachBeforeReleaseSemantics<'_> {
+    type State = ExplorationState;
+    /// Offset of a target instruction reached while the resource is held.
+    type Finding = u32;
+
+    fn initial_states(&self, _method: &Method) -> Vec<Self::State> {
+        vec![ExplorationState {
+            block_start: self.start_block,
+            instruction_index: self.start_index,
+            release_seen: false,
+        }]
+    }
+
+    fn transfer_instruction(
+        &self,
+        _method: &Method,
+        instruction: &Instruction,
+        _state: &mut Self::State,
+    ) -> Result<InstructionStep<Self::Finding>> {
+        if self.release_offsets.contains(&instruction.offset) {
+            return Ok(InstructionStep::terminate_path());
+        }
+        if self.target_offsets.contains(&instruction.offset) {
+            return Ok(InstructionStep::continue_path().with_finding(instruction.offset));
+        }
+        Ok(InstructionStep::continue_path())
+    }
+
+    fn on_block_end(
+        &self,
+        _method: &Method,
+        state: &Self::State,
+        successors: &[u32],
+    ) -> Result<BlockEndStep<Self::State, Self::Finding>> {
+        if successors.is_empty() {
+            return Ok(BlockEndStep::terminal());
+        }
+        Ok(BlockEndStep::follow_all_successors(state, successors))
+    }
+}
+
+/// Returns the instructions matching `is_acquire`, ordered by offset.
+pub(crate) fn acquire_sites(
+    method: &Method,
+    is_acquire: impl Fn(&Instruction) -> bool,
+) -> Vec<AcquireSite> {
+    let mut sites = Vec::new();
+    for block in &method.cfg.blocks {
+        for (instruction_index, instruction) in block.instructions.iter().enumerate() {
+            if is_acquire(instruction) {
+                sites.push(AcquireSite {
+                    block_start: block.start_offset,
+                    instruction_index,
+                    offset: instruction.offset,
+                });
+            }
+        }
+    }
+    sites.sort_by_key(|site| site.offset);
+    sites
+}
+
+/// Returns the block path from `site` to an exit that skips every release offset, if any.
+/// Methods whose exploration exceeds the worklist budget yield `None`.
+pub(crate) fn exit_path_without_release(
+    method: &Method,
+    site: AcquireSite,
+    release_offsets: &BTreeSet<u32>,
+) -> Result<Option<Vec<u32>>> {
+    let semantics = ReleasePathSemantics {
+        start_block: site.block_start,
+        start_index: site.instruction_index + 1,
+        release_offsets,
+    };
+    let outcome = analyze_method_with_config(method, &semantics, WorklistConfig::from_env())?;
+    if outcome.aborted {
+        return Ok(None);
+    }
+    let Some(exit_block) = outcome.findings.into_iter().min() else {
+        return Ok(None);
+    };
+    Ok(Some(release_free_block_path(
+        method,
+        site.block_start,
+        exit_block,
+        release_offsets,
+    )))
+}
+
+/// Returns the offsets in `target_offsets` reachable from `site` along a path that passes no
+/// release offset. Methods whose exploration exceeds the worklist budget yield none.
+pub(crate) fn reachable_before_release(
+    method: &Method,
+    site: AcquireSite,
+    release_offsets: &BTreeSet<u32>,
+    target_offsets: &BTreeSet<u32>,
+) -> Result<BTreeSet<u32>> {
+    if target_offsets.is_empty() {
+        return Ok(BTreeSet::new());
+    }
+    let semantics = ReachBeforeReleaseSemantics {
+        start_block: site.block_start,
+        start_index: site.instruction_index + 1,
+        release_offsets,
+        target_offsets,
+    };
+    let outcome = analyze_method_with_config(method, &semantics, WorklistConfig::from_env())?;
+    if outcome.aborted {
+        return Ok(BTreeSet::new());
+    }
+    Ok(outcome.findings.into_iter().collect())
+}
+
+/// Returns true when every exit of `method` is reached only after one of `release_offsets`.
+pub(crate) fn always_released_from_entry(
+    method: &Method,
+    release_offsets: &BTreeSet<u32>,
+) -> Result<bool> {
+    let Some(entry_block) = method
+        .cfg
+        .blocks
+        .iter()
+        .map(|block| block.start_offset)
+        .min()
+    else {
+        return Ok(false);
+    };
+    if release_offsets.is_empty() {
+        return Ok(false);
+    }
+    let semantics = ReleasePathSemantics {
+        start_block: entry_block,
+        start_index: 0,
+        release_offsets,
+    };
+    let outcome = analyze_method_with_config(method, &semantics, WorklistConfig::from_env())?;
+    Ok(!outcome.aborted && outcome.findings.is_empty())
+}
+
+/// Shortest block path from `start` to `exit_block` through blocks that never release.
+fn release_free_block_path(
+    method: &Method,
+    start: u32,
+    exit_block: u32,
+    release_offsets: &BTreeSet<u32>,
+) -> Vec<u32> {
+    let mut successors: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for edge in &method.cfg.edges {
+        successors.entry(edge.from).or_default().push(edge.to);
+    }
+    let release_blocks: BTreeSet<u32> = method
+        .cfg
+        .blocks
+        .iter()
+        .filter(|block| {
+            block
+                .instructions
+                .iter()
+                .any(|instruction| release_offsets.contains(&instruction.offset))
+        })
+        .map(|block| block.start_offset)
+        .collect();
+
+    let mut parents = BTreeMap::new();
+    let mut visited = BTreeSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(block) = queue.pop_front() {
+        if block == exit_block {
+            let mut path = vec![block];
+            let mut current = block;
+            while let Some(parent) = parents.get(&current) {
+                path.push(*parent);
+                current = *parent;
+            }
+            path.reverse();
+            return path;
+        }
+        for successor in successors.get(&block).into_iter().flatten() {
+            if release_blocks.contains(successor) || !visited.insert(*successor) {
+                continue;
+            }
+            parents.insert(*successor, block);
+            queue.push_back(*successor);
+        }
+    }
+    vec![start, exit_block]
+}
+
+/// Code flow steps: the acquisition, each intermediate block, and the exiting instruction.
+pub(crate) fn code_flow_steps(
+    method: &Method,
+    site: AcquireSite,
+    path: &[u32],
+    acquire_message: &'static str,
+    exit_message: &'static str,
+) -> Vec<(u32, Option<&'static str>)> {
+    let mut steps = vec![(site.offset, Some(acquire_message))];
+    let intermediate = path.len().saturating_sub(1);
+    for block_start in path.iter().take(intermediate).skip(1) {
+        steps.push((*block_start, None));
+    }
+    let exit_offset = path
+        .last()
+        .and_then(|exit_block| {
+            method
+                .cfg
+                .blocks
+                .iter()
+                .find(|block| block.start_offset == *exit_block)
+        })
+        .and_then(|block| block.instructions.last())
+        .map(|instruction| instruction.offset);
+    if let Some(offset) = exit_offset {
+        steps.push((offset, Some(exit_message)));
+    }
+    steps
+}
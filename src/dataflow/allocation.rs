@@ -0,0 +1,39 @@
+use crate::dataflow::opcode_semantics::{ApplyOutcome, SemanticsHooks};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::ir::Method;
+use crate::opcodes;
+
+/// Semantics hook that pushes a rule-specific value for the result of a `new` instruction.
+///
+/// `allocation` maps the offset of the `new` instruction to the value, so rules can follow a
+/// fresh object through `dup`, locals, and its constructor call.
+pub(crate) struct NewAllocationHook<F> {
+    offset: u32,
+    allocation: F,
+}
+
+impl<F> NewAllocationHook<F> {
+    /// Creates a hook for the instruction at `offset`.
+    pub(crate) fn new(offset: u32, allocation: F) -> Self {
+        Self { offset, allocation }
+    }
+}
+
+impl<V, F> SemanticsHooks<V> for NewAllocationHook<F>
+where
+    F: FnMut(u32) -> V,
+{
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<V>,
+        _method: &Method,
+        _offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        if opcode == opcodes::NEW {
+            machine.push((self.allocation)(self.offset));
+            return ApplyOutcome::Applied;
+        }
+        ApplyOutcome::NotHandled
+    }
+}
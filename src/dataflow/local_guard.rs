@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+
+use crate::ir::{CallSite, Method};
+use crate::opcodes;
+
+/// Bytecode instruction metadata needed for local guard tracking.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct BytecodeInstruction {
+    pub(crate) offset: u32,
+    pub(crate) opcode: u8,
+    pub(crate) length: usize,
+}
+
+/// Bytecode range where a boolean check on a local is known to have passed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct GuardRange {
+    start_offset: u32,
+    end_offset: u32,
+    local_index: usize,
+}
+
+/// Returns the offsets of calls matching `is_target` whose receiver local is guarded by a
+/// check call on the same local, with no store to that local in between.
+///
+/// `guard_polarity` classifies check calls such as `isPresent()Z`: `Some(true)` when a `true`
+/// result guards the receiver, `Some(false)` when a `false` result does, and `None` for
+/// other calls. Only the fall-through branch of an `ifeq`/`ifne` right after the check counts
+/// as guarded.
+pub(crate) fn guarded_call_offsets(
+    method: &Method,
+    is_target: impl Fn(&CallSite) -> bool,
+    guard_polarity: impl Fn(&CallSite) -> Option<bool>,
+) -> Result<BTreeSet<u32>> {
+    let instructions = collect_instructions(method)?;
+    let offset_to_instruction_index: BTreeMap<u32, usize> = instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| (instruction.offset, index))
+        .collect();
+    let guard_ranges = collect_guard_ranges(method, &instructions, guard_polarity)?;
+
+    let mut guarded_offsets = BTreeSet::new();
+    for call in &method.calls {
+        if !is_target(call) {
+            continue;
+        }
+        let Some(instruction_index) = offset_to_instruction_index.get(&call.offset).copied() else {
+            continue;
+        };
+        let Some(local_index) = receiver_local_index(method, &instructions, instruction_index)
+        else {
+            continue;
+        };
+        let guarded = guard_ranges.iter().any(|range| {
+            range.local_index == local_index
+                && call.offset >= range.start_offset
+                && call.offset < range.end_offset
+                && !has_store_to_local_between(
+                    method,
+                    &instructions,
+                    local_index,
+                    range.start_offset,
+                    call.offset,
+                )
+        });
+        if guarded {
+            guarded_offsets.insert(call.offset);
+        }
+    }
+
+    Ok(guarded_offsets)
+}
+
+pub(crate) fn collect_instructions(method: &Method) -> Result<Vec<BytecodeInstruction>> {
+    let mut instructions = Vec::new();
+    let mut offset = 0usize;
+    while offset < method.bytecode.len() {
+        let opcode = method.bytecode[offset];
+        let length = crate::scan::opcode_length(&method.bytecode, offset)?;
+        instructions.push(BytecodeInstruction {
+            offset: offset as u32,
+            opcode,
+            length,
+        });
+        offset += length;
+    }
+    Ok(instructions)
+}
+
+fn collect_guard_ranges(
+    method: &Method,
+    instructions: &[BytecodeInstruction],
+    guard_polarity: impl Fn(&CallSite) -> Option<bool>,
+) -> Result<Vec<GuardRange>> {
+    let calls_by_offset: BTreeMap<u32, &CallSite> = method
+        .calls
+        .iter()
+        .map(|call| (call.offset, call))
+        .collect();
+    let mut ranges = Vec::new();
+    for (index, instruction) in instructions.iter().enumerate() {
+        if !is_invoke_opcode(instruction.opcode) {
+            continue;
+        }
+        let Some(call) = calls_by_offset.get(&instruction.offset).copied() else {
+            continue;
+        };
+        let Some(guards_when_true) = guard_polarity(call) else {
+            continue;
+        };
+        let Some(local_index) = receiver_local_index(method, instructions, index) else {
+            continue;
+        };
+        let Some(branch) = instructions.get(index + 1) else {
+            continue;
+        };
+        let Some(target_offset) = conditional_branch_target(&method.bytecode, branch)? else {
+            continue;
+        };
+        if let Some(range) = fallthrough_guard_range(guards_when_true, branch, target_offset) {
+            ranges.push(GuardRange {
+                start_offset: range.0,
+                end_offset: range.1,
+                local_index,
+            });
+        }
+    }
+    Ok(ranges)
+}
+
+fn is_invoke_opcode(opcode: u8) -> bool {
+    matches!(
+        opcode,
+        opcodes::INVOKEVIRTUAL
+            | opcodes::INVOKESPECIAL
+            | opcodes::INVOKEINTERFACE
+            | opcodes::INVOKESTATIC
+    )
+}
+
+/// Returns the local loaded right before the instruction at `instruction_index`, which is the
+/// receiver of a no-argument call there.
+pub(crate) fn receiver_local_index(
+    method: &Method,
+    instructions: &[BytecodeInstruction],
+    instruction_index: usize,
+) -> Option<usize> {
+    let previous = instructions.get(instruction_index.checked_sub(1)?)?;
+    aload_local_index(&method.bytecode, previous)
+}
+
+/// Returns the local read by an `aload` variant, including `wide aload`.
+pub(crate) fn aload_local_index(code: &[u8], instruction: &BytecodeInstruction) -> Option<usize> {
+    match instruction.opcode {
+        opcodes::ALOAD => code
+            .get(instruction.offset as usize + 1)
+            .copied()
+            .map(usize::from),
+        opcodes::ALOAD_0..=opcodes::ALOAD_3 => {
+            Some((instruction.opcode - opcodes::ALOAD_0) as usize)
+        }
+        0xc4 => {
+            if code.get(instruction.offset as usize + 1).copied() != Some(opcodes::ALOAD) {
+                return None;
+            }
+            crate::scan::read_u16(code, instruction.offset as usize + 2)
+                .ok()
+                .map(usize::from)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the local written by an `astore` variant, including `wide astore`.
+pub(crate) fn astore_local_index(code: &[u8], instruction: &BytecodeInstruction) -> Option<usize> {
+    match instruction.opcode {
+        opcodes::ASTORE => code
+            .get(instruction.offset as usize + 1)
+            .copied()
+            .map(usize::from),
+        opcodes::ASTORE_0..=opcodes::ASTORE_3 => {
+            Some((instruction.opcode - opcodes::ASTORE_0) as usize)
+        }
+        0xc4 => {
+            if code.get(instruction.offset as usize + 1).copied() != Some(opcodes::ASTORE) {
+                return None;
+            }
+            crate::scan::read_u16(code, instruction.offset as usize + 2)
+                .ok()
+                .map(usize::from)
+        }
+        _ => None,
+    }
+}
+
+fn conditional_branch_target(
+    code: &[u8],
+    instruction: &BytecodeInstruction,
+) -> Result<Option<u32>> {
+    if !matches!(instruction.opcode, opcodes::IFEQ | opcodes::IFNE) {
+        return Ok(None);
+    }
+    let branch = crate::scan::read_u16(code, instruction.offset as usize + 1)?;
+    let branch = i16::from_be_bytes(branch.to_be_bytes()) as i32;
+    let target = instruction.offset as i32 + branch;
+    if target < 0 {
+        return Ok(None);
+    }
+    Ok(Some(target as u32))
+}
+
+fn fallthrough_guard_range(
+    guards_when_true: bool,
+    branch: &BytecodeInstruction,
+    branch_target: u32,
+) -> Option<(u32, u32)> {
+    let guarded_on_fallthrough = matches!(
+        (guards_when_true, branch.opcode),
+        (true, opcodes::IFEQ) | (false, opcodes::IFNE)
+    );
+    if !guarded_on_fallthrough {
+        return None;
+    }
+
+    let start_offset = branch.offset + branch.length as u32;
+    if start_offset >= branch_target {
+        return None;
+    }
+    Some((start_offset, branch_target))
+}
+
+/// Whether an `astore` to `local_index` lies in `start_offset..end_offset`.
+pub(crate) fn has_store_to_local_between(
+    method: &Method,
+    instructions: &[BytecodeInstruction],
+    local_index: usize,
+    start_offset: u32,
+    end_offset: u32,
+) -> bool {
+    instructions
+        .iter()
+        .filter(|instruction| instruction.offset >= start_offset && instruction.offset < end_offset)
+        .filter_map(|instruction| astore_local_index(&method.bytecode, instruction))
+        .any(|stored| stored == local_index)
+}
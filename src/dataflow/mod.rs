@@ -1,3 +1,6 @@
+pub(crate) mod acquire_release;
+pub(crate) mod allocation;
+pub(crate) mod local_guard;
 pub(crate) mod opcode_semantics;
 pub(crate) mod stack_machine;
 pub(crate) mod worklist;
@@ -470,9 +470,11 @@ mod tests {
             signature: None,
             access: MethodAccess {
                 is_public: false,
+                is_private: false,
                 is_static: true,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
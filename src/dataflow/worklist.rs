@@ -1,9 +1,54 @@
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::OnceLock;
 
 use anyhow::Result;
+use tracing::warn;
 
 use crate::ir::{BasicBlock, Instruction, Method};
 
+/// Environment variable that overrides the worklist iteration cap; `0` disables the cap.
+const MAX_ITERATIONS_ENV: &str = "INSPEQUTE_MAX_WORKLIST_ITERS";
+/// Default number of processed states after which a worklist run is aborted.
+const DEFAULT_MAX_ITERATIONS: usize = 1_000_000;
+
+/// Budget applied to a single worklist run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct WorklistConfig {
+    /// Maximum number of processed states; `None` means unlimited.
+    pub(crate) max_iterations: Option<usize>,
+}
+
+impl WorklistConfig {
+    /// Returns the configuration derived from `INSPEQUTE_MAX_WORKLIST_ITERS`.
+    pub(crate) fn from_env() -> Self {
+        static CONFIG: OnceLock<WorklistConfig> = OnceLock::new();
+        *CONFIG.get_or_init(|| WorklistConfig {
+            max_iterations: parse_max_iterations(std::env::var(MAX_ITERATIONS_ENV).ok().as_deref()),
+        })
+    }
+}
+
+fn parse_max_iterations(value: Option<&str>) -> Option<usize> {
+    let Some(value) = value else {
+        return Some(DEFAULT_MAX_ITERATIONS);
+    };
+    match value.trim().parse::<usize>() {
+        Ok(0) => None,
+        Ok(limit) => Some(limit),
+        Err(_) => {
+            warn!("ignoring invalid {MAX_ITERATIONS_ENV}={value}; using {DEFAULT_MAX_ITERATIONS}");
+            Some(DEFAULT_MAX_ITERATIONS)
+        }
+    }
+}
+
+/// Findings of a worklist run and whether it stopped early at the iteration cap.
+pub(crate) struct WorklistOutcome<F> {
+    pub(crate) findings: Vec<F>,
+    /// True when the run hit `max_iterations`; `findings` is then partial.
+    pub(crate) aborted: bool,
+}
+
 /// Program-point state tracked by the worklist engine.
 pub(crate) trait WorklistState: Clone + Ord {
     fn block_start(&self) -> u32;
@@ -105,7 +150,21 @@ pub(crate) trait WorklistSemantics {
 }
 
 /// Deterministic intraprocedural worklist runner for bytecode dataflow analyses.
+///
+/// Uses the iteration cap from the environment and returns partial findings when it is hit.
 pub(crate) fn analyze_method<S>(method: &Method, semantics: &S) -> Result<Vec<S::Finding>>
+where
+    S: WorklistSemantics,
+{
+    Ok(analyze_method_with_config(method, semantics, WorklistConfig::from_env())?.findings)
+}
+
+/// Worklist runner that reports whether the run was aborted by `config`.
+pub(crate) fn analyze_method_with_config<S>(
+    method: &Method,
+    semantics: &S,
+    config: WorklistConfig,
+) -> Result<WorklistOutcome<S::Finding>>
 where
     S: WorklistSemantics,
 {
@@ -113,6 +172,7 @@ where
     let mut queue = VecDeque::new();
     let mut visited = BTreeSet::new();
     let mut findings = Vec::new();
+    let mut iterations = 0usize;
 
     for mut state in semantics.initial_states(method) {
         semantics.canonicalize_state(&mut state);
@@ -124,6 +184,23 @@ where
         if !visited.insert(state.clone()) {
             continue;
         }
+        iterations += 1;
+        if config
+            .max_iterations
+            .is_some_and(|max_iterations| iterations > max_iterations)
+        {
+            warn!(
+                "worklist analysis of {}{} aborted after {} iterations; set {} to raise the cap",
+                method.name,
+                method.descriptor,
+                iterations - 1,
+                MAX_ITERATIONS_ENV
+            );
+            return Ok(WorklistOutcome {
+                findings,
+                aborted: true,
+            });
+        }
 
         let Some(block) = graph.blocks.get(&state.block_start()) else {
             continue;
@@ -166,7 +243,10 @@ where
         enqueue_block_end_step(semantics, end_step, &mut queue, &mut findings);
     }
 
-    Ok(findings)
+    Ok(WorklistOutcome {
+        findings,
+        aborted: false,
+    })
 }
 
 /// CFG lookup tables used by the worklist loop.
@@ -226,7 +306,10 @@ mod tests {
 
     use anyhow::Result;
 
-    use super::{BlockEndStep, InstructionStep, WorklistSemantics, WorklistState, analyze_method};
+    use super::{
+        BlockEndStep, DEFAULT_MAX_ITERATIONS, InstructionStep, WorklistConfig, WorklistSemantics,
+        WorklistState, analyze_method, analyze_method_with_config, parse_max_iterations,
+    };
     use crate::ir::{
         BasicBlock, CallSite, ControlFlowGraph, EdgeKind, FlowEdge, Instruction, InstructionKind,
         LineNumber, LocalVariableType, Method, MethodAccess, MethodNullness, Nullness,
@@ -390,9 +473,11 @@ mod tests {
             signature: None,
             access: MethodAccess {
                 is_public: false,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -522,4 +607,53 @@ mod tests {
 
         assert!(findings.contains(&20), "expected handler block traversal");
     }
+
+    #[test]
+    fn aborts_large_method_at_iteration_cap() {
+        let blocks = (0..1000).map(|index| block(index, &[index])).collect();
+        let edges = (0..999)
+            .map(|index| FlowEdge {
+                from: index,
+                to: index + 1,
+                kind: EdgeKind::FallThrough,
+            })
+            .collect();
+        let method = build_method(blocks, edges);
+        let semantics = SinglePathSemantics {
+            transfer_calls: Cell::new(0),
+        };
+
+        let capped = analyze_method_with_config(
+            &method,
+            &semantics,
+            WorklistConfig {
+                max_iterations: Some(100),
+            },
+        )
+        .expect("worklist run");
+        let uncapped = analyze_method_with_config(
+            &method,
+            &semantics,
+            WorklistConfig {
+                max_iterations: None,
+            },
+        )
+        .expect("worklist run");
+
+        assert!(capped.aborted);
+        assert!(capped.findings.is_empty());
+        assert!(!uncapped.aborted);
+        assert_eq!(uncapped.findings, vec!["terminal"]);
+    }
+
+    #[test]
+    fn parses_max_iterations_from_env_value() {
+        assert_eq!(parse_max_iterations(None), Some(DEFAULT_MAX_ITERATIONS));
+        assert_eq!(parse_max_iterations(Some("5000")), Some(5000));
+        assert_eq!(parse_max_iterations(Some("0")), None);
+        assert_eq!(
+            parse_max_iterations(Some("many")),
+            Some(DEFAULT_MAX_ITERATIONS)
+        );
+    }
 }
@@ -0,0 +1,160 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_sarif::sarif::{Artifact, Result as SarifResult};
+
+/// What makes two findings identical for `--dedupe-findings`: the rule, message, logical
+/// location (class, method, and descriptor), and line of each location, but not the artifact.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct FindingFingerprint {
+    rule_id: String,
+    message: String,
+    locations: Vec<(Option<String>, Option<i64>)>,
+}
+
+impl From<&SarifResult> for FindingFingerprint {
+    fn from(result: &SarifResult) -> Self {
+        let locations = result
+            .locations
+            .iter()
+            .flatten()
+            .map(|location| {
+                let logical = location
+                    .logical_locations
+                    .as_ref()
+                    .and_then(|logical| logical.first())
+                    .and_then(|logical| logical.name.clone());
+                let start_line = location
+                    .physical_location
+                    .as_ref()
+                    .and_then(|physical| physical.region.as_ref())
+                    .and_then(|region| region.start_line);
+                (logical, start_line)
+            })
+            .collect();
+        Self {
+            rule_id: result.rule_id.clone().unwrap_or_default(),
+            message: result.message.text.clone().unwrap_or_default(),
+            locations,
+        }
+    }
+}
+
+/// Keeps one result per fingerprint, preferring the one whose location points at the artifact
+/// listed first in `artifacts`. The order of the kept results is unchanged.
+pub(crate) fn dedupe_findings(
+    results: Vec<SarifResult>,
+    artifacts: &[Artifact],
+) -> Vec<SarifResult> {
+    let artifact_order: BTreeMap<&str, usize> = artifacts
+        .iter()
+        .enumerate()
+        .filter_map(|(index, artifact)| Some((artifact.location.as_ref()?.uri.as_deref()?, index)))
+        .collect();
+    let artifact_rank = |result: &SarifResult| {
+        result
+            .locations
+            .iter()
+            .flatten()
+            .find_map(|location| {
+                location
+                    .physical_location
+                    .as_ref()?
+                    .artifact_location
+                    .as_ref()?
+                    .uri
+                    .as_deref()
+            })
+            .and_then(|uri| artifact_order.get(uri).copied())
+            .unwrap_or(usize::MAX)
+    };
+
+    let mut kept: BTreeMap<FindingFingerprint, (usize, usize)> = BTreeMap::new();
+    for (index, result) in results.iter().enumerate() {
+        let rank = artifact_rank(result);
+        kept.entry(FindingFingerprint::from(result))
+            .and_modify(|(kept_index, kept_rank)| {
+                if rank < *kept_rank {
+                    *kept_index = index;
+                    *kept_rank = rank;
+                }
+            })
+            .or_insert((index, rank));
+    }
+    let kept_indices: BTreeSet<usize> = kept.into_values().map(|(index, _)| index).collect();
+    results
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| kept_indices.contains(index))
+        .map(|(_, result)| result)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_sarif::sarif::ArtifactLocation;
+
+    use crate::rules::{method_location_with_line, result_message};
+
+    fn artifact(uri: &str) -> Artifact {
+        Artifact::builder()
+            .location(ArtifactLocation::builder().uri(uri.to_string()).build())
+            .build()
+    }
+
+    fn finding(rule_id: &str, uri: &str, line: u32) -> SarifResult {
+        SarifResult::builder()
+            .rule_id(rule_id.to_string())
+            .message(result_message("finding"))
+            .locations(vec![method_location_with_line(
+                "com/example/ClassA",
+                "methodX",
+                "()V",
+                Some(uri),
+                Some(line),
+            )])
+            .build()
+    }
+
+    fn uri_and_line(result: &SarifResult) -> (Option<&str>, Option<i64>) {
+        let physical = result.locations.as_ref().expect("locations")[0]
+            .physical_location
+            .as_ref()
+            .expect("physical location");
+        (
+            physical
+                .artifact_location
+                .as_ref()
+                .and_then(|artifact| artifact.uri.as_deref()),
+            physical
+                .region
+                .as_ref()
+                .and_then(|region| region.start_line),
+        )
+    }
+
+    #[test]
+    fn dedupe_findings_keeps_the_first_artifact_of_identical_findings() {
+        let first = "file:///first/com/example/ClassA.class";
+        let second = "file:///second/com/example/ClassA.class";
+        let results = vec![
+            finding("SYSTEM_EXIT", second, 3),
+            finding("SYSTEM_EXIT", first, 3),
+            finding("SYSTEM_EXIT", second, 4),
+            finding("EXPLICIT_GC_CALL", second, 3),
+        ];
+
+        let deduped = dedupe_findings(results, &[artifact(first), artifact(second)]);
+
+        let kept: Vec<(Option<&str>, Option<i64>)> = deduped.iter().map(uri_and_line).collect();
+        assert_eq!(
+            kept,
+            vec![
+                (Some(first), Some(3)),
+                (Some(second), Some(4)),
+                (Some(second), Some(3)),
+            ]
+        );
+        assert_eq!(deduped[2].rule_id.as_deref(), Some("EXPLICIT_GC_CALL"));
+    }
+}
@@ -54,6 +54,22 @@ pub(crate) fn method_return_class_name(descriptor: &str) -> Result<Option<String
     }
 }
 
+/// Extract the class name of each parameter in a JVM method descriptor.
+///
+/// Returns `[Some("java/lang/String"), None]` for `(Ljava/lang/String;I)V`;
+/// primitive and array parameters map to `None`.
+pub(crate) fn method_param_class_names(descriptor: &str) -> Result<Vec<Option<String>>> {
+    let desc = MethodDescriptor::from_str(descriptor).context("parse method descriptor")?;
+    Ok(desc
+        .parameter_types()
+        .iter()
+        .map(|param| match param {
+            TypeDescriptor::Object(name) => Some(name.to_string()),
+            _ => None,
+        })
+        .collect())
+}
+
 /// Number of local variable slots a JVM type occupies (2 for long/double, 1 otherwise).
 fn slot_width(ty: &TypeDescriptor) -> u16 {
     match ty {
@@ -90,3 +106,398 @@ pub(crate) fn method_param_start_slots(descriptor: &str) -> Result<Vec<u16>> {
     }
     Ok(result)
 }
+
+/// Kind of declaration a `Signature` attribute belongs to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum SignatureKind {
+    Method,
+    Field,
+}
+
+/// Parsed method or field `Signature` attribute (JVMS 4.7.9.1).
+///
+/// Type parameters and `throws` clauses of method signatures are validated but not kept.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum GenericSignature {
+    Method {
+        parameters: Vec<GenericType>,
+        return_type: GenericType,
+    },
+    Field(GenericType),
+}
+
+/// Type appearing in a generic signature.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum GenericType {
+    /// Primitive type such as `I`, `J`, or `Z`.
+    Base,
+    Void,
+    Class(ClassType),
+    /// Type variable such as `TT;`.
+    TypeVariable,
+    Array(Box<GenericType>),
+}
+
+/// Class type with its type arguments.
+///
+/// For `Lcom/example/Outer<TT;>.Inner<TU;>;` the name is `com/example/Outer$Inner`,
+/// the type arguments are `[U]`, and `outer` holds `com/example/Outer<T>`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ClassType {
+    pub(crate) name: String,
+    pub(crate) type_arguments: Vec<TypeArgument>,
+    pub(crate) outer: Option<Box<ClassType>>,
+}
+
+/// Type argument of a parameterized class type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TypeArgument {
+    /// `?`
+    Any,
+    /// `? extends T`
+    Extends(GenericType),
+    /// `? super T`
+    Super(GenericType),
+    Exact(GenericType),
+}
+
+/// Parse a method or field `Signature` attribute value.
+pub(crate) fn parse_signature(signature: &str, kind: SignatureKind) -> Result<GenericSignature> {
+    let mut parser = SignatureParser {
+        signature,
+        offset: 0,
+    };
+    let parsed = match kind {
+        SignatureKind::Method => parser.method_signature(),
+        SignatureKind::Field => parser.reference_type().map(GenericSignature::Field),
+    }
+    .with_context(|| format!("parse signature {signature}"))?;
+    if parser.offset != signature.len() {
+        anyhow::bail!(
+            "parse signature {signature}: unexpected trailing input at offset {}",
+            parser.offset
+        );
+    }
+    Ok(parsed)
+}
+
+/// Recursive-descent parser over the signature grammar.
+struct SignatureParser<'a> {
+    signature: &'a str,
+    offset: usize,
+}
+
+impl SignatureParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.signature.as_bytes().get(self.offset).copied()
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        match self.peek() {
+            Some(byte) if byte == expected => {
+                self.offset += 1;
+                Ok(())
+            }
+            Some(byte) => anyhow::bail!(
+                "expected '{}' but found '{}' at offset {}",
+                expected as char,
+                byte as char,
+                self.offset
+            ),
+            None => anyhow::bail!("expected '{}' but reached the end", expected as char),
+        }
+    }
+
+    /// Consume an identifier up to (not including) one of the ASCII `terminators`.
+    fn identifier(&mut self, terminators: &[u8]) -> Result<&str> {
+        let start = self.offset;
+        while let Some(byte) = self.peek() {
+            if terminators.contains(&byte) {
+                break;
+            }
+            self.offset += 1;
+        }
+        if self.offset == start {
+            anyhow::bail!("expected an identifier at offset {start}");
+        }
+        Ok(&self.signature[start..self.offset])
+    }
+
+    fn method_signature(&mut self) -> Result<GenericSignature> {
+        self.skip_type_parameters()?;
+        self.expect(b'(')?;
+        let mut parameters = Vec::new();
+        while self.peek() != Some(b')') {
+            parameters.push(self.java_type()?);
+        }
+        self.expect(b')')?;
+        let return_type = if self.peek() == Some(b'V') {
+            self.offset += 1;
+            GenericType::Void
+        } else {
+            self.java_type()?
+        };
+        while self.peek() == Some(b'^') {
+            self.offset += 1;
+            self.reference_type()?;
+        }
+        Ok(GenericSignature::Method {
+            parameters,
+            return_type,
+        })
+    }
+
+    /// Consume formal type parameters such as `<T:Ljava/lang/Object;>`, if present.
+    fn skip_type_parameters(&mut self) -> Result<()> {
+        if self.peek() != Some(b'<') {
+            return Ok(());
+        }
+        self.offset += 1;
+        while self.peek() != Some(b'>') {
+            self.identifier(b":")?;
+            self.expect(b':')?;
+            if matches!(self.peek(), Some(b'L' | b'T' | b'[')) {
+                self.reference_type()?;
+            }
+            while self.peek() == Some(b':') {
+                self.offset += 1;
+                self.reference_type()?;
+            }
+        }
+        self.expect(b'>')
+    }
+
+    fn java_type(&mut self) -> Result<GenericType> {
+        match self.peek() {
+            Some(b'B' | b'C' | b'D' | b'F' | b'I' | b'J' | b'S' | b'Z') => {
+                self.offset += 1;
+                Ok(GenericType::Base)
+            }
+            _ => self.reference_type(),
+        }
+    }
+
+    fn reference_type(&mut self) -> Result<GenericType> {
+        match self.peek() {
+            Some(b'L') => Ok(GenericType::Class(self.class_type()?)),
+            Some(b'T') => {
+                self.offset += 1;
+                self.identifier(b";")?;
+                self.expect(b';')?;
+                Ok(GenericType::TypeVariable)
+            }
+            Some(b'[') => {
+                self.offset += 1;
+                Ok(GenericType::Array(Box::new(self.java_type()?)))
+            }
+            Some(byte) => anyhow::bail!(
+                "expected a reference type but found '{}' at offset {}",
+                byte as char,
+                self.offset
+            ),
+            None => anyhow::bail!("expected a reference type but reached the end"),
+        }
+    }
+
+    fn class_type(&mut self) -> Result<ClassType> {
+        self.expect(b'L')?;
+        let name = self.identifier(b"<.;")?.to_string();
+        let mut class_type = ClassType {
+            name,
+            type_arguments: self.type_arguments()?,
+            outer: None,
+        };
+        while self.peek() == Some(b'.') {
+            self.offset += 1;
+            let simple_name = self.identifier(b"<.;")?;
+            let name = format!("{}${simple_name}", class_type.name);
+            class_type = ClassType {
+                name,
+                type_arguments: self.type_arguments()?,
+                outer: Some(Box::new(class_type)),
+            };
+        }
+        self.expect(b';')?;
+        Ok(class_type)
+    }
+
+    fn type_arguments(&mut self) -> Result<Vec<TypeArgument>> {
+        let mut arguments = Vec::new();
+        if self.peek() != Some(b'<') {
+            return Ok(arguments);
+        }
+        self.offset += 1;
+        while self.peek() != Some(b'>') {
+            let argument = match self.peek() {
+                Some(b'*') => {
+                    self.offset += 1;
+                    TypeArgument::Any
+                }
+                Some(b'+') => {
+                    self.offset += 1;
+                    TypeArgument::Extends(self.reference_type()?)
+                }
+                Some(b'-') => {
+                    self.offset += 1;
+                    TypeArgument::Super(self.reference_type()?)
+                }
+                _ => TypeArgument::Exact(self.reference_type()?),
+            };
+            arguments.push(argument);
+        }
+        self.expect(b'>')?;
+        Ok(arguments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn class(name: &str, type_arguments: Vec<TypeArgument>) -> GenericType {
+        GenericType::Class(ClassType {
+            name: name.to_string(),
+            type_arguments,
+            outer: None,
+        })
+    }
+
+    #[test]
+    fn method_param_class_names_skips_primitive_and_array_parameters() {
+        let names =
+            method_param_class_names("(Ljava/util/Optional;I[Ljava/lang/String;)V").expect("parse");
+
+        assert_eq!(
+            names,
+            vec![Some("java/util/Optional".to_string()), None, None]
+        );
+    }
+
+    #[test]
+    fn parse_signature_reads_nested_generic_field() {
+        let signature = parse_signature(
+            "Ljava/util/Map<Ljava/lang/String;Ljava/util/List<Ljava/lang/Integer;>;>;",
+            SignatureKind::Field,
+        )
+        .expect("parse");
+
+        assert_eq!(
+            signature,
+            GenericSignature::Field(class(
+                "java/util/Map",
+                vec![
+                    TypeArgument::Exact(class("java/lang/String", Vec::new())),
+                    TypeArgument::Exact(class(
+                        "java/util/List",
+                        vec![TypeArgument::Exact(class("java/lang/Integer", Vec::new()))],
+                    )),
+                ],
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_signature_reads_wildcards() {
+        let signature = parse_signature(
+            "(Ljava/util/List<+Ljava/lang/Number;>;Ljava/util/List<-TT;>;Ljava/util/List<*>;)V",
+            SignatureKind::Method,
+        )
+        .expect("parse");
+
+        let GenericSignature::Method { parameters, .. } = signature else {
+            panic!("expected method signature");
+        };
+        assert_eq!(
+            parameters,
+            vec![
+                class(
+                    "java/util/List",
+                    vec![TypeArgument::Extends(class("java/lang/Number", Vec::new()))],
+                ),
+                class(
+                    "java/util/List",
+                    vec![TypeArgument::Super(GenericType::TypeVariable)]
+                ),
+                class("java/util/List", vec![TypeArgument::Any]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_signature_reads_array_type_arguments_and_parameters() {
+        let signature = parse_signature(
+            "<T:Ljava/lang/Object;>([TT;[[I)Ljava/util/List<[Ljava/lang/String;>;",
+            SignatureKind::Method,
+        )
+        .expect("parse");
+
+        assert_eq!(
+            signature,
+            GenericSignature::Method {
+                parameters: vec![
+                    GenericType::Array(Box::new(GenericType::TypeVariable)),
+                    GenericType::Array(Box::new(GenericType::Array(Box::new(GenericType::Base)))),
+                ],
+                return_type: class(
+                    "java/util/List",
+                    vec![TypeArgument::Exact(GenericType::Array(Box::new(class(
+                        "java/lang/String",
+                        Vec::new()
+                    ))))],
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_signature_skips_bounds_and_throws() {
+        let signature = parse_signature(
+            "<T::Ljava/lang/Comparable<-TT;>;X:Ljava/lang/Exception;>(TT;)TT;^TX;^Ljava/io/IOException;",
+            SignatureKind::Method,
+        )
+        .expect("parse");
+
+        assert_eq!(
+            signature,
+            GenericSignature::Method {
+                parameters: vec![GenericType::TypeVariable],
+                return_type: GenericType::TypeVariable,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_signature_reads_inner_class_type() {
+        let signature = parse_signature(
+            "Lcom/example/Outer<TK;>.Inner<Ljava/lang/String;>;",
+            SignatureKind::Field,
+        )
+        .expect("parse");
+
+        let GenericSignature::Field(GenericType::Class(inner)) = signature else {
+            panic!("expected class type");
+        };
+        assert_eq!(inner.name, "com/example/Outer$Inner");
+        assert_eq!(
+            inner.type_arguments,
+            vec![TypeArgument::Exact(class("java/lang/String", Vec::new()))]
+        );
+        let outer = inner.outer.as_deref().expect("outer type");
+        assert_eq!(outer.name, "com/example/Outer");
+        assert_eq!(
+            outer.type_arguments,
+            vec![TypeArgument::Exact(GenericType::TypeVariable)]
+        );
+    }
+
+    #[test]
+    fn parse_signature_rejects_malformed_input() {
+        assert!(
+            parse_signature("Ljava/util/List<Ljava/lang/String;", SignatureKind::Field).is_err()
+        );
+        assert!(parse_signature("(I", SignatureKind::Method).is_err());
+        assert!(parse_signature("<T>()V", SignatureKind::Method).is_err());
+        assert!(parse_signature("Ljava/lang/Object;X", SignatureKind::Field).is_err());
+        assert!(parse_signature("I", SignatureKind::Field).is_err());
+    }
+}
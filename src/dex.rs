@@ -0,0 +1,1000 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+
+use crate::ir::{
+    BasicBlock, CallKind, CallSite, Class, ControlFlowGraph, EdgeKind, Field, FieldAccess,
+    FieldRef, FlowEdge, Instruction, InstructionKind, LineNumber, Method, MethodAccess,
+    MethodNullness,
+};
+use crate::opcodes;
+
+/// Magic prefix of a DEX file; the four bytes after it hold the format version.
+const DEX_MAGIC: &[u8] = b"dex\n";
+const HEADER_SIZE: usize = 0x70;
+/// Marks an absent superclass or source file index.
+const NO_INDEX: u32 = 0xffff_ffff;
+
+const ACC_PUBLIC: u32 = 0x1;
+const ACC_PRIVATE: u32 = 0x2;
+const ACC_STATIC: u32 = 0x8;
+const ACC_FINAL: u32 = 0x10;
+const ACC_SYNCHRONIZED: u32 = 0x20;
+const ACC_VOLATILE: u32 = 0x40;
+const ACC_BRIDGE: u32 = 0x40;
+const ACC_TRANSIENT: u32 = 0x80;
+const ACC_ABSTRACT: u32 = 0x400;
+const ACC_SYNTHETIC: u32 = 0x1000;
+/// DEX-only flag for `synchronized` methods that are not native.
+const ACC_DECLARED_SYNCHRONIZED: u32 = 0x2_0000;
+
+/// Returns whether `name` is a `classes.dex`, `classes2.dex`, ... entry at the root of an APK.
+pub(crate) fn is_apk_dex_entry(name: &str) -> bool {
+    name.strip_prefix("classes")
+        .and_then(|rest| rest.strip_suffix(".dex"))
+        .is_some_and(|number| number.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Parses the classes defined in a DEX file into the IR used for class files.
+///
+/// DEX methods carry no JVM bytecode, so `Method::bytecode` is empty and rules that decode it
+/// skip them. Calls, field accesses, string and class constants, monitors, casts, returns, and
+/// throws become `Instruction`s with the equivalent JVM opcode, placed in a control-flow graph
+/// built from the Dalvik branches. Offsets are Dalvik code-unit offsets.
+pub(crate) fn parse_dex_classes(data: &[u8]) -> Result<Vec<Class>> {
+    let dex = DexFile::parse(data)?;
+    let class_defs_size = dex.u32_at(96)? as usize;
+    let class_defs_off = dex.u32_at(100)? as usize;
+    (0..class_defs_size)
+        .map(|index| {
+            dex.parse_class_def(class_defs_off + index * 32)
+                .with_context(|| format!("failed to parse DEX class_def {index}"))
+        })
+        .collect()
+}
+
+/// Index sections of a DEX file, resolved lazily while class definitions are read.
+struct DexFile<'a> {
+    data: &'a [u8],
+    string_ids: Section,
+    type_ids: Section,
+    proto_ids: Section,
+    field_ids: Section,
+    method_ids: Section,
+}
+
+#[derive(Clone, Copy)]
+struct Section {
+    size: u32,
+    offset: usize,
+}
+
+/// Decoded Dalvik instruction with what the CFG needs from it.
+struct DalvikInstruction {
+    offset: u32,
+    width: u32,
+    translated: Option<Instruction>,
+    branch_targets: Vec<u32>,
+    falls_through: bool,
+}
+
+impl<'a> DexFile<'a> {
+    fn parse(data: &'a [u8]) -> Result<Self> {
+        if data.len() < HEADER_SIZE || !data.starts_with(DEX_MAGIC) {
+            anyhow::bail!("not a DEX file");
+        }
+        let mut dex = Self {
+            data,
+            string_ids: Section { size: 0, offset: 0 },
+            type_ids: Section { size: 0, offset: 0 },
+            proto_ids: Section { size: 0, offset: 0 },
+            field_ids: Section { size: 0, offset: 0 },
+            method_ids: Section { size: 0, offset: 0 },
+        };
+        dex.string_ids = dex.section(56)?;
+        dex.type_ids = dex.section(64)?;
+        dex.proto_ids = dex.section(72)?;
+        dex.field_ids = dex.section(80)?;
+        dex.method_ids = dex.section(88)?;
+        Ok(dex)
+    }
+
+    /// Reads a `size, offset` pair of the header.
+    fn section(&self, header_offset: usize) -> Result<Section> {
+        Ok(Section {
+            size: self.u32_at(header_offset)?,
+            offset: self.u32_at(header_offset + 4)? as usize,
+        })
+    }
+
+    fn bytes(&self, offset: usize, len: usize) -> Result<&'a [u8]> {
+        offset
+            .checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
+            .with_context(|| format!("DEX read of {len} bytes at {offset:#x} is out of bounds"))
+    }
+
+    fn u16_at(&self, offset: usize) -> Result<u16> {
+        let bytes = self.bytes(offset, 2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32_at(&self, offset: usize) -> Result<u32> {
+        let bytes = self.bytes(offset, 4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn uleb128(&self, offset: &mut usize) -> Result<u32> {
+        let mut value = 0u32;
+        for shift in (0..35).step_by(7) {
+            let byte = self.bytes(*offset, 1)?[0];
+            *offset += 1;
+            value |= u32::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        anyhow::bail!("malformed uleb128 in DEX file")
+    }
+
+    fn sleb128(&self, offset: &mut usize) -> Result<i32> {
+        let mut value = 0i32;
+        let mut shift = 0;
+        loop {
+            let byte = self.bytes(*offset, 1)?[0];
+            *offset += 1;
+            value |= i32::from(byte & 0x7f) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if shift < 32 && byte & 0x40 != 0 {
+                    value |= -1 << shift;
+                }
+                return Ok(value);
+            }
+            if shift >= 35 {
+                anyhow::bail!("malformed sleb128 in DEX file");
+            }
+        }
+    }
+
+    /// Offset of entry `index` of `section`, whose entries are `entry_size` bytes.
+    fn entry(&self, section: Section, index: u32, entry_size: usize) -> Result<usize> {
+        if index >= section.size {
+            anyhow::bail!("DEX index {index} out of range {}", section.size);
+        }
+        Ok(section.offset + index as usize * entry_size)
+    }
+
+    fn string(&self, index: u32) -> Result<String> {
+        let mut offset = self.u32_at(self.entry(self.string_ids, index, 4)?)? as usize;
+        self.uleb128(&mut offset)?;
+        let rest = self
+            .data
+            .get(offset..)
+            .context("DEX string out of bounds")?;
+        let len = rest
+            .iter()
+            .position(|byte| *byte == 0)
+            .context("unterminated DEX string")?;
+        // MUTF-8 differs from UTF-8 only for NUL and supplementary characters, which do not
+        // occur in the names and descriptors rules match on.
+        Ok(String::from_utf8_lossy(&rest[..len]).into_owned())
+    }
+
+    fn type_descriptor(&self, index: u32) -> Result<String> {
+        self.string(self.u32_at(self.entry(self.type_ids, index, 4)?)?)
+    }
+
+    /// Internal class name such as `java/lang/String`; array types keep descriptor form.
+    fn class_name(&self, index: u32) -> Result<String> {
+        Ok(internal_name(self.type_descriptor(index)?))
+    }
+
+    fn type_list(&self, offset: u32) -> Result<Vec<String>> {
+        if offset == 0 {
+            return Ok(Vec::new());
+        }
+        let offset = offset as usize;
+        let size = self.u32_at(offset)? as usize;
+        (0..size)
+            .map(|index| self.type_descriptor(u32::from(self.u16_at(offset + 4 + index * 2)?)))
+            .collect()
+    }
+
+    fn method_descriptor(&self, proto_index: u32) -> Result<String> {
+        let offset = self.entry(self.proto_ids, proto_index, 12)?;
+        let return_type = self.type_descriptor(self.u32_at(offset + 4)?)?;
+        let parameters = self.type_list(self.u32_at(offset + 8)?)?;
+        Ok(format!("({}){return_type}", parameters.concat()))
+    }
+
+    /// Owner, name, and descriptor of a method reference.
+    fn method_ref(&self, index: u32) -> Result<(String, String, String)> {
+        let offset = self.entry(self.method_ids, index, 8)?;
+        Ok((
+            self.class_name(u32::from(self.u16_at(offset)?))?,
+            self.string(self.u32_at(offset + 4)?)?,
+            self.method_descriptor(u32::from(self.u16_at(offset + 2)?))?,
+        ))
+    }
+
+    fn field_ref(&self, index: u32) -> Result<FieldRef> {
+        let offset = self.entry(self.field_ids, index, 8)?;
+        Ok(FieldRef {
+            owner: self.class_name(u32::from(self.u16_at(offset)?))?,
+            name: self.string(self.u32_at(offset + 4)?)?,
+            descriptor: self.type_descriptor(u32::from(self.u16_at(offset + 2)?))?,
+        })
+    }
+
+    fn parse_class_def(&self, offset: usize) -> Result<Class> {
+        let name = self.class_name(self.u32_at(offset)?)?;
+        let access_flags = self.u32_at(offset + 4)?;
+        let superclass_index = self.u32_at(offset + 8)?;
+        let super_name = if superclass_index == NO_INDEX {
+            None
+        } else {
+            Some(self.class_name(superclass_index)?)
+        };
+        let interfaces = self
+            .type_list(self.u32_at(offset + 12)?)?
+            .into_iter()
+            .map(internal_name)
+            .collect();
+        let source_file_index = self.u32_at(offset + 16)?;
+        let source_file = if source_file_index == NO_INDEX {
+            None
+        } else {
+            Some(self.string(source_file_index)?)
+        };
+        let class_data_off = self.u32_at(offset + 24)? as usize;
+        let (fields, methods) = if class_data_off == 0 {
+            (Vec::new(), Vec::new())
+        } else {
+            self.parse_class_data(class_data_off)
+                .with_context(|| format!("failed to parse class data of {name}"))?
+        };
+
+        let mut referenced = BTreeSet::new();
+        for instruction in methods
+            .iter()
+            .flat_map(|method: &Method| method.cfg.blocks.iter())
+            .flat_map(|block| block.instructions.iter())
+        {
+            match &instruction.kind {
+                InstructionKind::Invoke(call) => referenced.insert(call.owner.clone()),
+                InstructionKind::FieldAccess(field) => referenced.insert(field.owner.clone()),
+                InstructionKind::ConstClass(name)
+                | InstructionKind::Checkcast(name)
+                | InstructionKind::InstanceOf(name) => referenced.insert(name.clone()),
+                _ => false,
+            };
+        }
+        referenced.remove(&name);
+
+        // Classes in one DEX file share its bytes, so the class name keeps their hashes apart.
+        let mut hasher = Sha256::new();
+        hasher.update(self.data);
+        hasher.update(name.as_bytes());
+        let content_hash = format!("{:x}", hasher.finalize());
+
+        Ok(Class {
+            is_record: super_name.as_deref() == Some("java/lang/Record"),
+            name,
+            source_file,
+            super_name,
+            interfaces,
+            type_parameters: Vec::new(),
+            referenced_classes: referenced.into_iter().collect(),
+            fields,
+            methods,
+            annotation_defaults: Vec::new(),
+            artifact_index: -1,
+            is_final: access_flags & ACC_FINAL != 0,
+            content_hash,
+        })
+    }
+
+    fn parse_class_data(&self, offset: usize) -> Result<(Vec<Field>, Vec<Method>)> {
+        let mut cursor = offset;
+        let static_fields_size = self.uleb128(&mut cursor)?;
+        let instance_fields_size = self.uleb128(&mut cursor)?;
+        let direct_methods_size = self.uleb128(&mut cursor)?;
+        let virtual_methods_size = self.uleb128(&mut cursor)?;
+
+        let mut fields = Vec::new();
+        for size in [static_fields_size, instance_fields_size] {
+            let mut field_index = 0u32;
+            for _ in 0..size {
+                field_index += self.uleb128(&mut cursor)?;
+                let access_flags = self.uleb128(&mut cursor)?;
+                let field = self.field_ref(field_index)?;
+                fields.push(Field {
+                    name: field.name,
+                    descriptor: field.descriptor,
+                    signature: None,
+                    type_use: None,
+                    access: FieldAccess {
+                        is_public: access_flags & ACC_PUBLIC != 0,
+                        is_static: access_flags & ACC_STATIC != 0,
+                        is_private: access_flags & ACC_PRIVATE != 0,
+                        is_final: access_flags & ACC_FINAL != 0,
+                        is_volatile: access_flags & ACC_VOLATILE != 0,
+                        is_transient: access_flags & ACC_TRANSIENT != 0,
+                        is_synthetic: access_flags & ACC_SYNTHETIC != 0,
+                    },
+                });
+            }
+        }
+
+        let mut methods = Vec::new();
+        for size in [direct_methods_size, virtual_methods_size] {
+            let mut method_index = 0u32;
+            for _ in 0..size {
+                method_index += self.uleb128(&mut cursor)?;
+                let access_flags = self.uleb128(&mut cursor)?;
+                let code_off = self.uleb128(&mut cursor)? as usize;
+                let (_, name, descriptor) = self.method_ref(method_index)?;
+                let mut method = Method {
+                    nullness: MethodNullness::unknown(crate::descriptor::method_param_count(
+                        &descriptor,
+                    )?),
+                    name,
+                    descriptor,
+                    signature: None,
+                    access: MethodAccess {
+                        is_public: access_flags & ACC_PUBLIC != 0,
+                        is_private: access_flags & ACC_PRIVATE != 0,
+                        is_static: access_flags & ACC_STATIC != 0,
+                        is_synchronized: access_flags
+                            & (ACC_SYNCHRONIZED | ACC_DECLARED_SYNCHRONIZED)
+                            != 0,
+                        is_abstract: access_flags & ACC_ABSTRACT != 0,
+                        is_final: access_flags & ACC_FINAL != 0,
+                        is_synthetic: access_flags & ACC_SYNTHETIC != 0,
+                        is_bridge: access_flags & ACC_BRIDGE != 0,
+                    },
+                    type_use: None,
+                    bytecode: Vec::new(),
+                    line_numbers: Vec::new(),
+                    cfg: ControlFlowGraph {
+                        blocks: Vec::new(),
+                        edges: Vec::new(),
+                    },
+                    calls: Vec::new(),
+                    string_literals: Vec::new(),
+                    exception_handlers: Vec::new(),
+                    local_variables: Vec::new(),
+                    local_variable_types: Vec::new(),
+                };
+                if code_off != 0 {
+                    self.parse_code(code_off, &mut method).with_context(|| {
+                        format!(
+                            "failed to parse code of {}{}",
+                            method.name, method.descriptor
+                        )
+                    })?;
+                }
+                methods.push(method);
+            }
+        }
+        Ok((fields, methods))
+    }
+
+    fn parse_code(&self, offset: usize, method: &mut Method) -> Result<()> {
+        let debug_info_off = self.u32_at(offset + 8)? as usize;
+        let insns_size = self.u32_at(offset + 12)? as usize;
+        let units: Vec<u16> = (0..insns_size)
+            .map(|index| self.u16_at(offset + 16 + index * 2))
+            .collect::<Result<_>>()?;
+        let instructions = self.decode_instructions(&units)?;
+        for instruction in instructions
+            .iter()
+            .filter_map(|decoded| decoded.translated.as_ref())
+        {
+            match &instruction.kind {
+                InstructionKind::Invoke(call) => method.calls.push(call.clone()),
+                InstructionKind::ConstString(value) => method.string_literals.push(value.clone()),
+                _ => {}
+            }
+        }
+        method.cfg = build_dalvik_cfg(instructions);
+        if debug_info_off != 0 {
+            method.line_numbers = self.parse_line_numbers(debug_info_off)?;
+        }
+        Ok(())
+    }
+
+    fn decode_instructions(&self, units: &[u16]) -> Result<Vec<DalvikInstruction>> {
+        let unit = |index: usize| -> Result<u16> {
+            units
+                .get(index)
+                .copied()
+                .context("truncated Dalvik instruction")
+        };
+        let mut instructions = Vec::new();
+        let mut pc = 0usize;
+        while pc < units.len() {
+            let op = (units[pc] & 0xff) as u8;
+            let high = (units[pc] >> 8) as u8;
+            if op == 0x00 && high != 0 {
+                pc += payload_width(units, pc)?;
+                continue;
+            }
+            let width = instruction_width(op);
+            let offset = pc as u32;
+            let relative = |delta: i32| (offset as i64 + i64::from(delta)) as u32;
+            let mut branch_targets = Vec::new();
+            let mut falls_through = true;
+            let translated = match op {
+                0x0e => Some((opcodes::RETURN, InstructionKind::Other(opcodes::RETURN))),
+                0x0f => Some((opcodes::IRETURN, InstructionKind::Other(opcodes::IRETURN))),
+                0x10 => Some((opcodes::LRETURN, InstructionKind::Other(opcodes::LRETURN))),
+                0x11 => Some((opcodes::ARETURN, InstructionKind::Other(opcodes::ARETURN))),
+                0x1a => Some((
+                    opcodes::LDC,
+                    InstructionKind::ConstString(self.string(u32::from(unit(pc + 1)?))?),
+                )),
+                0x1b => Some((
+                    opcodes::LDC,
+                    InstructionKind::ConstString(self.string(wide_operand(units, pc)?)?),
+                )),
+                0x1c => Some((
+                    opcodes::LDC,
+                    InstructionKind::ConstClass(self.class_name(u32::from(unit(pc + 1)?))?),
+                )),
+                0x1d => Some((
+                    opcodes::MONITORENTER,
+                    InstructionKind::Other(opcodes::MONITORENTER),
+                )),
+                0x1e => Some((
+                    opcodes::MONITOREXIT,
+                    InstructionKind::Other(opcodes::MONITOREXIT),
+                )),
+                0x1f => Some((
+                    opcodes::CHECKCAST,
+                    InstructionKind::Checkcast(self.class_name(u32::from(unit(pc + 1)?))?),
+                )),
+                0x20 => Some((
+                    opcodes::INSTANCEOF,
+                    InstructionKind::InstanceOf(self.class_name(u32::from(unit(pc + 1)?))?),
+                )),
+                0x27 => Some((opcodes::ATHROW, InstructionKind::Other(opcodes::ATHROW))),
+                0x52..=0x6d => {
+                    let opcode = match op {
+                        0x52..=0x58 => opcodes::GETFIELD,
+                        0x59..=0x5f => opcodes::PUTFIELD,
+                        0x60..=0x66 => opcodes::GETSTATIC,
+                        _ => opcodes::PUTSTATIC,
+                    };
+                    let field = self.field_ref(u32::from(unit(pc + 1)?))?;
+                    Some((opcode, InstructionKind::FieldAccess(field)))
+                }
+                0x6e..=0x72 | 0x74..=0x78 => {
+                    let (opcode, kind) = match (op - 0x6e) % 6 {
+                        0 => (opcodes::INVOKEVIRTUAL, CallKind::Virtual),
+                        1 | 2 => (opcodes::INVOKESPECIAL, CallKind::Special),
+                        3 => (opcodes::INVOKESTATIC, CallKind::Static),
+                        _ => (opcodes::INVOKEINTERFACE, CallKind::Interface),
+                    };
+                    let (owner, name, descriptor) = self.method_ref(u32::from(unit(pc + 1)?))?;
+                    Some((
+                        opcode,
+                        InstructionKind::Invoke(CallSite {
+                            owner,
+                            name,
+                            descriptor,
+                            kind,
+                            offset,
+                        }),
+                    ))
+                }
+                _ => None,
+            };
+            match op {
+                0x0e..=0x11 | 0x27 => falls_through = false,
+                0x28 => {
+                    branch_targets.push(relative(i32::from((units[pc] >> 8) as i8)));
+                    falls_through = false;
+                }
+                0x29 => {
+                    branch_targets.push(relative(i32::from(unit(pc + 1)? as i16)));
+                    falls_through = false;
+                }
+                0x2a => {
+                    branch_targets.push(relative(wide_operand(units, pc)? as i32));
+                    falls_through = false;
+                }
+                0x2b | 0x2c => {
+                    let payload = relative(wide_operand(units, pc)? as i32) as usize;
+                    branch_targets.extend(
+                        switch_payload_targets(units, payload)?
+                            .into_iter()
+                            .map(relative),
+                    );
+                }
+                0x32..=0x3d => branch_targets.push(relative(i32::from(unit(pc + 1)? as i16))),
+                _ => {}
+            }
+            instructions.push(DalvikInstruction {
+                offset,
+                width: width as u32,
+                translated: translated.map(|(opcode, kind)| Instruction {
+                    offset,
+                    opcode,
+                    kind,
+                }),
+                branch_targets,
+                falls_through,
+            });
+            pc += width;
+        }
+        Ok(instructions)
+    }
+
+    /// Reads the line table of a `debug_info_item` by running its state machine.
+    fn parse_line_numbers(&self, offset: usize) -> Result<Vec<LineNumber>> {
+        let mut cursor = offset;
+        let mut line = i64::from(self.uleb128(&mut cursor)?);
+        let parameters_size = self.uleb128(&mut cursor)?;
+        for _ in 0..parameters_size {
+            self.uleb128(&mut cursor)?;
+        }
+        let mut address = 0u32;
+        let mut entries = Vec::new();
+        loop {
+            let opcode = self.bytes(cursor, 1)?[0];
+            cursor += 1;
+            match opcode {
+                0x00 => break,
+                0x01 => address += self.uleb128(&mut cursor)?,
+                0x02 => line += i64::from(self.sleb128(&mut cursor)?),
+                0x03 => {
+                    for _ in 0..3 {
+                        self.uleb128(&mut cursor)?;
+                    }
+                }
+                0x04 => {
+                    for _ in 0..4 {
+                        self.uleb128(&mut cursor)?;
+                    }
+                }
+                0x05 | 0x06 | 0x09 => {
+                    self.uleb128(&mut cursor)?;
+                }
+                0x07 | 0x08 => {}
+                special => {
+                    let adjusted = u32::from(special - 0x0a);
+                    line += i64::from(adjusted % 15) - 4;
+                    address += adjusted / 15;
+                    entries.push(LineNumber {
+                        start_pc: address,
+                        line: u32::try_from(line).unwrap_or(0),
+                    });
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Splits Dalvik instructions into basic blocks holding their translated instructions.
+fn build_dalvik_cfg(instructions: Vec<DalvikInstruction>) -> ControlFlowGraph {
+    let starts: BTreeSet<u32> = instructions
+        .iter()
+        .map(|instruction| instruction.offset)
+        .collect();
+    let mut leaders = BTreeSet::new();
+    if let Some(first) = starts.first() {
+        leaders.insert(*first);
+    }
+    for instruction in &instructions {
+        let ends_block = !instruction.falls_through || !instruction.branch_targets.is_empty();
+        if ends_block {
+            leaders.insert(instruction.offset + instruction.width);
+        }
+        leaders.extend(instruction.branch_targets.iter().copied());
+    }
+    leaders.retain(|offset| starts.contains(offset));
+
+    let code_end = instructions
+        .last()
+        .map_or(0, |instruction| instruction.offset + instruction.width);
+    let mut blocks: BTreeMap<u32, BasicBlock> = leaders
+        .iter()
+        .map(|start| {
+            let end = leaders
+                .range(start + 1..)
+                .next()
+                .copied()
+                .unwrap_or(code_end);
+            (
+                *start,
+                BasicBlock {
+                    start_offset: *start,
+                    end_offset: end,
+                    instructions: Vec::new(),
+                },
+            )
+        })
+        .collect();
+
+    let mut edges = BTreeSet::new();
+    for instruction in instructions {
+        let Some((block_start, block)) = blocks.range_mut(..=instruction.offset).next_back() else {
+            continue;
+        };
+        let block_start = *block_start;
+        let is_last = instruction.offset + instruction.width == block.end_offset;
+        if let Some(translated) = instruction.translated {
+            block.instructions.push(translated);
+        }
+        for target in &instruction.branch_targets {
+            if starts.contains(target) {
+                edges.insert((block_start, *target, EdgeKind::Branch));
+            }
+        }
+        let next = instruction.offset + instruction.width;
+        if is_last && instruction.falls_through && starts.contains(&next) {
+            edges.insert((block_start, next, EdgeKind::FallThrough));
+        }
+    }
+
+    ControlFlowGraph {
+        blocks: blocks.into_values().collect(),
+        edges: edges
+            .into_iter()
+            .map(|(from, to, kind)| FlowEdge { from, to, kind })
+            .collect(),
+    }
+}
+
+/// Converts an object type descriptor such as `Ljava/lang/String;` to its internal name.
+fn internal_name(descriptor: String) -> String {
+    match descriptor
+        .strip_prefix('L')
+        .and_then(|name| name.strip_suffix(';'))
+    {
+        Some(name) => name.to_string(),
+        None => descriptor,
+    }
+}
+
+/// Width in 16-bit code units of the instruction with opcode `op`.
+fn instruction_width(op: u8) -> usize {
+    match op {
+        0x02
+        | 0x05
+        | 0x08
+        | 0x13
+        | 0x15
+        | 0x16
+        | 0x19
+        | 0x1a
+        | 0x1c
+        | 0x1f
+        | 0x20
+        | 0x22
+        | 0x23
+        | 0x29
+        | 0x2d..=0x3d
+        | 0x44..=0x6d
+        | 0x90..=0xaf
+        | 0xd0..=0xe2
+        | 0xfe
+        | 0xff => 2,
+        0x03
+        | 0x06
+        | 0x09
+        | 0x14
+        | 0x17
+        | 0x1b
+        | 0x24..=0x26
+        | 0x2a..=0x2c
+        | 0x6e..=0x72
+        | 0x74..=0x78
+        | 0xfc
+        | 0xfd => 3,
+        0xfa | 0xfb => 4,
+        0x18 => 5,
+        _ => 1,
+    }
+}
+
+/// Width of the switch or array-data payload pseudo-instruction at `pc`.
+fn payload_width(units: &[u16], pc: usize) -> Result<usize> {
+    let size = *units.get(pc + 1).context("truncated Dalvik payload")? as usize;
+    match units[pc] {
+        0x0100 => Ok(size * 2 + 4),
+        0x0200 => Ok(size * 4 + 2),
+        0x0300 => {
+            let element_width = units[pc + 1] as usize;
+            let count = ((units.get(pc + 2).copied().unwrap_or(0) as usize)
+                | ((units.get(pc + 3).copied().unwrap_or(0) as usize) << 16))
+                * element_width;
+            Ok(count.div_ceil(2) + 4)
+        }
+        _ => Ok(1),
+    }
+}
+
+/// Relative branch offsets of the packed or sparse switch payload at `payload`.
+fn switch_payload_targets(units: &[u16], payload: usize) -> Result<Vec<i32>> {
+    let ident = *units
+        .get(payload)
+        .context("missing Dalvik switch payload")?;
+    let size = *units
+        .get(payload + 1)
+        .context("truncated Dalvik switch payload")? as usize;
+    let targets_start = match ident {
+        0x0100 => payload + 4,
+        0x0200 => payload + 2 + size * 2,
+        _ => anyhow::bail!("invalid Dalvik switch payload at {payload}"),
+    };
+    (0..size)
+        .map(|index| wide_operand(units, targets_start + index * 2 - 1).map(|value| value as i32))
+        .collect()
+}
+
+/// Reads the 32-bit operand stored little-endian in the two code units after `pc`.
+fn wide_operand(units: &[u16], pc: usize) -> Result<u32> {
+    let low = *units.get(pc + 1).context("truncated Dalvik instruction")?;
+    let high = *units.get(pc + 2).context("truncated Dalvik instruction")?;
+    Ok(u32::from(low) | (u32::from(high) << 16))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::io::Write;
+
+    use zip::write::SimpleFileOptions;
+
+    use super::*;
+    use crate::engine::{Engine, build_context};
+    use crate::scan::{ScanOptions, scan_inputs};
+
+    /// Builds a DEX file with one class `com/example/ClassA` whose `methodX()V` runs
+    /// `System.gc()` on line 7 and returns.
+    fn tiny_dex() -> Vec<u8> {
+        // Sorted like a real DEX string table.
+        let strings = [
+            "<init>",
+            "ClassA.java",
+            "Lcom/example/ClassA;",
+            "Ljava/lang/Object;",
+            "Ljava/lang/System;",
+            "V",
+            "gc",
+            "methodX",
+        ];
+        // Types reference strings 2, 3, 4, and 5.
+        let types = [2u32, 3, 4, 5];
+        // One proto: shorty "V", returns V (type 3), no parameters.
+        let protos = [(5u32, 3u32, 0u32)];
+        // Methods: ClassA.methodX()V and System.gc()V.
+        let methods = [(0u16, 0u16, 7u32), (2, 0, 6)];
+
+        let string_ids_off = HEADER_SIZE;
+        let type_ids_off = string_ids_off + strings.len() * 4;
+        let proto_ids_off = type_ids_off + types.len() * 4;
+        let method_ids_off = proto_ids_off + protos.len() * 12;
+        let class_defs_off = method_ids_off + methods.len() * 8;
+        let data_off = class_defs_off + 32;
+
+        let mut data = Vec::new();
+        let mut string_data_offs = Vec::new();
+        for string in strings {
+            string_data_offs.push((data_off + data.len()) as u32);
+            data.push(string.len() as u8);
+            data.extend_from_slice(string.as_bytes());
+            data.push(0);
+        }
+        let debug_info_off = (data_off + data.len()) as u32;
+        // line_start 7, no parameters, special opcode for address +0 and line +0, end.
+        data.extend_from_slice(&[7, 0, 0x0e, 0x00]);
+        while (data_off + data.len()) % 4 != 0 {
+            data.push(0);
+        }
+        let code_off = (data_off + data.len()) as u32;
+        for value in [1u16, 1, 0, 0] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&debug_info_off.to_le_bytes());
+        data.extend_from_slice(&4u32.to_le_bytes());
+        // invoke-static {}, System.gc()V; return-void
+        for unit in [0x0071u16, 1, 0, 0x000e] {
+            data.extend_from_slice(&unit.to_le_bytes());
+        }
+        let class_data_off = (data_off + data.len()) as u32;
+        data.extend_from_slice(&[0, 0, 0, 1]);
+        data.push(0);
+        data.push(ACC_PUBLIC as u8);
+        let mut code_off_leb = Vec::new();
+        let mut value = code_off;
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                code_off_leb.push(byte);
+                break;
+            }
+            code_off_leb.push(byte | 0x80);
+        }
+        data.extend_from_slice(&code_off_leb);
+
+        let mut dex = vec![0u8; HEADER_SIZE];
+        dex[..8].copy_from_slice(b"dex\n035\0");
+        let header = [
+            (56, strings.len(), string_ids_off),
+            (64, types.len(), type_ids_off),
+            (72, protos.len(), proto_ids_off),
+            (88, methods.len(), method_ids_off),
+            (96, 1, class_defs_off),
+        ];
+        for (offset, size, section_off) in header {
+            dex[offset..offset + 4].copy_from_slice(&(size as u32).to_le_bytes());
+            dex[offset + 4..offset + 8].copy_from_slice(&(section_off as u32).to_le_bytes());
+        }
+        for offset in string_data_offs {
+            dex.extend_from_slice(&offset.to_le_bytes());
+        }
+        for index in types {
+            dex.extend_from_slice(&index.to_le_bytes());
+        }
+        for (shorty, return_type, parameters) in protos {
+            for value in [shorty, return_type, parameters] {
+                dex.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        for (class_index, proto_index, name_index) in methods {
+            dex.extend_from_slice(&class_index.to_le_bytes());
+            dex.extend_from_slice(&proto_index.to_le_bytes());
+            dex.extend_from_slice(&name_index.to_le_bytes());
+        }
+        for value in [0u32, ACC_PUBLIC, 1, 0, 1, 0, class_data_off, 0] {
+            dex.extend_from_slice(&value.to_le_bytes());
+        }
+        dex.extend_from_slice(&data);
+        dex
+    }
+
+    #[test]
+    fn parse_dex_classes_reads_class_methods_and_calls() {
+        let classes = parse_dex_classes(&tiny_dex()).expect("parse DEX");
+
+        assert_eq!(classes.len(), 1);
+        let class = &classes[0];
+        assert_eq!(class.name, "com/example/ClassA");
+        assert_eq!(class.super_name.as_deref(), Some("java/lang/Object"));
+        assert_eq!(class.source_file.as_deref(), Some("ClassA.java"));
+        assert_eq!(
+            class.referenced_classes,
+            vec!["java/lang/System".to_string()]
+        );
+        let method = &class.methods[0];
+        assert_eq!(
+            (method.name.as_str(), method.descriptor.as_str()),
+            ("methodX", "()V")
+        );
+        assert!(method.bytecode.is_empty());
+        let call = &method.calls[0];
+        assert_eq!(
+            (
+                call.owner.as_str(),
+                call.name.as_str(),
+                call.descriptor.as_str(),
+                call.kind
+            ),
+            ("java/lang/System", "gc", "()V", CallKind::Static)
+        );
+        assert_eq!(method.line_for_offset(call.offset), Some(7));
+        let opcodes: Vec<u8> = method.cfg.blocks[0]
+            .instructions
+            .iter()
+            .map(|instruction| instruction.opcode)
+            .collect();
+        assert_eq!(opcodes, vec![opcodes::INVOKESTATIC, opcodes::RETURN]);
+    }
+
+    #[test]
+    fn build_dalvik_cfg_splits_blocks_at_branches() {
+        // 0: if-eqz v0, +3; 2: return-void; 3: return-void
+        let units = [0x0038u16, 3, 0x000e, 0x000e];
+        let dex = DexFile {
+            data: &[],
+            string_ids: Section { size: 0, offset: 0 },
+            type_ids: Section { size: 0, offset: 0 },
+            proto_ids: Section { size: 0, offset: 0 },
+            field_ids: Section { size: 0, offset: 0 },
+            method_ids: Section { size: 0, offset: 0 },
+        };
+
+        let cfg = build_dalvik_cfg(dex.decode_instructions(&units).expect("decode"));
+
+        let starts: Vec<u32> = cfg.blocks.iter().map(|block| block.start_offset).collect();
+        assert_eq!(starts, vec![0, 2, 3]);
+        let edges: Vec<(u32, u32, EdgeKind)> = cfg
+            .edges
+            .iter()
+            .map(|edge| (edge.from, edge.to, edge.kind))
+            .collect();
+        assert_eq!(
+            edges,
+            vec![(0, 2, EdgeKind::FallThrough), (0, 3, EdgeKind::Branch)]
+        );
+    }
+
+    #[test]
+    fn scan_inputs_reads_apk_dex_entries_with_dex_option() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let apk_path = dir.path().join("app.apk");
+        let mut writer = zip::ZipWriter::new(fs::File::create(&apk_path).expect("create apk"));
+        for (name, data) in [
+            ("AndroidManifest.xml", Vec::new()),
+            ("classes.dex", tiny_dex()),
+        ] {
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .expect("start entry");
+            writer.write_all(&data).expect("write entry");
+        }
+        writer.finish().expect("finish apk");
+        let options = ScanOptions {
+            dex: true,
+            ..ScanOptions::default()
+        };
+
+        let output = scan_inputs(&[apk_path.clone()], &[], &options, None).expect("scan apk");
+        let context = build_context(output.classes, &output.artifacts);
+        let engine = Engine::new_with_allowed_rule_ids(Some(&BTreeSet::from([
+            "EXPLICIT_GC_CALL".to_string()
+        ])))
+        .expect("build engine");
+        let results = engine.analyze(context).expect("analysis").results;
+
+        assert_eq!(output.class_count, 1);
+        assert_eq!(results.len(), 1);
+        let location = &results[0].locations.as_ref().expect("locations")[0];
+        let physical = location
+            .physical_location
+            .as_ref()
+            .expect("physical location");
+        let uri = physical
+            .artifact_location
+            .as_ref()
+            .and_then(|artifact| artifact.uri.as_deref())
+            .expect("artifact uri");
+        assert!(uri.starts_with("jar:file://"), "{uri}");
+        assert!(uri.ends_with("app.apk!/classes.dex"), "{uri}");
+        assert_eq!(
+            physical
+                .region
+                .as_ref()
+                .and_then(|region| region.start_line),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn scan_inputs_rejects_dex_input_without_dex_option() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let dex_path = dir.path().join("classes.dex");
+        fs::write(&dex_path, tiny_dex()).expect("write dex");
+
+        let error = scan_inputs(&[dex_path], &[], &ScanOptions::default(), None)
+            .expect_err("dex input without --dex");
+
+        assert!(error.to_string().contains("pass --dex"), "{error}");
+    }
+
+    #[test]
+    fn is_apk_dex_entry_matches_root_dex_files() {
+        assert!(is_apk_dex_entry("classes.dex"));
+        assert!(is_apk_dex_entry("classes2.dex"));
+        assert!(!is_apk_dex_entry("assets/classes.dex"));
+        assert!(!is_apk_dex_entry("classes.jar"));
+    }
+}
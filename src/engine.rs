@@ -1,28 +1,132 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use opentelemetry::Context as OtelContext;
 use opentelemetry::KeyValue;
 use rayon::prelude::*;
+use serde::Serialize;
+use serde_json::json;
 use serde_sarif::sarif::Artifact;
-use serde_sarif::sarif::{MultiformatMessageString, ReportingDescriptor, Result as SarifResult};
-
-use crate::ir::Class;
+use serde_sarif::sarif::{
+    MultiformatMessageString, PropertyBag, ReportingConfiguration, ReportingDescriptor,
+    Result as SarifResult,
+};
+use tracing::warn;
+
+use crate::cache::{CachedClass, ResultCache, class_dependency_digest};
+use crate::class_filter::ClassFilter;
+use crate::ir::{Class, ModuleDescriptor};
+use crate::jdk_types::jdk_supertypes;
 use crate::rules::{Rule, RuleMetadata};
 use crate::telemetry::{Telemetry, with_span};
 
+/// Rule behavior switches configured from the command line and the scan config.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct RuleOptions {
+    /// Whether SYSTEM_EXIT ignores calls made from `main` entry points.
+    pub(crate) allow_exit_in_main: bool,
+    pub(crate) magic_number: MagicNumberOptions,
+    pub(crate) exception_cause: ExceptionCauseOptions,
+}
+
+impl Default for RuleOptions {
+    fn default() -> Self {
+        Self {
+            allow_exit_in_main: true,
+            magic_number: MagicNumberOptions::default(),
+            exception_cause: ExceptionCauseOptions::default(),
+        }
+    }
+}
+
+/// Operand stack depth EXCEPTION_CAUSE_NOT_PRESERVED tracks unless configured otherwise.
+pub(crate) const DEFAULT_EXCEPTION_CAUSE_MAX_STACK_DEPTH: usize = 24;
+
+/// Exception allocations EXCEPTION_CAUSE_NOT_PRESERVED tracks at once unless configured otherwise.
+pub(crate) const DEFAULT_EXCEPTION_CAUSE_MAX_ALLOCATIONS: usize = 4;
+
+/// EXCEPTION_CAUSE_NOT_PRESERVED simulation budgets. Values deeper in the stack or allocations
+/// beyond the budget are forgotten, so raising them finds more issues in large handlers at the
+/// cost of analysis time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct ExceptionCauseOptions {
+    pub(crate) max_stack_depth: usize,
+    pub(crate) max_allocations: usize,
+}
+
+impl Default for ExceptionCauseOptions {
+    fn default() -> Self {
+        Self {
+            max_stack_depth: DEFAULT_EXCEPTION_CAUSE_MAX_STACK_DEPTH,
+            max_allocations: DEFAULT_EXCEPTION_CAUSE_MAX_ALLOCATIONS,
+        }
+    }
+}
+
+/// Largest power of two MAGIC_NUMBER accepts unless configured otherwise.
+pub(crate) const DEFAULT_MAGIC_NUMBER_MAX_POWER_OF_TWO: i64 = 1024;
+
+/// MAGIC_NUMBER settings that extend its built-in allowlist.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct MagicNumberOptions {
+    /// Integer values accepted in addition to the built-in ones.
+    pub(crate) allowlist: Vec<i64>,
+    /// Floating-point values accepted in addition to `0.0` and `1.0`.
+    pub(crate) float_allowlist: Vec<f64>,
+    /// Largest power of two that is accepted.
+    pub(crate) max_power_of_two: i64,
+}
+
+impl Default for MagicNumberOptions {
+    fn default() -> Self {
+        Self {
+            allowlist: Vec::new(),
+            float_allowlist: Vec::new(),
+            max_power_of_two: DEFAULT_MAGIC_NUMBER_MAX_POWER_OF_TWO,
+        }
+    }
+}
+
 /// Inputs shared by analysis rules.
 pub(crate) struct AnalysisContext {
-    analysis_target_classes: Vec<Class>,
-    dependency_classes: Vec<Class>,
-    class_artifact_uri_cache: BTreeMap<i64, BTreeMap<String, String>>,
+    analysis_target_classes: Arc<Vec<Class>>,
+    dependency_classes: Arc<Vec<Class>>,
+    class_artifact_uri_cache: Arc<BTreeMap<i64, BTreeMap<String, String>>>,
+    modules: Arc<Vec<ModuleDescriptor>>,
+    module_artifact_uris: Arc<BTreeMap<i64, String>>,
+    focus: Focus,
     telemetry: Option<Arc<Telemetry>>,
     has_slf4j: bool,
     has_log4j2: bool,
     has_koin: bool,
+    rule_options: Arc<RuleOptions>,
+    /// Direct supertypes (superclass, then interfaces) of every loaded class, by class name.
+    type_hierarchy: Arc<BTreeMap<String, Vec<String>>>,
+    /// Position of every loaded class, by class name, so rules look classes up without
+    /// indexing `all_classes` on each run.
+    class_index: Arc<BTreeMap<String, ClassSlot>>,
+}
+
+/// Position of a loaded class in `AnalysisContext`.
+#[derive(Clone, Copy, Debug)]
+enum ClassSlot {
+    Target(usize),
+    Dependency(usize),
+}
+
+/// Analysis targets an `AnalysisContext` exposes to rules.
+#[derive(Clone, Copy, Debug)]
+enum Focus {
+    /// Every analysis target class and module declaration.
+    All,
+    /// Only the analysis target class at this index.
+    Class(usize),
+    /// Only module declarations, for the pass that follows per-class runs.
+    Modules,
 }
 
 /// Timing breakdown for context construction.
@@ -34,9 +138,15 @@ pub(crate) struct ContextTimings {
     pub(crate) call_graph_edges_duration_ms: u128,
 }
 
+/// Callback told how many (rule, analysis target class) pairs are done, as `(done, total)`.
+pub(crate) type ProgressHook = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
 /// Analysis engine that executes configured rules.
 pub(crate) struct Engine {
     rules: Vec<Box<dyn Rule + Sync>>,
+    class_timeout: Option<Duration>,
+    result_cache: Option<ResultCache>,
+    progress: Option<ProgressHook>,
 }
 
 impl Engine {
@@ -58,82 +168,559 @@ impl Engine {
                 anyhow::bail!("unknown rule ID(s) in --rules: {}", unknown_ids.join(", "));
             }
             rules.retain(|rule| allowed.contains(rule.metadata().id));
+        } else {
+            rules.retain(|rule| rule.enabled_by_default());
         }
         rules.sort_by_key(|a| a.metadata().id);
-        Ok(Self { rules })
+        Ok(Self {
+            rules,
+            class_timeout: None,
+            result_cache: None,
+            progress: None,
+        })
+    }
+
+    /// Drops the rules named in `excluded_rule_ids`, e.g. from `--exclude-rules`.
+    pub(crate) fn without_rule_ids(mut self, excluded_rule_ids: &BTreeSet<String>) -> Result<Self> {
+        if excluded_rule_ids.is_empty() {
+            return Ok(self);
+        }
+        let available_ids: BTreeSet<String> = crate::rules::all_rules()
+            .iter()
+            .map(|rule| rule.metadata().id.to_string())
+            .collect();
+        let unknown_ids: Vec<String> = excluded_rule_ids
+            .iter()
+            .filter(|id| !available_ids.contains(*id))
+            .cloned()
+            .collect();
+        if !unknown_ids.is_empty() {
+            anyhow::bail!(
+                "unknown rule ID(s) in --exclude-rules: {}",
+                unknown_ids.join(", ")
+            );
+        }
+        self.rules
+            .retain(|rule| !excluded_rule_ids.contains(rule.metadata().id));
+        Ok(self)
+    }
+
+    /// Sets the wall-clock budget per analysis target class; `None` means unlimited.
+    ///
+    /// The budget is only checked between rules, so a rule that stalls on a class is never
+    /// interrupted; it only keeps the remaining rules from starting.
+    pub(crate) fn with_class_timeout(mut self, class_timeout: Option<Duration>) -> Self {
+        self.class_timeout = class_timeout;
+        self
+    }
+
+    /// Reuses results from `result_cache` for unchanged classes and runs rules one class at a
+    /// time; `None` runs every rule on every class.
+    pub(crate) fn with_result_cache(mut self, result_cache: Option<ResultCache>) -> Self {
+        self.result_cache = result_cache;
+        self
     }
 
+    /// Calls `progress` whenever a rule finishes on every class, or with a class budget or
+    /// result cache, whenever a class finishes; `None` reports no progress.
+    pub(crate) fn with_progress(mut self, progress: Option<ProgressHook>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// IDs of the rules this engine runs, in execution order.
+    pub(crate) fn rule_ids(&self) -> Vec<String> {
+        self.rules
+            .iter()
+            .map(|rule| rule.metadata().id.to_string())
+            .collect()
+    }
+
+    /// Runs the rules and returns their results in the order described by `compare_results`,
+    /// which does not depend on rule scheduling or on the order of the input classes.
     pub(crate) fn analyze(&self, context: AnalysisContext) -> Result<EngineOutput> {
+        let mut results = Vec::new();
+        let summary = self.analyze_streaming(context, |result| {
+            results.push(result);
+            Ok(())
+        })?;
+
+        results.sort_by(compare_results);
+
+        Ok(summary.into_output(results))
+    }
+
+    /// Runs the rules and hands each result to `on_result` as soon as its rule (or, with a
+    /// class budget or result cache, its class) finishes, instead of collecting all results.
+    /// Results arrive in no particular order; an error from `on_result` stops the run.
+    pub(crate) fn analyze_streaming<F>(
+        &self,
+        context: AnalysisContext,
+        mut on_result: F,
+    ) -> Result<EngineSummary>
+    where
+        F: FnMut(SarifResult) -> Result<()>,
+    {
+        let per_class = self.class_timeout.is_some() || self.result_cache.is_some();
+        let mut class_runs = if per_class {
+            self.run_rules_per_class(&context, &mut on_result)?
+        } else {
+            ClassRunsOutput {
+                rule_outputs: self.run_rules(&context, &mut on_result)?,
+                skipped_classes: Vec::new(),
+                cached_classes: Vec::new(),
+                result_cache: None,
+            }
+        };
+
+        class_runs
+            .rule_outputs
+            .sort_by(|left, right| left.id.cmp(&right.id));
+        let mut rules = Vec::with_capacity(class_runs.rule_outputs.len());
+        let mut rule_timings = Vec::with_capacity(class_runs.rule_outputs.len());
+        for output in class_runs.rule_outputs {
+            rules.push(output.descriptor);
+            rule_timings.push(output.timing);
+        }
+
+        Ok(EngineSummary {
+            rules,
+            rule_timings,
+            skipped_classes: class_runs.skipped_classes,
+            cached_classes: class_runs.cached_classes,
+            result_cache: class_runs.result_cache,
+        })
+    }
+
+    fn run_rules(
+        &self,
+        context: &AnalysisContext,
+        on_result: &mut dyn FnMut(SarifResult) -> Result<()>,
+    ) -> Result<Vec<RuleOutput>> {
         let parent_context = OtelContext::current();
-        let mut rule_outputs: Vec<RuleOutput> = self
-            .rules
-            .par_iter()
-            .map(|rule| {
+        let mut rule_outputs = Vec::with_capacity(self.rules.len());
+        let class_count = context.analysis_target_classes().len();
+        let total_units = self.rules.len() * class_count;
+        let mut done_units = 0;
+        stream_parallel(
+            &self.rules,
+            |rule| {
                 let metadata = rule.metadata();
                 let rule_span_attributes = [KeyValue::new("inspequte.rule_id", metadata.id)];
-                let mut rule_results = match context.telemetry() {
+                let started_at = Instant::now();
+                let rule_results = match context.telemetry() {
                     Some(telemetry) => telemetry.in_span_with_parent(
                         &format!("rule:{}", metadata.id),
                         &rule_span_attributes,
                         &parent_context,
-                        || rule.run(&context),
-                    )?,
-                    None => rule.run(&context)?,
+                        || rule.run(context),
+                    ),
+                    None => rule.run(context),
                 };
-                for result in &mut rule_results {
-                    if result.rule_id.is_none() {
-                        result.rule_id = Some(metadata.id.to_string());
+                (metadata, started_at.elapsed(), rule_results)
+            },
+            |(metadata, duration, rule_results)| {
+                let mut rule_results = rule_results?;
+                fill_result_defaults(&metadata, &mut rule_results);
+                rule_outputs.push(rule_output(
+                    context,
+                    &metadata,
+                    duration,
+                    rule_results.len(),
+                ));
+                done_units += class_count;
+                self.report_progress(done_units, total_units);
+                rule_results.into_iter().try_for_each(&mut *on_result)
+            },
+        )?;
+        Ok(rule_outputs)
+    }
+
+    /// Runs all rules one analysis target class at a time. Classes found unchanged in the
+    /// result cache reuse their cached results. Once a class has used up the class budget,
+    /// its remaining rules are skipped and the class name is reported as skipped.
+    fn run_rules_per_class(
+        &self,
+        context: &AnalysisContext,
+        on_result: &mut dyn FnMut(SarifResult) -> Result<()>,
+    ) -> Result<ClassRunsOutput> {
+        let rule_indices: BTreeMap<&str, usize> = self
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| (rule.metadata().id, index))
+            .collect();
+        let mut durations = vec![Duration::ZERO; self.rules.len()];
+        let mut finding_counts = vec![0; self.rules.len()];
+        let mut skipped_classes = Vec::new();
+        let mut cached_classes = Vec::new();
+        let mut result_cache = self.result_cache.as_ref().map(ResultCache::emptied);
+        let total_units = self.rules.len() * context.analysis_target_classes.len();
+        let mut done_units = 0;
+        stream_parallel(
+            0..context.analysis_target_classes.len(),
+            |index| {
+                let class = &context.analysis_target_classes[index];
+                let dependency_digest = self
+                    .result_cache
+                    .as_ref()
+                    .map(|_| class_dependency_digest(context, class));
+                let class_run =
+                    self.run_rules_for_class(context, index, dependency_digest.as_deref());
+                (index, dependency_digest, class_run)
+            },
+            |(class_index, dependency_digest, class_run)| {
+                let class = &context.analysis_target_classes[class_index];
+                let (class_results, complete) = match class_run? {
+                    ClassRun::Cached(results) => {
+                        cached_classes.push(class.name.clone());
+                        (results, true)
+                    }
+                    ClassRun::Executed {
+                        rule_runs,
+                        complete,
+                    } => {
+                        if !complete {
+                            skipped_classes.push(class.name.clone());
+                        }
+                        let mut class_results = Vec::new();
+                        for (index, (duration, results)) in rule_runs.into_iter().enumerate() {
+                            durations[index] += duration;
+                            class_results.extend(results);
+                        }
+                        (class_results, complete)
+                    }
+                };
+                // Partial runs are not cached so the next run analyzes the class again.
+                if complete
+                    && let (Some(cache), Some(dependency_digest)) =
+                        (result_cache.as_mut(), dependency_digest)
+                {
+                    cache.insert(
+                        class.name.clone(),
+                        CachedClass {
+                            dependency_digest,
+                            artifact_uri: context.class_artifact_uri(class),
+                            results: class_results.clone(),
+                        },
+                    );
+                }
+                for result in class_results {
+                    let rule_index = result
+                        .rule_id
+                        .as_deref()
+                        .and_then(|rule_id| rule_indices.get(rule_id));
+                    if let Some(&rule_index) = rule_index {
+                        finding_counts[rule_index] += 1;
+                        on_result(result)?;
                     }
                 }
-                Ok(RuleOutput {
-                    id: metadata.id.to_string(),
-                    descriptor: rule_descriptor(&metadata),
-                    results: rule_results,
-                })
+                done_units += self.rules.len();
+                self.report_progress(done_units, total_units);
+                Ok(())
+            },
+        )?;
+        skipped_classes.sort();
+        cached_classes.sort();
+
+        // Per-class views hide module declarations, so rules see them once in a separate pass.
+        // Its results are not cached because they belong to no class.
+        if !context.modules.is_empty() {
+            let module_context = context.with_focus(Focus::Modules);
+            for (index, rule) in self.rules.iter().enumerate() {
+                let metadata = rule.metadata();
+                let started_at = Instant::now();
+                let mut rule_results = rule.run(&module_context)?;
+                fill_result_defaults(&metadata, &mut rule_results);
+                durations[index] += started_at.elapsed();
+                finding_counts[index] += rule_results.len();
+                rule_results.into_iter().try_for_each(&mut *on_result)?;
+            }
+        }
+
+        let rule_outputs = self
+            .rules
+            .iter()
+            .zip(durations.into_iter().zip(finding_counts))
+            .map(|(rule, (duration, finding_count))| {
+                rule_output(context, &rule.metadata(), duration, finding_count)
             })
-            .collect::<Result<Vec<_>>>()?;
+            .collect();
+        Ok(ClassRunsOutput {
+            rule_outputs,
+            skipped_classes,
+            cached_classes,
+            result_cache,
+        })
+    }
 
-        rule_outputs.sort_by(|left, right| left.id.cmp(&right.id));
-        let mut rules = Vec::with_capacity(rule_outputs.len());
-        let mut results = Vec::new();
-        for output in rule_outputs {
-            rules.push(output.descriptor);
-            results.extend(output.results);
+    fn report_progress(&self, done_units: usize, total_units: usize) {
+        if total_units > 0
+            && let Some(progress) = &self.progress
+        {
+            progress(done_units, total_units);
         }
+    }
+
+    fn run_rules_for_class(
+        &self,
+        context: &AnalysisContext,
+        index: usize,
+        dependency_digest: Option<&str>,
+    ) -> Result<ClassRun> {
+        let class = &context.analysis_target_classes[index];
+        if let (Some(cache), Some(dependency_digest)) = (&self.result_cache, dependency_digest) {
+            let artifact_uri = context.class_artifact_uri(class);
+            if let Some(results) =
+                cache.get(&class.name, dependency_digest, artifact_uri.as_deref())
+            {
+                return Ok(ClassRun::Cached(results.to_vec()));
+            }
+        }
+        let class_context = context.focused_on(index);
+        let started_at = Instant::now();
+        let mut rule_runs = Vec::with_capacity(self.rules.len());
+        for rule in &self.rules {
+            if let Some(budget) = self.class_timeout
+                && started_at.elapsed() > budget
+            {
+                warn!(
+                    "{} exceeded the {}ms class budget; skipped {} of {} rules",
+                    class.name,
+                    budget.as_millis(),
+                    self.rules.len() - rule_runs.len(),
+                    self.rules.len()
+                );
+                return Ok(ClassRun::Executed {
+                    rule_runs,
+                    complete: false,
+                });
+            }
+            let metadata = rule.metadata();
+            let rule_started_at = Instant::now();
+            let mut rule_results = rule.run(&class_context)?;
+            fill_result_defaults(&metadata, &mut rule_results);
+            rule_runs.push((rule_started_at.elapsed(), rule_results));
+        }
+        Ok(ClassRun::Executed {
+            rule_runs,
+            complete: true,
+        })
+    }
+}
 
-        results.sort_by(|left, right| {
-            let left_id = left.rule_id.as_deref().unwrap_or("");
-            let right_id = right.rule_id.as_deref().unwrap_or("");
-            let left_msg = left.message.text.as_deref().unwrap_or("").to_string();
-            let right_msg = right.message.text.as_deref().unwrap_or("").to_string();
-            left_id.cmp(right_id).then(left_msg.cmp(&right_msg))
+/// Rule outputs and per-class bookkeeping from one engine run.
+struct ClassRunsOutput {
+    rule_outputs: Vec<RuleOutput>,
+    skipped_classes: Vec<String>,
+    cached_classes: Vec<String>,
+    result_cache: Option<ResultCache>,
+}
+
+/// Outcome of running the rules for one analysis target class.
+enum ClassRun {
+    /// Results reused from the result cache.
+    Cached(Vec<SarifResult>),
+    /// Per-rule durations and results in rule order. `complete` is false when the class
+    /// budget cut the run short.
+    Executed {
+        rule_runs: Vec<(Duration, Vec<SarifResult>)>,
+        complete: bool,
+    },
+}
+
+/// Records the rule's metrics and builds its descriptor and timing.
+fn rule_output(
+    context: &AnalysisContext,
+    metadata: &RuleMetadata,
+    duration: Duration,
+    finding_count: usize,
+) -> RuleOutput {
+    if let Some(telemetry) = context.telemetry() {
+        telemetry.record_rule_metrics(metadata.id, duration, finding_count);
+    }
+    RuleOutput {
+        id: metadata.id.to_string(),
+        descriptor: rule_descriptor(metadata),
+        timing: RuleTiming {
+            rule_id: metadata.id.to_string(),
+            duration,
+            finding_count,
+        },
+    }
+}
+
+/// Runs `produce` for every item on the rayon pool and passes each output to `consume` on the
+/// calling thread as soon as it is ready. Stops consuming at the first error; outputs
+/// produced after that are dropped.
+fn stream_parallel<I, T, P, C>(items: I, produce: P, mut consume: C) -> Result<()>
+where
+    I: IntoParallelIterator + Send,
+    T: Send,
+    P: Fn(I::Item) -> T + Sync + Send,
+    C: FnMut(T) -> Result<()>,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            items.into_par_iter().for_each_with(sender, |sender, item| {
+                // The receiver is gone only after `consume` failed; nothing is waiting then.
+                let _ = sender.send(produce(item));
+            });
         });
+        receiver.into_iter().try_for_each(&mut consume)
+    })
+}
 
-        Ok(EngineOutput { rules, results })
+/// Sets the rule ID, level, and rank from `metadata` where a result leaves them unset.
+fn fill_result_defaults(metadata: &RuleMetadata, results: &mut [SarifResult]) {
+    for result in results {
+        if result.rule_id.is_none() {
+            result.rule_id = Some(metadata.id.to_string());
+        }
+        if result.level.is_none() {
+            result.level = Some(json!(metadata.severity.as_str()));
+        }
+        if result.rank.is_none() {
+            result.rank = metadata.rank.map(f64::from);
+        }
     }
 }
 
 struct RuleOutput {
     id: String,
     descriptor: ReportingDescriptor,
-    results: Vec<SarifResult>,
+    timing: RuleTiming,
+}
+
+/// Execution cost of a single rule.
+#[derive(Clone, Debug)]
+pub(crate) struct RuleTiming {
+    pub(crate) rule_id: String,
+    pub(crate) duration: Duration,
+    pub(crate) finding_count: usize,
+}
+
+/// Run-level output of a streaming engine run; the results went to the callback.
+pub(crate) struct EngineSummary {
+    pub(crate) rules: Vec<ReportingDescriptor>,
+    /// Per-rule timings ordered by rule ID.
+    pub(crate) rule_timings: Vec<RuleTiming>,
+    /// Sorted names of classes whose remaining rules were skipped by the class budget.
+    pub(crate) skipped_classes: Vec<String>,
+    /// Sorted names of classes whose results were reused from the result cache.
+    pub(crate) cached_classes: Vec<String>,
+    /// Results of every fully analyzed class in this run, when a result cache was given.
+    pub(crate) result_cache: Option<ResultCache>,
+}
+
+impl EngineSummary {
+    /// Combines this summary with the results collected from the run.
+    pub(crate) fn into_output(self, results: Vec<SarifResult>) -> EngineOutput {
+        EngineOutput {
+            rules: self.rules,
+            results,
+            rule_timings: self.rule_timings,
+            skipped_classes: self.skipped_classes,
+            cached_classes: self.cached_classes,
+            result_cache: self.result_cache,
+        }
+    }
 }
 
 /// Aggregated SARIF payload from rule execution.
 pub(crate) struct EngineOutput {
     pub(crate) rules: Vec<ReportingDescriptor>,
+    /// Results ordered by `compare_results`, so identical input yields identical output.
     pub(crate) results: Vec<SarifResult>,
+    /// Per-rule timings ordered by rule ID.
+    pub(crate) rule_timings: Vec<RuleTiming>,
+    /// Sorted names of classes whose remaining rules were skipped by the class budget.
+    pub(crate) skipped_classes: Vec<String>,
+    /// Sorted names of classes whose results were reused from the result cache.
+    pub(crate) cached_classes: Vec<String>,
+    /// Results of every fully analyzed class in this run, when a result cache was given.
+    pub(crate) result_cache: Option<ResultCache>,
+}
+
+/// Sort key of a result: rule ID, then the artifact URI, class, method, descriptor, and start
+/// line of its first location.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct ResultSortKey<'a> {
+    rule_id: &'a str,
+    artifact_uri: &'a str,
+    class_name: &'a str,
+    method_name: &'a str,
+    descriptor: &'a str,
+    line: Option<i64>,
+}
+
+impl<'a> ResultSortKey<'a> {
+    fn of(result: &'a SarifResult) -> Self {
+        let location = result.locations.iter().flatten().next();
+        let physical = location.and_then(|location| location.physical_location.as_ref());
+        let logical = location
+            .and_then(|location| location.logical_locations.as_ref())
+            .and_then(|logical| logical.first())
+            .and_then(|logical| logical.name.as_deref())
+            .unwrap_or("");
+        // Method locations are named `<class>.<method><descriptor>`; class names use `/`, so
+        // the first `.` separates the class. Class locations have no `.`.
+        let (class_name, member) = logical.split_once('.').unwrap_or((logical, ""));
+        let (method_name, descriptor) = member
+            .find('(')
+            .map(|index| member.split_at(index))
+            .unwrap_or((member, ""));
+        Self {
+            rule_id: result.rule_id.as_deref().unwrap_or(""),
+            artifact_uri: physical
+                .and_then(|physical| physical.artifact_location.as_ref())
+                .and_then(|artifact| artifact.uri.as_deref())
+                .unwrap_or(""),
+            class_name,
+            method_name,
+            descriptor,
+            line: physical
+                .and_then(|physical| physical.region.as_ref())
+                .and_then(|region| region.start_line),
+        }
+    }
+}
+
+/// Total order of results in `EngineOutput`: by `ResultSortKey`, then by message text, then by
+/// the serialized result.
+///
+/// Results carry no bytecode offset, so findings on the same line are told apart by their
+/// message; the serialized form only breaks ties between results that differ in other fields,
+/// such as code flows, so the order never depends on the order results were produced in.
+pub(crate) fn compare_results(left: &SarifResult, right: &SarifResult) -> std::cmp::Ordering {
+    ResultSortKey::of(left)
+        .cmp(&ResultSortKey::of(right))
+        .then_with(|| left.message.text.cmp(&right.message.text))
+        .then_with(|| {
+            let left = serde_json::to_string(left).unwrap_or_default();
+            let right = serde_json::to_string(right).unwrap_or_default();
+            left.cmp(&right)
+        })
 }
 
 #[cfg(test)]
 pub(crate) fn build_context(classes: Vec<Class>, artifacts: &[Artifact]) -> AnalysisContext {
-    let (context, _) = build_context_with_timings(classes, artifacts, None);
+    let (context, _) = build_context_with_timings(
+        classes,
+        Vec::new(),
+        artifacts,
+        &ClassFilter::default(),
+        None,
+    );
     context
 }
 
 pub(crate) fn build_context_with_timings(
     classes: Vec<Class>,
+    modules: Vec<ModuleDescriptor>,
     artifacts: &[Artifact],
+    class_filter: &ClassFilter,
     telemetry: Option<Arc<Telemetry>>,
 ) -> (AnalysisContext, ContextTimings) {
     let call_graph_duration_ms = 0;
@@ -145,35 +732,62 @@ pub(crate) fn build_context_with_timings(
         || analyze_artifacts(artifacts),
     );
     let (has_slf4j, has_log4j2, has_koin) = detect_known_frameworks(&classes, telemetry.as_deref());
-    let (analysis_target_classes, dependency_classes) =
-        partition_classes(classes, &analysis_target_artifacts, &artifact_parents);
+    let (analysis_target_classes, dependency_classes) = partition_classes(
+        classes,
+        &analysis_target_artifacts,
+        &artifact_parents,
+        class_filter,
+    );
     let class_artifact_uri_cache = build_class_artifact_uri_cache(
         &analysis_target_classes,
         &dependency_classes,
         &artifact_uris,
     );
+    let module_artifact_uris = modules
+        .iter()
+        .filter_map(|module| {
+            artifact_uris
+                .get(&module.artifact_index)
+                .map(|uri| (module.artifact_index, uri.clone()))
+        })
+        .collect();
     let artifact_duration_ms = artifact_started_at.elapsed().as_millis();
+    let hierarchy_started_at = Instant::now();
+    let type_hierarchy = with_span(
+        telemetry.as_deref(),
+        "type_hierarchy",
+        &[KeyValue::new("inspequte.phase", "type_hierarchy")],
+        || build_type_hierarchy(&analysis_target_classes, &dependency_classes),
+    );
+    let class_index = build_class_index(&analysis_target_classes, &dependency_classes);
+    let call_graph_hierarchy_duration_ms = hierarchy_started_at.elapsed().as_millis();
     let timings = ContextTimings {
         call_graph_duration_ms,
         artifact_duration_ms,
-        call_graph_hierarchy_duration_ms: 0,
+        call_graph_hierarchy_duration_ms,
         call_graph_index_duration_ms: 0,
         call_graph_edges_duration_ms: 0,
     };
     let context = AnalysisContext {
-        analysis_target_classes,
-        dependency_classes,
-        class_artifact_uri_cache,
+        analysis_target_classes: Arc::new(analysis_target_classes),
+        dependency_classes: Arc::new(dependency_classes),
+        class_artifact_uri_cache: Arc::new(class_artifact_uri_cache),
+        modules: Arc::new(modules),
+        module_artifact_uris: Arc::new(module_artifact_uris),
+        focus: Focus::All,
         telemetry,
         has_slf4j,
         has_log4j2,
         has_koin,
+        rule_options: Arc::new(RuleOptions::default()),
+        type_hierarchy: Arc::new(type_hierarchy),
+        class_index: Arc::new(class_index),
     };
     (context, timings)
 }
 
-fn rule_descriptor(metadata: &RuleMetadata) -> ReportingDescriptor {
-    ReportingDescriptor::builder()
+pub(crate) fn rule_descriptor(metadata: &RuleMetadata) -> ReportingDescriptor {
+    let mut descriptor = ReportingDescriptor::builder()
         .id(metadata.id)
         .name(metadata.name)
         .short_description(
@@ -181,12 +795,79 @@ fn rule_descriptor(metadata: &RuleMetadata) -> ReportingDescriptor {
                 .text(metadata.description)
                 .build(),
         )
-        .build()
+        .default_configuration(
+            ReportingConfiguration::builder()
+                .level(json!(metadata.severity.as_str()))
+                .build(),
+        )
+        .build();
+    if !metadata.tags.is_empty() || metadata.precision().is_some() {
+        let mut properties = BTreeMap::new();
+        if let Some(precision) = metadata.precision() {
+            properties.insert("precision".to_string(), json!(precision));
+        }
+        let tags = metadata.tags.iter().map(|tag| tag.to_string()).collect();
+        descriptor.properties = Some(
+            PropertyBag::builder()
+                .tags(tags)
+                .additional_properties(properties)
+                .build(),
+        );
+    }
+    descriptor
 }
 
 impl AnalysisContext {
     pub(crate) fn analysis_target_classes(&self) -> &[Class] {
-        &self.analysis_target_classes
+        match self.focus {
+            Focus::All => self.analysis_target_classes.as_slice(),
+            Focus::Class(index) => &self.analysis_target_classes[index..=index],
+            Focus::Modules => &[],
+        }
+    }
+
+    /// Module declarations read from `module-info.class` files among the inputs.
+    pub(crate) fn modules(&self) -> &[ModuleDescriptor] {
+        match self.focus {
+            Focus::All | Focus::Modules => self.modules.as_slice(),
+            Focus::Class(_) => &[],
+        }
+    }
+
+    pub(crate) fn module_artifact_uri(&self, module: &ModuleDescriptor) -> Option<String> {
+        self.module_artifact_uris
+            .get(&module.artifact_index)
+            .cloned()
+    }
+
+    /// Returns a view that exposes only the analysis target class at `index` to rules.
+    /// Other classes stay reachable through `all_classes`.
+    fn focused_on(&self, index: usize) -> AnalysisContext {
+        self.with_focus(Focus::Class(index))
+    }
+
+    fn with_focus(&self, focus: Focus) -> AnalysisContext {
+        AnalysisContext {
+            analysis_target_classes: Arc::clone(&self.analysis_target_classes),
+            dependency_classes: Arc::clone(&self.dependency_classes),
+            class_artifact_uri_cache: Arc::clone(&self.class_artifact_uri_cache),
+            modules: Arc::clone(&self.modules),
+            module_artifact_uris: Arc::clone(&self.module_artifact_uris),
+            focus,
+            telemetry: self.telemetry.clone(),
+            has_slf4j: self.has_slf4j,
+            has_log4j2: self.has_log4j2,
+            has_koin: self.has_koin,
+            rule_options: Arc::clone(&self.rule_options),
+            type_hierarchy: Arc::clone(&self.type_hierarchy),
+            class_index: Arc::clone(&self.class_index),
+        }
+    }
+
+    /// Replaces the rule options, which default to `RuleOptions::default()`.
+    pub(crate) fn with_rule_options(mut self, rule_options: RuleOptions) -> Self {
+        self.rule_options = Arc::new(rule_options);
+        self
     }
 
     #[allow(dead_code)]
@@ -200,6 +881,15 @@ impl AnalysisContext {
             .chain(self.dependency_classes.iter())
     }
 
+    /// Returns the loaded class named `name`, preferring an analysis target over a dependency
+    /// of the same name.
+    pub(crate) fn class_by_name(&self, name: &str) -> Option<&Class> {
+        match self.class_index.get(name)? {
+            ClassSlot::Target(index) => self.analysis_target_classes.get(*index),
+            ClassSlot::Dependency(index) => self.dependency_classes.get(*index),
+        }
+    }
+
     pub(crate) fn telemetry(&self) -> Option<&Telemetry> {
         self.telemetry.as_deref()
     }
@@ -229,6 +919,73 @@ impl AnalysisContext {
     pub(crate) fn has_koin(&self) -> bool {
         self.has_koin
     }
+
+    pub(crate) fn rule_options(&self) -> &RuleOptions {
+        &self.rule_options
+    }
+
+    /// Returns true when `owner` is `supertype` or extends or implements it, directly or
+    /// transitively, through the loaded classes.
+    ///
+    /// Types that were not loaded continue through the common JDK supertypes in
+    /// `jdk_types`, so JDK throwables and serializable types resolve without the JDK on the
+    /// classpath; any other type outside the scanned classes is only matched by name.
+    pub(crate) fn is_subtype_of(&self, owner: &str, supertype: &str) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut pending = vec![owner];
+        while let Some(current) = pending.pop() {
+            if current == supertype {
+                return true;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            match self.type_hierarchy.get(current) {
+                Some(parents) => pending.extend(parents.iter().map(String::as_str)),
+                None => pending.extend(jdk_supertypes(current).iter().copied()),
+            }
+        }
+        false
+    }
+}
+
+fn build_type_hierarchy(
+    analysis_target_classes: &[Class],
+    dependency_classes: &[Class],
+) -> BTreeMap<String, Vec<String>> {
+    let mut type_hierarchy = BTreeMap::new();
+    for class in analysis_target_classes
+        .iter()
+        .chain(dependency_classes.iter())
+    {
+        let parents = class
+            .super_name
+            .iter()
+            .chain(class.interfaces.iter())
+            .cloned()
+            .collect();
+        type_hierarchy.entry(class.name.clone()).or_insert(parents);
+    }
+    type_hierarchy
+}
+
+fn build_class_index(
+    analysis_target_classes: &[Class],
+    dependency_classes: &[Class],
+) -> BTreeMap<String, ClassSlot> {
+    let mut class_index = BTreeMap::new();
+    let targets = analysis_target_classes
+        .iter()
+        .enumerate()
+        .map(|(index, class)| (class, ClassSlot::Target(index)));
+    let dependencies = dependency_classes
+        .iter()
+        .enumerate()
+        .map(|(index, class)| (class, ClassSlot::Dependency(index)));
+    for (class, slot) in targets.chain(dependencies) {
+        class_index.entry(class.name.clone()).or_insert(slot);
+    }
+    class_index
 }
 
 fn build_class_artifact_uri_cache(
@@ -486,19 +1243,19 @@ fn partition_classes(
     classes: Vec<Class>,
     analysis_target_artifacts: &BTreeSet<i64>,
     artifact_parents: &BTreeMap<i64, i64>,
+    class_filter: &ClassFilter,
 ) -> (Vec<Class>, Vec<Class>) {
-    if analysis_target_artifacts.is_empty() {
-        return (classes, Vec::new());
-    }
-
     let mut analysis_target_classes = Vec::new();
     let mut dependency_classes = Vec::new();
     for class in classes {
-        if is_analysis_target_artifact(
-            class.artifact_index,
-            analysis_target_artifacts,
-            artifact_parents,
-        ) {
+        // Classes filtered out stay loaded as dependencies so hierarchy lookups still see them.
+        let in_target_artifact = analysis_target_artifacts.is_empty()
+            || is_analysis_target_artifact(
+                class.artifact_index,
+                analysis_target_artifacts,
+                artifact_parents,
+            );
+        if in_target_artifact && class_filter.matches_class(&class) {
             analysis_target_classes.push(class);
         } else {
             dependency_classes.push(class);
@@ -529,9 +1286,11 @@ mod tests {
     use std::path::{Path, PathBuf};
     use std::time::{SystemTime, UNIX_EPOCH};
 
+    use crate::cache::{CacheKey, load_result_cache, write_result_cache};
     use crate::ir::{Field, FieldAccess};
+    use crate::rules::{Severity, method_location_with_line};
     use serde_json::json;
-    use serde_sarif::sarif::{ArtifactLocation, ArtifactRoles};
+    use serde_sarif::sarif::{ArtifactLocation, ArtifactRoles, Message};
 
     use super::*;
 
@@ -548,6 +1307,148 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
+        }
+    }
+
+    /// Test rule that reports every analysis target class and module it sees and stalls on
+    /// `stall_on`.
+    struct ClassEchoRule {
+        id: &'static str,
+        stall_on: Option<&'static str>,
+    }
+
+    impl Rule for ClassEchoRule {
+        fn metadata(&self) -> RuleMetadata {
+            RuleMetadata {
+                id: self.id,
+                name: "Class echo",
+                description: "Reports every analysis target class",
+                severity: Severity::Note,
+                rank: None,
+                tags: &[],
+            }
+        }
+
+        fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+            let mut results = Vec::new();
+            for class in context.analysis_target_classes() {
+                if self.stall_on == Some(class.name.as_str()) {
+                    std::thread::sleep(Duration::from_millis(200));
+                }
+                results.push(
+                    SarifResult::builder()
+                        .message(Message::builder().text(class.name.clone()).build())
+                        .build(),
+                );
+            }
+            for module in context.modules() {
+                results.push(
+                    SarifResult::builder()
+                        .message(Message::builder().text(module.name.clone()).build())
+                        .build(),
+                );
+            }
+            Ok(results)
+        }
+    }
+
+    /// Test rule that reports the content hash of each analysis target class's superclass.
+    struct SuperclassHashRule;
+
+    impl Rule for SuperclassHashRule {
+        fn metadata(&self) -> RuleMetadata {
+            RuleMetadata {
+                id: "SUPERCLASS_HASH",
+                name: "Superclass hash",
+                description: "Reports the content hash of every superclass",
+                severity: Severity::Note,
+                rank: None,
+                tags: &[],
+            }
+        }
+
+        fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+            Ok(context
+                .analysis_target_classes()
+                .iter()
+                .filter_map(|class| {
+                    let superclass = context.class_by_name(class.super_name.as_deref()?)?;
+                    let message = format!("{} extends {}", class.name, superclass.content_hash);
+                    Some(
+                        SarifResult::builder()
+                            .message(Message::builder().text(message).build())
+                            .build(),
+                    )
+                })
+                .collect())
+        }
+    }
+
+    /// Test rule that records the address of the class index each run sees.
+    struct IndexProbeRule {
+        seen: Arc<std::sync::Mutex<Vec<usize>>>,
+    }
+
+    impl Rule for IndexProbeRule {
+        fn metadata(&self) -> RuleMetadata {
+            RuleMetadata {
+                id: "INDEX_PROBE",
+                name: "Index probe",
+                description: "Records the class index of every run",
+                severity: Severity::Note,
+                rank: None,
+                tags: &[],
+            }
+        }
+
+        fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+            self.seen
+                .lock()
+                .expect("seen indexes")
+                .push(Arc::as_ptr(&context.class_index) as usize);
+            Ok(Vec::new())
+        }
+    }
+
+    /// Test rule that reports `methodY` before `methodX` of every analysis target class, with
+    /// the same message, so only their locations tell the results apart.
+    struct LocatedEchoRule {
+        id: &'static str,
+    }
+
+    impl Rule for LocatedEchoRule {
+        fn metadata(&self) -> RuleMetadata {
+            RuleMetadata {
+                id: self.id,
+                name: "Located echo",
+                description: "Reports two methods of every analysis target class",
+                severity: Severity::Note,
+                rank: None,
+                tags: &[],
+            }
+        }
+
+        fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+            let mut results = Vec::new();
+            for class in context.analysis_target_classes() {
+                for (method, line) in [("methodY", 20), ("methodX", 10)] {
+                    results.push(
+                        SarifResult::builder()
+                            .message(Message::builder().text("finding").build())
+                            .locations(vec![method_location_with_line(
+                                &class.name,
+                                method,
+                                "()V",
+                                None,
+                                Some(line),
+                            )])
+                            .build(),
+                    );
+                }
+            }
+            Ok(results)
         }
     }
 
@@ -565,6 +1466,24 @@ mod tests {
         format!("file://{}", path.to_string_lossy())
     }
 
+    #[test]
+    fn rule_descriptor_publishes_tags_and_precision() {
+        let metadata = crate::rules::all_rules()
+            .into_iter()
+            .map(|rule| rule.metadata())
+            .find(|metadata| metadata.id == "LOCK_NOT_RELEASED_ON_EXCEPTION_PATH")
+            .expect("lock rule registered");
+
+        let descriptor = rule_descriptor(&metadata);
+        let properties = descriptor.properties.expect("properties");
+
+        assert_eq!(properties.tags, Some(vec!["concurrency".to_string()]));
+        assert_eq!(
+            properties.additional_properties.get("precision"),
+            Some(&json!("high"))
+        );
+    }
+
     #[test]
     fn build_context_partitions_analysis_target_and_dependency_classes() {
         let classes = vec![
@@ -617,6 +1536,161 @@ mod tests {
         assert_eq!(dependency_names, vec!["com/example/ClassB"]);
     }
 
+    #[test]
+    fn build_context_keeps_filtered_out_classes_as_dependencies() {
+        let classes = vec![
+            class_with_artifact("com/example/ClassA", 0),
+            class_with_artifact("com/example/generated/ClassB", 0),
+            class_with_artifact("org/other/ClassC", 0),
+            class_with_artifact("com/example/ClassD", 1),
+        ];
+        let artifacts = vec![
+            Artifact::builder()
+                .location(
+                    ArtifactLocation::builder()
+                        .uri("file:///tmp/app.jar".to_string())
+                        .build(),
+                )
+                .roles(vec![json!(ArtifactRoles::AnalysisTarget)])
+                .build(),
+            Artifact::builder()
+                .location(
+                    ArtifactLocation::builder()
+                        .uri("file:///tmp/lib.jar".to_string())
+                        .build(),
+                )
+                .build(),
+        ];
+        let class_filter = ClassFilter::new(
+            vec!["com/example/**".to_string()],
+            vec!["**/generated/**".to_string()],
+        );
+
+        let (context, _) =
+            build_context_with_timings(classes, Vec::new(), &artifacts, &class_filter, None);
+        let analysis_target_names = context
+            .analysis_target_classes()
+            .iter()
+            .map(|class| class.name.as_str())
+            .collect::<Vec<_>>();
+        let dependency_names = context
+            .dependency_classes()
+            .iter()
+            .map(|class| class.name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(analysis_target_names, vec!["com/example/ClassA"]);
+        assert_eq!(
+            dependency_names,
+            vec![
+                "com/example/generated/ClassB",
+                "org/other/ClassC",
+                "com/example/ClassD"
+            ]
+        );
+    }
+
+    #[test]
+    fn build_context_limits_targets_to_changed_source_files() {
+        let mut class_a = class_with_artifact("com/example/ClassA", 0);
+        class_a.source_file = Some("ClassA.java".to_string());
+        let mut class_b = class_with_artifact("com/example/ClassB", 0);
+        class_b.source_file = Some("ClassB.java".to_string());
+        let class_filter = ClassFilter::default().with_changed_files(Some(vec![
+            "src/main/java/com/example/ClassB.java".to_string(),
+            "src/main/java/com/example/Removed.java".to_string(),
+        ]));
+
+        let (context, _) = build_context_with_timings(
+            vec![class_a, class_b],
+            Vec::new(),
+            &[],
+            &class_filter,
+            None,
+        );
+
+        let analysis_target_names = context
+            .analysis_target_classes()
+            .iter()
+            .map(|class| class.name.as_str())
+            .collect::<Vec<_>>();
+        let dependency_names = context
+            .dependency_classes()
+            .iter()
+            .map(|class| class.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(analysis_target_names, vec!["com/example/ClassB"]);
+        assert_eq!(dependency_names, vec!["com/example/ClassA"]);
+    }
+
+    #[test]
+    fn is_subtype_of_follows_superclasses_and_interfaces_transitively() {
+        let mut base = class_with_artifact("com/example/BaseTask", 0);
+        base.super_name = Some("java/lang/Object".to_string());
+        base.interfaces = vec!["com/example/TaskFuture".to_string()];
+        let mut task_future = class_with_artifact("com/example/TaskFuture", 0);
+        task_future.super_name = Some("java/lang/Object".to_string());
+        task_future.interfaces = vec!["java/util/concurrent/Future".to_string()];
+        let mut task = class_with_artifact("com/example/Task", 0);
+        task.super_name = Some("com/example/BaseTask".to_string());
+
+        let (context, _) = build_context_with_timings(
+            vec![task, base, task_future],
+            Vec::new(),
+            &[],
+            &ClassFilter::default(),
+            None,
+        );
+
+        assert!(context.is_subtype_of("com/example/Task", "com/example/Task"));
+        assert!(context.is_subtype_of("com/example/Task", "com/example/BaseTask"));
+        assert!(context.is_subtype_of("com/example/Task", "java/util/concurrent/Future"));
+        assert!(context.is_subtype_of("com/example/TaskFuture", "java/lang/Object"));
+        assert!(!context.is_subtype_of("com/example/BaseTask", "com/example/Task"));
+        assert!(!context.is_subtype_of("com/example/Task", "java/lang/Runnable"));
+    }
+
+    #[test]
+    fn is_subtype_of_falls_back_to_known_jdk_supertypes() {
+        let mut custom = class_with_artifact("com/example/ClassA", 0);
+        custom.super_name = Some("java/io/FileNotFoundException".to_string());
+        let context = build_context(vec![custom], &[]);
+
+        assert!(context.is_subtype_of("com/example/ClassA", "java/io/IOException"));
+        assert!(context.is_subtype_of("com/example/ClassA", "java/io/Serializable"));
+        assert!(context.is_subtype_of(
+            "java/lang/NumberFormatException",
+            "java/lang/RuntimeException"
+        ));
+        assert!(context.is_subtype_of("java/lang/Integer", "java/io/Serializable"));
+        assert!(!context.is_subtype_of("com/example/ClassA", "java/lang/RuntimeException"));
+    }
+
+    #[test]
+    fn is_subtype_of_only_matches_types_outside_the_index_by_name() {
+        let mut cyclic_a = class_with_artifact("com/example/CyclicA", 0);
+        cyclic_a.super_name = Some("com/example/CyclicB".to_string());
+        let mut cyclic_b = class_with_artifact("com/example/CyclicB", 0);
+        cyclic_b.super_name = Some("com/example/CyclicA".to_string());
+
+        let (context, _) = build_context_with_timings(
+            vec![cyclic_a, cyclic_b],
+            Vec::new(),
+            &[],
+            &ClassFilter::default(),
+            None,
+        );
+
+        assert!(
+            context.is_subtype_of("java/util/concurrent/Future", "java/util/concurrent/Future")
+        );
+        assert!(!context.is_subtype_of(
+            "java/util/concurrent/CompletableFuture",
+            "java/util/concurrent/Future"
+        ));
+        assert!(!context.is_subtype_of("com/example/CyclicA", "java/lang/Object"));
+    }
+
     #[test]
     fn build_context_treats_all_classes_as_targets_without_analysis_target_artifacts() {
         let classes = vec![
@@ -665,6 +1739,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -697,16 +1773,21 @@ mod tests {
                 signature: None,
                 type_use: None,
                 access: FieldAccess {
+                    is_public: false,
                     is_static: false,
                     is_private: true,
                     is_final: true,
                     is_volatile: false,
+                    is_transient: false,
+                    is_synthetic: false,
                 },
             }],
             methods: Vec::new(),
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -736,6 +1817,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -765,6 +1848,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -819,6 +1904,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -852,6 +1939,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -894,6 +1983,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -936,6 +2027,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -977,6 +2070,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -1012,6 +2107,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -1055,6 +2152,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }];
         let artifacts = vec![
             Artifact::builder()
@@ -1087,6 +2186,416 @@ mod tests {
         assert_eq!(engine.rules[0].metadata().id, "SYSTEM_EXIT");
     }
 
+    #[test]
+    fn without_rule_ids_drops_excluded_rules() {
+        let excluded = BTreeSet::from(["SYSTEM_EXIT".to_string()]);
+
+        let ids = Engine::new_with_allowed_rule_ids(None)
+            .expect("build engine")
+            .without_rule_ids(&excluded)
+            .expect("exclude rules")
+            .rule_ids();
+        let unknown = Engine::new_with_allowed_rule_ids(None)
+            .expect("build engine")
+            .without_rule_ids(&BTreeSet::from(["NOT_A_RULE".to_string()]));
+
+        assert!(!ids.is_empty());
+        assert!(!ids.contains(&"SYSTEM_EXIT".to_string()));
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn new_with_allowed_rule_ids_runs_opt_in_rules_only_when_named() {
+        let allowed = BTreeSet::from(["MODULE_INFO_REQUIRES_TRANSITIVE".to_string()]);
+
+        let default_ids = Engine::new_with_allowed_rule_ids(None)
+            .expect("build engine")
+            .rule_ids();
+        let named_ids = Engine::new_with_allowed_rule_ids(Some(&allowed))
+            .expect("build filtered engine")
+            .rule_ids();
+
+        assert!(!default_ids.contains(&"MODULE_INFO_REQUIRES_TRANSITIVE".to_string()));
+        assert!(default_ids.contains(&"SYSTEM_EXIT".to_string()));
+        assert_eq!(
+            named_ids,
+            vec!["MODULE_INFO_REQUIRES_TRANSITIVE".to_string()]
+        );
+    }
+
+    #[test]
+    fn analyze_per_class_reports_module_findings_once() {
+        let classes = vec![
+            class_with_artifact("com/example/ClassA", 0),
+            class_with_artifact("com/example/ClassB", 0),
+        ];
+        let modules = vec![ModuleDescriptor {
+            name: "com.example".to_string(),
+            requires: Vec::new(),
+            exports: Vec::new(),
+            artifact_index: 0,
+        }];
+        let (context, _) =
+            build_context_with_timings(classes, modules, &[], &ClassFilter::default(), None);
+        let engine = Engine {
+            rules: vec![Box::new(ClassEchoRule {
+                id: "ECHO",
+                stall_on: None,
+            })],
+            class_timeout: Some(Duration::from_secs(60)),
+            result_cache: None,
+            progress: None,
+        };
+
+        let output = engine.analyze(context).expect("analysis");
+
+        let messages: Vec<&str> = output
+            .results
+            .iter()
+            .filter_map(|result| result.message.text.as_deref())
+            .collect();
+        assert_eq!(
+            messages,
+            vec!["com.example", "com/example/ClassA", "com/example/ClassB"]
+        );
+        assert_eq!(output.rule_timings[0].finding_count, 3);
+    }
+
+    #[test]
+    fn analyze_per_class_shares_the_class_index_built_with_the_context() {
+        let classes = vec![
+            class_with_artifact("com/example/ClassA", 0),
+            class_with_artifact("com/example/ClassB", 0),
+            class_with_artifact("com/example/ClassC", 0),
+        ];
+        let context = build_context(classes, &[]);
+        let index = Arc::as_ptr(&context.class_index) as usize;
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let engine = Engine {
+            rules: vec![Box::new(IndexProbeRule {
+                seen: Arc::clone(&seen),
+            })],
+            class_timeout: Some(Duration::from_secs(60)),
+            result_cache: None,
+            progress: None,
+        };
+
+        engine.analyze(context).expect("analysis");
+
+        assert_eq!(*seen.lock().expect("seen indexes"), vec![index; 3]);
+    }
+
+    #[test]
+    fn analyze_reports_progress_per_finished_rule_without_running_per_class() {
+        let classes = vec![
+            class_with_artifact("com/example/ClassA", 0),
+            class_with_artifact("com/example/ClassB", 0),
+            class_with_artifact("com/example/ClassC", 0),
+        ];
+        let context = build_context(classes, &[]);
+        let calls = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&calls);
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let engine = Engine {
+            rules: vec![
+                Box::new(ClassEchoRule {
+                    id: "ECHO",
+                    stall_on: None,
+                }),
+                Box::new(IndexProbeRule {
+                    seen: Arc::clone(&seen),
+                }),
+            ],
+            class_timeout: None,
+            result_cache: None,
+            progress: None,
+        }
+        .with_progress(Some(Arc::new(move |done, total| {
+            recorded.lock().expect("progress calls").push((done, total));
+        })));
+
+        engine.analyze(context).expect("analysis");
+
+        assert_eq!(seen.lock().expect("seen indexes").len(), 1);
+        assert_eq!(*calls.lock().expect("progress calls"), vec![(3, 6), (6, 6)]);
+    }
+
+    #[test]
+    fn analyze_per_class_reports_progress_once_per_class() {
+        let classes = vec![
+            class_with_artifact("com/example/ClassA", 0),
+            class_with_artifact("com/example/ClassB", 0),
+            class_with_artifact("com/example/ClassC", 0),
+        ];
+        let context = build_context(classes, &[]);
+        let calls = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&calls);
+        let engine =
+            Engine::new_with_allowed_rule_ids(Some(&BTreeSet::from(["SYSTEM_EXIT".to_string()])))
+                .expect("build engine")
+                .with_class_timeout(Some(Duration::from_secs(60)))
+                .with_progress(Some(Arc::new(move |done, total| {
+                    recorded.lock().expect("progress calls").push((done, total));
+                })));
+
+        engine.analyze(context).expect("analysis");
+
+        assert_eq!(
+            *calls.lock().expect("progress calls"),
+            vec![(1, 3), (2, 3), (3, 3)]
+        );
+    }
+
+    #[test]
+    fn analyze_skips_remaining_rules_for_classes_over_budget() {
+        let classes = vec![
+            class_with_artifact("com/example/Fast", 0),
+            class_with_artifact("com/example/Slow", 0),
+        ];
+        let context = build_context(classes, &[]);
+        let engine = Engine {
+            rules: vec![
+                Box::new(ClassEchoRule {
+                    id: "A_STALL",
+                    stall_on: Some("com/example/Slow"),
+                }),
+                Box::new(ClassEchoRule {
+                    id: "B_ECHO",
+                    stall_on: None,
+                }),
+            ],
+            class_timeout: None,
+            result_cache: None,
+            progress: None,
+        }
+        .with_class_timeout(Some(Duration::from_millis(50)));
+
+        let output = engine.analyze(context).expect("analysis");
+
+        assert_eq!(output.skipped_classes, vec!["com/example/Slow".to_string()]);
+        assert_eq!(output.rules.len(), 2);
+        let reported: Vec<(&str, &str)> = output
+            .results
+            .iter()
+            .map(|result| {
+                (
+                    result.rule_id.as_deref().unwrap_or(""),
+                    result.message.text.as_deref().unwrap_or(""),
+                )
+            })
+            .collect();
+        assert_eq!(
+            reported,
+            vec![
+                ("A_STALL", "com/example/Fast"),
+                ("A_STALL", "com/example/Slow"),
+                ("B_ECHO", "com/example/Fast"),
+            ]
+        );
+    }
+
+    #[test]
+    fn analyze_reruns_rules_only_for_classes_changed_since_cached_run() {
+        fn classes(changed_hash: &str) -> Vec<Class> {
+            let mut changed = class_with_artifact("com/example/Changed", 0);
+            changed.content_hash = changed_hash.to_string();
+            let mut unchanged = class_with_artifact("com/example/Unchanged", 0);
+            unchanged.content_hash = "unchanged".to_string();
+            vec![changed, unchanged]
+        }
+        fn engine(result_cache: Option<ResultCache>) -> Engine {
+            Engine {
+                rules: vec![Box::new(ClassEchoRule {
+                    id: "ECHO",
+                    stall_on: None,
+                })],
+                class_timeout: None,
+                result_cache,
+                progress: None,
+            }
+        }
+
+        let first_context = build_context(classes("v1"), &[]);
+        let key = CacheKey::new(vec!["ECHO".to_string()], first_context.rule_options())
+            .expect("cache key");
+        let first = engine(Some(ResultCache::new(key)))
+            .analyze(first_context)
+            .expect("first analysis");
+        let second = engine(first.result_cache)
+            .analyze(build_context(classes("v2"), &[]))
+            .expect("second analysis");
+
+        assert!(first.cached_classes.is_empty());
+        assert_eq!(
+            second.cached_classes,
+            vec!["com/example/Unchanged".to_string()]
+        );
+        let messages: Vec<&str> = second
+            .results
+            .iter()
+            .filter_map(|result| result.message.text.as_deref())
+            .collect();
+        assert_eq!(
+            messages,
+            vec!["com/example/Changed", "com/example/Unchanged"]
+        );
+        assert_eq!(second.rule_timings[0].finding_count, 2);
+    }
+
+    #[test]
+    fn analyze_reruns_dependent_classes_when_only_their_superclass_changed() {
+        fn classes(base_hash: &str) -> Vec<Class> {
+            let mut base = class_with_artifact("com/example/Base", 0);
+            base.content_hash = base_hash.to_string();
+            let mut derived = class_with_artifact("com/example/Derived", 0);
+            derived.super_name = Some("com/example/Base".to_string());
+            derived.content_hash = "derived".to_string();
+            let mut other = class_with_artifact("com/example/Other", 0);
+            other.content_hash = "other".to_string();
+            vec![base, derived, other]
+        }
+        fn analyze_cached(dir: &Path, classes: Vec<Class>) -> EngineOutput {
+            let context = build_context(classes, &[]);
+            let key = CacheKey::new(vec!["SUPERCLASS_HASH".to_string()], context.rule_options())
+                .expect("cache key");
+            let engine = Engine {
+                rules: vec![Box::new(SuperclassHashRule)],
+                class_timeout: None,
+                result_cache: Some(load_result_cache(dir, key).expect("load cache")),
+                progress: None,
+            };
+            let output = engine.analyze(context).expect("analysis");
+            write_result_cache(dir, output.result_cache.as_ref().expect("result cache"))
+                .expect("write cache");
+            output
+        }
+        fn messages(output: &EngineOutput) -> Vec<&str> {
+            output
+                .results
+                .iter()
+                .filter_map(|result| result.message.text.as_deref())
+                .collect()
+        }
+        let dir = tempfile::tempdir().expect("temp dir");
+
+        analyze_cached(dir.path(), classes("v1"));
+        let unchanged = analyze_cached(dir.path(), classes("v1"));
+        let changed = analyze_cached(dir.path(), classes("v2"));
+
+        assert_eq!(unchanged.cached_classes.len(), 3);
+        assert_eq!(messages(&unchanged), vec!["com/example/Derived extends v1"]);
+        assert_eq!(
+            changed.cached_classes,
+            vec!["com/example/Other".to_string()]
+        );
+        assert_eq!(messages(&changed), vec!["com/example/Derived extends v2"]);
+    }
+
+    #[test]
+    fn analyze_streaming_emits_one_json_line_per_batch_result() {
+        fn engine() -> Engine {
+            Engine {
+                rules: vec![
+                    Box::new(ClassEchoRule {
+                        id: "A_ECHO",
+                        stall_on: None,
+                    }),
+                    Box::new(ClassEchoRule {
+                        id: "B_ECHO",
+                        stall_on: None,
+                    }),
+                ],
+                class_timeout: None,
+                result_cache: None,
+                progress: None,
+            }
+        }
+        fn classes() -> Vec<Class> {
+            vec![
+                class_with_artifact("com/example/ClassA", 0),
+                class_with_artifact("com/example/ClassB", 0),
+            ]
+        }
+
+        let batch = engine()
+            .analyze(build_context(classes(), &[]))
+            .expect("batch analysis");
+        let mut lines = Vec::new();
+        let summary = engine()
+            .analyze_streaming(build_context(classes(), &[]), |result| {
+                lines.push(serde_json::to_string(&result)?);
+                Ok(())
+            })
+            .expect("streaming analysis");
+
+        assert_eq!(lines.len(), batch.results.len());
+        let mut streamed = lines
+            .iter()
+            .map(|line| serde_json::from_str::<SarifResult>(line).expect("valid JSON line"))
+            .collect::<Vec<_>>();
+        streamed.sort_by_key(|result| (result.rule_id.clone(), result.message.text.clone()));
+        assert_eq!(streamed, batch.results);
+        assert_eq!(summary.rules.len(), 2);
+        assert_eq!(summary.rule_timings[1].finding_count, 2);
+    }
+
+    #[test]
+    fn analyze_orders_results_by_rule_and_location_regardless_of_class_order() {
+        const NAMES: [&str; 3] = [
+            "com/example/ClassA",
+            "com/example/ClassB",
+            "com/example/ClassC",
+        ];
+        fn analyze(order: [usize; 3]) -> Vec<SarifResult> {
+            let classes = order
+                .iter()
+                .map(|index| class_with_artifact(NAMES[*index], 0))
+                .collect();
+            let engine = Engine {
+                rules: vec![
+                    Box::new(LocatedEchoRule { id: "B_LOCATED" }),
+                    Box::new(LocatedEchoRule { id: "A_LOCATED" }),
+                ],
+                class_timeout: None,
+                result_cache: None,
+                progress: None,
+            };
+            engine
+                .analyze(build_context(classes, &[]))
+                .expect("analysis")
+                .results
+        }
+
+        let first = analyze([0, 1, 2]);
+        let second = analyze([0, 1, 2]);
+        let shuffled = analyze([2, 0, 1]);
+
+        let serialize = |results: &[SarifResult]| serde_json::to_string(results).expect("JSON");
+        assert_eq!(serialize(&first), serialize(&second));
+        assert_eq!(serialize(&first), serialize(&shuffled));
+        let reported: Vec<(String, String)> = first
+            .iter()
+            .map(|result| {
+                let location = &result.locations.as_ref().expect("locations")[0];
+                let logical = &location.logical_locations.as_ref().expect("logical")[0];
+                (
+                    result.rule_id.clone().unwrap_or_default(),
+                    logical.name.clone().unwrap_or_default(),
+                )
+            })
+            .collect();
+        let expected: Vec<(String, String)> = ["A_LOCATED", "B_LOCATED"]
+            .iter()
+            .flat_map(|rule_id| {
+                NAMES.iter().flat_map(move |class_name| {
+                    ["methodX", "methodY"]
+                        .map(|method| (rule_id.to_string(), format!("{class_name}.{method}()V")))
+                })
+            })
+            .collect();
+        assert_eq!(reported, expected);
+    }
+
     #[test]
     fn new_with_allowed_rule_ids_rejects_unknown_rule_id() {
         let allowed = BTreeSet::from(["RULE_DOES_NOT_EXIST".to_string()]);
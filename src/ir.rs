@@ -14,6 +14,9 @@ pub(crate) struct Class {
     pub(crate) annotation_defaults: Vec<AnnotationDefaultValue>,
     pub(crate) artifact_index: i64,
     pub(crate) is_record: bool,
+    pub(crate) is_final: bool,
+    /// SHA-256 of the class file bytes, used to key cached results.
+    pub(crate) content_hash: String,
 }
 
 /// Numeric default value from an annotation method's AnnotationDefault attribute.
@@ -31,6 +34,26 @@ pub(crate) enum AnnotationDefaultNumeric {
     Float(f64),
 }
 
+/// Module declaration read from the Module attribute of a `module-info.class`.
+#[derive(Clone, Debug)]
+pub(crate) struct ModuleDescriptor {
+    pub(crate) name: String,
+    pub(crate) requires: Vec<ModuleRequires>,
+    /// Exported packages in internal form, such as `com/example/api`.
+    pub(crate) exports: Vec<String>,
+    pub(crate) artifact_index: i64,
+}
+
+/// `requires` directive of a module declaration.
+#[derive(Clone, Debug)]
+pub(crate) struct ModuleRequires {
+    pub(crate) name: String,
+    pub(crate) is_transitive: bool,
+    pub(crate) is_static: bool,
+    /// Implicitly declared, like the `requires java.base` every module gets.
+    pub(crate) is_mandated: bool,
+}
+
 /// Field definition for a class.
 #[derive(Clone, Debug)]
 pub(crate) struct Field {
@@ -44,10 +67,13 @@ pub(crate) struct Field {
 /// Field access flags used for rule filtering.
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct FieldAccess {
+    pub(crate) is_public: bool,
     pub(crate) is_static: bool,
     pub(crate) is_private: bool,
     pub(crate) is_final: bool,
     pub(crate) is_volatile: bool,
+    pub(crate) is_transient: bool,
+    pub(crate) is_synthetic: bool,
 }
 
 /// Intermediate representation for a method and its bytecode.
@@ -94,9 +120,11 @@ pub(crate) struct LocalVariableType {
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct MethodAccess {
     pub(crate) is_public: bool,
+    pub(crate) is_private: bool,
     pub(crate) is_static: bool,
     pub(crate) is_synchronized: bool,
     pub(crate) is_abstract: bool,
+    pub(crate) is_final: bool,
     pub(crate) is_synthetic: bool,
     pub(crate) is_bridge: bool,
 }
@@ -162,6 +190,8 @@ pub(crate) enum InstructionKind {
     Invoke(CallSite),
     FieldAccess(FieldRef),
     InvokeDynamic {
+        /// Call site name, e.g. `makeConcatWithConstants` for string concatenation.
+        name: String,
         descriptor: String,
         impl_method: Option<String>,
     },
@@ -171,6 +201,12 @@ pub(crate) enum InstructionKind {
     ConstInt(i64),
     /// Float or double constant loaded via ldc/ldc2_w.
     ConstFloat(f64),
+    /// Target type of a checkcast; array types keep descriptor form, e.g. `[Ljava/lang/String;`.
+    Checkcast(String),
+    /// Target type of an instanceof, in the same form as `Checkcast`.
+    InstanceOf(String),
+    /// Component type of an anewarray, in the same form as `Checkcast`.
+    ANewArray(String),
     Other(u8),
 }
 
@@ -0,0 +1,254 @@
+/// Direct supertypes of common JDK types, used by `AnalysisContext::is_subtype_of` when the
+/// JDK is not among the loaded classes.
+///
+/// Only the supertypes that rules ask about are listed: the throwable hierarchy and
+/// `java/io/Serializable`. Interfaces such as `java/util/Map` are left out, so those still only
+/// match types that were loaded.
+const JDK_SUPERTYPES: &[(&str, &[&str])] = &[
+    ("java/io/Externalizable", &["java/io/Serializable"]),
+    (
+        "java/io/File",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/lang/Boolean",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/lang/Character",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/lang/Enum",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/lang/Number",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    ("java/lang/Byte", &["java/lang/Number"]),
+    ("java/lang/Double", &["java/lang/Number"]),
+    ("java/lang/Float", &["java/lang/Number"]),
+    ("java/lang/Integer", &["java/lang/Number"]),
+    ("java/lang/Long", &["java/lang/Number"]),
+    ("java/lang/Short", &["java/lang/Number"]),
+    ("java/math/BigDecimal", &["java/lang/Number"]),
+    ("java/math/BigInteger", &["java/lang/Number"]),
+    (
+        "java/lang/String",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/lang/StringBuilder",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/net/URI",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/net/URL",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/time/Duration",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/time/Instant",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/time/LocalDate",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/time/LocalDateTime",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/time/LocalTime",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/time/OffsetDateTime",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/time/ZonedDateTime",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    ("java/util/ArrayDeque", &["java/io/Serializable"]),
+    ("java/util/ArrayList", &["java/io/Serializable"]),
+    (
+        "java/util/Date",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    ("java/util/EnumMap", &["java/io/Serializable"]),
+    ("java/util/HashMap", &["java/io/Serializable"]),
+    ("java/util/HashSet", &["java/io/Serializable"]),
+    ("java/util/LinkedHashMap", &["java/util/HashMap"]),
+    ("java/util/LinkedHashSet", &["java/util/HashSet"]),
+    ("java/util/LinkedList", &["java/io/Serializable"]),
+    (
+        "java/util/Locale",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    ("java/util/TreeMap", &["java/io/Serializable"]),
+    ("java/util/TreeSet", &["java/io/Serializable"]),
+    (
+        "java/util/UUID",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    (
+        "java/util/concurrent/ConcurrentHashMap",
+        &["java/io/Serializable"],
+    ),
+    (
+        "java/lang/Throwable",
+        &["java/lang/Object", "java/io/Serializable"],
+    ),
+    ("java/lang/Exception", &["java/lang/Throwable"]),
+    ("java/lang/Error", &["java/lang/Throwable"]),
+    ("java/lang/RuntimeException", &["java/lang/Exception"]),
+    ("java/lang/InterruptedException", &["java/lang/Exception"]),
+    (
+        "java/lang/CloneNotSupportedException",
+        &["java/lang/Exception"],
+    ),
+    (
+        "java/lang/ReflectiveOperationException",
+        &["java/lang/Exception"],
+    ),
+    (
+        "java/lang/ClassNotFoundException",
+        &["java/lang/ReflectiveOperationException"],
+    ),
+    (
+        "java/lang/IllegalAccessException",
+        &["java/lang/ReflectiveOperationException"],
+    ),
+    (
+        "java/lang/InstantiationException",
+        &["java/lang/ReflectiveOperationException"],
+    ),
+    (
+        "java/lang/NoSuchFieldException",
+        &["java/lang/ReflectiveOperationException"],
+    ),
+    (
+        "java/lang/NoSuchMethodException",
+        &["java/lang/ReflectiveOperationException"],
+    ),
+    (
+        "java/lang/reflect/InvocationTargetException",
+        &["java/lang/ReflectiveOperationException"],
+    ),
+    (
+        "java/lang/ArithmeticException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/lang/ClassCastException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/lang/IllegalArgumentException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/lang/NumberFormatException",
+        &["java/lang/IllegalArgumentException"],
+    ),
+    (
+        "java/lang/IllegalStateException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/lang/IndexOutOfBoundsException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/lang/ArrayIndexOutOfBoundsException",
+        &["java/lang/IndexOutOfBoundsException"],
+    ),
+    (
+        "java/lang/StringIndexOutOfBoundsException",
+        &["java/lang/IndexOutOfBoundsException"],
+    ),
+    (
+        "java/lang/NullPointerException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/lang/SecurityException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/lang/UnsupportedOperationException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/util/ConcurrentModificationException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/util/NoSuchElementException",
+        &["java/lang/RuntimeException"],
+    ),
+    (
+        "java/io/UncheckedIOException",
+        &["java/lang/RuntimeException"],
+    ),
+    ("java/io/IOException", &["java/lang/Exception"]),
+    ("java/io/EOFException", &["java/io/IOException"]),
+    ("java/io/FileNotFoundException", &["java/io/IOException"]),
+    ("java/net/MalformedURLException", &["java/io/IOException"]),
+    ("java/net/SocketException", &["java/io/IOException"]),
+    ("java/net/UnknownHostException", &["java/io/IOException"]),
+    (
+        "java/nio/file/FileSystemException",
+        &["java/io/IOException"],
+    ),
+    (
+        "java/nio/file/NoSuchFileException",
+        &["java/nio/file/FileSystemException"],
+    ),
+    ("java/sql/SQLException", &["java/lang/Exception"]),
+    (
+        "java/util/concurrent/ExecutionException",
+        &["java/lang/Exception"],
+    ),
+    (
+        "java/util/concurrent/TimeoutException",
+        &["java/lang/Exception"],
+    ),
+    ("java/lang/AssertionError", &["java/lang/Error"]),
+    ("java/lang/LinkageError", &["java/lang/Error"]),
+    (
+        "java/lang/NoClassDefFoundError",
+        &["java/lang/LinkageError"],
+    ),
+    (
+        "java/lang/ExceptionInInitializerError",
+        &["java/lang/LinkageError"],
+    ),
+    ("java/lang/VirtualMachineError", &["java/lang/Error"]),
+    (
+        "java/lang/OutOfMemoryError",
+        &["java/lang/VirtualMachineError"],
+    ),
+    (
+        "java/lang/StackOverflowError",
+        &["java/lang/VirtualMachineError"],
+    ),
+];
+
+/// Returns the known direct supertypes of the JDK type `name`, or an empty slice for types
+/// outside the table.
+pub(crate) fn jdk_supertypes(name: &str) -> &'static [&'static str] {
+    JDK_SUPERTYPES
+        .iter()
+        .find(|(type_name, _)| *type_name == name)
+        .map_or(&[], |(_, supertypes)| supertypes)
+}
@@ -1,27 +1,43 @@
+mod artifact_uri;
 mod baseline;
+mod cache;
+mod call_graph;
+mod catalog;
 mod cfg;
+mod class_filter;
 mod classpath;
 mod dataflow;
+mod dedupe;
 mod descriptor;
+mod dex;
 mod engine;
 mod ir;
+mod jdk_types;
 mod opcodes;
+mod progress;
+mod remote;
 mod rules;
 mod scan;
+mod scan_config;
+mod summary;
 mod telemetry;
 #[cfg(test)]
 mod test_harness;
+mod text_report;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::fmt;
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use flate2::Compression;
+use flate2::write::GzEncoder;
 use opentelemetry::KeyValue;
 use serde::Deserialize;
 use serde_json::json;
@@ -32,13 +48,33 @@ use serde_sarif::sarif::{
 };
 use tracing::error;
 
-use crate::baseline::{load_baseline, write_baseline};
+use crate::artifact_uri::{DEFAULT_URI_BASE_ID, UriRelativizer};
+use crate::baseline::{load_baseline, write_baseline, write_baseline_file};
+use crate::cache::{CacheKey, DEFAULT_CACHE_DIR, load_result_cache, write_result_cache};
+use crate::call_graph::build_call_graph;
+use crate::catalog::{rule_catalog, rule_ids_with_tag, tag_taxa};
+use crate::class_filter::{ClassFilter, IGNORE_FILE_NAME, load_ignore_file};
 use crate::classpath::resolve_classpath;
-use crate::engine::{Engine, build_context_with_timings};
-use crate::scan::scan_inputs;
+use crate::dedupe::dedupe_findings;
+use crate::engine::{Engine, RuleOptions, RuleTiming, build_context_with_timings, compare_results};
+use crate::progress::{PROGRESS_INTERVAL, ProgressReporter};
+use crate::remote::{DEFAULT_REMOTE_CACHE_DIR, fetch_remote_inputs, is_remote_input};
+use crate::rules::Severity;
+use crate::scan::{
+    ScanOptions, build_input_manifest, replay_input_manifest, scan_inputs, write_input_manifest,
+};
+use crate::scan_config::{RuleOptionsConfig, ScanConfig, load_scan_config};
+use crate::summary::{ScanSummary, write_scan_summary};
 use crate::telemetry::{Telemetry, current_trace_id, init_logging, with_span};
+use crate::text_report::write_text_report;
 
 const DEFAULT_BASELINE_PATH: &str = ".inspequte/baseline.json";
+/// Exit code used when `--fail-on` matches a finding; 1 is reserved for errors.
+const FINDINGS_EXIT_CODE: u8 = 2;
+/// Maximum number of nested `@file` references in `--input`, `--classpath`, and `--rules`.
+const MAX_AT_FILE_DEPTH: usize = 32;
+/// Prefix of a `--rules` entry that selects every rule carrying the tag after it.
+const CATEGORY_RULE_PREFIX: &str = "@category:";
 
 /// CLI arguments for inspequte execution.
 #[derive(Parser, Debug)]
@@ -56,11 +92,145 @@ struct Cli {
     )]
     json: Option<String>,
     #[command(flatten)]
+    log: LogArgs,
+    #[command(flatten)]
     scan: ScanArgs,
     #[command(subcommand)]
     command: Option<Command>,
 }
 
+/// Logging options shared by all commands, independent of telemetry.
+#[derive(Args, Debug, Clone)]
+struct LogArgs {
+    #[arg(
+        long,
+        value_enum,
+        value_name = "LEVEL",
+        global = true,
+        help = "Log level for stderr output. Defaults to warn, or RUST_LOG when set."
+    )]
+    log_level: Option<LogLevel>,
+    #[arg(
+        long,
+        global = true,
+        help = "Suppress informational stderr lines such as trace-id. Logs only errors unless --log-level is set."
+    )]
+    quiet: bool,
+    #[arg(
+        long,
+        global = true,
+        conflicts_with = "quiet",
+        help = "Shortcut for --log-level debug."
+    )]
+    verbose: bool,
+}
+
+/// Verbosity accepted by `--log-level`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+/// Minimum finding level that makes `scan` exit with [`FINDINGS_EXIT_CODE`].
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum FailOn {
+    None,
+    Note,
+    Warning,
+    Error,
+}
+
+impl FailOn {
+    fn threshold(self) -> Option<Severity> {
+        match self {
+            FailOn::None => None,
+            FailOn::Note => Some(Severity::Note),
+            FailOn::Warning => Some(Severity::Warning),
+            FailOn::Error => Some(Severity::Error),
+        }
+    }
+}
+
+/// Serialization of scan findings.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// A single SARIF log written after the analysis finishes.
+    Sarif,
+    /// One JSON SARIF result per line, written while rules run.
+    Ndjson,
+    /// One line per finding, for reading in a terminal or CI log.
+    Text,
+}
+
+/// A format and destination that one scan writes its findings to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct OutputSink {
+    format: OutputFormat,
+    /// Destination file; `None` or `-` writes to stdout.
+    path: Option<PathBuf>,
+    compression: Option<OutputCompression>,
+}
+
+/// Parses an `--additional-output` value of the form `FORMAT=PATH`.
+fn parse_output_sink(value: &str) -> Result<OutputSink, String> {
+    let (format, path) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected FORMAT=PATH, got '{value}'"))?;
+    let format = OutputFormat::from_str(format, true)?;
+    if format == OutputFormat::Ndjson {
+        return Err("ndjson is streamed while rules run; use --format ndjson instead".to_string());
+    }
+    if path.is_empty() {
+        return Err(format!("missing PATH in '{value}'"));
+    }
+    Ok(OutputSink {
+        format,
+        path: Some(PathBuf::from(path)),
+        compression: None,
+    })
+}
+
+/// Compression applied to the SARIF output.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputCompression {
+    None,
+    Gzip,
+}
+
+impl OutputCompression {
+    /// Uses the explicit choice, otherwise gzip for `.gz` output files and none for stdout.
+    fn resolve(explicit: Option<OutputCompression>, output: Option<&Path>) -> OutputCompression {
+        if let Some(compression) = explicit {
+            return compression;
+        }
+        match output {
+            Some(path)
+                if path != Path::new("-")
+                    && path.extension().is_some_and(|extension| extension == "gz") =>
+            {
+                OutputCompression::Gzip
+            }
+            _ => OutputCompression::None,
+        }
+    }
+}
+
 /// Options for running a scan.
 #[derive(Args, Debug, Clone)]
 struct ScanArgs {
@@ -68,6 +238,58 @@ struct ScanArgs {
     input: InputArgs,
     #[arg(long, value_name = "PATH", conflicts_with = "json")]
     output: Option<PathBuf>,
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        default_value = "sarif",
+        conflicts_with = "json",
+        help = "Output format. ndjson streams one SARIF result object per line as findings are produced, without tool, artifact, or invocation metadata. text prints one line per finding."
+    )]
+    format: OutputFormat,
+    #[arg(
+        long,
+        value_name = "FORMAT=PATH",
+        value_parser = parse_output_sink,
+        conflicts_with = "json",
+        help = "Also write the findings of the same scan as FORMAT (sarif or text) to PATH. Can be repeated. Compression follows the .gz extension of PATH. Not supported with --format ndjson."
+    )]
+    additional_output: Vec<OutputSink>,
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        conflicts_with = "json",
+        help = "Compress the SARIF output. Defaults to gzip when --output ends with .gz, and to none otherwise (including stdout)."
+    )]
+    output_format_compression: Option<OutputCompression>,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Indent the SARIF output for easier reading and diffing. Has no effect with --format ndjson."
+    )]
+    sarif_pretty: bool,
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "json",
+        help = "Write the class/JAR files consumed by this scan, with SHA-256 hashes and the options that select classes from them, to PATH."
+    )]
+    manifest: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["json", "input", "classpath"],
+        help = "Re-analyze the files recorded by a previous --manifest run with its --release, --spring-boot, --dex, and --follow-manifest-classpath settings. Warns when a file's content changed and fails when a file is missing or a directory gained files."
+    )]
+    replay_manifest: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["json", "replay_manifest"],
+        help = "Read inputs, classpath, rules, exclude_rules, baseline, release, and rule_options from a JSON or TOML (.toml) config file. Command-line flags take precedence over the file."
+    )]
+    config: Option<PathBuf>,
     #[arg(
         long,
         value_name = "ID",
@@ -75,11 +297,13 @@ struct ScanArgs {
         help = "Optional SARIF run.automationDetails.id (GitHub code scanning category)."
     )]
     automation_details_id: Option<String>,
+    #[command(flatten)]
+    artifact_uris: ArtifactUriArgs,
     #[arg(
         long,
         value_name = "URL",
         conflicts_with = "json",
-        help = "OTLP HTTP collector URL (recommended: http://localhost:4318/)."
+        help = "OTLP collector URL. Use http://localhost:4318/ for OTLP/HTTP or grpc://localhost:4317 for OTLP/gRPC."
     )]
     otel: Option<String>,
     #[arg(
@@ -87,22 +311,162 @@ struct ScanArgs {
         value_name = "RULE_ID[,RULE_ID...]|@PATH",
         action = clap::ArgAction::Append,
         conflicts_with = "json",
-        help = "Rule IDs to run. Accepts comma-separated IDs and @file references (one rule ID per line). Repeatable."
+        help = "Rule IDs to run. Accepts comma-separated IDs, @category:TAG for every rule with that tag, and @file references (one rule ID per line). Repeatable."
     )]
     rules: Vec<String>,
+    #[arg(
+        long,
+        value_name = "TAG[,TAG...]",
+        action = clap::ArgAction::Append,
+        conflicts_with = "json",
+        help = "Run every rule carrying one of these tags, such as concurrency, in addition to --rules. Repeatable."
+    )]
+    tags: Vec<String>,
+    #[arg(
+        long,
+        value_name = "RULE_ID[,RULE_ID...]|@PATH",
+        action = clap::ArgAction::Append,
+        conflicts_with = "json",
+        help = "Rule IDs to skip, applied after --rules. Accepts comma-separated IDs and @file references (one rule ID per line). Repeatable."
+    )]
+    exclude_rules: Vec<String>,
     #[arg(
         long,
         value_name = "PATH",
-        default_value = DEFAULT_BASELINE_PATH,
-        conflicts_with = "json"
+        conflicts_with = "json",
+        help = "Suppress findings recorded in this baseline file. Defaults to the config file's baseline, then .inspequte/baseline.json."
     )]
-    baseline: PathBuf,
+    baseline: Option<PathBuf>,
     #[arg(
         long,
         conflicts_with = "json",
         help = "Warn instead of failing when the same class name appears in multiple inputs. The class from the lexicographically first artifact path is used."
     )]
     allow_duplicate_classes: bool,
+    #[arg(
+        long,
+        value_name = "GLOB",
+        action = clap::ArgAction::Append,
+        conflicts_with = "json",
+        help = "Only analyze classes whose slash-separated name (e.g. com/example/Foo) matches GLOB. `*` stays within a package, `**` spans packages. Other classes are still loaded for resolution. Repeatable."
+    )]
+    include_classes: Vec<String>,
+    #[arg(
+        long,
+        value_name = "GLOB",
+        action = clap::ArgAction::Append,
+        conflicts_with = "json",
+        help = "Skip analysis of classes whose slash-separated name matches GLOB, e.g. '**/generated/**'. Applied after --include-classes. Repeatable."
+    )]
+    exclude_classes: Vec<String>,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Do not read .inspequteignore from the working directory. By default its gitignore-style patterns skip analysis of matching classes, which are still loaded for resolution."
+    )]
+    no_ignore_file: bool,
+    #[arg(
+        long,
+        value_name = "@PATH",
+        conflicts_with = "json",
+        help = "Only analyze classes compiled from the source files listed in PATH, one path per line as printed by `git diff --name-only`. Classes are matched by package and SourceFile attribute; other classes are still loaded for resolution."
+    )]
+    changed_files: Option<String>,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Report findings that are identical except for their artifact, e.g. from a class duplicated across JARs, only once. The finding from the first input artifact is kept. Not supported with --format ndjson."
+    )]
+    dedupe_findings: bool,
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with = "json",
+        help = "Write at most N findings, taken in rule and location order after baseline filtering, and mark the invocation as truncated when more were found. --fail-on and --summary still count every finding."
+    )]
+    max_findings: Option<usize>,
+    #[arg(
+        long,
+        value_enum,
+        value_name = "LEVEL",
+        default_value = "none",
+        conflicts_with = "json",
+        help = "Exit with code 2 when a finding not suppressed by the baseline has this level or higher."
+    )]
+    fail_on: FailOn,
+    #[arg(
+        long,
+        value_name = "MS",
+        conflicts_with = "json",
+        help = "Wall-clock budget per analyzed class in milliseconds. Checked between rules: remaining rules for a class over budget are skipped. Unlimited by default."
+    )]
+    timeout_per_class: Option<u64>,
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_value_t = true,
+        action = clap::ArgAction::Set,
+        conflicts_with = "json",
+        help = "Do not report SYSTEM_EXIT for calls inside main entry points. Pass false to report them too."
+    )]
+    allow_exit_in_main: bool,
+    /// Per-rule options from the `rule_options` table of the `--config` file.
+    #[arg(skip)]
+    rule_options: RuleOptionsConfig,
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = DEFAULT_CACHE_DIR,
+        conflicts_with = "json",
+        help = "Directory for cached per-class results. A class reuses its results while it, the classes it references, their supertypes, and the selected rules and rule options are unchanged."
+    )]
+    cache_dir: PathBuf,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Analyze every class without reading or writing the result cache."
+    )]
+    no_cache: bool,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Print phase timings, class and artifact counts, and findings per rule to stderr after the scan."
+    )]
+    stats: bool,
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "json",
+        help = "Write finding counts (total, per rule, suppressed by the baseline) and scanned class and artifact counts to PATH as JSON."
+    )]
+    summary: Option<PathBuf>,
+    #[arg(
+        long,
+        conflicts_with_all = ["json", "quiet"],
+        help = "Print \"analyzed N/M rule-class pairs\" to stderr at most twice a second while rules run. Cannot be combined with --quiet."
+    )]
+    progress: bool,
+}
+
+impl ScanArgs {
+    /// The `--output`/`--format` destination followed by every `--additional-output`.
+    fn output_sinks(&self) -> Vec<OutputSink> {
+        let primary = OutputSink {
+            format: self.format,
+            path: self.output.clone(),
+            compression: self.output_format_compression,
+        };
+        std::iter::once(primary)
+            .chain(self.additional_output.iter().cloned())
+            .collect()
+    }
+
+    /// The `--baseline` path, or the default location when neither the flag nor a config set one.
+    fn baseline_path(&self) -> &Path {
+        self.baseline
+            .as_deref()
+            .unwrap_or(Path::new(DEFAULT_BASELINE_PATH))
+    }
 }
 
 /// Input configuration shared by all commands.
@@ -113,7 +477,7 @@ struct InputArgs {
         value_name = "PATH",
         num_args = 1..,
         conflicts_with = "json",
-        help = "Input class/JAR/directory paths. Use @file to read paths (one per line)."
+        help = "Input class/JAR/jmod/directory paths. Use @file to read paths (one per line). With --allow-remote, http(s):// URLs and mvn:group:artifact:version coordinates are downloaded."
     )]
     input: Vec<String>,
     #[arg(
@@ -124,6 +488,71 @@ struct InputArgs {
         help = "Classpath entries. Use @file to read paths (one per line)."
     )]
     classpath: Vec<String>,
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with = "json",
+        help = "Java release used to select META-INF/versions/N entries in multi-release JARs. Without this flag only base entries are scanned."
+    )]
+    release: Option<u32>,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Add JARs listed in the Class-Path attribute of JAR manifests to the classpath. Missing entries are skipped with a warning."
+    )]
+    follow_manifest_classpath: bool,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Analyze BOOT-INF/classes of Spring Boot fat JARs and use BOOT-INF/lib JARs as classpath."
+    )]
+    spring_boot: bool,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Record the SHA-256 of each scanned class file, JAR, and JAR entry artifact in SARIF artifacts[].hashes."
+    )]
+    emit_artifact_hashes: bool,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Analyze Android .dex files and the classes*.dex entries of .apk files."
+    )]
+    dex: bool,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Allow --input to download http(s):// URLs and mvn: coordinates into .inspequte/remote."
+    )]
+    allow_remote: bool,
+}
+
+/// Artifact URI options shared by commands that report findings.
+#[derive(Args, Debug, Clone, Default)]
+struct ArtifactUriArgs {
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "json",
+        help = "Emit file URIs under PATH relative to it and declare PATH in SARIF originalUriBaseIds. JAR entry URIs keep their jar:...!/... form with the JAR path relativized."
+    )]
+    relative_to: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "ID",
+        requires = "relative_to",
+        conflicts_with = "json",
+        help = "uriBaseId for URIs relativized by --relative-to. Defaults to SRCROOT."
+    )]
+    uri_base_id: Option<String>,
+}
+
+impl ArtifactUriArgs {
+    fn relativizer(&self) -> Option<UriRelativizer> {
+        let base_dir = self.relative_to.as_deref()?;
+        let uri_base_id = self.uri_base_id.as_deref().unwrap_or(DEFAULT_URI_BASE_ID);
+        Some(UriRelativizer::new(base_dir, uri_base_id))
+    }
 }
 
 /// Expanded input configuration after resolving @file references.
@@ -131,6 +560,7 @@ struct InputArgs {
 struct ExpandedInputArgs {
     input: Vec<PathBuf>,
     classpath: Vec<PathBuf>,
+    scan_options: ScanOptions,
 }
 
 /// Subcommands supported by the CLI.
@@ -138,6 +568,86 @@ struct ExpandedInputArgs {
 enum Command {
     /// Create a baseline file containing all current findings.
     Baseline(BaselineArgs),
+    /// Maintenance commands for the rule catalog.
+    #[command(hide = true, subcommand)]
+    Rules(RulesCommand),
+    /// Validate a SARIF file against the bundled SARIF 2.1.0 schema and print every error.
+    Verify(VerifyArgs),
+    /// Combine the runs of several SARIF files into one SARIF file.
+    Merge(MergeArgs),
+    /// Maintenance commands that dump intermediate analysis data.
+    #[command(hide = true, subcommand)]
+    Dump(DumpCommand),
+}
+
+/// Arguments for validating a SARIF file.
+#[derive(Args, Debug, Clone)]
+struct VerifyArgs {
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "SARIF file to validate. Files ending with .gz are decompressed first."
+    )]
+    input: PathBuf,
+}
+
+/// Arguments for merging SARIF files.
+#[derive(Args, Debug, Clone)]
+struct MergeArgs {
+    #[arg(
+        long = "input",
+        value_name = "PATH",
+        required = true,
+        help = "SARIF file to merge. Repeat to merge several files; their runs are kept in order. Files ending with .gz are decompressed first."
+    )]
+    inputs: Vec<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write the merged SARIF to this file instead of stdout. Paths ending with .gz are gzip-compressed."
+    )]
+    output: Option<PathBuf>,
+    #[arg(long, help = "Indent the merged SARIF for easier reading and diffing.")]
+    sarif_pretty: bool,
+}
+
+/// Subcommands of `inspequte rules`.
+#[derive(Subcommand, Debug, Clone)]
+enum RulesCommand {
+    /// Write every rule with its name, description, default level, and tags, plus one SARIF
+    /// taxon per tag, as a SARIF tool component JSON document.
+    Export(RulesExportArgs),
+}
+
+/// Arguments for exporting the rule catalog.
+#[derive(Args, Debug, Clone)]
+struct RulesExportArgs {
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write the catalog to this file instead of stdout."
+    )]
+    output: Option<PathBuf>,
+}
+
+/// Subcommands of `inspequte dump`.
+#[derive(Subcommand, Debug, Clone)]
+enum DumpCommand {
+    /// Write the resolved call graph of the input classes as JSON, without running rules.
+    Callgraph(DumpCallgraphArgs),
+}
+
+/// Arguments for dumping the call graph.
+#[derive(Args, Debug, Clone)]
+struct DumpCallgraphArgs {
+    #[command(flatten)]
+    input: InputArgs,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write the call graph to this file instead of stdout."
+    )]
+    output: Option<PathBuf>,
 }
 
 /// Arguments for creating a baseline file.
@@ -156,7 +666,7 @@ struct BaselineArgs {
         long,
         value_name = "URL",
         conflicts_with = "json",
-        help = "OTLP HTTP collector URL (recommended: http://localhost:4318/)."
+        help = "OTLP collector URL. Use http://localhost:4318/ for OTLP/HTTP or grpc://localhost:4317 for OTLP/gRPC."
     )]
     otel: Option<String>,
     #[arg(
@@ -165,6 +675,14 @@ struct BaselineArgs {
         help = "Warn instead of failing when the same class name appears in multiple inputs. The class from the lexicographically first artifact path is used."
     )]
     allow_duplicate_classes: bool,
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Rewrite the existing baseline at --output, keeping only entries that still match a current finding. New findings are not added."
+    )]
+    prune: bool,
+    #[command(flatten)]
+    artifact_uris: ArtifactUriArgs,
 }
 
 /// Supported command kinds in JSON request mode.
@@ -189,6 +707,15 @@ struct JsonRequest {
     output: Option<String>,
     #[serde(default)]
     allow_duplicate_classes: bool,
+    release: Option<u32>,
+    #[serde(default)]
+    follow_manifest_classpath: bool,
+    #[serde(default)]
+    spring_boot: bool,
+    #[serde(default)]
+    emit_artifact_hashes: bool,
+    #[serde(default)]
+    dex: bool,
 }
 
 /// Internal normalized request selected from CLI flags or JSON input.
@@ -196,12 +723,17 @@ struct JsonRequest {
 enum ExecutionRequest {
     Scan(ScanArgs),
     Baseline(BaselineArgs),
+    RulesExport(RulesExportArgs),
+    Verify(VerifyArgs),
+    Merge(MergeArgs),
+    DumpCallgraph(DumpCallgraphArgs),
 }
 
 fn main() -> std::process::ExitCode {
     let cli = Cli::parse();
     match run(cli) {
-        Ok(()) => std::process::ExitCode::SUCCESS,
+        Ok(false) => std::process::ExitCode::SUCCESS,
+        Ok(true) => std::process::ExitCode::from(FINDINGS_EXIT_CODE),
         Err(err) => {
             eprintln!("{err:?}");
             std::process::ExitCode::from(1)
@@ -209,10 +741,38 @@ fn main() -> std::process::ExitCode {
     }
 }
 
-fn run(cli: Cli) -> Result<()> {
+/// Runs the requested command. Returns true when findings reached the `--fail-on` threshold.
+fn run(cli: Cli) -> Result<bool> {
+    let quiet = cli.log.quiet;
+    let log_level = match cli.log.log_level {
+        Some(level) => Some(level.as_str()),
+        None if quiet => Some(LogLevel::Error.as_str()),
+        None if cli.log.verbose => Some(LogLevel::Debug.as_str()),
+        None => None,
+    };
+    init_logging(log_level);
     match resolve_execution_request(cli)? {
-        ExecutionRequest::Scan(args) => run_scan(args),
-        ExecutionRequest::Baseline(args) => run_baseline(args),
+        ExecutionRequest::Scan(args) => run_scan(args, quiet),
+        ExecutionRequest::Baseline(args) => {
+            run_baseline(args, quiet)?;
+            Ok(false)
+        }
+        ExecutionRequest::RulesExport(args) => {
+            run_rules_export(&args)?;
+            Ok(false)
+        }
+        ExecutionRequest::Verify(args) => {
+            run_verify(&args)?;
+            Ok(false)
+        }
+        ExecutionRequest::Merge(args) => {
+            run_merge(&args)?;
+            Ok(false)
+        }
+        ExecutionRequest::DumpCallgraph(args) => {
+            run_dump_callgraph(&args)?;
+            Ok(false)
+        }
     }
 }
 
@@ -225,6 +785,12 @@ fn resolve_execution_request(cli: Cli) -> Result<ExecutionRequest> {
     }
     match cli.command {
         Some(Command::Baseline(args)) => Ok(ExecutionRequest::Baseline(args)),
+        Some(Command::Rules(RulesCommand::Export(args))) => Ok(ExecutionRequest::RulesExport(args)),
+        Some(Command::Verify(args)) => Ok(ExecutionRequest::Verify(args)),
+        Some(Command::Merge(args)) => Ok(ExecutionRequest::Merge(args)),
+        Some(Command::Dump(DumpCommand::Callgraph(args))) => {
+            Ok(ExecutionRequest::DumpCallgraph(args))
+        }
         None => Ok(ExecutionRequest::Scan(cli.scan)),
     }
 }
@@ -276,6 +842,12 @@ fn build_execution_request_from_json(request: JsonRequest) -> Result<ExecutionRe
     let input = InputArgs {
         input: request.input,
         classpath: request.classpath,
+        release: request.release,
+        follow_manifest_classpath: request.follow_manifest_classpath,
+        spring_boot: request.spring_boot,
+        emit_artifact_hashes: request.emit_artifact_hashes,
+        dex: request.dex,
+        allow_remote: false,
     };
 
     match request.command {
@@ -283,14 +855,36 @@ fn build_execution_request_from_json(request: JsonRequest) -> Result<ExecutionRe
             let scan = ScanArgs {
                 input,
                 output: request.output.map(PathBuf::from),
+                format: OutputFormat::Sarif,
+                additional_output: Vec::new(),
+                output_format_compression: None,
+                sarif_pretty: false,
+                manifest: None,
+                replay_manifest: None,
+                config: None,
                 automation_details_id: None,
+                artifact_uris: ArtifactUriArgs::default(),
                 otel: None,
                 rules: request.rules,
-                baseline: request
-                    .baseline
-                    .map(PathBuf::from)
-                    .unwrap_or_else(|| PathBuf::from(DEFAULT_BASELINE_PATH)),
+                tags: Vec::new(),
+                exclude_rules: Vec::new(),
+                baseline: request.baseline.map(PathBuf::from),
                 allow_duplicate_classes: request.allow_duplicate_classes,
+                include_classes: Vec::new(),
+                exclude_classes: Vec::new(),
+                no_ignore_file: false,
+                changed_files: None,
+                dedupe_findings: false,
+                max_findings: None,
+                fail_on: FailOn::None,
+                timeout_per_class: None,
+                allow_exit_in_main: true,
+                rule_options: RuleOptionsConfig::default(),
+                cache_dir: PathBuf::from(DEFAULT_CACHE_DIR),
+                no_cache: false,
+                stats: false,
+                summary: None,
+                progress: false,
             };
             Ok(ExecutionRequest::Scan(scan))
         }
@@ -313,15 +907,29 @@ fn build_execution_request_from_json(request: JsonRequest) -> Result<ExecutionRe
                     .unwrap_or_else(|| PathBuf::from(DEFAULT_BASELINE_PATH)),
                 otel: None,
                 allow_duplicate_classes: request.allow_duplicate_classes,
+                prune: false,
+                artifact_uris: ArtifactUriArgs::default(),
             };
             Ok(ExecutionRequest::Baseline(baseline))
         }
     }
 }
 
-fn run_scan(args: ScanArgs) -> Result<()> {
-    let expanded = expand_input_args(&args.input)?;
-    let selected_rule_ids = expand_rule_args(&args.rules)?;
+fn run_scan(mut args: ScanArgs, quiet: bool) -> Result<bool> {
+    if let Some(path) = args.config.clone() {
+        apply_scan_config(&mut args, load_scan_config(&path)?);
+    }
+    let expanded = match &args.replay_manifest {
+        Some(path) => replay_input_args(path, &args.input)?,
+        None => expand_input_args(&args.input)?,
+    };
+    if let Some(path) = &args.manifest {
+        let manifest =
+            build_input_manifest(&expanded.input, &expanded.classpath, &expanded.scan_options)?;
+        write_input_manifest(path, &manifest)?;
+    }
+    let selected_rule_ids = expand_rule_selection(&args.rules, &args.tags)?;
+    let excluded_rule_ids = expand_excluded_rule_args(&args.exclude_rules)?;
     let root_span_name = build_root_span_name(&expanded.input);
     let root_span_attributes = build_root_span_attributes("scan", &expanded.input);
 
@@ -329,43 +937,118 @@ fn run_scan(args: ScanArgs) -> Result<()> {
         Some(url) => Some(Arc::new(Telemetry::new(url.clone())?)),
         None => None,
     };
-    init_logging();
     let result = with_span(
         telemetry.as_deref(),
         &root_span_name,
         &root_span_attributes,
         || {
-            if let Some(trace_id) = current_trace_id() {
+            let trace_id = current_trace_id();
+            if !quiet && let Some(trace_id) = &trace_id {
                 eprintln!("trace-id={trace_id}");
             }
+            let mut summary = ScanSummary::new(trace_id);
+            let changed_files = args
+                .changed_files
+                .as_deref()
+                .map(read_changed_files)
+                .transpose()?;
+            let ignore_patterns = if args.no_ignore_file {
+                Vec::new()
+            } else {
+                load_ignore_file(Path::new(IGNORE_FILE_NAME))?
+            };
+            let options = AnalysisOptions {
+                allow_duplicate_classes: args.allow_duplicate_classes,
+                class_timeout: args.timeout_per_class.map(Duration::from_millis),
+                excluded_rule_ids,
+                rule_options: args.rule_options.to_rule_options(args.allow_exit_in_main),
+                cache_dir: (!args.no_cache).then(|| args.cache_dir.clone()),
+                class_filter: ClassFilter::new(
+                    args.include_classes.clone(),
+                    args.exclude_classes.clone(),
+                )
+                .with_changed_files(changed_files)
+                .with_ignore_patterns(ignore_patterns),
+                uri_relativizer: args.artifact_uris.relativizer(),
+                progress: args.progress,
+            };
+            if args.format == OutputFormat::Ndjson {
+                if !args.additional_output.is_empty() {
+                    anyhow::bail!("--additional-output cannot be combined with --format ndjson");
+                }
+                if args.max_findings.is_some() {
+                    anyhow::bail!("--max-findings cannot be combined with --format ndjson");
+                }
+                if args.dedupe_findings {
+                    anyhow::bail!("--dedupe-findings cannot be combined with --format ndjson");
+                }
+                let fail_on_reached = stream_ndjson(
+                    &args,
+                    &expanded,
+                    selected_rule_ids.as_ref(),
+                    telemetry.clone(),
+                    options,
+                    &mut summary,
+                )?;
+                if let Some(path) = &args.summary {
+                    write_scan_summary(path, &summary)?;
+                }
+                return Ok(fail_on_reached);
+            }
             let mut analysis = analyze(
                 &expanded.input,
                 &expanded.classpath,
+                &expanded.scan_options,
                 selected_rule_ids.as_ref(),
                 telemetry.clone(),
-                args.allow_duplicate_classes,
+                options,
+                None,
             )?;
+            report_stats(
+                &analysis.invocation_stats,
+                args.stats,
+                &mut io::stderr().lock(),
+            )?;
+            if args.dedupe_findings {
+                analysis.results =
+                    dedupe_findings(std::mem::take(&mut analysis.results), &analysis.artifacts);
+            }
             let analysis_ref = &mut analysis;
             let baseline_result = with_span(
                 telemetry.as_deref(),
                 "baseline",
                 &[KeyValue::new("inspequte.phase", "baseline")],
                 || -> Result<()> {
-                    if let Some(baseline) = load_baseline(&args.baseline)? {
+                    if let Some(baseline) = load_baseline(args.baseline_path())? {
+                        let unfiltered_count = analysis_ref.results.len();
                         let filtered = baseline.filter(std::mem::take(&mut analysis_ref.results));
+                        summary.record_suppressed(unfiltered_count - filtered.len());
                         analysis_ref.results = filtered;
                     }
                     Ok(())
                 },
             );
             baseline_result?;
+            summary.record_scan(&analysis.invocation_stats);
+            analysis
+                .results
+                .iter()
+                .for_each(|result| summary.record_finding(result));
+            if let Some(path) = &args.summary {
+                write_scan_summary(path, &summary)?;
+            }
+            let fail_on_reached = reaches_fail_on(&analysis.results, args.fail_on);
+            if let Some(max_findings) = args.max_findings {
+                analysis.invocation_stats.truncated_finding_total =
+                    truncate_findings(&mut analysis.results, max_findings);
+            }
             with_span(
                 telemetry.as_deref(),
                 "sarif",
                 &[KeyValue::new("inspequte.phase", "sarif")],
                 || -> Result<()> {
                     let invocation = build_invocation(&analysis.invocation_stats);
-                    let sarif = build_sarif(
+                    let mut sarif = build_sarif(
                         telemetry.as_deref(),
                         analysis.artifacts,
                         invocation,
@@ -373,6 +1056,12 @@ fn run_scan(args: ScanArgs) -> Result<()> {
                         analysis.results,
                         args.automation_details_id.clone(),
                     );
+                    if let Some(relativizer) = args.artifact_uris.relativizer() {
+                        sarif
+                            .runs
+                            .iter_mut()
+                            .for_each(|run| relativizer.declare_base(run));
+                    }
                     if should_validate_sarif() {
                         validate_sarif(&sarif)?;
                     }
@@ -380,22 +1069,14 @@ fn run_scan(args: ScanArgs) -> Result<()> {
                         telemetry.as_deref(),
                         "sarif.write",
                         &[KeyValue::new("inspequte.phase", "write")],
-                        || -> Result<()> {
-                            let mut writer = output_writer(args.output.as_deref())?;
-                            serde_json::to_writer(&mut writer, &sarif)
-                                .context("failed to serialize SARIF output")?;
-                            writer
-                                .write_all(b"\n")
-                                .context("failed to write SARIF output")?;
-                            Ok(())
-                        },
+                        || write_output_sinks(&sarif, &args.output_sinks(), args.sarif_pretty),
                     );
                     write_result?;
                     Ok(())
                 },
             )?;
 
-            Ok(())
+            Ok(fail_on_reached)
         },
     );
 
@@ -408,7 +1089,171 @@ fn run_scan(args: ScanArgs) -> Result<()> {
     result
 }
 
-fn run_baseline(args: BaselineArgs) -> Result<()> {
+/// Runs a scan that writes each finding left after baseline filtering as one JSON line as soon
+/// as the engine produces it. Returns true when a written finding reached `--fail-on`.
+fn stream_ndjson(
+    args: &ScanArgs,
+    expanded: &ExpandedInputArgs,
+    selected_rule_ids: Option<&BTreeSet<String>>,
+    telemetry: Option<Arc<Telemetry>>,
+    options: AnalysisOptions,
+    summary: &mut ScanSummary,
+) -> Result<bool> {
+    let baseline = load_baseline(args.baseline_path())?;
+    let mut fail_on_reached = false;
+    with_output_writer(
+        args.output.as_deref(),
+        args.output_format_compression,
+        |writer| {
+            let mut on_result = |result: SarifResult| -> Result<()> {
+                if baseline
+                    .as_ref()
+                    .is_some_and(|baseline| baseline.contains(&result))
+                {
+                    summary.record_suppressed(1);
+                    return Ok(());
+                }
+                summary.record_finding(&result);
+                fail_on_reached |= reaches_fail_on(std::slice::from_ref(&result), args.fail_on);
+                write_ndjson_line(writer, &result)
+            };
+            let analysis = analyze(
+                &expanded.input,
+                &expanded.classpath,
+                &expanded.scan_options,
+                selected_rule_ids,
+                telemetry,
+                options,
+                Some(&mut on_result),
+            )?;
+            summary.record_scan(&analysis.invocation_stats);
+            report_stats(
+                &analysis.invocation_stats,
+                args.stats,
+                &mut io::stderr().lock(),
+            )
+        },
+    )?;
+    Ok(fail_on_reached)
+}
+
+/// Returns true when any result has a level at or above the `--fail-on` threshold.
+/// Results without an explicit level are treated as `warning`, the SARIF default.
+fn reaches_fail_on(results: &[SarifResult], fail_on: FailOn) -> bool {
+    let Some(threshold) = fail_on.threshold() else {
+        return false;
+    };
+    results.iter().any(|result| {
+        let severity = match result.level.as_ref().and_then(|level| level.as_str()) {
+            Some("error") => Some(Severity::Error),
+            Some("note") => Some(Severity::Note),
+            Some("none") => None,
+            _ => Some(Severity::Warning),
+        };
+        severity.is_some_and(|severity| severity >= threshold)
+    })
+}
+
+/// Keeps the first `max_findings` results in rule and location order. Returns the number of
+/// results before truncation when any were dropped.
+fn truncate_findings(results: &mut Vec<SarifResult>, max_findings: usize) -> Option<usize> {
+    let total = results.len();
+    if total <= max_findings {
+        return None;
+    }
+    results.sort_by(compare_results);
+    results.truncate(max_findings);
+    Some(total)
+}
+
+fn run_verify(args: &VerifyArgs) -> Result<()> {
+    read_sarif_value(&args.input).map(|_| ())
+}
+
+/// Writes one SARIF log holding every run of the inputs, in input order. Runs are copied
+/// unchanged, so each keeps its own tool, rules, invocations, and results.
+fn run_merge(args: &MergeArgs) -> Result<()> {
+    let mut runs = Vec::new();
+    for input in &args.inputs {
+        let mut value = read_sarif_value(input)?;
+        if let Some(serde_json::Value::Array(input_runs)) = value.get_mut("runs") {
+            runs.append(input_runs);
+        }
+    }
+    let merged = json!({
+        "$schema": SCHEMA_URL,
+        "version": "2.1.0",
+        "runs": runs,
+    });
+    validate_sarif_value(&merged).context("merged SARIF is not valid SARIF 2.1.0")?;
+    with_output_writer(args.output.as_deref(), None, |writer| {
+        if args.sarif_pretty {
+            serde_json::to_writer_pretty(&mut *writer, &merged)
+        } else {
+            serde_json::to_writer(&mut *writer, &merged)
+        }
+        .context("failed to serialize merged SARIF")?;
+        writer
+            .write_all(b"\n")
+            .context("failed to write merged SARIF")
+    })
+}
+
+/// Reads the SARIF file at `path`, decompressing it when it ends with `.gz`, and checks it
+/// against the bundled schema.
+fn read_sarif_value(path: &Path) -> Result<serde_json::Value> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let reader: Box<dyn Read> = if path.extension().is_some_and(|extension| extension == "gz") {
+        Box::new(flate2::read::GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    let value: serde_json::Value = serde_json::from_reader(io::BufReader::new(reader))
+        .with_context(|| format!("failed to parse {} as JSON", path.display()))?;
+    validate_sarif_value(&value)
+        .with_context(|| format!("{} is not valid SARIF 2.1.0", path.display()))?;
+    Ok(value)
+}
+
+fn run_rules_export(args: &RulesExportArgs) -> Result<()> {
+    let catalog = rule_catalog();
+    with_output_writer(args.output.as_deref(), None, |writer| {
+        serde_json::to_writer_pretty(&mut *writer, &catalog)
+            .context("failed to serialize rule catalog")?;
+        writer
+            .write_all(b"\n")
+            .context("failed to write rule catalog")
+    })
+}
+
+/// Scans the inputs and writes their call graph without running any rule, to diagnose
+/// hierarchy-based rules.
+fn run_dump_callgraph(args: &DumpCallgraphArgs) -> Result<()> {
+    let expanded = expand_input_args(&args.input)?;
+    let scan = scan_inputs(
+        &expanded.input,
+        &expanded.classpath,
+        &expanded.scan_options,
+        None,
+    )?;
+    let (context, _) = build_context_with_timings(
+        scan.classes,
+        scan.modules,
+        &scan.artifacts,
+        &ClassFilter::default(),
+        None,
+    );
+    let call_graph = build_call_graph(&context);
+    with_output_writer(args.output.as_deref(), None, |writer| {
+        serde_json::to_writer_pretty(&mut *writer, &call_graph)
+            .context("failed to serialize call graph")?;
+        writer
+            .write_all(b"\n")
+            .context("failed to write call graph")
+    })
+}
+
+fn run_baseline(args: BaselineArgs, quiet: bool) -> Result<()> {
     let expanded = expand_input_args(&args.input)?;
     let root_span_name = build_root_span_name(&expanded.input);
     let root_span_attributes = build_root_span_attributes("baseline", &expanded.input);
@@ -416,23 +1261,42 @@ fn run_baseline(args: BaselineArgs) -> Result<()> {
         Some(url) => Some(Arc::new(Telemetry::new(url.clone())?)),
         None => None,
     };
-    init_logging();
     let result = with_span(
         telemetry.as_deref(),
         &root_span_name,
         &root_span_attributes,
         || -> Result<()> {
-            if let Some(trace_id) = current_trace_id() {
+            if !quiet && let Some(trace_id) = current_trace_id() {
                 eprintln!("trace-id={trace_id}");
             }
             let analysis = analyze(
                 &expanded.input,
                 &expanded.classpath,
+                &expanded.scan_options,
                 None,
                 telemetry.clone(),
-                args.allow_duplicate_classes,
+                AnalysisOptions {
+                    allow_duplicate_classes: args.allow_duplicate_classes,
+                    uri_relativizer: args.artifact_uris.relativizer(),
+                    ..AnalysisOptions::default()
+                },
+                None,
             )?;
-            write_baseline(&args.output, &analysis.results)?;
+            if !args.prune {
+                return write_baseline(&args.output, &analysis.results);
+            }
+            let Some(existing) = load_baseline(&args.output)? else {
+                anyhow::bail!("no baseline to prune at {}", args.output.display());
+            };
+            let pruned = existing.pruned(&analysis.results);
+            write_baseline_file(&args.output, &pruned)?;
+            if !quiet {
+                eprintln!(
+                    "removed {} stale baseline entries from {}",
+                    existing.finding_count() - pruned.finding_count(),
+                    args.output.display()
+                );
+            }
             Ok(())
         },
     );
@@ -448,6 +1312,11 @@ fn expand_input_args(args: &InputArgs) -> Result<ExpandedInputArgs> {
     let base_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
     let input =
         expand_path_args(&args.input, &base_dir).context("failed to expand --input arguments")?;
+    let input = fetch_remote_inputs(
+        input,
+        args.allow_remote,
+        &base_dir.join(DEFAULT_REMOTE_CACHE_DIR),
+    )?;
     let input = filter_missing_paths("input", input)?;
     if input.is_empty() {
         anyhow::bail!("no input paths provided");
@@ -455,7 +1324,31 @@ fn expand_input_args(args: &InputArgs) -> Result<ExpandedInputArgs> {
     let classpath = expand_path_args(&args.classpath, &base_dir)
         .context("failed to expand --classpath arguments")?;
     let classpath = filter_missing_paths("classpath entry", classpath)?;
-    Ok(ExpandedInputArgs { input, classpath })
+    Ok(ExpandedInputArgs {
+        input,
+        classpath,
+        scan_options: build_scan_options(args),
+    })
+}
+
+/// Reads the source paths listed by `--changed-files`, with or without a leading `@`.
+///
+/// Blank lines and `#` comments are skipped. For tab-separated `git diff --name-status`
+/// lines, the last field is used, which is the new path of a renamed file.
+fn read_changed_files(arg: &str) -> Result<Vec<String>> {
+    let path = Path::new(arg.strip_prefix('@').unwrap_or(arg));
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read --changed-files {}", path.display()))?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            line.rsplit_once('\t')
+                .map_or(line, |(_, path)| path)
+                .to_string()
+        })
+        .collect())
 }
 
 fn expand_path_args(args: &[String], base_dir: &Path) -> Result<Vec<PathBuf>> {
@@ -483,9 +1376,7 @@ fn expand_path_arg(arg: &str, base_dir: &Path, stack: &mut Vec<PathBuf>) -> Resu
     let canonical = resolved
         .canonicalize()
         .with_context(|| format!("failed to resolve {}", resolved.display()))?;
-    if stack.contains(&canonical) {
-        anyhow::bail!("circular @file reference: {}", canonical.display());
-    }
+    check_at_file_nesting(stack, &canonical, "")?;
     let content = fs::read_to_string(&canonical)
         .with_context(|| format!("failed to read {}", canonical.display()))?;
     stack.push(canonical.clone());
@@ -500,6 +1391,10 @@ fn expand_path_arg(arg: &str, base_dir: &Path, stack: &mut Vec<PathBuf>) -> Resu
             paths.extend(expand_path_arg(line, file_dir, stack)?);
             continue;
         }
+        if is_remote_input(line) {
+            paths.push(PathBuf::from(line));
+            continue;
+        }
         let entry = PathBuf::from(line);
         let resolved_entry = if entry.is_absolute() {
             entry
@@ -512,6 +1407,36 @@ fn expand_path_arg(arg: &str, base_dir: &Path, stack: &mut Vec<PathBuf>) -> Resu
     Ok(paths)
 }
 
+/// Checks that `canonical` can be opened inside the `@file` references in `stack`: it must not
+/// be one of them, and the chain must stay within `MAX_AT_FILE_DEPTH` files. `option` is
+/// appended to the error message, e.g. " in --rules".
+fn check_at_file_nesting(stack: &[PathBuf], canonical: &Path, option: &str) -> Result<()> {
+    if stack.iter().any(|open| open == canonical) {
+        anyhow::bail!(
+            "circular @file reference{option}: {}",
+            format_include_chain(stack, canonical)
+        );
+    }
+    if stack.len() >= MAX_AT_FILE_DEPTH {
+        anyhow::bail!(
+            "@file references{option} are nested more than {MAX_AT_FILE_DEPTH} levels deep: {}",
+            format_include_chain(stack, canonical)
+        );
+    }
+    Ok(())
+}
+
+/// Formats the files in `stack` followed by `next` as `a.txt -> b.txt -> c.txt`.
+fn format_include_chain(stack: &[PathBuf], next: &Path) -> String {
+    stack
+        .iter()
+        .map(PathBuf::as_path)
+        .chain(std::iter::once(next))
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
 fn filter_missing_paths(label: &str, paths: Vec<PathBuf>) -> Result<Vec<PathBuf>> {
     let mut filtered = Vec::new();
     for path in paths {
@@ -527,6 +1452,44 @@ fn filter_missing_paths(label: &str, paths: Vec<PathBuf>) -> Result<Vec<PathBuf>
     Ok(filtered)
 }
 
+fn build_scan_options(args: &InputArgs) -> ScanOptions {
+    ScanOptions {
+        release: args.release,
+        follow_manifest_classpath: args.follow_manifest_classpath,
+        spring_boot: args.spring_boot,
+        emit_artifact_hashes: args.emit_artifact_hashes,
+        dex: args.dex,
+    }
+}
+
+/// Restores the inputs and scan options of a `--manifest` run. Flags that would change how
+/// the recorded files are read are rejected rather than silently ignored.
+fn replay_input_args(path: &Path, args: &InputArgs) -> Result<ExpandedInputArgs> {
+    let overridden = [
+        ("--release", args.release.is_some()),
+        (
+            "--follow-manifest-classpath",
+            args.follow_manifest_classpath,
+        ),
+        ("--spring-boot", args.spring_boot),
+        ("--dex", args.dex),
+    ];
+    if let Some((flag, _)) = overridden.iter().find(|(_, set)| *set) {
+        anyhow::bail!(
+            "{flag} cannot be used with --replay-manifest, which restores the recorded scan options"
+        );
+    }
+    let replayed = replay_input_manifest(path)?;
+    Ok(ExpandedInputArgs {
+        input: replayed.input,
+        classpath: replayed.classpath,
+        scan_options: ScanOptions {
+            emit_artifact_hashes: args.emit_artifact_hashes,
+            ..replayed.scan_options
+        },
+    })
+}
+
 /// Aggregated analysis output before SARIF serialization.
 struct AnalysisOutput {
     artifacts: Vec<Artifact>,
@@ -535,19 +1498,42 @@ struct AnalysisOutput {
     results: Vec<SarifResult>,
 }
 
+/// Classpath, engine, and rule settings; most of them only scans change from their defaults.
+#[derive(Clone, Debug, Default)]
+struct AnalysisOptions {
+    /// Warn instead of failing when a class name appears in multiple inputs.
+    allow_duplicate_classes: bool,
+    class_timeout: Option<Duration>,
+    /// Rules skipped even when they are enabled by default or named in `--rules`.
+    excluded_rule_ids: BTreeSet<String>,
+    rule_options: RuleOptions,
+    /// Result cache directory; `None` analyzes every class without caching.
+    cache_dir: Option<PathBuf>,
+    /// Narrows which classes are analysis targets; other classes stay loaded as dependencies.
+    class_filter: ClassFilter,
+    /// Relativizes artifact URIs of the output; `None` keeps absolute URIs.
+    uri_relativizer: Option<UriRelativizer>,
+    /// Print throttled rule progress to stderr.
+    progress: bool,
+}
+
+/// Scans the inputs and runs the rules. With `on_result`, each finding is passed to it while
+/// rules run and the returned output carries no results.
 fn analyze(
     input: &[PathBuf],
     classpath: &[PathBuf],
+    scan_options: &ScanOptions,
     selected_rule_ids: Option<&BTreeSet<String>>,
     telemetry: Option<Arc<Telemetry>>,
-    allow_duplicate_classes: bool,
+    options: AnalysisOptions,
+    on_result: Option<&mut dyn FnMut(SarifResult) -> Result<()>>,
 ) -> Result<AnalysisOutput> {
     let scan_started_at = Instant::now();
     let scan = with_span(
         telemetry.as_deref(),
         "scan",
         &[KeyValue::new("inspequte.phase", "scan")],
-        || scan_inputs(input, classpath, telemetry.as_deref()),
+        || scan_inputs(input, classpath, scan_options, telemetry.as_deref()),
     )?;
     let scan_duration_ms = scan_started_at.elapsed().as_millis();
     let artifact_count = scan.artifacts.len();
@@ -556,22 +1542,62 @@ fn analyze(
         telemetry.as_deref(),
         "classpath",
         &[KeyValue::new("inspequte.phase", "classpath")],
-        || resolve_classpath(&scan.classes, &scan.artifacts, allow_duplicate_classes),
+        || {
+            resolve_classpath(
+                &scan.classes,
+                &scan.artifacts,
+                options.allow_duplicate_classes,
+            )
+        },
     )?;
     let classpath_duration_ms = classpath_started_at.elapsed().as_millis();
     let classpath_class_count = classpath_index.classes.len();
     let artifacts = scan.artifacts;
     let classes = scan.classes;
-    let (context, context_timings) =
-        build_context_with_timings(classes, &artifacts, telemetry.clone());
+    let (context, context_timings) = build_context_with_timings(
+        classes,
+        scan.modules,
+        &artifacts,
+        &options.class_filter,
+        telemetry.clone(),
+    );
+    let context = context.with_rule_options(options.rule_options);
     let analysis_rules_started_at = Instant::now();
-    let engine = Engine::new_with_allowed_rule_ids(selected_rule_ids)?;
+    let engine = Engine::new_with_allowed_rule_ids(selected_rule_ids)?
+        .without_rule_ids(&options.excluded_rule_ids)?
+        .with_class_timeout(options.class_timeout)
+        .with_progress(
+            options
+                .progress
+                .then(|| ProgressReporter::new(PROGRESS_INTERVAL).into_stderr_hook()),
+        );
+    let engine = match &options.cache_dir {
+        Some(cache_dir) => {
+            let key = CacheKey::new(engine.rule_ids(), context.rule_options())?;
+            let result_cache = load_result_cache(cache_dir, key)?;
+            engine.with_result_cache(Some(result_cache))
+        }
+        None => engine,
+    };
     let analysis = with_span(
         telemetry.as_deref(),
         "analysis_rules",
         &[KeyValue::new("inspequte.phase", "analysis_rules")],
-        || engine.analyze(context),
+        || match on_result {
+            Some(on_result) => engine
+                .analyze_streaming(context, |mut result| {
+                    if let Some(relativizer) = &options.uri_relativizer {
+                        relativizer.relativize_result(&mut result);
+                    }
+                    on_result(result)
+                })
+                .map(|summary| summary.into_output(Vec::new())),
+            None => engine.analyze(context),
+        },
     )?;
+    if let (Some(cache_dir), Some(result_cache)) = (&options.cache_dir, &analysis.result_cache) {
+        write_result_cache(cache_dir, result_cache)?;
+    }
     let analysis_rules_duration_ms = analysis_rules_started_at.elapsed().as_millis();
     let invocation_stats = InvocationStats {
         scan_duration_ms,
@@ -585,16 +1611,45 @@ fn analyze(
         class_count: scan.class_count,
         artifact_count,
         classpath_class_count,
+        rule_timings: analysis.rule_timings,
+        skipped_classes: analysis.skipped_classes,
+        cached_class_count: analysis.cached_classes.len(),
+        duplicate_classes: classpath_index.duplicate_classes,
+        truncated_finding_total: None,
     };
 
+    let mut artifacts = artifacts;
+    let mut results = analysis.results;
+    if let Some(relativizer) = &options.uri_relativizer {
+        relativizer.relativize_artifacts(&mut artifacts);
+        for result in &mut results {
+            relativizer.relativize_result(result);
+        }
+    }
+
     Ok(AnalysisOutput {
         artifacts,
         invocation_stats,
         rules: analysis.rules,
-        results: analysis.results,
+        results,
     })
 }
 
+/// Resolves `--rules` and `--tags` into the union of the selected rule IDs.
+fn expand_rule_selection(
+    rule_args: &[String],
+    tag_args: &[String],
+) -> Result<Option<BTreeSet<String>>> {
+    let tag_tokens = tag_args
+        .iter()
+        .flat_map(|arg| arg.split(','))
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(|tag| format!("{CATEGORY_RULE_PREFIX}{tag}"));
+    let args: Vec<String> = rule_args.iter().cloned().chain(tag_tokens).collect();
+    expand_rule_args(&args)
+}
+
 fn expand_rule_args(args: &[String]) -> Result<Option<BTreeSet<String>>> {
     if args.is_empty() {
         return Ok(None);
@@ -611,6 +1666,39 @@ fn expand_rule_args(args: &[String]) -> Result<Option<BTreeSet<String>>> {
     Ok(Some(rules))
 }
 
+fn expand_excluded_rule_args(args: &[String]) -> Result<BTreeSet<String>> {
+    let mut rules = BTreeSet::new();
+    let mut stack = Vec::new();
+    let base_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    for arg in args {
+        collect_rules_from_cli_arg(arg, &base_dir, &mut stack, &mut rules)?;
+    }
+    Ok(rules)
+}
+
+/// Fills scan settings that were not given on the command line from a `--config` file.
+fn apply_scan_config(args: &mut ScanArgs, config: ScanConfig) {
+    if args.input.input.is_empty() {
+        args.input.input = config.inputs;
+    }
+    if args.input.classpath.is_empty() {
+        args.input.classpath = config.classpath;
+    }
+    if args.rules.is_empty() {
+        args.rules = config.rules;
+    }
+    if args.exclude_rules.is_empty() {
+        args.exclude_rules = config.exclude_rules;
+    }
+    if args.input.release.is_none() {
+        args.input.release = config.release;
+    }
+    args.rule_options = config.rule_options;
+    if args.baseline.is_none() {
+        args.baseline = config.baseline.map(PathBuf::from);
+    }
+}
+
 fn collect_rules_from_cli_arg(
     arg: &str,
     base_dir: &Path,
@@ -622,6 +1710,10 @@ fn collect_rules_from_cli_arg(
         if token.is_empty() {
             continue;
         }
+        if let Some(tag) = token.strip_prefix(CATEGORY_RULE_PREFIX) {
+            rules.extend(rule_ids_with_tag(tag.trim())?);
+            continue;
+        }
         if let Some(path_str) = token.strip_prefix('@') {
             collect_rules_from_file(path_str, base_dir, stack, rules)?;
             continue;
@@ -650,12 +1742,7 @@ fn collect_rules_from_file(
     let canonical = resolved
         .canonicalize()
         .with_context(|| format!("failed to resolve {}", resolved.display()))?;
-    if stack.contains(&canonical) {
-        anyhow::bail!(
-            "circular @file reference in --rules: {}",
-            canonical.display()
-        );
-    }
+    check_at_file_nesting(stack, &canonical, " in --rules")?;
     let content = fs::read_to_string(&canonical)
         .with_context(|| format!("failed to read {}", canonical.display()))?;
     stack.push(canonical.clone());
@@ -665,6 +1752,10 @@ fn collect_rules_from_file(
         if line.is_empty() || line.starts_with('#') {
             continue;
         }
+        if let Some(tag) = line.strip_prefix(CATEGORY_RULE_PREFIX) {
+            rules.extend(rule_ids_with_tag(tag.trim())?);
+            continue;
+        }
         if let Some(nested_path) = line.strip_prefix('@') {
             collect_rules_from_file(nested_path, file_dir, stack, rules)?;
             continue;
@@ -682,6 +1773,86 @@ fn collect_rules_from_file(
     Ok(())
 }
 
+/// Writes `sarif` to every sink, so consumers that need different formats share one scan.
+fn write_output_sinks(sarif: &Sarif, sinks: &[OutputSink], pretty: bool) -> Result<()> {
+    for sink in sinks {
+        match sink.format {
+            OutputFormat::Sarif => {
+                write_sarif_output(sarif, sink.path.as_deref(), sink.compression, pretty)?
+            }
+            OutputFormat::Text => {
+                with_output_writer(sink.path.as_deref(), sink.compression, |writer| {
+                    write_text_report(writer, sarif)
+                })?
+            }
+            OutputFormat::Ndjson => {
+                anyhow::bail!("ndjson output is streamed and cannot be written from a SARIF log")
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_sarif_output(
+    sarif: &Sarif,
+    output: Option<&Path>,
+    compression: Option<OutputCompression>,
+    pretty: bool,
+) -> Result<()> {
+    with_output_writer(output, compression, |writer| {
+        write_sarif_json(writer, sarif, pretty)
+    })
+}
+
+/// Opens `output` with the resolved compression, lets `write` fill it, and finishes the stream.
+fn with_output_writer<F>(
+    output: Option<&Path>,
+    compression: Option<OutputCompression>,
+    write: F,
+) -> Result<()>
+where
+    F: FnOnce(&mut dyn Write) -> Result<()>,
+{
+    let mut writer = output_writer(output)?;
+    match OutputCompression::resolve(compression, output) {
+        OutputCompression::None => {
+            write(&mut writer)?;
+            writer.flush().context("failed to write output")
+        }
+        OutputCompression::Gzip => {
+            let mut encoder = GzEncoder::new(writer, Compression::default());
+            write(&mut encoder)?;
+            encoder
+                .finish()
+                .context("failed to finish gzip output")?
+                .flush()
+                .context("failed to write output")
+        }
+    }
+}
+
+/// Writes `result` as one JSON line with a single write so the line appears as a whole.
+fn write_ndjson_line(writer: &mut dyn Write, result: &SarifResult) -> Result<()> {
+    let mut line = serde_json::to_vec(result).context("failed to serialize finding")?;
+    line.push(b'\n');
+    writer
+        .write_all(&line)
+        .context("failed to write NDJSON output")
+}
+
+fn write_sarif_json<W: Write>(mut writer: W, sarif: &Sarif, pretty: bool) -> Result<()> {
+    if pretty {
+        serde_json::to_writer_pretty(&mut writer, sarif)
+    } else {
+        serde_json::to_writer(&mut writer, sarif)
+    }
+    .context("failed to serialize SARIF output")?;
+    writer
+        .write_all(b"\n")
+        .context("failed to write SARIF output")?;
+    writer.flush().context("failed to write SARIF output")
+}
+
 fn output_writer(output: Option<&Path>) -> Result<Box<dyn Write>> {
     match output {
         Some(path) if path == Path::new("-") => Ok(Box::new(io::stdout())),
@@ -731,6 +1902,7 @@ fn classify_target_kind(inputs: &[PathBuf]) -> &'static str {
         } else {
             match path.extension().and_then(|ext| ext.to_str()) {
                 Some(ext) if ext.eq_ignore_ascii_case("jar") => "jar",
+                Some(ext) if ext.eq_ignore_ascii_case("jmod") => "jmod",
                 Some(ext) if ext.eq_ignore_ascii_case("class") => "class",
                 _ => "mixed",
             }
@@ -799,8 +1971,116 @@ struct InvocationStats {
     class_count: usize,
     artifact_count: usize,
     classpath_class_count: usize,
+    rule_timings: Vec<RuleTiming>,
+    skipped_classes: Vec<String>,
+    cached_class_count: usize,
+    /// Classes defined by several artifacts, mapped to the competing artifact URIs.
+    duplicate_classes: BTreeMap<String, Vec<String>>,
+    /// Number of findings before `--max-findings` dropped some; `None` when none were dropped.
+    truncated_finding_total: Option<usize>,
+}
+
+/// Human-readable summary printed by `--stats`. Finding counts are taken before baseline
+/// filtering.
+impl fmt::Display for InvocationStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "inspequte stats:")?;
+        writeln!(f, "  scan: {} ms", self.scan_duration_ms)?;
+        writeln!(f, "  classpath: {} ms", self.classpath_duration_ms)?;
+        writeln!(
+            f,
+            "  call graph: {} ms (hierarchy {} ms, index {} ms, edges {} ms)",
+            self.analysis_call_graph_duration_ms,
+            self.analysis_call_graph_hierarchy_duration_ms,
+            self.analysis_call_graph_index_duration_ms,
+            self.analysis_call_graph_edges_duration_ms
+        )?;
+        writeln!(
+            f,
+            "  artifact analysis: {} ms",
+            self.analysis_artifact_duration_ms
+        )?;
+        writeln!(f, "  rules: {} ms", self.analysis_rules_duration_ms)?;
+        writeln!(
+            f,
+            "  classes: {} scanned, {} on classpath, {} cached, {} skipped",
+            self.class_count,
+            self.classpath_class_count,
+            self.cached_class_count,
+            self.skipped_classes.len()
+        )?;
+        writeln!(f, "  artifacts: {}", self.artifact_count)?;
+        let total: usize = self
+            .rule_timings
+            .iter()
+            .map(|timing| timing.finding_count)
+            .sum();
+        writeln!(f, "  findings: {total}")?;
+        for timing in &self.rule_timings {
+            if timing.finding_count == 0 {
+                continue;
+            }
+            writeln!(
+                f,
+                "    {}: {} ({:.1} ms)",
+                timing.rule_id,
+                timing.finding_count,
+                timing.duration.as_secs_f64() * 1000.0
+            )?;
+        }
+        Ok(())
+    }
 }
 
+/// Writes the `--stats` summary to `writer` when `enabled`.
+fn report_stats(stats: &InvocationStats, enabled: bool, writer: &mut dyn Write) -> Result<()> {
+    if enabled {
+        write!(writer, "{stats}").context("failed to write stats")?;
+    }
+    Ok(())
+}
+
+/// Counts findings in total, per rule ID, and per level for SARIF `run.properties`.
+/// Results without an explicit level use their rule's default level, then `warning`.
+fn findings_summary(rules: &[ReportingDescriptor], results: &[SarifResult]) -> PropertyBag {
+    let default_levels: BTreeMap<&str, &str> = rules
+        .iter()
+        .filter_map(|rule| {
+            let level = rule
+                .default_configuration
+                .as_ref()?
+                .level
+                .as_ref()?
+                .as_str()?;
+            Some((rule.id.as_str(), level))
+        })
+        .collect();
+    let mut by_rule: BTreeMap<String, usize> = BTreeMap::new();
+    let mut by_level: BTreeMap<String, usize> = BTreeMap::new();
+    for result in results {
+        let rule_id = result.rule_id.as_deref().unwrap_or_default();
+        let level = result
+            .level
+            .as_ref()
+            .and_then(|level| level.as_str())
+            .or_else(|| default_levels.get(rule_id).copied())
+            .unwrap_or("warning");
+        *by_rule.entry(rule_id.to_string()).or_default() += 1;
+        *by_level.entry(level.to_string()).or_default() += 1;
+    }
+    let properties = BTreeMap::from([
+        ("inspequte.finding_count".to_string(), json!(results.len())),
+        ("inspequte.findings_by_rule".to_string(), json!(by_rule)),
+        ("inspequte.findings_by_level".to_string(), json!(by_level)),
+    ]);
+    PropertyBag::builder()
+        .additional_properties(properties)
+        .build()
+}
+
+/// Number of slowest rules reported in `inspequte.rule_timings`.
+const RULE_TIMINGS_TOP_N: usize = 10;
+
 fn build_invocation(stats: &InvocationStats) -> Invocation {
     let arguments: Vec<String> = std::env::args().collect();
     let command_line = arguments.join(" ");
@@ -849,6 +2129,35 @@ fn build_invocation(stats: &InvocationStats) -> Invocation {
         "inspequte.classpath_class_count".to_string(),
         json!(stats.classpath_class_count),
     );
+    properties.insert(
+        "inspequte.rule_timings".to_string(),
+        rule_timings_property(&stats.rule_timings),
+    );
+    if !stats.skipped_classes.is_empty() {
+        properties.insert(
+            "inspequte.skipped_classes".to_string(),
+            json!(stats.skipped_classes),
+        );
+    }
+    if stats.cached_class_count > 0 {
+        properties.insert(
+            "inspequte.cached_class_count".to_string(),
+            json!(stats.cached_class_count),
+        );
+    }
+    if !stats.duplicate_classes.is_empty() {
+        properties.insert(
+            "inspequte.duplicate_classes".to_string(),
+            json!(stats.duplicate_classes),
+        );
+    }
+    if let Some(total) = stats.truncated_finding_total {
+        properties.insert("inspequte.truncated".to_string(), json!(true));
+        properties.insert(
+            "inspequte.findings_total_estimate".to_string(),
+            json!(total),
+        );
+    }
 
     Invocation::builder()
         .execution_successful(true)
@@ -862,6 +2171,28 @@ fn build_invocation(stats: &InvocationStats) -> Invocation {
         .build()
 }
 
+fn rule_timings_property(timings: &[RuleTiming]) -> serde_json::Value {
+    let mut slowest: Vec<&RuleTiming> = timings.iter().collect();
+    slowest.sort_by(|left, right| {
+        right
+            .duration
+            .cmp(&left.duration)
+            .then_with(|| left.rule_id.cmp(&right.rule_id))
+    });
+    let entries: Vec<serde_json::Value> = slowest
+        .into_iter()
+        .take(RULE_TIMINGS_TOP_N)
+        .map(|timing| {
+            json!({
+                "ruleId": timing.rule_id,
+                "ms": timing.duration.as_secs_f64() * 1000.0,
+                "findings": timing.finding_count,
+            })
+        })
+        .collect();
+    json!(entries)
+}
+
 fn should_validate_sarif() -> bool {
     std::env::var("INSPEQUTE_VALIDATE_SARIF")
         .ok()
@@ -869,14 +2200,19 @@ fn should_validate_sarif() -> bool {
         .unwrap_or(false)
 }
 
-fn validate_sarif(sarif: &Sarif) -> Result<()> {
+fn validate_sarif(sarif: &Sarif) -> Result<()> {
+    let value = serde_json::to_value(sarif).context("serialize SARIF")?;
+    validate_sarif_value(&value)
+}
+
+/// Checks `value` against the bundled SARIF 2.1.0 schema and reports every violation at once.
+fn validate_sarif_value(value: &serde_json::Value) -> Result<()> {
     let schema = serde_json::from_str(include_str!("assets/sarif-2.1.0.json"))
         .context("load SARIF schema")?;
     let compiled = jsonschema::validator_for(&schema)
         .map_err(|err| anyhow::anyhow!("compile SARIF schema: {err}"))?;
-    let value = serde_json::to_value(sarif).context("serialize SARIF")?;
     let errors: Vec<String> = compiled
-        .iter_errors(&value)
+        .iter_errors(value)
         .map(|error| error.to_string())
         .collect();
     if !errors.is_empty() {
@@ -895,6 +2231,7 @@ fn build_sarif(
     automation_details_id: Option<String>,
 ) -> Sarif {
     with_span(telemetry, "sarif.build", &[], || {
+        let summary = findings_summary(&rules, &results);
         let semantic_version = env!("CARGO_PKG_VERSION").to_string();
         let driver = if rules.is_empty() {
             ToolComponent::builder()
@@ -903,12 +2240,14 @@ fn build_sarif(
                 .semantic_version(semantic_version.clone())
                 .build()
         } else {
-            ToolComponent::builder()
+            let mut driver = ToolComponent::builder()
                 .name("inspequte")
                 .information_uri("https://github.com/KengoTODA/inspequte")
-                .rules(rules)
                 .semantic_version(semantic_version)
-                .build()
+                .build();
+            driver.taxa = tag_taxa(&rules);
+            driver.rules = Some(rules);
+            driver
         };
         let tool = Tool {
             driver,
@@ -927,7 +2266,7 @@ fn build_sarif(
                 )
             }
         });
-        let run = match (artifacts, automation_details) {
+        let mut run = match (artifacts, automation_details) {
             (artifacts, Some(automation_details)) if artifacts.is_empty() => Run::builder()
                 .tool(tool)
                 .invocations(vec![invocation])
@@ -953,6 +2292,7 @@ fn build_sarif(
                 .artifacts(artifacts)
                 .build(),
         };
+        run.properties = Some(summary);
 
         Sarif::builder()
             .schema(SCHEMA_URL)
@@ -969,7 +2309,7 @@ mod tests {
     use std::fs;
     use std::io::Write;
     use std::path::PathBuf;
-    use std::time::{SystemTime, UNIX_EPOCH};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
     use crate::engine::{Engine, build_context};
     use crate::scan::scan_inputs;
@@ -1211,6 +2551,281 @@ mod tests {
         fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
     }
 
+    /// Writes `chain-0.txt` .. `chain-{depth - 1}.txt`, each referencing the next, and returns
+    /// the first one. The last file lists `classes`.
+    fn write_at_file_chain(dir: &Path, depth: usize) -> PathBuf {
+        for index in 0..depth {
+            let content = if index + 1 == depth {
+                "classes\n".to_string()
+            } else {
+                format!("@chain-{}.txt\n", index + 1)
+            };
+            fs::write(dir.join(format!("chain-{index}.txt")), content).expect("write chain file");
+        }
+        dir.join("chain-0.txt")
+    }
+
+    #[test]
+    fn expand_path_args_follows_nested_at_file_chain() {
+        let temp_dir = make_temp_test_dir();
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let first = write_at_file_chain(&temp_dir, 5);
+
+        let args = vec![format!("@{}", first.display())];
+        let expanded = expand_path_args(&args, Path::new(".")).expect("expand inputs");
+
+        let canonical_temp_dir = temp_dir.canonicalize().expect("canonicalize temp dir");
+        assert_eq!(expanded, vec![canonical_temp_dir.join("classes")]);
+
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn expand_path_args_rejects_at_file_chain_over_depth_limit() {
+        let temp_dir = make_temp_test_dir();
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let first = write_at_file_chain(&temp_dir, MAX_AT_FILE_DEPTH + 1);
+
+        let args = vec![format!("@{}", first.display())];
+        let message = expand_path_args(&args, Path::new("."))
+            .expect_err("chain over the limit")
+            .to_string();
+
+        assert!(
+            message.starts_with("@file references are nested more than 32 levels deep: "),
+            "message: {message}"
+        );
+        assert!(message.contains("chain-0.txt -> "), "message: {message}");
+        assert!(message.ends_with("chain-32.txt"), "message: {message}");
+
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn expand_rule_args_reports_include_chain_of_circular_reference() {
+        let temp_dir = make_temp_test_dir();
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        fs::write(temp_dir.join("a.txt"), "SYSTEM_EXIT\n@b.txt\n").expect("write a");
+        fs::write(temp_dir.join("b.txt"), "@a.txt\n").expect("write b");
+
+        let args = vec![format!("@{}", temp_dir.join("a.txt").display())];
+        let message = expand_rule_args(&args)
+            .expect_err("circular reference")
+            .to_string();
+
+        let canonical_temp_dir = temp_dir.canonicalize().expect("canonicalize temp dir");
+        let a = canonical_temp_dir.join("a.txt").display().to_string();
+        let b = canonical_temp_dir.join("b.txt").display().to_string();
+        assert_eq!(
+            message,
+            format!("circular @file reference in --rules: {a} -> {b} -> {a}")
+        );
+
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    /// Serves `body` to the next `requests` HTTP requests on a local port and returns the
+    /// server's base URL.
+    fn serve_http_fixture(body: Vec<u8>, requests: usize) -> (String, std::thread::JoinHandle<()>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind fixture server");
+        let base_url = format!("http://{}", listener.local_addr().expect("fixture address"));
+        let server = std::thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.expect("accept fixture request");
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    let read = stream.read(&mut buffer).expect("read fixture request");
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                )
+                .expect("write fixture headers");
+                stream.write_all(&body).expect("write fixture body");
+            }
+        });
+        (base_url, server)
+    }
+
+    #[test]
+    fn remote_input_url_is_downloaded_once_and_scanned() {
+        let temp_dir = make_temp_test_dir();
+        let cache_dir = temp_dir.join("remote");
+        let (base_url, server) = serve_http_fixture(build_class_b(), 1);
+        let url = format!("{base_url}/classes/B.class");
+
+        let input =
+            expand_path_args(std::slice::from_ref(&url), Path::new(".")).expect("expand inputs");
+        let input = fetch_remote_inputs(input, true, &cache_dir).expect("download input");
+        server.join().expect("fixture server");
+        // The server is gone, so a second fetch must come from the cache.
+        let cached = fetch_remote_inputs(vec![PathBuf::from(&url)], true, &cache_dir)
+            .expect("reuse cached input");
+
+        assert_eq!(cached, input);
+        assert!(input[0].ends_with("B.class"), "input: {input:?}");
+        let scan = scan_inputs(&input, &[], &ScanOptions::default(), None).expect("scan input");
+        let class_names = scan
+            .classes
+            .iter()
+            .map(|class| class.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(class_names, vec!["B"]);
+
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn config_alone_configures_scan() {
+        let temp_dir = make_temp_test_dir();
+        fs::create_dir_all(temp_dir.join("classes")).expect("create classes dir");
+        fs::write(temp_dir.join("classes/B.class"), build_class_b()).expect("write class");
+        let config_path = temp_dir.join("inspequte.json");
+        fs::write(
+            &config_path,
+            r#"{
+  "inputs": ["classes"],
+  "exclude_rules": ["SYSTEM_EXIT"],
+  "release": 17,
+  "rule_options": {"MAGIC_NUMBER": {"allowlist": [3600]}}
+}"#,
+        )
+        .expect("write config");
+        let config_arg = config_path.display().to_string();
+        let mut cli =
+            Cli::try_parse_from(["inspequte", "--config", &config_arg]).expect("parse CLI");
+
+        apply_scan_config(
+            &mut cli.scan,
+            load_scan_config(&config_path).expect("load config"),
+        );
+        let expanded = expand_input_args(&cli.scan.input).expect("expand inputs");
+        let scan = scan_inputs(
+            &expanded.input,
+            &expanded.classpath,
+            &expanded.scan_options,
+            None,
+        )
+        .expect("scan input");
+
+        assert_eq!(expanded.input, vec![temp_dir.join("classes")]);
+        assert_eq!(expanded.scan_options.release, Some(17));
+        assert_eq!(
+            expand_excluded_rule_args(&cli.scan.exclude_rules).expect("expand exclusions"),
+            BTreeSet::from(["SYSTEM_EXIT".to_string()])
+        );
+        assert_eq!(
+            cli.scan
+                .rule_options
+                .to_rule_options(true)
+                .magic_number
+                .allowlist,
+            vec![3600]
+        );
+        assert_eq!(scan.classes.len(), 1);
+        assert_eq!(scan.classes[0].name, "B");
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn command_line_flags_take_precedence_over_config() {
+        let mut cli = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "cli.jar",
+            "--rules",
+            "SYSTEM_EXIT",
+            "--baseline",
+            "cli-baseline.json",
+            "--config",
+            "inspequte.json",
+        ])
+        .expect("parse CLI");
+        let config = ScanConfig {
+            inputs: vec!["manifest.jar".to_string()],
+            classpath: vec!["lib.jar".to_string()],
+            rules: vec!["MAGIC_NUMBER".to_string()],
+            exclude_rules: vec!["PRINT_STACK_TRACE".to_string()],
+            baseline: Some("manifest-baseline.json".to_string()),
+            release: Some(21),
+            rule_options: RuleOptionsConfig::default(),
+        };
+
+        apply_scan_config(&mut cli.scan, config);
+
+        assert_eq!(cli.scan.input.input, vec!["cli.jar".to_string()]);
+        assert_eq!(cli.scan.input.classpath, vec!["lib.jar".to_string()]);
+        assert_eq!(cli.scan.rules, vec!["SYSTEM_EXIT".to_string()]);
+        assert_eq!(
+            cli.scan.exclude_rules,
+            vec!["PRINT_STACK_TRACE".to_string()]
+        );
+        assert_eq!(cli.scan.baseline_path(), Path::new("cli-baseline.json"));
+        assert_eq!(cli.scan.input.release, Some(21));
+    }
+
+    #[test]
+    fn explicit_default_baseline_takes_precedence_over_config() {
+        let mut cli = Cli::try_parse_from([
+            "inspequte",
+            "--baseline",
+            DEFAULT_BASELINE_PATH,
+            "--config",
+            "inspequte.json",
+        ])
+        .expect("parse CLI");
+        let config = ScanConfig {
+            baseline: Some("config-baseline.json".to_string()),
+            ..ScanConfig::default()
+        };
+
+        apply_scan_config(&mut cli.scan, config);
+
+        assert_eq!(cli.scan.baseline_path(), Path::new(DEFAULT_BASELINE_PATH));
+    }
+
+    #[test]
+    fn config_baseline_replaces_default_baseline() {
+        let mut cli =
+            Cli::try_parse_from(["inspequte", "--config", "inspequte.json"]).expect("parse CLI");
+        let config = ScanConfig {
+            baseline: Some("config-baseline.json".to_string()),
+            ..ScanConfig::default()
+        };
+
+        apply_scan_config(&mut cli.scan, config);
+
+        assert_eq!(cli.scan.baseline_path(), Path::new("config-baseline.json"));
+    }
+
+    #[test]
+    fn replay_manifest_rejects_flags_that_change_recorded_options() {
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "--replay-manifest",
+            "inspequte.manifest.json",
+            "--release",
+            "17",
+        ])
+        .expect("parse CLI");
+
+        let error = replay_input_args(Path::new("inspequte.manifest.json"), &cli.scan.input)
+            .expect_err("--release conflicts with the recorded options");
+
+        assert!(
+            error
+                .to_string()
+                .contains("--release cannot be used with --replay-manifest"),
+            "{error}"
+        );
+    }
+
     #[test]
     fn filter_missing_paths_ignores_missing_directory() {
         let temp_dir = make_temp_test_dir();
@@ -1226,52 +2841,361 @@ mod tests {
     }
 
     #[test]
-    fn filter_missing_paths_rejects_missing_file() {
-        let temp_dir = make_temp_test_dir();
-        fs::create_dir_all(&temp_dir).expect("create temp dir");
-        let missing = temp_dir.join("missing.jar");
-
-        let result = filter_missing_paths("classpath entry", vec![missing]);
+    fn filter_missing_paths_rejects_missing_file() {
+        let temp_dir = make_temp_test_dir();
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let missing = temp_dir.join("missing.jar");
+
+        let result = filter_missing_paths("classpath entry", vec![missing]);
+
+        assert!(result.is_err());
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn cli_accepts_automation_details_id_option() {
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "target/classes",
+            "--automation-details-id",
+            "inspequte/./main",
+        ])
+        .expect("parse CLI");
+
+        assert_eq!(
+            cli.scan.automation_details_id.as_deref(),
+            Some("inspequte/./main")
+        );
+    }
+
+    #[test]
+    fn cli_accepts_log_level_and_quiet_for_subcommands() {
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "baseline",
+            "--input",
+            "target/classes",
+            "--log-level",
+            "debug",
+            "--quiet",
+        ])
+        .expect("parse CLI");
+
+        assert_eq!(cli.log.log_level, Some(LogLevel::Debug));
+        assert!(cli.log.quiet);
+    }
+
+    #[test]
+    fn cli_rejects_quiet_with_verbose() {
+        let result = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "target/classes",
+            "--quiet",
+            "--verbose",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn cli_rejects_unknown_log_level() {
+        let result = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "target/classes",
+            "--log-level",
+            "verbose",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn cli_format_defaults_to_sarif_and_accepts_ndjson() {
+        let cli = Cli::try_parse_from(["inspequte", "--input", "target/classes"]).expect("parse");
+        assert_eq!(cli.scan.format, OutputFormat::Sarif);
+
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "target/classes",
+            "--format",
+            "ndjson",
+        ])
+        .expect("parse");
+        assert_eq!(cli.scan.format, OutputFormat::Ndjson);
+    }
+
+    #[test]
+    fn cli_parses_repeated_additional_outputs() {
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "target/classes",
+            "--output",
+            "results.sarif",
+            "--additional-output",
+            "text=results.txt",
+            "--additional-output",
+            "sarif=copy.sarif.gz",
+        ])
+        .expect("parse");
+
+        assert_eq!(
+            cli.scan.output_sinks(),
+            vec![
+                OutputSink {
+                    format: OutputFormat::Sarif,
+                    path: Some(PathBuf::from("results.sarif")),
+                    compression: None,
+                },
+                OutputSink {
+                    format: OutputFormat::Text,
+                    path: Some(PathBuf::from("results.txt")),
+                    compression: None,
+                },
+                OutputSink {
+                    format: OutputFormat::Sarif,
+                    path: Some(PathBuf::from("copy.sarif.gz")),
+                    compression: None,
+                },
+            ]
+        );
+        for invalid in [
+            "results.txt",
+            "ndjson=results.ndjson",
+            "text=",
+            "xml=out.xml",
+        ] {
+            let result = Cli::try_parse_from([
+                "inspequte",
+                "--input",
+                "target/classes",
+                "--additional-output",
+                invalid,
+            ]);
+            assert!(result.is_err(), "{invalid} should be rejected");
+        }
+    }
+
+    #[test]
+    fn cli_accepts_prune_for_baseline_subcommand() {
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "baseline",
+            "--input",
+            "target/classes",
+            "--prune",
+        ])
+        .expect("parse");
+
+        let Some(Command::Baseline(args)) = cli.command else {
+            panic!("expected baseline subcommand");
+        };
+        assert!(args.prune);
+    }
+
+    #[test]
+    fn cli_parses_hidden_rules_export_subcommand() {
+        let cli = Cli::try_parse_from(["inspequte", "rules", "export", "--output", "rules.json"])
+            .expect("parse");
+
+        let Some(Command::Rules(RulesCommand::Export(args))) = cli.command else {
+            panic!("expected rules export subcommand");
+        };
+        assert_eq!(args.output, Some(PathBuf::from("rules.json")));
+    }
+
+    #[test]
+    fn cli_fail_on_defaults_to_none() {
+        let cli = Cli::try_parse_from(["inspequte", "--input", "target/classes"]).expect("parse");
+
+        assert_eq!(cli.scan.fail_on, FailOn::None);
+    }
+
+    #[test]
+    fn reaches_fail_on_compares_result_levels_with_threshold() {
+        let result_with_level = |level: Option<&str>| {
+            let mut result = SarifResult::builder()
+                .message(crate::rules::result_message("finding"))
+                .build();
+            result.level = level.map(|level| json!(level));
+            result
+        };
+        let warnings = vec![result_with_level(Some("warning"))];
+        let implicit = vec![result_with_level(None)];
+
+        assert!(!reaches_fail_on(&warnings, FailOn::None));
+        assert!(reaches_fail_on(&warnings, FailOn::Note));
+        assert!(reaches_fail_on(&warnings, FailOn::Warning));
+        assert!(!reaches_fail_on(&warnings, FailOn::Error));
+        assert!(reaches_fail_on(&implicit, FailOn::Warning));
+        assert!(!reaches_fail_on(&[], FailOn::Note));
+    }
+
+    #[test]
+    fn cli_rejects_replay_manifest_with_input() {
+        let result = Cli::try_parse_from([
+            "inspequte",
+            "--replay-manifest",
+            "manifest.json",
+            "--input",
+            "target/classes",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn cli_accepts_repeatable_rules_option() {
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "target/classes",
+            "--rules",
+            "SYSTEM_EXIT,THREAD_RUN_DIRECT_CALL",
+            "--rules",
+            "RETURN_IN_FINALLY",
+        ])
+        .expect("parse CLI");
+
+        assert_eq!(
+            cli.scan.rules,
+            vec![
+                "SYSTEM_EXIT,THREAD_RUN_DIRECT_CALL".to_string(),
+                "RETURN_IN_FINALLY".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn cli_allows_exit_in_main_by_default() {
+        let cli = Cli::try_parse_from(["inspequte", "--input", "target/classes"]).expect("parse");
+
+        assert!(cli.scan.allow_exit_in_main);
+    }
+
+    #[test]
+    fn cli_accepts_allow_exit_in_main_false() {
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "target/classes",
+            "--allow-exit-in-main",
+            "false",
+        ])
+        .expect("parse CLI");
 
-        assert!(result.is_err());
-        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+        assert!(!cli.scan.allow_exit_in_main);
     }
 
     #[test]
-    fn cli_accepts_automation_details_id_option() {
+    fn cli_requires_relative_to_for_uri_base_id() {
         let cli = Cli::try_parse_from([
             "inspequte",
             "--input",
             "target/classes",
-            "--automation-details-id",
-            "inspequte/./main",
+            "--relative-to",
+            "/work/repo",
         ])
         .expect("parse CLI");
+        let missing_base = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "target/classes",
+            "--uri-base-id",
+            "PROJECT",
+        ]);
 
         assert_eq!(
-            cli.scan.automation_details_id.as_deref(),
-            Some("inspequte/./main")
+            cli.scan.artifact_uris.relative_to,
+            Some(PathBuf::from("/work/repo"))
         );
+        assert!(cli.scan.artifact_uris.relativizer().is_some());
+        assert!(missing_base.is_err());
     }
 
     #[test]
-    fn cli_accepts_repeatable_rules_option() {
+    fn analyze_relativizes_artifact_uris_under_relative_to() {
+        let temp_dir = make_temp_test_dir();
+        let classes_dir = temp_dir.join("classes");
+        fs::create_dir_all(&classes_dir).expect("create classes dir");
+        fs::write(classes_dir.join("A.class"), build_class_a()).expect("write A.class");
+        let input = vec![classes_dir.clone()];
+        let artifact_uris = |options: AnalysisOptions| {
+            analyze(
+                &input,
+                &[],
+                &ScanOptions::default(),
+                None,
+                None,
+                options,
+                None,
+            )
+            .expect("analyze")
+            .artifacts
+            .into_iter()
+            .filter_map(|artifact| artifact.location?.uri)
+            .collect::<Vec<_>>()
+        };
+
+        let absolute = artifact_uris(AnalysisOptions::default());
+        let relative = artifact_uris(AnalysisOptions {
+            uri_relativizer: Some(UriRelativizer::new(&temp_dir, DEFAULT_URI_BASE_ID)),
+            ..AnalysisOptions::default()
+        });
+
+        assert!(!absolute.is_empty());
+        assert!(absolute.iter().all(|uri| uri.starts_with("file:///")));
+        assert_eq!(relative.len(), absolute.len());
+        assert!(relative.iter().all(|uri| uri.starts_with("classes")));
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn cli_accepts_repeated_class_filters() {
         let cli = Cli::try_parse_from([
             "inspequte",
             "--input",
             "target/classes",
-            "--rules",
-            "SYSTEM_EXIT,THREAD_RUN_DIRECT_CALL",
-            "--rules",
-            "RETURN_IN_FINALLY",
+            "--include-classes",
+            "com/example/**",
+            "--include-classes",
+            "org/example/**",
+            "--exclude-classes",
+            "**/generated/**",
         ])
         .expect("parse CLI");
 
         assert_eq!(
-            cli.scan.rules,
+            cli.scan.include_classes,
+            vec!["com/example/**".to_string(), "org/example/**".to_string()]
+        );
+        assert_eq!(
+            cli.scan.exclude_classes,
+            vec!["**/generated/**".to_string()]
+        );
+    }
+
+    #[test]
+    fn read_changed_files_accepts_name_only_and_name_status_lines() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("changed.txt");
+        fs::write(
+            &path,
+            "# changed in this PR\nsrc/main/java/com/example/ClassA.java\n\nR100\tsrc/Old.java\tsrc/main/java/com/example/ClassB.java\n",
+        )
+        .expect("write changed files");
+
+        let changed = read_changed_files(&format!("@{}", path.display())).expect("read list");
+
+        assert_eq!(
+            changed,
             vec![
-                "SYSTEM_EXIT,THREAD_RUN_DIRECT_CALL".to_string(),
-                "RETURN_IN_FINALLY".to_string()
+                "src/main/java/com/example/ClassA.java".to_string(),
+                "src/main/java/com/example/ClassB.java".to_string(),
             ]
         );
     }
@@ -1307,7 +3231,7 @@ mod tests {
     #[test]
     fn parse_json_execution_request_supports_inline_json() {
         let request = parse_json_execution_request(
-            "{\"command\":\"scan\",\"input\":[\"target/classes\"],\"classpath\":[\"target/lib\"],\"rules\":[\"SYSTEM_EXIT\"],\"baseline\":\"baseline.json\",\"output\":\"result.sarif\",\"allowDuplicateClasses\":true}",
+            "{\"command\":\"scan\",\"input\":[\"target/classes\"],\"classpath\":[\"target/lib\"],\"rules\":[\"SYSTEM_EXIT\"],\"baseline\":\"baseline.json\",\"output\":\"result.sarif\",\"allowDuplicateClasses\":true,\"release\":17,\"followManifestClasspath\":true}",
         )
         .expect("parse json request");
 
@@ -1317,9 +3241,11 @@ mod tests {
         assert_eq!(scan.input.input, vec!["target/classes".to_string()]);
         assert_eq!(scan.input.classpath, vec!["target/lib".to_string()]);
         assert_eq!(scan.rules, vec!["SYSTEM_EXIT".to_string()]);
-        assert_eq!(scan.baseline, PathBuf::from("baseline.json"));
+        assert_eq!(scan.baseline_path(), Path::new("baseline.json"));
         assert_eq!(scan.output, Some(PathBuf::from("result.sarif")));
         assert!(scan.allow_duplicate_classes);
+        assert_eq!(scan.input.release, Some(17));
+        assert!(scan.input.follow_manifest_classpath);
     }
 
     #[test]
@@ -1406,6 +3332,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expand_rule_selection_unions_tags_and_rule_ids() {
+        let concurrency = rule_ids_with_tag("concurrency").expect("tagged rules");
+        let cli = Cli::try_parse_from([
+            "inspequte",
+            "--input",
+            "app.jar",
+            "--tags",
+            "concurrency",
+            "--rules",
+            "SYSTEM_EXIT",
+        ])
+        .expect("parse tags");
+
+        let from_flag =
+            expand_rule_selection(&cli.scan.rules, &cli.scan.tags).expect("expand selection");
+        let from_prefix =
+            expand_rule_args(&["@category:concurrency".to_string()]).expect("expand category");
+
+        let mut expected = concurrency.clone();
+        expected.insert("SYSTEM_EXIT".to_string());
+        assert_eq!(from_flag, Some(expected));
+        assert_eq!(from_prefix, Some(concurrency));
+    }
+
+    #[test]
+    fn expand_rule_selection_rejects_tag_without_rules() {
+        let error =
+            expand_rule_selection(&[], &["no-such-tag".to_string()]).expect_err("unknown tag");
+
+        assert_eq!(error.to_string(), "no rules are tagged no-such-tag");
+    }
+
     #[test]
     fn expand_rule_args_supports_at_file() {
         let temp_dir = make_temp_test_dir();
@@ -1460,6 +3419,11 @@ mod tests {
             class_count: 0,
             artifact_count: 0,
             classpath_class_count: 0,
+            rule_timings: Vec::new(),
+            skipped_classes: Vec::new(),
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::new(),
+            truncated_finding_total: None,
         });
         let sarif = build_sarif(None, Vec::new(), invocation, Vec::new(), Vec::new(), None);
         let value = serde_json::to_value(&sarif).expect("serialize SARIF");
@@ -1488,6 +3452,317 @@ mod tests {
         assert!(value["runs"][0]["automationDetails"].is_null());
     }
 
+    #[test]
+    fn invocation_properties_include_slowest_rule_timings() {
+        let rule_timings = (0..12)
+            .map(|index| RuleTiming {
+                rule_id: format!("RULE_{index:02}"),
+                duration: Duration::from_millis(index),
+                finding_count: index as usize,
+            })
+            .collect();
+        let invocation = build_invocation(&InvocationStats {
+            scan_duration_ms: 0,
+            classpath_duration_ms: 0,
+            analysis_call_graph_duration_ms: 0,
+            analysis_artifact_duration_ms: 0,
+            analysis_call_graph_hierarchy_duration_ms: 0,
+            analysis_call_graph_index_duration_ms: 0,
+            analysis_call_graph_edges_duration_ms: 0,
+            analysis_rules_duration_ms: 0,
+            class_count: 0,
+            artifact_count: 0,
+            classpath_class_count: 0,
+            rule_timings,
+            skipped_classes: Vec::new(),
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::new(),
+            truncated_finding_total: None,
+        });
+        let value = serde_json::to_value(&invocation).expect("serialize invocation");
+
+        let timings = value["properties"]["inspequte.rule_timings"]
+            .as_array()
+            .expect("rule timings array");
+        assert_eq!(timings.len(), RULE_TIMINGS_TOP_N);
+        assert_eq!(timings[0]["ruleId"], "RULE_11");
+        assert_eq!(timings[0]["ms"], 11.0);
+        assert_eq!(timings[0]["findings"], 11);
+        assert_eq!(timings[9]["ruleId"], "RULE_02");
+        assert!(value["properties"]["inspequte.skipped_classes"].is_null());
+    }
+
+    #[test]
+    fn report_stats_prints_summary_only_when_enabled() {
+        let stats = InvocationStats {
+            scan_duration_ms: 12,
+            classpath_duration_ms: 3,
+            analysis_call_graph_duration_ms: 0,
+            analysis_artifact_duration_ms: 0,
+            analysis_call_graph_hierarchy_duration_ms: 0,
+            analysis_call_graph_index_duration_ms: 0,
+            analysis_call_graph_edges_duration_ms: 0,
+            analysis_rules_duration_ms: 40,
+            class_count: 7,
+            artifact_count: 2,
+            classpath_class_count: 9,
+            rule_timings: vec![
+                RuleTiming {
+                    rule_id: "RETURN_IN_FINALLY".to_string(),
+                    duration: Duration::from_millis(1),
+                    finding_count: 0,
+                },
+                RuleTiming {
+                    rule_id: "SYSTEM_EXIT".to_string(),
+                    duration: Duration::from_millis(2),
+                    finding_count: 3,
+                },
+            ],
+            skipped_classes: Vec::new(),
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::new(),
+            truncated_finding_total: None,
+        };
+
+        let mut enabled = Vec::new();
+        report_stats(&stats, true, &mut enabled).expect("write stats");
+        let mut disabled = Vec::new();
+        report_stats(&stats, false, &mut disabled).expect("write stats");
+
+        let enabled = String::from_utf8(enabled).expect("utf-8 stats");
+        let lines: Vec<&str> = enabled.lines().collect();
+        assert_eq!(lines[0], "inspequte stats:");
+        assert!(lines.contains(&"  scan: 12 ms"));
+        assert!(lines.contains(&"  classes: 7 scanned, 9 on classpath, 0 cached, 0 skipped"));
+        assert!(lines.contains(&"  findings: 3"));
+        assert!(lines.contains(&"    SYSTEM_EXIT: 3 (2.0 ms)"));
+        assert!(!enabled.contains("RETURN_IN_FINALLY"));
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn invocation_properties_list_classes_skipped_by_class_budget() {
+        let invocation = build_invocation(&InvocationStats {
+            scan_duration_ms: 0,
+            classpath_duration_ms: 0,
+            analysis_call_graph_duration_ms: 0,
+            analysis_artifact_duration_ms: 0,
+            analysis_call_graph_hierarchy_duration_ms: 0,
+            analysis_call_graph_index_duration_ms: 0,
+            analysis_call_graph_edges_duration_ms: 0,
+            analysis_rules_duration_ms: 0,
+            class_count: 0,
+            artifact_count: 0,
+            classpath_class_count: 0,
+            rule_timings: Vec::new(),
+            skipped_classes: vec!["com/example/Slow".to_string()],
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::new(),
+            truncated_finding_total: None,
+        });
+        let value = serde_json::to_value(&invocation).expect("serialize invocation");
+
+        assert_eq!(
+            value["properties"]["inspequte.skipped_classes"],
+            json!(["com/example/Slow"])
+        );
+    }
+
+    #[test]
+    fn invocation_properties_list_duplicate_classes_with_artifacts() {
+        let invocation = build_invocation(&InvocationStats {
+            scan_duration_ms: 0,
+            classpath_duration_ms: 0,
+            analysis_call_graph_duration_ms: 0,
+            analysis_artifact_duration_ms: 0,
+            analysis_call_graph_hierarchy_duration_ms: 0,
+            analysis_call_graph_index_duration_ms: 0,
+            analysis_call_graph_edges_duration_ms: 0,
+            analysis_rules_duration_ms: 0,
+            class_count: 0,
+            artifact_count: 0,
+            classpath_class_count: 0,
+            rule_timings: Vec::new(),
+            skipped_classes: Vec::new(),
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::from([(
+                "com/example/Foo".to_string(),
+                vec!["file:///aaa.jar".to_string(), "file:///zzz.jar".to_string()],
+            )]),
+            truncated_finding_total: None,
+        });
+        let value = serde_json::to_value(&invocation).expect("serialize invocation");
+
+        assert_eq!(
+            value["properties"]["inspequte.duplicate_classes"],
+            json!({"com/example/Foo": ["file:///aaa.jar", "file:///zzz.jar"]})
+        );
+    }
+
+    #[test]
+    fn truncate_findings_keeps_first_results_in_order_and_marks_invocation() {
+        let result = |rule_id: &str, message: &str| {
+            SarifResult::builder()
+                .rule_id(rule_id)
+                .message(crate::rules::result_message(message))
+                .build()
+        };
+        let mut results = vec![
+            result("SYSTEM_EXIT", "second"),
+            result("ARRAY_EQUALS", "first"),
+            result("SYSTEM_EXIT", "first"),
+        ];
+
+        let total = truncate_findings(&mut results, 2);
+        let invocation = build_invocation(&InvocationStats {
+            scan_duration_ms: 0,
+            classpath_duration_ms: 0,
+            analysis_call_graph_duration_ms: 0,
+            analysis_artifact_duration_ms: 0,
+            analysis_call_graph_hierarchy_duration_ms: 0,
+            analysis_call_graph_index_duration_ms: 0,
+            analysis_call_graph_edges_duration_ms: 0,
+            analysis_rules_duration_ms: 0,
+            class_count: 0,
+            artifact_count: 0,
+            classpath_class_count: 0,
+            rule_timings: Vec::new(),
+            skipped_classes: Vec::new(),
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::new(),
+            truncated_finding_total: total,
+        });
+        let value = serde_json::to_value(&invocation).expect("serialize invocation");
+
+        assert_eq!(total, Some(3));
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| (result.rule_id.as_deref(), result.message.text.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some("ARRAY_EQUALS"), Some("first")),
+                (Some("SYSTEM_EXIT"), Some("first")),
+            ]
+        );
+        assert_eq!(value["properties"]["inspequte.truncated"], json!(true));
+        assert_eq!(
+            value["properties"]["inspequte.findings_total_estimate"],
+            json!(3)
+        );
+        assert_eq!(truncate_findings(&mut results, 2), None);
+    }
+
+    #[test]
+    fn gzip_sarif_output_round_trips_to_valid_sarif() {
+        let invocation = build_invocation(&InvocationStats {
+            scan_duration_ms: 0,
+            classpath_duration_ms: 0,
+            analysis_call_graph_duration_ms: 0,
+            analysis_artifact_duration_ms: 0,
+            analysis_call_graph_hierarchy_duration_ms: 0,
+            analysis_call_graph_index_duration_ms: 0,
+            analysis_call_graph_edges_duration_ms: 0,
+            analysis_rules_duration_ms: 0,
+            class_count: 0,
+            artifact_count: 0,
+            classpath_class_count: 0,
+            rule_timings: Vec::new(),
+            skipped_classes: Vec::new(),
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::new(),
+            truncated_finding_total: None,
+        });
+        let sarif = build_sarif(None, Vec::new(), invocation, Vec::new(), Vec::new(), None);
+        let temp_dir = make_temp_test_dir();
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let output = temp_dir.join("results.sarif.gz");
+
+        write_sarif_output(&sarif, Some(&output), None, false).expect("write gzip SARIF");
+
+        let mut json = String::new();
+        flate2::read::GzDecoder::new(File::open(&output).expect("open gzip SARIF"))
+            .read_to_string(&mut json)
+            .expect("decompress SARIF");
+        let decoded: Sarif = serde_json::from_str(&json).expect("parse SARIF");
+        validate_sarif(&decoded).expect("valid SARIF");
+        assert_eq!(
+            serde_json::to_value(&decoded).expect("serialize decoded SARIF"),
+            serde_json::to_value(&sarif).expect("serialize SARIF")
+        );
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn pretty_sarif_output_matches_compact_output() {
+        let invocation = build_invocation(&InvocationStats {
+            scan_duration_ms: 0,
+            classpath_duration_ms: 0,
+            analysis_call_graph_duration_ms: 0,
+            analysis_artifact_duration_ms: 0,
+            analysis_call_graph_hierarchy_duration_ms: 0,
+            analysis_call_graph_index_duration_ms: 0,
+            analysis_call_graph_edges_duration_ms: 0,
+            analysis_rules_duration_ms: 0,
+            class_count: 0,
+            artifact_count: 0,
+            classpath_class_count: 0,
+            rule_timings: Vec::new(),
+            skipped_classes: Vec::new(),
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::new(),
+            truncated_finding_total: None,
+        });
+        let sarif = build_sarif(None, Vec::new(), invocation, Vec::new(), Vec::new(), None);
+        let mut compact = Vec::new();
+        let mut pretty = Vec::new();
+
+        write_sarif_json(&mut compact, &sarif, false).expect("write compact SARIF");
+        write_sarif_json(&mut pretty, &sarif, true).expect("write pretty SARIF");
+
+        let compact = String::from_utf8(compact).expect("compact SARIF is UTF-8");
+        let pretty = String::from_utf8(pretty).expect("pretty SARIF is UTF-8");
+        assert_eq!(compact.lines().count(), 1);
+        assert!(pretty.lines().count() > 1);
+        assert!(compact.ends_with("}\n"));
+        assert!(pretty.ends_with("}\n"));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&pretty).expect("parse pretty SARIF"),
+            serde_json::from_str::<serde_json::Value>(&compact).expect("parse compact SARIF")
+        );
+    }
+
+    #[test]
+    fn output_compression_is_inferred_only_for_gz_files() {
+        assert_eq!(
+            OutputCompression::resolve(None, Some(Path::new("results.sarif.gz"))),
+            OutputCompression::Gzip
+        );
+        assert_eq!(
+            OutputCompression::resolve(None, Some(Path::new("results.sarif"))),
+            OutputCompression::None
+        );
+        assert_eq!(
+            OutputCompression::resolve(None, Some(Path::new("-"))),
+            OutputCompression::None
+        );
+        assert_eq!(
+            OutputCompression::resolve(None, None),
+            OutputCompression::None
+        );
+        assert_eq!(
+            OutputCompression::resolve(Some(OutputCompression::Gzip), None),
+            OutputCompression::Gzip
+        );
+        assert_eq!(
+            OutputCompression::resolve(
+                Some(OutputCompression::None),
+                Some(Path::new("results.sarif.gz"))
+            ),
+            OutputCompression::None
+        );
+    }
+
     #[test]
     fn sarif_includes_automation_details_id_when_requested() {
         let invocation = build_invocation(&InvocationStats {
@@ -1502,6 +3777,11 @@ mod tests {
             class_count: 0,
             artifact_count: 0,
             classpath_class_count: 0,
+            rule_timings: Vec::new(),
+            skipped_classes: Vec::new(),
+            cached_class_count: 0,
+            duplicate_classes: BTreeMap::new(),
+            truncated_finding_total: None,
         });
         let sarif = build_sarif(
             None,
@@ -1519,6 +3799,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sarif_run_properties_count_findings_by_rule_and_level() {
+        let finding = |rule_id: &str, level: Option<&str>| {
+            let mut result = SarifResult::builder()
+                .message(crate::rules::result_message("finding"))
+                .rule_id(rule_id)
+                .build();
+            result.level = level.map(|level| json!(level));
+            result
+        };
+        let rule = |id: &str, level: &str| {
+            let mut rule = ReportingDescriptor::builder().id(id).build();
+            rule.default_configuration = Some(
+                serde_sarif::sarif::ReportingConfiguration::builder()
+                    .level(json!(level))
+                    .build(),
+            );
+            rule
+        };
+        let results = vec![
+            finding("SYSTEM_EXIT", Some("warning")),
+            finding("MAGIC_NUMBER", None),
+            finding("SYSTEM_EXIT", Some("warning")),
+            finding("ARRAY_EQUALS", Some("error")),
+        ];
+        let rules = vec![
+            rule("ARRAY_EQUALS", "error"),
+            rule("MAGIC_NUMBER", "note"),
+            rule("SYSTEM_EXIT", "warning"),
+        ];
+        let invocation = Invocation::builder()
+            .execution_successful(true)
+            .arguments(Vec::<String>::new())
+            .build();
+
+        let sarif = build_sarif(None, Vec::new(), invocation, rules, results, None);
+        let value = serde_json::to_value(&sarif).expect("serialize SARIF");
+
+        assert_eq!(
+            value["runs"][0]["properties"],
+            json!({
+                "inspequte.finding_count": 4,
+                "inspequte.findings_by_rule": {
+                    "ARRAY_EQUALS": 1,
+                    "MAGIC_NUMBER": 1,
+                    "SYSTEM_EXIT": 2
+                },
+                "inspequte.findings_by_level": {"error": 1, "note": 1, "warning": 2}
+            })
+        );
+        validate_sarif(&sarif).expect("valid SARIF");
+    }
+
     #[test]
     fn sarif_callgraph_snapshot() {
         let temp_dir = make_temp_test_dir();
@@ -1529,7 +3862,8 @@ mod tests {
         fs::write(temp_dir.join("A.class"), class_a).expect("write A.class");
         fs::write(temp_dir.join("B.class"), class_b).expect("write B.class");
 
-        let scan = scan_inputs(&[temp_dir.clone()], &[], None).expect("scan classes");
+        let scan = scan_inputs(&[temp_dir.clone()], &[], &ScanOptions::default(), None)
+            .expect("scan classes");
         let artifacts = scan.artifacts.clone();
         let context = build_context(scan.classes.clone(), &artifacts);
         let engine = Engine::new_with_allowed_rule_ids(None).expect("build engine");
@@ -1569,6 +3903,57 @@ mod tests {
         fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
     }
 
+    #[test]
+    fn dump_callgraph_writes_resolved_edges_of_snapshot_classes() {
+        let temp_dir = make_temp_test_dir();
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        fs::write(temp_dir.join("A.class"), build_class_a()).expect("write A.class");
+        fs::write(temp_dir.join("B.class"), build_class_b()).expect("write B.class");
+        let output = temp_dir.join("graph.json");
+
+        let cli = Cli::try_parse_from([
+            "inspequte".to_string(),
+            "dump".to_string(),
+            "callgraph".to_string(),
+            "--input".to_string(),
+            temp_dir.display().to_string(),
+            "--output".to_string(),
+            output.display().to_string(),
+        ])
+        .expect("parse");
+        let Some(Command::Dump(DumpCommand::Callgraph(args))) = cli.command else {
+            panic!("expected dump callgraph subcommand");
+        };
+        run_dump_callgraph(&args).expect("dump call graph");
+
+        let graph: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&output).expect("read graph"))
+                .expect("parse graph");
+        let node_ids: Vec<&str> = graph["nodes"]
+            .as_array()
+            .expect("nodes")
+            .iter()
+            .filter_map(|node| node["id"].as_str())
+            .collect();
+        assert_eq!(
+            node_ids,
+            vec!["A.<init>()V", "A.foo()V", "B.<init>()V", "B.bar()V"]
+        );
+        let edges = graph["edges"].as_array().expect("edges");
+        assert!(
+            edges.contains(&json!({
+                "from": "A.foo()V",
+                "to": "B.bar()V",
+                "kind": "virtual",
+                "offset": 7,
+                "resolved": true
+            })),
+            "missing A.foo -> B.bar edge: {edges:?}"
+        );
+
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
     fn normalize_sarif_for_snapshot(value: &mut serde_json::Value) {
         let Some(driver) = value.pointer_mut("/runs/0/tool/driver") else {
             return;
@@ -42,6 +42,11 @@ pub(crate) const ISTORE_0: u8 = 0x3b;
 pub(crate) const ISTORE_1: u8 = 0x3c;
 pub(crate) const ISTORE_2: u8 = 0x3d;
 pub(crate) const ISTORE_3: u8 = 0x3e;
+pub(crate) const LSTORE: u8 = 0x37;
+pub(crate) const LSTORE_0: u8 = 0x3f;
+pub(crate) const LSTORE_1: u8 = 0x40;
+pub(crate) const LSTORE_2: u8 = 0x41;
+pub(crate) const LSTORE_3: u8 = 0x42;
 pub(crate) const TABLESWITCH: u8 = 0xaa;
 pub(crate) const LOOKUPSWITCH: u8 = 0xab;
 pub(crate) const GOTO: u8 = 0xa7;
@@ -73,6 +78,7 @@ pub(crate) const POP2: u8 = 0x58;
 pub(crate) const DUP: u8 = 0x59;
 pub(crate) const AASTORE: u8 = 0x53;
 pub(crate) const AALOAD: u8 = 0x32;
+pub(crate) const IALOAD: u8 = 0x2e;
 pub(crate) const IFEQ: u8 = 0x99;
 pub(crate) const IFNE: u8 = 0x9a;
 pub(crate) const IFLT: u8 = 0x9b;
@@ -90,6 +96,8 @@ pub(crate) const IF_ACMPNE: u8 = 0xa6;
 pub(crate) const NEW: u8 = 0xbb;
 pub(crate) const NEWARRAY: u8 = 0xbc;
 pub(crate) const ANEWARRAY: u8 = 0xbd;
+pub(crate) const CHECKCAST: u8 = 0xc0;
+pub(crate) const INSTANCEOF: u8 = 0xc1;
 pub(crate) const MULTIANEWARRAY: u8 = 0xc5;
 pub(crate) const IFNULL: u8 = 0xc6;
 pub(crate) const IFNONNULL: u8 = 0xc7;
@@ -134,6 +142,7 @@ pub(crate) const IOR: u8 = 0x80;
 pub(crate) const LOR: u8 = 0x81;
 pub(crate) const IXOR: u8 = 0x82;
 pub(crate) const LXOR: u8 = 0x83;
+pub(crate) const IINC: u8 = 0x84;
 pub(crate) const LDC: u8 = 0x12;
 pub(crate) const LDC_W: u8 = 0x13;
 pub(crate) const LDC2_W: u8 = 0x14;
@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::engine::ProgressHook;
+
+/// Minimum time between two progress lines written by `--progress`.
+pub(crate) const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Throttles engine progress updates into occasional `analyzed N/M rule-class pairs` lines.
+pub(crate) struct ProgressReporter {
+    interval: Duration,
+    last_report: Mutex<Option<Instant>>,
+}
+
+impl ProgressReporter {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_report: Mutex::new(None),
+        }
+    }
+
+    /// Returns a hook that writes progress lines to stderr, never to the report output.
+    pub(crate) fn into_stderr_hook(self) -> ProgressHook {
+        Arc::new(move |done, total| {
+            if let Some(line) = self.progress_line(done, total, Instant::now()) {
+                eprintln!("{line}");
+            }
+        })
+    }
+
+    /// Returns the line for `done` of `total` rule-class pairs at `now`, or `None` when a line
+    /// was written less than one interval ago. The last pair is always reported.
+    fn progress_line(&self, done: usize, total: usize, now: Instant) -> Option<String> {
+        let mut last_report = self
+            .last_report
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let due = last_report.is_none_or(|last| now.duration_since(last) >= self.interval);
+        if !due && done < total {
+            return None;
+        }
+        *last_report = Some(now);
+        Some(format!("analyzed {done}/{total} rule-class pairs"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_line_is_throttled_except_for_the_last_pair() {
+        let reporter = ProgressReporter::new(Duration::from_millis(500));
+        let start = Instant::now();
+
+        let lines: Vec<Option<String>> = [
+            (1, start),
+            (2, start + Duration::from_millis(100)),
+            (3, start + Duration::from_millis(600)),
+            (4, start + Duration::from_millis(700)),
+        ]
+        .into_iter()
+        .map(|(done, now)| reporter.progress_line(done, 4, now))
+        .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                Some("analyzed 1/4 rule-class pairs".to_string()),
+                None,
+                Some("analyzed 3/4 rule-class pairs".to_string()),
+                Some("analyzed 4/4 rule-class pairs".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+
+/// Default directory for downloaded remote inputs, relative to the working directory.
+pub(crate) const DEFAULT_REMOTE_CACHE_DIR: &str = ".inspequte/remote";
+
+const MAVEN_CENTRAL_URL: &str = "https://repo.maven.apache.org/maven2";
+
+/// Returns whether `arg` names a remote input: an `http(s)://` URL or a
+/// `mvn:group:artifact:version` coordinate.
+pub(crate) fn is_remote_input(arg: &str) -> bool {
+    arg.starts_with("http://") || arg.starts_with("https://") || arg.starts_with("mvn:")
+}
+
+/// Replaces remote inputs in `paths` with local copies downloaded into `cache_dir`, keeping
+/// local paths unchanged. Remote inputs are rejected unless `allow_remote` is set.
+pub(crate) fn fetch_remote_inputs(
+    paths: Vec<PathBuf>,
+    allow_remote: bool,
+    cache_dir: &Path,
+) -> Result<Vec<PathBuf>> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let Some(arg) = path.to_str().filter(|arg| is_remote_input(arg)) else {
+                return Ok(path);
+            };
+            if !allow_remote {
+                anyhow::bail!("remote input {arg} requires --allow-remote");
+            }
+            let url = remote_input_url(arg)?;
+            fetch_remote_input(&url, cache_dir).with_context(|| format!("failed to fetch {arg}"))
+        })
+        .collect()
+}
+
+/// Returns the download URL of a remote input, resolving Maven coordinates against Maven
+/// Central.
+fn remote_input_url(arg: &str) -> Result<String> {
+    let Some(coordinate) = arg.strip_prefix("mvn:") else {
+        return Ok(arg.to_string());
+    };
+    let parts: Vec<&str> = coordinate.split(':').collect();
+    let [group, artifact, version] = parts.as_slice() else {
+        anyhow::bail!("invalid Maven coordinate {arg}: expected mvn:group:artifact:version");
+    };
+    if [group, artifact, version]
+        .iter()
+        .any(|part| part.is_empty())
+    {
+        anyhow::bail!("invalid Maven coordinate {arg}: expected mvn:group:artifact:version");
+    }
+    Ok(format!(
+        "{MAVEN_CENTRAL_URL}/{}/{artifact}/{version}/{artifact}-{version}.jar",
+        group.replace('.', "/")
+    ))
+}
+
+/// Downloads `url` into `cache_dir/<sha256 of url>/<file name>` unless it is already there.
+fn fetch_remote_input(url: &str, cache_dir: &Path) -> Result<PathBuf> {
+    let dir = cache_dir.join(format!("{:x}", Sha256::digest(url.as_bytes())));
+    let path = dir.join(remote_file_name(url));
+    if path.is_file() {
+        return Ok(path);
+    }
+    let response = reqwest::blocking::get(url)
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("failed to download {url}"))?;
+    let bytes = response
+        .bytes()
+        .with_context(|| format!("failed to read response from {url}"))?;
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("failed to create cache directory {}", dir.display()))?;
+    // Write to a temp file first so an interrupted download is never mistaken for a cache hit.
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, &bytes)
+        .with_context(|| format!("failed to write {}", tmp_path.display()))?;
+    fs::rename(&tmp_path, &path)
+        .with_context(|| format!("failed to move download to {}", path.display()))?;
+    Ok(path)
+}
+
+/// Returns the last path segment of `url` when it names a class, JAR, or jmod file, and
+/// `download.jar` otherwise.
+fn remote_file_name(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let name = path.rsplit('/').next().unwrap_or_default();
+    let supported = [".class", ".jar", ".jmod"]
+        .iter()
+        .any(|extension| name.len() > extension.len() && name.ends_with(extension));
+    if supported {
+        name.to_string()
+    } else {
+        "download.jar".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn remote_input_url_resolves_maven_coordinates() {
+        assert_eq!(
+            remote_input_url("mvn:org.jspecify:jspecify:1.0.0").expect("coordinate"),
+            "https://repo.maven.apache.org/maven2/org/jspecify/jspecify/1.0.0/jspecify-1.0.0.jar"
+        );
+        assert_eq!(
+            remote_input_url("https://example.com/lib.jar").expect("url"),
+            "https://example.com/lib.jar"
+        );
+        assert!(remote_input_url("mvn:org.jspecify:jspecify").is_err());
+        assert!(remote_input_url("mvn:org.jspecify::1.0.0").is_err());
+    }
+
+    #[test]
+    fn remote_file_name_keeps_supported_extensions() {
+        assert_eq!(
+            remote_file_name("https://example.com/repo/lib-1.0.jar?token=abc"),
+            "lib-1.0.jar"
+        );
+        assert_eq!(remote_file_name("http://localhost/A.class"), "A.class");
+        assert_eq!(
+            remote_file_name("https://example.com/download?id=1"),
+            "download.jar"
+        );
+    }
+
+    #[test]
+    fn fetch_remote_inputs_requires_allow_remote() {
+        let dir = tempdir().expect("temp dir");
+        let paths = vec![
+            PathBuf::from("app.jar"),
+            PathBuf::from("https://example.com/lib.jar"),
+        ];
+
+        let err = fetch_remote_inputs(paths, false, dir.path()).expect_err("remote rejected");
+
+        assert_eq!(
+            err.to_string(),
+            "remote input https://example.com/lib.jar requires --allow-remote"
+        );
+    }
+
+    #[test]
+    fn fetch_remote_inputs_reuses_cached_download() {
+        let dir = tempdir().expect("temp dir");
+        let url = "https://example.com/lib.jar";
+        let cached = dir
+            .path()
+            .join(format!("{:x}", Sha256::digest(url.as_bytes())))
+            .join("lib.jar");
+        fs::create_dir_all(cached.parent().expect("parent")).expect("create cache dir");
+        fs::write(&cached, b"cached").expect("write cached jar");
+
+        let paths = vec![PathBuf::from("app.jar"), PathBuf::from(url)];
+        let fetched = fetch_remote_inputs(paths, true, dir.path()).expect("fetch inputs");
+
+        assert_eq!(fetched, vec![PathBuf::from("app.jar"), cached]);
+    }
+}
@@ -12,9 +12,9 @@ use crate::dataflow::opcode_semantics::{
 };
 use crate::dataflow::stack_machine::StackMachine;
 use crate::engine::AnalysisContext;
-use crate::ir::{CallSite, Method};
+use crate::ir::{CallSite, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that flags array comparisons using == or equals().
 #[derive(Default)]
@@ -28,6 +28,9 @@ impl Rule for ArrayEqualsRule {
             id: "ARRAY_EQUALS",
             name: "Array equals",
             description: "Array comparisons using == or equals()",
+            severity: Severity::Error,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -88,6 +91,8 @@ impl ValueDomain<ValueKind> for ArrayValueDomain {
 #[derive(Default)]
 struct ArraySemanticsHook {
     reference_equality_offsets: Vec<u32>,
+    /// Value kinds of the fields read or written, keyed by instruction offset.
+    field_kinds: BTreeMap<u32, ValueKind>,
 }
 
 impl ArraySemanticsHook {
@@ -138,6 +143,25 @@ impl SemanticsHooks<ValueKind> for ArraySemanticsHook {
                 machine.push(ValueKind::NonArray);
                 ApplyOutcome::Applied
             }
+            opcodes::GETSTATIC | opcodes::GETFIELD => {
+                if opcode == opcodes::GETFIELD {
+                    machine.pop();
+                }
+                let value = self
+                    .field_kinds
+                    .get(&(offset as u32))
+                    .copied()
+                    .unwrap_or(ValueKind::Unknown);
+                machine.push(value);
+                ApplyOutcome::Applied
+            }
+            opcodes::PUTSTATIC | opcodes::PUTFIELD => {
+                machine.pop();
+                if opcode == opcodes::PUTFIELD {
+                    machine.pop();
+                }
+                ApplyOutcome::Applied
+            }
             opcodes::IF_ACMPEQ | opcodes::IF_ACMPNE => {
                 let right = machine.pop();
                 let left = machine.pop();
@@ -169,7 +193,10 @@ fn analyze_method(
 
     let mut machine = StackMachine::new(ValueKind::Unknown);
     let domain = ArrayValueDomain;
-    let mut hooks = ArraySemanticsHook::default();
+    let mut hooks = ArraySemanticsHook {
+        field_kinds: field_kinds(method),
+        ..ArraySemanticsHook::default()
+    };
     let mut coverage = SemanticsCoverage::default();
     let debug = SemanticsDebugConfig {
         enabled: opcode_semantics_debug_enabled(),
@@ -236,7 +263,7 @@ fn analyze_method(
                             && matches!(receiver, ValueKind::Array(_))
                         {
                             let message = result_message(format!(
-                                "Array comparison uses equals(): {}.{}{}",
+                                "Array comparison uses equals(): {}.{}{}; use java.util.Arrays.equals to compare elements",
                                 class_name, method.name, method.descriptor
                             ));
                             let line = method.line_for_offset(offset as u32);
@@ -296,6 +323,32 @@ fn callsite_for_offset<'a>(
     None
 }
 
+/// Returns the value kind of each field access in `method` from the field's descriptor.
+fn field_kinds(method: &Method) -> BTreeMap<u32, ValueKind> {
+    method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .filter_map(|instruction| match &instruction.kind {
+            InstructionKind::FieldAccess(field) => {
+                let dims = field
+                    .descriptor
+                    .bytes()
+                    .take_while(|byte| *byte == b'[')
+                    .count();
+                let value = match u8::try_from(dims) {
+                    Ok(0) => ValueKind::NonArray,
+                    Ok(dims) => ValueKind::Array(dims),
+                    Err(_) => ValueKind::Unknown,
+                };
+                Some((instruction.offset, value))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 fn initial_locals(method: &Method) -> Result<BTreeMap<usize, ValueKind>> {
     let mut locals = BTreeMap::new();
     let mut index = 0usize;
@@ -505,6 +558,54 @@ public class ClassA {
         return null;
     }
 }
+"#
+            .to_string(),
+        }];
+        let messages = analyze_sources(sources);
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn array_equals_reports_equals_call_on_array_field() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+public class ClassA {
+    private int[] fieldOne = new int[0];
+
+    public boolean methodOne(Object varOne) {
+        return fieldOne.equals(varOne);
+    }
+}
+"#
+            .to_string(),
+        }];
+        let messages = analyze_sources(sources);
+        assert_eq!(
+            messages,
+            vec![
+                "Array comparison uses equals(): com/example/ClassA.methodOne(Ljava/lang/Object;)Z; use java.util.Arrays.equals to compare elements"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn array_equals_ignores_equals_call_on_list_field() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+import java.util.ArrayList;
+import java.util.List;
+public class ClassA {
+    private List<String> fieldOne = new ArrayList<>();
+
+    public boolean methodOne(Object varOne) {
+        return fieldOne.equals(varOne);
+    }
+}
 "#
             .to_string(),
         }];
@@ -0,0 +1,290 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{BasicBlock, CallKind, CallSite, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `Arrays.asList` called with a single primitive array.
+#[derive(Default)]
+pub(crate) struct ArraysAsListPrimitiveArrayRule;
+
+crate::register_rule!(ArraysAsListPrimitiveArrayRule);
+
+impl Rule for ArraysAsListPrimitiveArrayRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "ARRAYS_ASLIST_PRIMITIVE_ARRAY",
+            name: "Arrays.asList on primitive array",
+            description: "Arrays.asList of a primitive array returns a one-element list holding the array",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() {
+                            continue;
+                        }
+                        for block in &method.cfg.blocks {
+                            let analysis = analyze_block(method, block)?;
+                            rule_coverage.merge_from(&analysis.coverage);
+                            for (offset, array_type) in analysis.findings {
+                                let message = result_message(format!(
+                                    "Arrays.asList in {}.{}{} receives a single {array_type} array and returns a List with that array as its only element; box the values, or use Arrays.stream(...).boxed() or a List of wrapper types.",
+                                    class.name, method.name, method.descriptor
+                                ));
+                                let location = method_location_with_line(
+                                    &class.name,
+                                    &method.name,
+                                    &method.descriptor,
+                                    artifact_uri.as_deref(),
+                                    method.line_for_offset(offset),
+                                );
+                                class_results.push(
+                                    SarifResult::builder()
+                                        .message(message)
+                                        .locations(vec![location])
+                                        .build(),
+                                );
+                            }
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("ARRAYS_ASLIST_PRIMITIVE_ARRAY", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Whether an operand stack or local value is a varargs array javac built around one
+/// primitive array.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    /// One-element array holding a primitive array of this Java type, such as `int[]`.
+    WrappedPrimitiveArray(&'static str),
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct ArrayValueDomain;
+
+impl ValueDomain<Value> for ArrayValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Hook that pushes `WrappedPrimitiveArray` for a one-element `anewarray` of a primitive array
+/// type.
+struct WrapperArrayHook {
+    wrapped: Option<&'static str>,
+}
+
+impl SemanticsHooks<Value> for WrapperArrayHook {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        _method: &Method,
+        _offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        let Some(array_type) = self.wrapped.filter(|_| opcode == opcodes::ANEWARRAY) else {
+            return ApplyOutcome::NotHandled;
+        };
+        machine.pop();
+        machine.push(Value::WrappedPrimitiveArray(array_type));
+        ApplyOutcome::Applied
+    }
+}
+
+/// Block-level analysis output with coverage summary for debug telemetry events.
+struct BlockAnalysis {
+    findings: Vec<(u32, &'static str)>,
+    coverage: SemanticsCoverage,
+}
+
+/// Simulates `block` from an unknown state and returns the `Arrays.asList` calls whose varargs
+/// array wraps a single primitive array, with that array's Java type.
+fn analyze_block(method: &Method, block: &BasicBlock) -> Result<BlockAnalysis> {
+    let mut machine = StackMachine::new(Value::Other);
+    let mut coverage = SemanticsCoverage::default();
+    let mut findings = Vec::new();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "ARRAYS_ASLIST_PRIMITIVE_ARRAY",
+    };
+    let mut previous_opcode = None;
+    for instruction in &block.instructions {
+        let wrapped = match &instruction.kind {
+            InstructionKind::ANewArray(component) if previous_opcode == Some(opcodes::ICONST_1) => {
+                primitive_array_type(component)
+            }
+            _ => None,
+        };
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &ArrayValueDomain,
+            &mut WrapperArrayHook { wrapped },
+            &mut coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => {
+                if is_arrays_as_list(call)
+                    && let Some(Value::WrappedPrimitiveArray(array_type)) = machine.peek().copied()
+                {
+                    findings.push((instruction.offset, array_type));
+                }
+                handle_invoke(call, &mut machine)?;
+            }
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+        previous_opcode = Some(instruction.opcode);
+    }
+    Ok(BlockAnalysis { findings, coverage })
+}
+
+fn is_arrays_as_list(call: &CallSite) -> bool {
+    call.owner == "java/util/Arrays"
+        && call.name == "asList"
+        && call.descriptor == "([Ljava/lang/Object;)Ljava/util/List;"
+}
+
+/// Returns the Java type of `component` when it is a one-dimensional primitive array descriptor.
+fn primitive_array_type(component: &str) -> Option<&'static str> {
+    match component {
+        "[Z" => Some("boolean[]"),
+        "[B" => Some("byte[]"),
+        "[C" => Some("char[]"),
+        "[S" => Some("short[]"),
+        "[I" => Some("int[]"),
+        "[J" => Some("long[]"),
+        "[F" => Some("float[]"),
+        "[D" => Some("double[]"),
+        _ => None,
+    }
+}
+
+fn handle_invoke(call: &CallSite, machine: &mut StackMachine<Value>) -> Result<()> {
+    machine.pop_n(method_param_count(&call.descriptor)?);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+    if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        machine.push(Value::Other);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("ARRAYS_ASLIST_PRIMITIVE_ARRAY"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_as_list_of_int_array() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Arrays;
+import java.util.List;
+public class ClassA {
+    int methodX(int[] varOne) {
+        List<int[]> varTwo = Arrays.asList(varOne);
+        return varTwo.size();
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Arrays.asList in com/example/ClassA.methodX([I)I receives a single int[] array and returns a List with that array as its only element; box the values, or use Arrays.stream(...).boxed() or a List of wrapper types."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_reference_arrays_or_several_primitive_arrays() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Arrays;
+import java.util.List;
+public class ClassA {
+    List<String> methodX(String[] varOne) {
+        return Arrays.asList(varOne);
+    }
+
+    List<Integer> methodY() {
+        return Arrays.asList(1, 2, 3);
+    }
+
+    List<int[]> methodZ(int[] varOne, int[] varTwo) {
+        return Arrays.asList(varOne, varTwo);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
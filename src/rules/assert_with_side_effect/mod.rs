@@ -0,0 +1,259 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallSite, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Method name prefixes treated as queries when followed by an upper-case letter.
+const QUERY_PREFIXES: &[&str] = &["get", "is", "has", "can", "should"];
+
+/// Method names treated as side-effect free regardless of owner.
+const QUERY_METHODS: &[&str] = &[
+    "compare",
+    "compareTo",
+    "contains",
+    "containsAll",
+    "containsKey",
+    "containsValue",
+    "endsWith",
+    "equals",
+    "equalsIgnoreCase",
+    "hashCode",
+    "length",
+    "matches",
+    "size",
+    "startsWith",
+    "toString",
+    "valueOf",
+];
+
+/// Rule that detects `assert` conditions calling methods that may mutate state.
+#[derive(Default)]
+pub(crate) struct AssertWithSideEffectRule;
+
+crate::register_rule!(AssertWithSideEffectRule);
+
+impl Rule for AssertWithSideEffectRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "ASSERT_WITH_SIDE_EFFECT",
+            name: "Assert with side effect",
+            description: "Assert conditions with side effects change behavior when assertions are disabled",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for call in side_effect_calls_in_asserts(method) {
+                            let message = result_message(format!(
+                                "Assertion in {}.{}{} calls {}.{}, which may have side effects that are skipped when assertions are disabled; move the call out of the assert.",
+                                class.name, method.name, method.descriptor, call.owner, call.name
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(call.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Calls inside desugared `assert` conditions that are not known queries.
+///
+/// javac compiles `assert cond : detail` to
+/// `getstatic $assertionsDisabled; ifne END; <cond>; new AssertionError; dup; <detail>;
+/// invokespecial AssertionError.<init>; athrow`.
+fn side_effect_calls_in_asserts(method: &Method) -> Vec<&CallSite> {
+    let mut instructions: Vec<&Instruction> = method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .collect();
+    instructions.sort_by_key(|instruction| instruction.offset);
+
+    let mut calls = Vec::new();
+    for (index, instruction) in instructions.iter().enumerate() {
+        if !is_assertions_disabled_read(instruction)
+            || instructions.get(index + 1).map(|next| next.opcode) != Some(opcodes::IFNE)
+        {
+            continue;
+        }
+        let condition_start = index + 2;
+        let Some(condition_end) = assertion_error_new_index(&instructions, condition_start) else {
+            continue;
+        };
+        for candidate in &instructions[condition_start..condition_end] {
+            if let InstructionKind::Invoke(call) = &candidate.kind
+                && !is_query_call(call)
+            {
+                calls.push(call);
+            }
+        }
+    }
+    calls
+}
+
+fn is_assertions_disabled_read(instruction: &Instruction) -> bool {
+    instruction.opcode == opcodes::GETSTATIC
+        && matches!(
+            &instruction.kind,
+            InstructionKind::FieldAccess(field)
+                if field.name == "$assertionsDisabled" && field.descriptor == "Z"
+        )
+}
+
+/// Index of the `new` that allocates the `AssertionError` thrown by the assert at `start`.
+///
+/// The matching `new` is found by walking back from the `AssertionError.<init>` call and
+/// skipping `new`/`<init>` pairs of objects constructed in the detail message.
+fn assertion_error_new_index(instructions: &[&Instruction], start: usize) -> Option<usize> {
+    let init_index = instructions[start..]
+        .iter()
+        .position(|instruction| {
+            matches!(
+                &instruction.kind,
+                InstructionKind::Invoke(call)
+                    if call.owner == "java/lang/AssertionError" && call.name == "<init>"
+            )
+        })
+        .map(|position| start + position)?;
+    let mut pending_inits = 0usize;
+    for index in (start..init_index).rev() {
+        let instruction = instructions[index];
+        if instruction.opcode == opcodes::NEW {
+            if pending_inits == 0 {
+                return Some(index);
+            }
+            pending_inits -= 1;
+        } else if let InstructionKind::Invoke(call) = &instruction.kind
+            && call.name == "<init>"
+        {
+            pending_inits += 1;
+        }
+    }
+    None
+}
+
+fn is_query_call(call: &CallSite) -> bool {
+    if call.name == "<init>" || QUERY_METHODS.contains(&call.name.as_str()) {
+        return true;
+    }
+    QUERY_PREFIXES.iter().any(|prefix| {
+        call.name
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.chars().next().is_none_or(char::is_uppercase))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(source: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: source.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("ASSERT_WITH_SIDE_EFFECT"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_mutating_call_in_assert_condition() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.List;
+public class ClassA {
+    public void methodX(List<String> varOne, String varTwo) {
+        assert varOne.add(varTwo);
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Assertion in com/example/ClassA.methodX(Ljava/util/List;Ljava/lang/String;)V calls java/util/List.add, which may have side effects that are skipped when assertions are disabled; move the call out of the assert."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_mutating_call_in_compound_condition_with_detail_message() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Iterator;
+public class ClassA {
+    public void methodX(Iterator<String> varOne) {
+        assert varOne.hasNext() && varOne.next() != null : new StringBuilder("x").append(1);
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].contains("calls java/util/Iterator.next"));
+    }
+
+    #[test]
+    fn does_not_report_query_calls_or_detail_message_calls() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.List;
+public class ClassA {
+    public void methodX(List<String> varOne, String varTwo) {
+        assert varOne.isEmpty() : "not empty: " + varOne.remove(0);
+        assert varOne.size() < 3 && !varOne.contains(varTwo);
+        varOne.add(varTwo);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
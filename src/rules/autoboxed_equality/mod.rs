@@ -0,0 +1,286 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{BasicBlock, CallKind, CallSite, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `==`/`!=` on values freshly boxed by `valueOf`.
+#[derive(Default)]
+pub(crate) struct AutoboxedEqualityRule;
+
+crate::register_rule!(AutoboxedEqualityRule);
+
+impl Rule for AutoboxedEqualityRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "AUTOBOXED_EQUALITY",
+            name: "Reference equality on boxed values",
+            description: "Boxed numbers compared with == or != depend on the valueOf cache",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() {
+                            continue;
+                        }
+                        for block in &method.cfg.blocks {
+                            let analysis = analyze_block(method, block)?;
+                            rule_coverage.merge_from(&analysis.coverage);
+                            for (offset, boxed_type) in analysis.findings {
+                                let message = result_message(format!(
+                                    "{}.{}{} compares boxed {boxed_type} values by reference; {boxed_type}.valueOf reuses instances only for small values, so the result depends on the value. Use equals() or compare the primitive values.",
+                                    class.name, method.name, method.descriptor
+                                ));
+                                let location = method_location_with_line(
+                                    &class.name,
+                                    &method.name,
+                                    &method.descriptor,
+                                    artifact_uri.as_deref(),
+                                    method.line_for_offset(offset),
+                                );
+                                class_results.push(
+                                    SarifResult::builder()
+                                        .message(message)
+                                        .locations(vec![location])
+                                        .build(),
+                                );
+                            }
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("AUTOBOXED_EQUALITY", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Whether an operand stack or local value came straight from a boxing `valueOf` call.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    /// Result of `valueOf` on the named wrapper type, such as `Integer`.
+    Boxed(&'static str),
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct BoxedValueDomain;
+
+impl ValueDomain<Value> for BoxedValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Hook that records `if_acmpeq`/`if_acmpne` comparisons with a boxed operand.
+#[derive(Default)]
+struct ReferenceComparisonHook {
+    findings: Vec<(u32, &'static str)>,
+}
+
+impl SemanticsHooks<Value> for ReferenceComparisonHook {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        _method: &Method,
+        offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        if !matches!(opcode, opcodes::IF_ACMPEQ | opcodes::IF_ACMPNE) {
+            return ApplyOutcome::NotHandled;
+        }
+        let right = machine.pop();
+        let left = machine.pop();
+        if let (Value::Boxed(boxed_type), _) | (_, Value::Boxed(boxed_type)) = (left, right) {
+            self.findings.push((offset as u32, boxed_type));
+        }
+        ApplyOutcome::Applied
+    }
+}
+
+/// Block-level analysis output with coverage summary for debug telemetry events.
+struct BlockAnalysis {
+    findings: Vec<(u32, &'static str)>,
+    coverage: SemanticsCoverage,
+}
+
+/// Simulates `block` from an unknown state and returns the reference comparisons that have an
+/// operand boxed within the block, with the operand's wrapper type.
+fn analyze_block(method: &Method, block: &BasicBlock) -> Result<BlockAnalysis> {
+    let mut machine = StackMachine::new(Value::Other);
+    let mut hooks = ReferenceComparisonHook::default();
+    let mut coverage = SemanticsCoverage::default();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "AUTOBOXED_EQUALITY",
+    };
+    for instruction in &block.instructions {
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &BoxedValueDomain,
+            &mut hooks,
+            &mut coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => handle_invoke(call, &mut machine)?,
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(BlockAnalysis {
+        findings: hooks.findings,
+        coverage,
+    })
+}
+
+fn handle_invoke(call: &CallSite, machine: &mut StackMachine<Value>) -> Result<()> {
+    machine.pop_n(method_param_count(&call.descriptor)?);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+    if let Some(boxed_type) = boxing_type(call) {
+        machine.push(Value::Boxed(boxed_type));
+    } else if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        machine.push(Value::Other);
+    }
+    Ok(())
+}
+
+/// Returns the wrapper type when `call` boxes a primitive with `valueOf`, as javac emits for
+/// autoboxing. `Boolean.valueOf` is excluded because it always returns one of two constants.
+fn boxing_type(call: &CallSite) -> Option<&'static str> {
+    if call.kind != CallKind::Static || call.name != "valueOf" {
+        return None;
+    }
+    let (boxed_type, descriptor) = match call.owner.as_str() {
+        "java/lang/Integer" => ("Integer", "(I)Ljava/lang/Integer;"),
+        "java/lang/Long" => ("Long", "(J)Ljava/lang/Long;"),
+        "java/lang/Short" => ("Short", "(S)Ljava/lang/Short;"),
+        "java/lang/Byte" => ("Byte", "(B)Ljava/lang/Byte;"),
+        "java/lang/Character" => ("Character", "(C)Ljava/lang/Character;"),
+        "java/lang/Float" => ("Float", "(F)Ljava/lang/Float;"),
+        "java/lang/Double" => ("Double", "(D)Ljava/lang/Double;"),
+        _ => return None,
+    };
+    (call.descriptor == descriptor).then_some(boxed_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("AUTOBOXED_EQUALITY"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_reference_comparison_of_boxed_values() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    boolean methodX(int varOne, int varTwo) {
+        Integer varThree = varOne;
+        Integer varFour = Integer.valueOf(varTwo);
+        return varThree == varFour;
+    }
+
+    boolean methodY(long varOne, Long varTwo) {
+        return Long.valueOf(varOne) != varTwo;
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.methodX(II)Z compares boxed Integer values by reference; Integer.valueOf reuses instances only for small values, so the result depends on the value. Use equals() or compare the primitive values."
+                    .to_string(),
+                "com/example/ClassA.methodY(JLjava/lang/Long;)Z compares boxed Long values by reference; Long.valueOf reuses instances only for small values, so the result depends on the value. Use equals() or compare the primitive values."
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_primitive_or_equals_comparisons() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    boolean methodX(int varOne, int varTwo) {
+        return varOne == varTwo;
+    }
+
+    boolean methodY(int varOne, int varTwo) {
+        Integer varThree = varOne;
+        return varThree.equals(varTwo);
+    }
+
+    boolean methodZ(boolean varOne, boolean varTwo) {
+        Boolean varThree = varOne;
+        Boolean varFour = varTwo;
+        return varThree == varFour;
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::collections::{BTreeSet, VecDeque};
 
 use anyhow::Result;
 use opentelemetry::KeyValue;
@@ -14,9 +14,9 @@ use crate::dataflow::worklist::{
 };
 use crate::descriptor::{ReturnKind, method_descriptor_summary, method_return_class_name};
 use crate::engine::AnalysisContext;
-use crate::ir::{CallKind, CallSite, Class, EdgeKind, Instruction, InstructionKind, Method};
+use crate::ir::{CallKind, CallSite, EdgeKind, Instruction, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 const MAX_TRACKED_STACK_DEPTH: usize = 32;
 
@@ -32,15 +32,14 @@ impl Rule for UnmanagedAutocloseableRule {
             id: "AUTOCLOSEABLE_NOT_CLOSED",
             name: "AutoCloseable not closed",
             description: "Locally created AutoCloseable instances should be closed on every exit path",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["resource"],
         }
     }
 
     fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
         let mut results = Vec::new();
-        let class_map = context
-            .all_classes()
-            .map(|class| (class.name.clone(), class))
-            .collect::<BTreeMap<_, _>>();
 
         for class in context.analysis_target_classes() {
             let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
@@ -68,7 +67,7 @@ impl Rule for UnmanagedAutocloseableRule {
                             continue;
                         }
 
-                        for creation_offset in analyze_closeable_lifecycle(method, &class_map)? {
+                        for creation_offset in analyze_closeable_lifecycle(method, context)? {
                             let cls_name = &class.name;
                             let met_name = &method.name;
                             let met_descriptor = &method.descriptor;
@@ -148,7 +147,7 @@ impl WorklistState for ExecutionState {
 /// Dataflow callbacks for local AutoCloseable lifecycle analysis.
 struct CloseableLifecycleSemantics<'a> {
     entry_block: u32,
-    class_map: &'a BTreeMap<String, &'a Class>,
+    context: &'a AnalysisContext,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -217,7 +216,7 @@ impl WorklistSemantics for CloseableLifecycleSemantics<'_> {
                 }
             }
             _ => match &instruction.kind {
-                InstructionKind::Invoke(call) => handle_invoke(call, state, self.class_map)?,
+                InstructionKind::Invoke(call) => handle_invoke(call, state, self.context)?,
                 InstructionKind::InvokeDynamic { descriptor, .. } => {
                     handle_invoke_dynamic(descriptor, state)?
                 }
@@ -259,7 +258,7 @@ impl WorklistSemantics for CloseableLifecycleSemantics<'_> {
 
 fn analyze_closeable_lifecycle(
     method: &Method,
-    class_map: &BTreeMap<String, &Class>,
+    context: &AnalysisContext,
 ) -> Result<Vec<u32>> {
     let entry_block = method
         .cfg
@@ -270,7 +269,7 @@ fn analyze_closeable_lifecycle(
         .unwrap_or(0);
     let semantics = CloseableLifecycleSemantics {
         entry_block,
-        class_map,
+        context,
     };
     let findings = analyze_method(method, &semantics)?;
     Ok(findings
@@ -322,7 +321,7 @@ impl SemanticsHooks<Value> for CloseableSemanticsHook {
 fn handle_invoke(
     call: &CallSite,
     state: &mut ExecutionState,
-    class_map: &BTreeMap<String, &Class>,
+    context: &AnalysisContext,
 ) -> Result<()> {
     let summary = method_descriptor_summary(&call.descriptor)?;
     let mut args = Vec::with_capacity(summary.param_count);
@@ -340,7 +339,7 @@ fn handle_invoke(
         let is_excluded = is_excluded_noop_type(&call.owner);
         if let Some(Value::Symbol(symbol)) = receiver
             && !is_excluded
-            && is_autocloseable_constructor(call, class_map)
+            && is_autocloseable_constructor(call, context)
         {
             // Tracked AutoCloseable: wrapper delegation escapes inner args.
             state.active_closeables.insert(symbol);
@@ -390,7 +389,7 @@ fn handle_invoke(
     if summary.return_kind == ReturnKind::Reference {
         if let Ok(Some(return_class)) = method_return_class_name(&call.descriptor) {
             if !is_excluded_noop_type(&return_class)
-                && is_autocloseable_type(&return_class, class_map)
+                && is_autocloseable_type(&return_class, context)
             {
                 let symbol = call.offset;
                 state.active_closeables.insert(symbol);
@@ -466,11 +465,11 @@ fn is_close_call(call: &CallSite) -> bool {
     call.name == "close" && call.descriptor == "()V"
 }
 
-fn is_autocloseable_constructor(call: &CallSite, class_map: &BTreeMap<String, &Class>) -> bool {
-    call.name == "<init>" && is_autocloseable_type(&call.owner, class_map)
+fn is_autocloseable_constructor(call: &CallSite, context: &AnalysisContext) -> bool {
+    call.name == "<init>" && is_autocloseable_type(&call.owner, context)
 }
 
-fn is_autocloseable_type(name: &str, class_map: &BTreeMap<String, &Class>) -> bool {
+fn is_autocloseable_type(name: &str, context: &AnalysisContext) -> bool {
     if is_known_autocloseable_name(name) {
         return true;
     }
@@ -484,7 +483,7 @@ fn is_autocloseable_type(name: &str, class_map: &BTreeMap<String, &Class>) -> bo
         if is_known_autocloseable_name(&next) {
             return true;
         }
-        let Some(class) = class_map.get(&next) else {
+        let Some(class) = context.class_by_name(&next) else {
             continue;
         };
         if let Some(super_name) = &class.super_name {
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects `BigDecimal.divide(BigDecimal)` calls without rounding config.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for BigDecimalDivideWithoutRoundingRule {
             id: "BIGDECIMAL_DIVIDE_WITHOUT_ROUNDING",
             name: "BigDecimal divide without rounding",
             description: "BigDecimal.divide(BigDecimal) can throw on non-terminating decimals",
+            severity: Severity::Error,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
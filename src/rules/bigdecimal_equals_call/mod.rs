@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct `BigDecimal.equals(Object)` calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for BigDecimalEqualsCallRule {
             id: "BIGDECIMAL_EQUALS_CALL",
             name: "BigDecimal equals call",
             description: "BigDecimal.equals compares value and scale instead of numeric equality",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects BigDecimal constructor calls that accept double values.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for BigDecimalFromDoubleRule {
             id: "BIGDECIMAL_FROM_DOUBLE",
             name: "BigDecimal from double",
             description: "BigDecimal constructors with double can introduce precision surprises",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects `BigDecimal.setScale(int)` calls without explicit rounding.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for BigDecimalSetScaleWithoutRoundingRule {
             id: "BIGDECIMAL_SET_SCALE_WITHOUT_ROUNDING",
             name: "BigDecimal setScale without rounding",
             description: "BigDecimal.setScale(int) can throw when rounding is required",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct Boolean.getBoolean calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for BooleanGetbooleanCallRule {
             id: "BOOLEAN_GETBOOLEAN_CALL",
             name: "Boolean.getBoolean call",
             description: "Boolean.getBoolean reads system properties, not text booleans",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
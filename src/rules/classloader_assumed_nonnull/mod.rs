@@ -0,0 +1,316 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{BasicBlock, CallKind, CallSite, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects dereferences of `Class.getClassLoader()` results without a null check.
+#[derive(Default)]
+pub(crate) struct ClassLoaderAssumedNonNullRule;
+
+crate::register_rule!(ClassLoaderAssumedNonNullRule);
+
+impl Rule for ClassLoaderAssumedNonNullRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "CLASSLOADER_ASSUMED_NONNULL",
+            name: "Class loader assumed non-null",
+            description: "Class.getClassLoader() results dereferenced without a null check",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty()
+                            || !method.calls.iter().any(is_get_class_loader)
+                        {
+                            continue;
+                        }
+                        for block in &method.cfg.blocks {
+                            let analysis = analyze_block(method, block)?;
+                            rule_coverage.merge_from(&analysis.coverage);
+                            for (offset, call_name) in analysis.findings {
+                                let message = result_message(format!(
+                                    "{}.{}{} calls ClassLoader.{call_name} on the result of Class.getClassLoader() without a null check; it returns null for classes loaded by the bootstrap class loader. Check for null or fall back to ClassLoader.getSystemClassLoader().",
+                                    class.name, method.name, method.descriptor
+                                ));
+                                let location = method_location_with_line(
+                                    &class.name,
+                                    &method.name,
+                                    &method.descriptor,
+                                    artifact_uri.as_deref(),
+                                    method.line_for_offset(offset),
+                                );
+                                class_results.push(
+                                    SarifResult::builder()
+                                        .message(message)
+                                        .locations(vec![location])
+                                        .build(),
+                                );
+                            }
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("CLASSLOADER_ASSUMED_NONNULL", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Provenance of an operand stack or local value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    /// `this` in an instance method.
+    This,
+    /// `Class` object of a class that is not loaded by the bootstrap class loader, such as
+    /// `this.getClass()` or a class literal outside the JDK.
+    NonBootstrapClass,
+    /// Unchecked result of `Class.getClassLoader()` on any other `Class` object.
+    Loader,
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct LoaderValueDomain;
+
+impl ValueDomain<Value> for LoaderValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Hook that pushes the provenance of a class literal for `ldc`.
+struct ClassLiteralHook {
+    literal: Option<Value>,
+}
+
+impl SemanticsHooks<Value> for ClassLiteralHook {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        _method: &Method,
+        _offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        match self.literal {
+            Some(value) if matches!(opcode, opcodes::LDC | opcodes::LDC_W) => {
+                machine.push(value);
+                ApplyOutcome::Applied
+            }
+            _ => ApplyOutcome::NotHandled,
+        }
+    }
+}
+
+/// Block-level analysis output with coverage summary for debug telemetry events.
+struct BlockAnalysis {
+    findings: Vec<(u32, String)>,
+    coverage: SemanticsCoverage,
+}
+
+/// Simulates `block` from an unknown state and returns the calls made on a class loader that
+/// was obtained in the block and not checked for null, with the called method name.
+///
+/// A null check ends the block, so a loader checked by `ifnull`/`ifnonnull` is unknown again
+/// where it is used.
+fn analyze_block(method: &Method, block: &BasicBlock) -> Result<BlockAnalysis> {
+    let mut machine = StackMachine::new(Value::Other);
+    if !method.access.is_static {
+        machine.store_local(0, Value::This);
+    }
+    let mut coverage = SemanticsCoverage::default();
+    let mut findings = Vec::new();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "CLASSLOADER_ASSUMED_NONNULL",
+    };
+    for instruction in &block.instructions {
+        let literal = match &instruction.kind {
+            InstructionKind::ConstClass(name) if !is_jdk_class(name) => {
+                Some(Value::NonBootstrapClass)
+            }
+            _ => None,
+        };
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &LoaderValueDomain,
+            &mut ClassLiteralHook { literal },
+            &mut coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => {
+                if handle_invoke(call, &mut machine)? == Some(Value::Loader) {
+                    findings.push((instruction.offset, call.name.clone()));
+                }
+            }
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(BlockAnalysis { findings, coverage })
+}
+
+/// Applies `call` to the stack and returns its receiver value, if it has one.
+fn handle_invoke(call: &CallSite, machine: &mut StackMachine<Value>) -> Result<Option<Value>> {
+    let arguments: Vec<Value> = (0..method_param_count(&call.descriptor)?)
+        .map(|_| machine.pop())
+        .collect();
+    let receiver = (call.kind != CallKind::Static).then(|| machine.pop());
+    if is_require_non_null(call) && arguments.contains(&Value::Loader) {
+        machine.rewrite_values(|value| {
+            if *value == Value::Loader {
+                *value = Value::Other;
+            }
+        });
+    }
+    if is_get_class(call) && receiver == Some(Value::This) {
+        machine.push(Value::NonBootstrapClass);
+    } else if is_get_class_loader(call) && receiver != Some(Value::NonBootstrapClass) {
+        machine.push(Value::Loader);
+    } else if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        machine.push(Value::Other);
+    }
+    Ok(receiver)
+}
+
+fn is_get_class(call: &CallSite) -> bool {
+    call.name == "getClass" && call.descriptor == "()Ljava/lang/Class;"
+}
+
+fn is_get_class_loader(call: &CallSite) -> bool {
+    call.owner == "java/lang/Class"
+        && call.name == "getClassLoader"
+        && call.descriptor == "()Ljava/lang/ClassLoader;"
+}
+
+fn is_require_non_null(call: &CallSite) -> bool {
+    call.owner == "java/util/Objects" && call.name == "requireNonNull"
+}
+
+/// Whether a class literal may name a class defined by the bootstrap class loader.
+fn is_jdk_class(name: &str) -> bool {
+    ["java/", "javax/", "jdk/", "sun/"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("CLASSLOADER_ASSUMED_NONNULL"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_unchecked_class_loader_chain() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.net.URL;
+public class ClassA {
+    URL methodX(Object varOne) {
+        return varOne.getClass().getClassLoader().getResource("app.properties");
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.methodX(Ljava/lang/Object;)Ljava/net/URL; calls ClassLoader.getResource on the result of Class.getClassLoader() without a null check; it returns null for classes loaded by the bootstrap class loader. Check for null or fall back to ClassLoader.getSystemClassLoader()."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_null_checked_or_own_class_loaders() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.net.URL;
+import java.util.Objects;
+public class ClassA {
+    URL methodX(Object varOne) {
+        ClassLoader varTwo = varOne.getClass().getClassLoader();
+        if (varTwo == null) {
+            varTwo = ClassLoader.getSystemClassLoader();
+        }
+        return varTwo.getResource("app.properties");
+    }
+
+    URL methodY() {
+        return getClass().getClassLoader().getResource("app.properties");
+    }
+
+    URL methodZ() {
+        return ClassA.class.getClassLoader().getResource("app.properties");
+    }
+
+    URL methodW(Class<?> varOne) {
+        ClassLoader varTwo = Objects.requireNonNull(varOne.getClassLoader());
+        return varTwo.getResource("app.properties");
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
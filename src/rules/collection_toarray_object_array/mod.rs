@@ -0,0 +1,193 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, Instruction, InstructionKind, Method};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects casting the `Object[]` from `Collection.toArray()` to another array type.
+#[derive(Default)]
+pub(crate) struct CollectionToarrayObjectArrayRule;
+
+crate::register_rule!(CollectionToarrayObjectArrayRule);
+
+/// `toArray()` call whose result is cast to another array type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CastSite {
+    owner: String,
+    target_type: String,
+    offset: u32,
+}
+
+impl Rule for CollectionToarrayObjectArrayRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "COLLECTION_TOARRAY_OBJECT_ARRAY",
+            name: "Collection.toArray() result cast",
+            description: "Casting the Object[] returned by Collection.toArray() to a narrower array type throws ClassCastException",
+            severity: Severity::Error,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for site in find_cast_sites(method) {
+                            let message = result_message(format!(
+                                "{}.{}{} casts the Object[] returned by {}.toArray() to {}, which throws ClassCastException; use toArray(T[]) or toArray(IntFunction) instead.",
+                                class.name,
+                                method.name,
+                                method.descriptor,
+                                site.owner,
+                                site.target_type
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(site.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+fn find_cast_sites(method: &Method) -> Vec<CastSite> {
+    let mut instructions: Vec<&Instruction> = method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .collect();
+    instructions.sort_by_key(|instruction| instruction.offset);
+
+    instructions
+        .windows(2)
+        .filter_map(|pair| {
+            let InstructionKind::Invoke(call) = &pair[0].kind else {
+                return None;
+            };
+            let InstructionKind::Checkcast(target_type) = &pair[1].kind else {
+                return None;
+            };
+            let is_collection_to_array =
+                matches!(call.kind, CallKind::Virtual | CallKind::Interface)
+                    && call.owner.starts_with("java/util/")
+                    && call.name == "toArray"
+                    && call.descriptor == "()[Ljava/lang/Object;";
+            (is_collection_to_array && target_type.starts_with('[')).then(|| CastSite {
+                owner: call.owner.clone(),
+                target_type: target_type.clone(),
+                offset: call.offset,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("COLLECTION_TOARRAY_OBJECT_ARRAY"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_cast_of_to_array_result() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.List;
+public class ClassA {
+    public String[] methodX(List<String> varOne) {
+        return (String[]) varOne.toArray();
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.methodX(Ljava/util/List;)[Ljava/lang/String; casts the Object[] returned by java/util/List.toArray() to [Ljava/lang/String;, which throws ClassCastException; use toArray(T[]) or toArray(IntFunction) instead."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_cast_on_concrete_collection_type() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.ArrayList;
+public class ClassA {
+    public Integer[] methodX(ArrayList<Integer> varOne) {
+        Integer[] tmpValue = (Integer[]) varOne.toArray();
+        return tmpValue;
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].contains("java/util/ArrayList.toArray()"));
+    }
+
+    #[test]
+    fn does_not_report_typed_to_array_or_uncast_result() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.List;
+public class ClassA {
+    public String[] methodX(List<String> varOne) {
+        return varOne.toArray(new String[0]);
+    }
+    public Object[] methodY(List<String> varOne) {
+        return varOne.toArray();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -0,0 +1,192 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::descriptor::method_param_class_names;
+use crate::engine::AnalysisContext;
+use crate::ir::Method;
+use crate::opcodes;
+use crate::rules::{
+    PrimitiveConstant, Rule, RuleMetadata, Severity, constant_return, method_location_with_line,
+    result_message,
+};
+
+/// Rule that detects `compare`/`compareTo` methods returning the same constant on every path.
+#[derive(Default)]
+pub(crate) struct ComparatorReturnsConstantRule;
+
+crate::register_rule!(ComparatorReturnsConstantRule);
+
+impl Rule for ComparatorReturnsConstantRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "COMPARATOR_RETURNS_CONSTANT",
+            name: "Comparator returns constant",
+            description: "compare/compareTo methods that return the same constant regardless of their arguments",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if !is_comparison_method(method)? {
+                            continue;
+                        }
+                        let Some((PrimitiveConstant::Int(value), offset)) =
+                            constant_return(method, &[opcodes::IRETURN], 1)
+                        else {
+                            continue;
+                        };
+                        let message = result_message(format!(
+                            "{}.{}{} always returns {value} regardless of its arguments; return a negative, zero, or positive value that reflects the order of the compared values.",
+                            class.name, method.name, method.descriptor
+                        ));
+                        let location = method_location_with_line(
+                            &class.name,
+                            &method.name,
+                            &method.descriptor,
+                            artifact_uri.as_deref(),
+                            method.line_for_offset(offset),
+                        );
+                        class_results.push(
+                            SarifResult::builder()
+                                .message(message)
+                                .locations(vec![location])
+                                .build(),
+                        );
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Whether `method` has the shape of `Comparator.compare` or `Comparable.compareTo`.
+fn is_comparison_method(method: &Method) -> Result<bool> {
+    if method.bytecode.is_empty()
+        || method.access.is_static
+        || method.access.is_synthetic
+        || method.access.is_bridge
+        || !method.descriptor.ends_with(")I")
+    {
+        return Ok(false);
+    }
+    let expected_params = match method.name.as_str() {
+        "compare" => 2,
+        "compareTo" => 1,
+        _ => return Ok(false),
+    };
+    let params = method_param_class_names(&method.descriptor)?;
+    Ok(params.len() == expected_params && params.iter().all(Option::is_some))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("COMPARATOR_RETURNS_CONSTANT"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_comparator_always_returning_zero() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Comparator;
+public class ClassA implements Comparator<String> {
+    @Override
+    public int compare(String varOne, String varTwo) {
+        return 0;
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.compare(Ljava/lang/String;Ljava/lang/String;)I always returns 0 regardless of its arguments; return a negative, zero, or positive value that reflects the order of the compared values."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_compare_to_returning_same_constant_on_every_path() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA implements Comparable<ClassA> {
+    private int fieldOne;
+
+    @Override
+    public int compareTo(ClassA varOne) {
+        if (varOne.fieldOne > fieldOne) {
+            return -1;
+        }
+        return -1;
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].contains("always returns -1"));
+    }
+
+    #[test]
+    fn does_not_report_real_comparison() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Comparator;
+public class ClassA implements Comparator<String>, Comparable<ClassA> {
+    private int fieldOne;
+
+    @Override
+    public int compare(String varOne, String varTwo) {
+        if (varOne.length() < varTwo.length()) {
+            return -1;
+        }
+        return varOne.length() == varTwo.length() ? 0 : 1;
+    }
+
+    @Override
+    public int compareTo(ClassA varOne) {
+        return Integer.compare(fieldOne, varOne.fieldOne);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -5,7 +5,7 @@ use serde_sarif::sarif::Result as SarifResult;
 use crate::engine::AnalysisContext;
 use crate::ir::Method;
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects integer subtraction used as the comparison result in `compareTo` methods,
 /// which can produce incorrect ordering for extreme values due to arithmetic overflow.
@@ -20,6 +20,9 @@ impl Rule for CompareToOverflowRule {
             id: "COMPARETO_OVERFLOW",
             name: "compareTo integer subtraction overflow",
             description: "compareTo using integer subtraction can overflow for extreme values",
+            severity: Severity::Error,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -0,0 +1,228 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::CallSite;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects character I/O classes constructed with the platform default charset.
+#[derive(Default)]
+pub(crate) struct DefaultCharsetIoRule;
+
+crate::register_rule!(DefaultCharsetIoRule);
+
+impl Rule for DefaultCharsetIoRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "DEFAULT_CHARSET_IO",
+            name: "Character I/O without explicit charset",
+            description: "Readers, writers, and scanners constructed without a Charset argument",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    for method in &class.methods {
+                        let artifact_uri = context.class_artifact_uri(class);
+                        for call in &method.calls {
+                            let Some(constructor) = default_charset_constructor(call) else {
+                                continue;
+                            };
+                            let message = result_message(format!(
+                                "{constructor} in {}.{}{} uses the platform default charset; pass StandardCharsets.UTF_8 (or an explicit Charset) to make behavior deterministic.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let line = method.line_for_offset(call.offset);
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                line,
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns the Java form of `call` when it constructs a character reader or writer that
+/// falls back to the default charset.
+fn default_charset_constructor(call: &CallSite) -> Option<&'static str> {
+    if call.name != "<init>" {
+        return None;
+    }
+    match (call.owner.as_str(), call.descriptor.as_str()) {
+        ("java/io/FileReader", "(Ljava/lang/String;)V") => Some("new FileReader(String)"),
+        ("java/io/FileReader", "(Ljava/io/File;)V") => Some("new FileReader(File)"),
+        ("java/io/FileReader", "(Ljava/io/FileDescriptor;)V") => {
+            Some("new FileReader(FileDescriptor)")
+        }
+        ("java/io/FileWriter", "(Ljava/lang/String;)V") => Some("new FileWriter(String)"),
+        ("java/io/FileWriter", "(Ljava/lang/String;Z)V") => Some("new FileWriter(String, boolean)"),
+        ("java/io/FileWriter", "(Ljava/io/File;)V") => Some("new FileWriter(File)"),
+        ("java/io/FileWriter", "(Ljava/io/File;Z)V") => Some("new FileWriter(File, boolean)"),
+        ("java/io/FileWriter", "(Ljava/io/FileDescriptor;)V") => {
+            Some("new FileWriter(FileDescriptor)")
+        }
+        ("java/io/InputStreamReader", "(Ljava/io/InputStream;)V") => {
+            Some("new InputStreamReader(InputStream)")
+        }
+        ("java/io/OutputStreamWriter", "(Ljava/io/OutputStream;)V") => {
+            Some("new OutputStreamWriter(OutputStream)")
+        }
+        ("java/io/PrintWriter", "(Ljava/io/File;)V") => Some("new PrintWriter(File)"),
+        ("java/io/PrintWriter", "(Ljava/lang/String;)V") => Some("new PrintWriter(String)"),
+        ("java/util/Scanner", "(Ljava/io/InputStream;)V") => Some("new Scanner(InputStream)"),
+        ("java/util/Scanner", "(Ljava/io/File;)V") => Some("new Scanner(File)"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze_sources(sources: Vec<SourceFile>) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("DEFAULT_CHARSET_IO"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_file_reader_without_charset() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.io.File;
+import java.io.FileReader;
+import java.io.Reader;
+
+class ClassA {
+    Reader methodX(File varOne) throws Exception {
+        return new FileReader(varOne);
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+
+        assert_eq!(messages.len(), 1, "expected one finding, got: {messages:?}");
+        assert!(messages[0].starts_with(
+            "new FileReader(File) in com/example/ClassA.methodX(Ljava/io/File;)Ljava/io/Reader;"
+        ));
+    }
+
+    #[test]
+    fn reports_print_writer_and_scanner_without_charset() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.io.File;
+import java.io.InputStream;
+import java.io.PrintWriter;
+import java.util.Scanner;
+
+class ClassA {
+    void methodX(File varOne, InputStream varTwo) throws Exception {
+        try (PrintWriter varThree = new PrintWriter(varOne);
+                Scanner varFour = new Scanner(varTwo)) {
+            varThree.println(varFour.nextLine());
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+
+        assert_eq!(
+            messages.len(),
+            2,
+            "expected two findings, got: {messages:?}"
+        );
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.starts_with("new PrintWriter(File) in "))
+        );
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.starts_with("new Scanner(InputStream) in "))
+        );
+    }
+
+    #[test]
+    fn does_not_report_charset_aware_overloads() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.io.File;
+import java.io.FileWriter;
+import java.io.InputStream;
+import java.io.InputStreamReader;
+import java.io.Reader;
+import java.io.Writer;
+import java.nio.charset.StandardCharsets;
+
+class ClassA {
+    Reader methodX(InputStream varOne) {
+        return new InputStreamReader(varOne, StandardCharsets.UTF_8);
+    }
+
+    Writer methodY(File varOne) throws Exception {
+        return new FileWriter(varOne, StandardCharsets.UTF_8, true);
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+
+        assert!(
+            messages.is_empty(),
+            "expected no findings, got: {messages:?}"
+        );
+    }
+}
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct `File.deleteOnExit()` calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for DeleteOnExitCallRule {
             id: "DELETE_ON_EXIT_CALL",
             name: "File.deleteOnExit call",
             description: "File.deleteOnExit can accumulate pending deletions in long-lived processes",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["resource"],
         }
     }
 
@@ -0,0 +1,150 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::CallSite;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects calls to the unsafe `Thread.stop`, `suspend`, `resume`, and `destroy`
+/// methods.
+#[derive(Default)]
+pub(crate) struct DeprecatedThreadControlRule;
+
+crate::register_rule!(DeprecatedThreadControlRule);
+
+impl Rule for DeprecatedThreadControlRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "DEPRECATED_THREAD_CONTROL",
+            name: "Deprecated thread control",
+            description: "Thread.stop, suspend, resume, and destroy are unsafe and deprecated",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for call in &method.calls {
+                            if !is_thread_control_call(context, call) {
+                                continue;
+                            }
+                            let message = result_message(format!(
+                                "Thread.{}() in {}.{}{} is unsafe and deprecated; signal the thread with interrupt() or a shared flag and let it stop itself.",
+                                call.name, class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(call.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+fn is_thread_control_call(context: &AnalysisContext, call: &CallSite) -> bool {
+    let is_control_method = matches!(
+        (call.name.as_str(), call.descriptor.as_str()),
+        ("stop" | "suspend" | "resume" | "destroy", "()V") | ("stop", "(Ljava/lang/Throwable;)V")
+    );
+    is_control_method && context.is_subtype_of(&call.owner, "java/lang/Thread")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("DEPRECATED_THREAD_CONTROL"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_stop_on_thread_and_suspend_on_subclass() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    static class ClassB extends Thread {
+    }
+
+    @SuppressWarnings("removal")
+    void methodX(Thread varOne, ClassB varTwo) {
+        varOne.stop();
+        varTwo.suspend();
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Thread.stop() in com/example/ClassA.methodX(Ljava/lang/Thread;Lcom/example/ClassA$ClassB;)V is unsafe and deprecated; signal the thread with interrupt() or a shared flag and let it stop itself."
+                    .to_string(),
+                "Thread.suspend() in com/example/ClassA.methodX(Ljava/lang/Thread;Lcom/example/ClassA$ClassB;)V is unsafe and deprecated; signal the thread with interrupt() or a shared flag and let it stop itself."
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_interrupt_or_unrelated_stop() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    static class ClassB {
+        void stop() {
+        }
+    }
+
+    void methodX(Thread varOne, ClassB varTwo) {
+        varOne.interrupt();
+        varTwo.stop();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct Java deserialization entry-point calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for DeserializationReadObjectCallRule {
             id: "DESERIALIZATION_READ_OBJECT_CALL",
             name: "ObjectInputStream deserialization call",
             description: "readObject/readUnshared are high-risk Java deserialization entry points",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["security"],
         }
     }
 
@@ -0,0 +1,331 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{Class, FieldRef, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Maximum instructions between the outer null-check and `monitorenter`.
+const OUTER_CHECK_WINDOW: usize = 6;
+/// Maximum instructions between `monitorenter` and the inner null-check.
+const INNER_CHECK_WINDOW: usize = 6;
+
+/// Rule that detects double-checked locking on fields that are not volatile.
+#[derive(Default)]
+pub(crate) struct DoubleCheckedLockingRule;
+
+crate::register_rule!(DoubleCheckedLockingRule);
+
+/// Field identity used while matching double-checked locking bytecode sequences.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct FieldKey {
+    owner: String,
+    name: String,
+    descriptor: String,
+    is_static: bool,
+}
+
+/// Inner null-check of a double-checked locking sequence.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct DclSite {
+    field_name: String,
+    offset: u32,
+}
+
+impl Rule for DoubleCheckedLockingRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "DOUBLE_CHECKED_LOCKING",
+            name: "Double-checked locking on non-volatile field",
+            description: "Double-checked locking requires the lazily initialized field to be volatile",
+            severity: Severity::Error,
+            rank: Some(80),
+            tags: &["concurrency"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let fields = non_volatile_fields(class);
+                    if fields.is_empty() {
+                        return Ok(class_results);
+                    }
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for site in find_dcl_sites(method, &fields) {
+                            let message = result_message(format!(
+                                "Double-checked locking on non-volatile field '{}' in {}.{}{}; declare the field volatile or use a holder class.",
+                                site.field_name, class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(site.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+fn non_volatile_fields(class: &Class) -> BTreeSet<FieldKey> {
+    class
+        .fields
+        .iter()
+        .filter(|field| !field.access.is_volatile && !field.access.is_final)
+        .map(|field| FieldKey {
+            owner: class.name.clone(),
+            name: field.name.clone(),
+            descriptor: field.descriptor.clone(),
+            is_static: field.access.is_static,
+        })
+        .collect()
+}
+
+/// Matches `read; null-check; monitorenter; read; null-check; ...; write; monitorexit`
+/// over instructions in offset order.
+fn find_dcl_sites(method: &Method, fields: &BTreeSet<FieldKey>) -> Vec<DclSite> {
+    let mut instructions: Vec<&Instruction> = method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .collect();
+    instructions.sort_by_key(|instruction| instruction.offset);
+
+    let mut sites = BTreeSet::new();
+    for (enter_index, instruction) in instructions.iter().enumerate() {
+        if instruction.opcode != opcodes::MONITORENTER {
+            continue;
+        }
+        let outer_start = enter_index.saturating_sub(OUTER_CHECK_WINDOW);
+        let outer_fields: BTreeSet<FieldKey> = (outer_start..enter_index)
+            .filter_map(|index| null_checked_read(&instructions, index, fields))
+            .map(|(field, _)| field)
+            .collect();
+        if outer_fields.is_empty() {
+            continue;
+        }
+
+        let inner_end = (enter_index + 1 + INNER_CHECK_WINDOW).min(instructions.len());
+        for index in enter_index + 1..inner_end {
+            let Some((field, check_offset)) = null_checked_read(&instructions, index, fields)
+            else {
+                continue;
+            };
+            if outer_fields.contains(&field)
+                && writes_field_before_monitor_exit(&instructions[index..], &field)
+            {
+                sites.insert(DclSite {
+                    field_name: field.name.clone(),
+                    offset: check_offset,
+                });
+            }
+        }
+    }
+    sites.into_iter().collect()
+}
+
+/// Returns the field read at `index` and the offset of the null-check that immediately follows it.
+fn null_checked_read(
+    instructions: &[&Instruction],
+    index: usize,
+    fields: &BTreeSet<FieldKey>,
+) -> Option<(FieldKey, u32)> {
+    let read = instructions.get(index)?;
+    if read.opcode != opcodes::GETFIELD && read.opcode != opcodes::GETSTATIC {
+        return None;
+    }
+    let check = instructions.get(index + 1)?;
+    if check.opcode != opcodes::IFNULL && check.opcode != opcodes::IFNONNULL {
+        return None;
+    }
+    let field = instruction_field(read)?;
+    fields.contains(&field).then_some((field, check.offset))
+}
+
+fn writes_field_before_monitor_exit(instructions: &[&Instruction], field: &FieldKey) -> bool {
+    for instruction in instructions {
+        if instruction.opcode == opcodes::MONITOREXIT {
+            return false;
+        }
+        if (instruction.opcode == opcodes::PUTFIELD || instruction.opcode == opcodes::PUTSTATIC)
+            && instruction_field(instruction).as_ref() == Some(field)
+        {
+            return true;
+        }
+    }
+    false
+}
+
+fn instruction_field(instruction: &Instruction) -> Option<FieldKey> {
+    let InstructionKind::FieldAccess(FieldRef {
+        owner,
+        name,
+        descriptor,
+    }) = &instruction.kind
+    else {
+        return None;
+    };
+    Some(FieldKey {
+        owner: owner.clone(),
+        name: name.clone(),
+        descriptor: descriptor.clone(),
+        is_static: matches!(instruction.opcode, opcodes::GETSTATIC | opcodes::PUTSTATIC),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze_sources(sources: Vec<SourceFile>) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("DOUBLE_CHECKED_LOCKING"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_double_checked_locking_on_non_volatile_field() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassA {
+    private Object varOne;
+
+    public Object methodX() {
+        if (varOne == null) {
+            synchronized (this) {
+                if (varOne == null) {
+                    varOne = new Object();
+                }
+            }
+        }
+        return varOne;
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("'varOne'"));
+    }
+
+    #[test]
+    fn reports_static_double_checked_locking() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassB.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassB {
+    private static Object varOne;
+
+    public static Object methodY() {
+        if (varOne == null) {
+            synchronized (ClassB.class) {
+                if (varOne == null) {
+                    varOne = new Object();
+                }
+            }
+        }
+        return varOne;
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn does_not_report_volatile_field() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassC.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassC {
+    private volatile Object varOne;
+
+    public Object methodZ() {
+        if (varOne == null) {
+            synchronized (this) {
+                if (varOne == null) {
+                    varOne = new Object();
+                }
+            }
+        }
+        return varOne;
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn does_not_report_single_check_under_lock() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassD.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassD {
+    private Object varOne;
+
+    public synchronized Object methodW() {
+        if (varOne == null) {
+            varOne = new Object();
+        }
+        return varOne;
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert!(messages.is_empty());
+    }
+}
@@ -0,0 +1,295 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `Class.forName` calls whose class name is not a string constant.
+#[derive(Default)]
+pub(crate) struct DynamicClassFornameRule;
+
+crate::register_rule!(DynamicClassFornameRule);
+
+impl Rule for DynamicClassFornameRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "DYNAMIC_CLASS_FORNAME",
+            name: "Class.forName with dynamic name",
+            description: "Class.forName with a class name computed at runtime can load attacker-chosen classes",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["security"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if !method.calls.iter().any(is_class_forname_call) {
+                            continue;
+                        }
+                        let analysis = analyze_method(method)?;
+                        rule_coverage.merge_from(&analysis.coverage);
+                        for offset in analysis.offsets {
+                            let message = result_message(format!(
+                                "Class.forName in {}.{}{} loads a class whose name is not a string constant; validate the name against an allowlist before loading it.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("DYNAMIC_CLASS_FORNAME", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Whether an operand stack value is a string constant loaded by `ldc`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    StringConstant,
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct ConstantValueDomain;
+
+impl ValueDomain<Value> for ConstantValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Rule-specific hook that tags string constants.
+struct ConstantSemanticsHook<'a> {
+    instruction: &'a Instruction,
+}
+
+impl SemanticsHooks<Value> for ConstantSemanticsHook<'_> {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        _method: &Method,
+        _offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        match opcode {
+            opcodes::LDC | opcodes::LDC_W
+                if matches!(self.instruction.kind, InstructionKind::ConstString(_)) =>
+            {
+                machine.push(Value::StringConstant);
+                ApplyOutcome::Applied
+            }
+            _ => ApplyOutcome::NotHandled,
+        }
+    }
+}
+
+/// Method-level analysis output with coverage summary for debug telemetry events.
+struct MethodAnalysis {
+    offsets: Vec<u32>,
+    coverage: SemanticsCoverage,
+}
+
+fn analyze_method(method: &Method) -> Result<MethodAnalysis> {
+    let mut instructions: Vec<&Instruction> = method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .collect();
+    instructions.sort_by_key(|instruction| instruction.offset);
+
+    let mut machine = StackMachine::new(Value::Other);
+    let mut coverage = SemanticsCoverage::default();
+    let mut offsets = Vec::new();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "DYNAMIC_CLASS_FORNAME",
+    };
+    for instruction in instructions {
+        let mut hooks = ConstantSemanticsHook { instruction };
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &ConstantValueDomain,
+            &mut hooks,
+            &mut coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => {
+                if is_class_forname_call(call)
+                    && class_name_operand(call, &machine)? != Value::StringConstant
+                {
+                    offsets.push(call.offset);
+                }
+                handle_invoke(call, &mut machine)?;
+            }
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(MethodAnalysis { offsets, coverage })
+}
+
+/// Returns the stack value passed as the first argument, the class name, of `Class.forName`.
+fn class_name_operand(call: &CallSite, machine: &StackMachine<Value>) -> Result<Value> {
+    let param_count = method_param_count(&call.descriptor)?;
+    let stack = machine.stack_values();
+    Ok(stack
+        .len()
+        .checked_sub(param_count)
+        .and_then(|index| stack.get(index))
+        .copied()
+        .unwrap_or(Value::Other))
+}
+
+fn handle_invoke(call: &CallSite, machine: &mut StackMachine<Value>) -> Result<()> {
+    machine.pop_n(method_param_count(&call.descriptor)?);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+    if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        machine.push(Value::Other);
+    }
+    Ok(())
+}
+
+fn is_class_forname_call(call: &CallSite) -> bool {
+    call.kind == CallKind::Static
+        && call.owner == "java/lang/Class"
+        && call.name == "forName"
+        && matches!(
+            call.descriptor.as_str(),
+            "(Ljava/lang/String;)Ljava/lang/Class;"
+                | "(Ljava/lang/String;ZLjava/lang/ClassLoader;)Ljava/lang/Class;"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("DYNAMIC_CLASS_FORNAME"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_forname_with_variable_name() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    Class<?> methodX(String varOne) throws ClassNotFoundException {
+        return Class.forName(varOne);
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Class.forName in com/example/ClassA.methodX(Ljava/lang/String;)Ljava/lang/Class; loads a class whose name is not a string constant; validate the name against an allowlist before loading it."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_forname_with_concatenated_name_and_class_loader() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    Class<?> methodX(String varOne, ClassLoader varTwo) throws ClassNotFoundException {
+        return Class.forName("com.example." + varOne, false, varTwo);
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_forname_with_constant_name() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    Class<?> methodX(ClassLoader varOne) throws ClassNotFoundException {
+        Class<?> varTwo = Class.forName("java.util.List");
+        return Class.forName("java.util.Map", true, varOne).isAssignableFrom(varTwo)
+                ? varTwo
+                : null;
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_param_slots, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{BasicBlock, CallKind, CallSite, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects elapsed time computed from two `System.currentTimeMillis()` readings.
+#[derive(Default)]
+pub(crate) struct ElapsedTimeWithWallclockRule;
+
+crate::register_rule!(ElapsedTimeWithWallclockRule);
+
+impl Rule for ElapsedTimeWithWallclockRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "ELAPSED_TIME_WITH_WALLCLOCK",
+            name: "Elapsed time with wall clock",
+            description: "Durations measured by subtracting System.currentTimeMillis() values",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty()
+                            || !method.calls.iter().any(is_current_time_millis)
+                        {
+                            continue;
+                        }
+                        let wall_clock_locals = wall_clock_locals(method)?;
+                        for block in &method.cfg.blocks {
+                            let analysis = analyze_block(method, block, &wall_clock_locals)?;
+                            rule_coverage.merge_from(&analysis.coverage);
+                            for offset in analysis.findings {
+                                let message = result_message(format!(
+                                    "{}.{}{} measures elapsed time by subtracting System.currentTimeMillis() values; the wall clock can jump when the system time is adjusted. Use System.nanoTime() for durations.",
+                                    class.name, method.name, method.descriptor
+                                ));
+                                let location = method_location_with_line(
+                                    &class.name,
+                                    &method.name,
+                                    &method.descriptor,
+                                    artifact_uri.as_deref(),
+                                    method.line_for_offset(offset),
+                                );
+                                class_results.push(
+                                    SarifResult::builder()
+                                        .message(message)
+                                        .locations(vec![location])
+                                        .build(),
+                                );
+                            }
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("ELAPSED_TIME_WITH_WALLCLOCK", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Whether an operand stack or local value is a `System.currentTimeMillis()` reading.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    WallClock,
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct WallClockValueDomain;
+
+impl ValueDomain<Value> for WallClockValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Hook that keeps `long` locals in single stack entries and records `lsub` of two wall-clock
+/// readings.
+#[derive(Default)]
+struct WallClockHook {
+    findings: Vec<u32>,
+}
+
+impl SemanticsHooks<Value> for WallClockHook {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        method: &Method,
+        offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        match opcode {
+            opcodes::LLOAD
+            | opcodes::LLOAD_0
+            | opcodes::LLOAD_1
+            | opcodes::LLOAD_2
+            | opcodes::LLOAD_3 => {
+                let index = long_local_index(method, offset, opcode);
+                machine.push(machine.load_local(index));
+                ApplyOutcome::Applied
+            }
+            opcode if is_lstore(opcode) => {
+                let value = machine.pop();
+                machine.store_local(long_local_index(method, offset, opcode), value);
+                ApplyOutcome::Applied
+            }
+            opcodes::LSUB => {
+                let right = machine.pop();
+                let left = machine.pop();
+                if left == Value::WallClock && right == Value::WallClock {
+                    self.findings.push(offset as u32);
+                }
+                machine.push(Value::Other);
+                ApplyOutcome::Applied
+            }
+            _ => ApplyOutcome::NotHandled,
+        }
+    }
+}
+
+/// Block-level analysis output with coverage summary for debug telemetry events.
+struct BlockAnalysis {
+    findings: Vec<u32>,
+    coverage: SemanticsCoverage,
+}
+
+/// Simulates `block` with `wall_clock_locals` holding wall-clock readings and returns the
+/// offsets of `lsub` instructions that subtract two readings.
+fn analyze_block(
+    method: &Method,
+    block: &BasicBlock,
+    wall_clock_locals: &BTreeSet<usize>,
+) -> Result<BlockAnalysis> {
+    let mut machine = StackMachine::new(Value::Other);
+    for index in wall_clock_locals {
+        machine.store_local(*index, Value::WallClock);
+    }
+    let mut hooks = WallClockHook::default();
+    let mut coverage = SemanticsCoverage::default();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "ELAPSED_TIME_WITH_WALLCLOCK",
+    };
+    for instruction in &block.instructions {
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &WallClockValueDomain,
+            &mut hooks,
+            &mut coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => handle_invoke(call, &mut machine)?,
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(BlockAnalysis {
+        findings: hooks.findings,
+        coverage,
+    })
+}
+
+/// Returns the non-parameter locals whose every store in `method` is an `lstore` of a
+/// `System.currentTimeMillis()` result, such as `long start = System.currentTimeMillis();`.
+///
+/// Such locals hold a reading wherever they are loaded, so blocks after the one that stored
+/// the reading still see it.
+fn wall_clock_locals(method: &Method) -> Result<BTreeSet<usize>> {
+    let first_local =
+        method_param_slots(&method.descriptor)? + usize::from(!method.access.is_static);
+    let mut stores: BTreeMap<usize, bool> = BTreeMap::new();
+    for block in &method.cfg.blocks {
+        for (index, instruction) in block.instructions.iter().enumerate() {
+            let Some(local) = store_local_index(method, instruction) else {
+                continue;
+            };
+            let reading = is_lstore(instruction.opcode)
+                && index
+                .checked_sub(1)
+                .and_then(|previous| block.instructions.get(previous))
+                .is_some_and(|previous| {
+                    matches!(&previous.kind, InstructionKind::Invoke(call) if is_current_time_millis(call))
+                });
+            *stores.entry(local).or_insert(true) &= reading;
+        }
+    }
+    Ok(stores
+        .into_iter()
+        .filter(|(local, reading)| *reading && *local >= first_local)
+        .map(|(local, _)| local)
+        .collect())
+}
+
+/// Returns the local written by a store instruction or `iinc`, of any type.
+fn store_local_index(method: &Method, instruction: &Instruction) -> Option<usize> {
+    let operand = || {
+        method
+            .bytecode
+            .get(instruction.offset as usize + 1)
+            .map(|index| usize::from(*index))
+    };
+    match instruction.opcode {
+        opcodes::ISTORE..=opcodes::ASTORE | opcodes::IINC => operand(),
+        opcodes::ISTORE_0..=opcodes::ASTORE_3 => {
+            Some(usize::from((instruction.opcode - opcodes::ISTORE_0) % 4))
+        }
+        _ => None,
+    }
+}
+
+fn is_lstore(opcode: u8) -> bool {
+    matches!(
+        opcode,
+        opcodes::LSTORE
+            | opcodes::LSTORE_0
+            | opcodes::LSTORE_1
+            | opcodes::LSTORE_2
+            | opcodes::LSTORE_3
+    )
+}
+
+fn long_local_index(method: &Method, offset: usize, opcode: u8) -> usize {
+    match opcode {
+        opcodes::LLOAD_0 | opcodes::LSTORE_0 => 0,
+        opcodes::LLOAD_1 | opcodes::LSTORE_1 => 1,
+        opcodes::LLOAD_2 | opcodes::LSTORE_2 => 2,
+        opcodes::LLOAD_3 | opcodes::LSTORE_3 => 3,
+        _ => method
+            .bytecode
+            .get(offset + 1)
+            .map(|index| usize::from(*index))
+            .unwrap_or(0),
+    }
+}
+
+fn is_current_time_millis(call: &CallSite) -> bool {
+    call.kind == CallKind::Static
+        && call.owner == "java/lang/System"
+        && call.name == "currentTimeMillis"
+        && call.descriptor == "()J"
+}
+
+fn handle_invoke(call: &CallSite, machine: &mut StackMachine<Value>) -> Result<()> {
+    machine.pop_n(method_param_count(&call.descriptor)?);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+    if is_current_time_millis(call) {
+        machine.push(Value::WallClock);
+    } else if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        machine.push(Value::Other);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("ELAPSED_TIME_WITH_WALLCLOCK"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_difference_of_current_time_millis_readings() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    long methodX(Runnable varOne, boolean varTwo) {
+        long varThree = System.currentTimeMillis();
+        if (varTwo) {
+            varOne.run();
+        }
+        long varFour = System.currentTimeMillis();
+        return varFour - varThree;
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.methodX(Ljava/lang/Runnable;Z)J measures elapsed time by subtracting System.currentTimeMillis() values; the wall clock can jump when the system time is adjusted. Use System.nanoTime() for durations."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_nano_time_or_deadlines() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    long methodX(Runnable varOne) {
+        long varTwo = System.nanoTime();
+        varOne.run();
+        return System.nanoTime() - varTwo;
+    }
+
+    long methodY(long varOne) {
+        long varTwo = System.currentTimeMillis() + varOne;
+        return varTwo - System.currentTimeMillis();
+    }
+
+    long methodZ(long varOne) {
+        return System.currentTimeMillis() - varOne;
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -5,7 +5,7 @@ use serde_sarif::sarif::Result as SarifResult;
 use crate::engine::AnalysisContext;
 use crate::ir::Instruction;
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects empty catch blocks.
 #[derive(Default)]
@@ -19,6 +19,9 @@ impl Rule for EmptyCatchRule {
             id: "EMPTY_CATCH",
             name: "Empty catch block",
             description: "Catch blocks with no meaningful instructions",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -136,9 +139,11 @@ mod tests {
     fn default_access() -> MethodAccess {
         MethodAccess {
             is_public: true,
+            is_private: false,
             is_static: false,
             is_synchronized: false,
             is_abstract: false,
+            is_final: false,
             is_synthetic: false,
             is_bridge: false,
         }
@@ -181,6 +186,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }
     }
 
@@ -201,6 +208,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }
     }
 
@@ -0,0 +1,362 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{
+    ReturnKind, method_param_class_names, method_param_count, method_param_start_slots,
+    method_return_class_name, method_return_kind,
+};
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `equals` calls on enum values, which should be compared with `==`.
+#[derive(Default)]
+pub(crate) struct EnumEqualsInsteadOfIdentityRule;
+
+crate::register_rule!(EnumEqualsInsteadOfIdentityRule);
+
+impl Rule for EnumEqualsInsteadOfIdentityRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "ENUM_EQUALS_INSTEAD_OF_IDENTITY",
+            name: "Enum compared with equals",
+            description: "Enum values compared with equals() instead of ==",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if !method.calls.iter().any(is_equals_call) {
+                            continue;
+                        }
+                        let analysis = analyze_method(method, context)?;
+                        rule_coverage.merge_from(&analysis.coverage);
+                        for (offset, enum_name) in analysis.findings {
+                            let message = result_message(format!(
+                                "{enum_name} value compared with equals() in {}.{}{}; compare enum constants with == instead.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("ENUM_EQUALS_INSTEAD_OF_IDENTITY", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Operand stack value, tagged with its enum type when its declared type is a known enum.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    Enum(String),
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct EnumValueDomain;
+
+impl ValueDomain<Value> for EnumValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Rule-specific hook that tags values read from enum-typed fields and casts.
+struct EnumSemanticsHook<'a> {
+    instruction: &'a Instruction,
+    context: &'a AnalysisContext,
+}
+
+impl SemanticsHooks<Value> for EnumSemanticsHook<'_> {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        _method: &Method,
+        _offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        match (opcode, &self.instruction.kind) {
+            (opcodes::GETFIELD | opcodes::GETSTATIC, InstructionKind::FieldAccess(field)) => {
+                if opcode == opcodes::GETFIELD {
+                    machine.pop();
+                }
+                let class_name = field
+                    .descriptor
+                    .strip_prefix('L')
+                    .and_then(|name| name.strip_suffix(';'));
+                machine.push(enum_value(class_name, self.context));
+                ApplyOutcome::Applied
+            }
+            (opcodes::CHECKCAST, InstructionKind::Checkcast(class_name)) => {
+                machine.pop();
+                machine.push(enum_value(Some(class_name), self.context));
+                ApplyOutcome::Applied
+            }
+            _ => ApplyOutcome::NotHandled,
+        }
+    }
+}
+
+/// Method-level analysis output with coverage summary for debug telemetry events.
+struct MethodAnalysis {
+    findings: Vec<(u32, String)>,
+    coverage: SemanticsCoverage,
+}
+
+fn analyze_method(method: &Method, context: &AnalysisContext) -> Result<MethodAnalysis> {
+    let mut instructions: Vec<&Instruction> = method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .collect();
+    instructions.sort_by_key(|instruction| instruction.offset);
+
+    let mut machine = StackMachine::new(Value::Other);
+    seed_parameter_locals(method, context, &mut machine)?;
+    let mut coverage = SemanticsCoverage::default();
+    let mut findings = Vec::new();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "ENUM_EQUALS_INSTEAD_OF_IDENTITY",
+    };
+    for instruction in instructions {
+        let mut hooks = EnumSemanticsHook {
+            instruction,
+            context,
+        };
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &EnumValueDomain,
+            &mut hooks,
+            &mut coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => {
+                if is_equals_call(call)
+                    && let Value::Enum(enum_name) = receiver_operand(&machine)
+                {
+                    findings.push((call.offset, enum_name));
+                }
+                handle_invoke(call, context, &mut machine)?;
+            }
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(MethodAnalysis { findings, coverage })
+}
+
+/// Tags enum-typed parameters in their local slots.
+fn seed_parameter_locals(
+    method: &Method,
+    context: &AnalysisContext,
+    machine: &mut StackMachine<Value>,
+) -> Result<()> {
+    let first_slot = usize::from(!method.access.is_static);
+    let class_names = method_param_class_names(&method.descriptor)?;
+    let start_slots = method_param_start_slots(&method.descriptor)?;
+    for (class_name, slot) in class_names.iter().zip(start_slots) {
+        if let Value::Enum(enum_name) = enum_value(class_name.as_deref(), context) {
+            machine.store_local(first_slot + usize::from(slot), Value::Enum(enum_name));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the receiver of the `equals(Object)` call about to run, below its argument.
+fn receiver_operand(machine: &StackMachine<Value>) -> Value {
+    let stack = machine.stack_values();
+    stack
+        .len()
+        .checked_sub(2)
+        .and_then(|index| stack.get(index))
+        .cloned()
+        .unwrap_or(Value::Other)
+}
+
+fn handle_invoke(
+    call: &CallSite,
+    context: &AnalysisContext,
+    machine: &mut StackMachine<Value>,
+) -> Result<()> {
+    machine.pop_n(method_param_count(&call.descriptor)?);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+    if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        let class_name = method_return_class_name(&call.descriptor)?;
+        machine.push(enum_value(class_name.as_deref(), context));
+    }
+    Ok(())
+}
+
+fn enum_value(class_name: Option<&str>, context: &AnalysisContext) -> Value {
+    match class_name {
+        Some(class_name) if is_enum_type(context, class_name) => Value::Enum(class_name.to_string()),
+        _ => Value::Other,
+    }
+}
+
+/// Returns true when `name` is a loaded enum class.
+fn is_enum_type(context: &AnalysisContext, name: &str) -> bool {
+    context
+        .class_by_name(name)
+        .is_some_and(|class| class.super_name.as_deref() == Some("java/lang/Enum"))
+}
+
+fn is_equals_call(call: &CallSite) -> bool {
+    call.kind == CallKind::Virtual
+        && call.name == "equals"
+        && call.descriptor == "(Ljava/lang/Object;)Z"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![
+            SourceFile {
+                path: "com/example/ClassB.java".to_string(),
+                contents: r#"
+package com.example;
+public enum ClassB {
+    ONE,
+    TWO
+}
+"#
+                .to_string(),
+            },
+            SourceFile {
+                path: "com/example/ClassA.java".to_string(),
+                contents: contents.to_string(),
+            },
+        ];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("ENUM_EQUALS_INSTEAD_OF_IDENTITY"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_equals_on_enum_field() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    private ClassB fieldOne = ClassB.ONE;
+
+    boolean methodX(Object varOne) {
+        return fieldOne.equals(varOne);
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassB value compared with equals() in com/example/ClassA.methodX(Ljava/lang/Object;)Z; compare enum constants with == instead."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_equals_on_enum_return_value_and_parameter() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    ClassB methodX() {
+        return ClassB.TWO;
+    }
+
+    boolean methodY(ClassB varOne) {
+        return methodX().equals(ClassB.ONE) || varOne.equals(ClassB.TWO);
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 2, "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_identity_comparison_or_non_enum_equals() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    private ClassB fieldOne = ClassB.ONE;
+    private String fieldTwo = "one";
+
+    boolean methodX(Object varOne) {
+        return fieldOne == ClassB.TWO || fieldTwo.equals(varOne) || varOne.equals(fieldOne);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{Class, InstructionKind, Method};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+const EQUALS_DESCRIPTOR: &str = "(Ljava/lang/Object;)Z";
+
+/// Rule that detects equals implementations whose type check can break symmetry.
+#[derive(Default)]
+pub(crate) struct EqualsGetclassVsInstanceofRule;
+
+crate::register_rule!(EqualsGetclassVsInstanceofRule);
+
+impl Rule for EqualsGetclassVsInstanceofRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "EQUALS_GETCLASS_VS_INSTANCEOF",
+            name: "equals type check breaks symmetry",
+            description: "equals using instanceof in an extensible class, or getClass() under an instanceof-based superclass equals, can be asymmetric",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let Some(method) = equals_method(class) else {
+                        return Ok(class_results);
+                    };
+                    let message = if uses_instanceof_self(class, method) {
+                        if class.is_final || class.is_record || method.access.is_final {
+                            return Ok(class_results);
+                        }
+                        format!(
+                            "{}.equals(Object) checks instanceof {} but neither the class nor equals is final; a subclass overriding equals would make it asymmetric. Make the class or equals final, or compare getClass() instead; this is a heuristic and the code is fine if no subclass overrides equals.",
+                            class.name, class.name
+                        )
+                    } else if uses_getclass(method) {
+                        let Some(superclass) = instanceof_superclass(class, context) else {
+                            return Ok(class_results);
+                        };
+                        format!(
+                            "{}.equals(Object) compares getClass() but superclass {}.equals(Object) checks instanceof; a {} can equal a {} that does not equal it back. Use the same type check in both classes; this is a heuristic based only on the type checks in each equals body.",
+                            class.name, superclass, superclass, class.name
+                        )
+                    } else {
+                        return Ok(class_results);
+                    };
+                    let artifact_uri = context.class_artifact_uri(class);
+                    let location = method_location_with_line(
+                        &class.name,
+                        &method.name,
+                        &method.descriptor,
+                        artifact_uri.as_deref(),
+                        method.line_for_offset(0),
+                    );
+                    class_results.push(
+                        SarifResult::builder()
+                            .message(result_message(message))
+                            .locations(vec![location])
+                            .build(),
+                    );
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns the concrete `equals(Object)` declared by `class`.
+fn equals_method(class: &Class) -> Option<&Method> {
+    class.methods.iter().find(|method| {
+        method.name == "equals"
+            && method.descriptor == EQUALS_DESCRIPTOR
+            && !method.access.is_static
+            && !method.access.is_abstract
+            && !method.access.is_synthetic
+            && !method.access.is_bridge
+    })
+}
+
+/// Returns whether `method` checks `instanceof` against its declaring class.
+fn uses_instanceof_self(class: &Class, method: &Method) -> bool {
+    method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .any(|instruction| {
+            matches!(&instruction.kind, InstructionKind::InstanceOf(target) if *target == class.name)
+        })
+}
+
+/// Returns whether `method` calls `getClass()`.
+fn uses_getclass(method: &Method) -> bool {
+    method
+        .calls
+        .iter()
+        .any(|call| call.name == "getClass" && call.descriptor == "()Ljava/lang/Class;")
+}
+
+/// Returns the nearest superclass that declares equals, when that equals checks `instanceof`
+/// against itself.
+fn instanceof_superclass<'a>(class: &Class, context: &'a AnalysisContext) -> Option<&'a str> {
+    let mut current = class.super_name.as_deref();
+    let mut seen = BTreeSet::new();
+    while let Some(name) = current {
+        if !seen.insert(name) {
+            return None;
+        }
+        let superclass = context.class_by_name(name)?;
+        if let Some(method) = equals_method(superclass) {
+            return uses_instanceof_self(superclass, method).then_some(superclass.name.as_str());
+        }
+        current = superclass.super_name.as_deref();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(sources: &[(&str, &str)]) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = sources
+            .iter()
+            .map(|(path, contents)| SourceFile {
+                path: path.to_string(),
+                contents: contents.to_string(),
+            })
+            .collect::<Vec<_>>();
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("EQUALS_GETCLASS_VS_INSTANCEOF"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_instanceof_in_non_final_class() {
+        let messages = analyze(&[(
+            "com/example/ClassA.java",
+            r#"
+package com.example;
+public class ClassA {
+    private int fieldA;
+    @Override
+    public boolean equals(Object varOne) {
+        if (!(varOne instanceof ClassA)) {
+            return false;
+        }
+        return fieldA == ((ClassA) varOne).fieldA;
+    }
+    @Override
+    public int hashCode() {
+        return fieldA;
+    }
+}
+"#,
+        )]);
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].starts_with(
+            "com/example/ClassA.equals(Object) checks instanceof com/example/ClassA but neither the class nor equals is final;"
+        ));
+    }
+
+    #[test]
+    fn does_not_report_instanceof_in_final_class_or_final_equals() {
+        let messages = analyze(&[
+            (
+                "com/example/ClassA.java",
+                r#"
+package com.example;
+public final class ClassA {
+    private int fieldA;
+    @Override
+    public boolean equals(Object varOne) {
+        return varOne instanceof ClassA varTwo && fieldA == varTwo.fieldA;
+    }
+    @Override
+    public int hashCode() {
+        return fieldA;
+    }
+}
+"#,
+            ),
+            (
+                "com/example/ClassB.java",
+                r#"
+package com.example;
+public class ClassB {
+    private int fieldA;
+    @Override
+    public final boolean equals(Object varOne) {
+        return varOne instanceof ClassB varTwo && fieldA == varTwo.fieldA;
+    }
+    @Override
+    public final int hashCode() {
+        return fieldA;
+    }
+}
+"#,
+            ),
+        ]);
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn reports_getclass_under_instanceof_superclass() {
+        let messages = analyze(&[
+            (
+                "com/example/ClassA.java",
+                r#"
+package com.example;
+public class ClassA {
+    protected int fieldA;
+    @Override
+    public final boolean equals(Object varOne) {
+        return varOne instanceof ClassA varTwo && fieldA == varTwo.fieldA;
+    }
+    @Override
+    public final int hashCode() {
+        return fieldA;
+    }
+}
+"#,
+            ),
+            (
+                "com/example/ClassB.java",
+                r#"
+package com.example;
+public class ClassB {
+    protected int fieldA;
+    @Override
+    public boolean equals(Object varOne) {
+        if (varOne == null || getClass() != varOne.getClass()) {
+            return false;
+        }
+        return fieldA == ((ClassB) varOne).fieldA;
+    }
+    @Override
+    public int hashCode() {
+        return fieldA;
+    }
+}
+"#,
+            ),
+            (
+                "com/example/ClassC.java",
+                r#"
+package com.example;
+public class ClassC extends ClassD {
+    @Override
+    public boolean equals(Object varOne) {
+        return varOne != null && getClass() == varOne.getClass();
+    }
+    @Override
+    public int hashCode() {
+        return 1;
+    }
+}
+"#,
+            ),
+            (
+                "com/example/ClassD.java",
+                r#"
+package com.example;
+public class ClassD {
+    @Override
+    public boolean equals(Object varOne) {
+        return varOne instanceof ClassD;
+    }
+    @Override
+    public int hashCode() {
+        return 1;
+    }
+}
+"#,
+            ),
+        ]);
+
+        assert_eq!(messages.len(), 2, "unexpected findings: {messages:?}");
+        assert!(messages.iter().any(|message| message.starts_with(
+            "com/example/ClassC.equals(Object) compares getClass() but superclass com/example/ClassD.equals(Object) checks instanceof;"
+        )));
+        assert!(messages.iter().any(|message| message.starts_with(
+            "com/example/ClassD.equals(Object) checks instanceof com/example/ClassD"
+        )));
+    }
+}
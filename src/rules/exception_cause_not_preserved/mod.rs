@@ -6,22 +6,24 @@ use anyhow::Result;
 use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
+use crate::dataflow::allocation::NewAllocationHook;
 use crate::dataflow::opcode_semantics::{
-    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
-    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+    SemanticsCoverage, SemanticsDebugConfig, ValueDomain, apply_semantics,
+    emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
 };
 use crate::dataflow::stack_machine::{StackMachine, StackMachineConfig};
 use crate::dataflow::worklist::{
-    BlockEndStep, InstructionStep, WorklistSemantics, WorklistState, analyze_method,
+    BlockEndStep, InstructionStep, WorklistConfig, WorklistSemantics, WorklistState,
+    analyze_method_with_config,
 };
 use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
-use crate::engine::AnalysisContext;
+use crate::engine::{AnalysisContext, ExceptionCauseOptions};
 use crate::ir::{CallKind, CallSite, Instruction, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
-
-const MAX_TRACKED_STACK_DEPTH: usize = 24;
-const MAX_TRACKED_ALLOCATIONS: usize = 4;
+use crate::rules::{
+    Rule, RuleMetadata, Severity, method_location_with_line, result_message,
+    result_with_related_locations,
+};
 
 /// Rule that detects catch handlers that drop the original exception cause.
 #[derive(Default)]
@@ -35,6 +37,9 @@ impl Rule for ExceptionCauseNotPreservedRule {
             id: "EXCEPTION_CAUSE_NOT_PRESERVED",
             name: "Exception cause not preserved",
             description: "Catch handlers that throw new exceptions without preserving the cause",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -42,6 +47,7 @@ impl Rule for ExceptionCauseNotPreservedRule {
         let mut results = Vec::new();
         let debug_enabled = opcode_semantics_debug_enabled();
         let mut rule_coverage = SemanticsCoverage::default();
+        let options = context.rule_options().exception_cause;
         for class in context.analysis_target_classes() {
             let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
             if let Some(uri) = context.class_artifact_uri(class) {
@@ -58,31 +64,39 @@ impl Rule for ExceptionCauseNotPreservedRule {
 
                         let mut seen_findings = BTreeSet::new();
                         for handler_pc in handler_offsets(method) {
-                            let analysis = analyze_handler(method, handler_pc)?;
+                            let analysis = analyze_handler(method, handler_pc, options)?;
                             rule_coverage.merge_from(&analysis.coverage);
-                            for throw_offset in analysis.findings {
-                                if !seen_findings.insert((handler_pc, throw_offset)) {
+                            for finding in analysis.findings {
+                                if !seen_findings.insert((handler_pc, finding.throw_offset)) {
                                     continue;
                                 }
 
                                 let message = result_message(
                                     "Catch handler throws a new exception without preserving the original cause; pass the caught exception as a cause or call initCause/addSuppressed before throwing.",
                                 );
-                                let line = method.line_for_offset(throw_offset);
                                 let artifact_uri = context.class_artifact_uri(class);
                                 let location = method_location_with_line(
                                     &class.name,
                                     &method.name,
                                     &method.descriptor,
                                     artifact_uri.as_deref(),
-                                    line,
+                                    method.line_for_offset(finding.throw_offset),
                                 );
-                                class_results.push(
-                                    SarifResult::builder()
-                                        .message(message)
-                                        .locations(vec![location])
-                                        .build(),
+                                let allocation_location = method_location_with_line(
+                                    &class.name,
+                                    &method.name,
+                                    &method.descriptor,
+                                    artifact_uri.as_deref(),
+                                    method.line_for_offset(finding.allocation_offset),
                                 );
+                                class_results.push(result_with_related_locations(
+                                    message,
+                                    location,
+                                    vec![(
+                                        allocation_location,
+                                        result_message("New exception allocated here"),
+                                    )],
+                                ));
                             }
                         }
                     }
@@ -144,6 +158,7 @@ impl WorklistState for ExecutionState {
 /// Dataflow callbacks for catch-handler symbolic execution.
 struct HandlerSemantics {
     handler_pc: u32,
+    options: ExceptionCauseOptions,
     debug_enabled: bool,
     opcode_debug_enabled: bool,
     stack_depth_dumped: Cell<bool>,
@@ -151,9 +166,10 @@ struct HandlerSemantics {
 }
 
 impl HandlerSemantics {
-    fn new(handler_pc: u32) -> Self {
+    fn new(handler_pc: u32, options: ExceptionCauseOptions) -> Self {
         Self {
             handler_pc,
+            options,
             debug_enabled: debug_stack_dump_enabled(),
             opcode_debug_enabled: opcode_semantics_debug_enabled(),
             stack_depth_dumped: Cell::new(false),
@@ -168,13 +184,13 @@ impl HandlerSemantics {
 
 impl WorklistSemantics for HandlerSemantics {
     type State = ExecutionState;
-    type Finding = u32;
+    type Finding = ThrowFinding;
 
     fn initial_states(&self, _method: &Method) -> Vec<Self::State> {
         vec![ExecutionState {
             block_start: self.handler_pc,
             instruction_index: 0,
-            machine: initial_machine(),
+            machine: initial_machine(self.options),
             preserved_allocations: BTreeSet::new(),
         }]
     }
@@ -207,7 +223,12 @@ impl WorklistSemantics for HandlerSemantics {
             if let Value::New(allocation_offset) = thrown
                 && !state.preserved_allocations.contains(&allocation_offset)
             {
-                return Ok(InstructionStep::terminate_path().with_finding(instruction.offset));
+                return Ok(
+                    InstructionStep::terminate_path().with_finding(ThrowFinding {
+                        throw_offset: instruction.offset,
+                        allocation_offset,
+                    }),
+                );
             }
             return Ok(InstructionStep::terminate_path());
         }
@@ -224,7 +245,7 @@ impl WorklistSemantics for HandlerSemantics {
         prune_preserved_allocations(state);
         if self.debug_enabled
             && !self.stack_depth_dumped.get()
-            && state.machine.stack_len() >= MAX_TRACKED_STACK_DEPTH
+            && state.machine.stack_len() >= self.options.max_stack_depth
         {
             dump_stack_depth(method, self.handler_pc, instruction, state);
             self.stack_depth_dumped.set(true);
@@ -260,10 +281,20 @@ fn handler_offsets(method: &Method) -> Vec<u32> {
     offsets.into_iter().collect()
 }
 
-fn analyze_handler(method: &Method, handler_pc: u32) -> Result<HandlerAnalysis> {
-    let semantics = HandlerSemantics::new(handler_pc);
-    let findings = analyze_method(method, &semantics)?;
+fn analyze_handler(
+    method: &Method,
+    handler_pc: u32,
+    options: ExceptionCauseOptions,
+) -> Result<HandlerAnalysis> {
+    let semantics = HandlerSemantics::new(handler_pc, options);
+    let outcome = analyze_method_with_config(method, &semantics, WorklistConfig::from_env())?;
     let coverage = semantics.coverage_snapshot();
+    // Partial exploration cannot prove the cause is dropped on every path.
+    let findings = if outcome.aborted {
+        Vec::new()
+    } else {
+        outcome.findings
+    };
     Ok(HandlerAnalysis {
         findings: findings
             .into_iter()
@@ -276,17 +307,24 @@ fn analyze_handler(method: &Method, handler_pc: u32) -> Result<HandlerAnalysis>
 
 /// Handler-level analysis output with coverage summary for debug telemetry events.
 struct HandlerAnalysis {
-    findings: Vec<u32>,
+    findings: Vec<ThrowFinding>,
     coverage: SemanticsCoverage,
 }
 
-fn initial_machine() -> StackMachine<Value> {
+/// `athrow` that drops the cause, with the `new` site of the thrown exception.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct ThrowFinding {
+    throw_offset: u32,
+    allocation_offset: u32,
+}
+
+fn initial_machine(options: ExceptionCauseOptions) -> StackMachine<Value> {
     let mut machine = StackMachine::with_config(
         Value::Other,
         StackMachineConfig {
-            max_stack_depth: Some(MAX_TRACKED_STACK_DEPTH),
+            max_stack_depth: Some(options.max_stack_depth),
             max_locals: None,
-            max_symbolic_identities: Some(MAX_TRACKED_ALLOCATIONS),
+            max_symbolic_identities: Some(options.max_allocations),
         },
     );
     machine.push(Value::Caught);
@@ -301,9 +339,7 @@ fn apply_stack_effect(
     opcode_debug_enabled: bool,
 ) -> Result<()> {
     let domain = ExceptionValueDomain;
-    let mut hooks = ExceptionSemanticsHook {
-        allocation_offset: instruction.offset,
-    };
+    let mut hooks = NewAllocationHook::new(instruction.offset, Value::New);
     let _ = apply_semantics(
         &mut state.machine,
         method,
@@ -329,27 +365,6 @@ fn apply_stack_effect(
     Ok(())
 }
 
-/// Rule-specific hook that preserves `new` allocation identities.
-struct ExceptionSemanticsHook {
-    allocation_offset: u32,
-}
-
-impl SemanticsHooks<Value> for ExceptionSemanticsHook {
-    fn pre_apply(
-        &mut self,
-        machine: &mut StackMachine<Value>,
-        _method: &Method,
-        _offset: usize,
-        opcode: u8,
-    ) -> ApplyOutcome {
-        if opcode == opcodes::NEW {
-            machine.push(Value::New(self.allocation_offset));
-            return ApplyOutcome::Applied;
-        }
-        ApplyOutcome::NotHandled
-    }
-}
-
 fn handle_invoke(call: &CallSite, state: &mut ExecutionState) -> Result<()> {
     let param_count = method_param_count(&call.descriptor)?;
     let mut args = Vec::with_capacity(param_count);
@@ -499,6 +514,7 @@ fn is_return_opcode(opcode: u8) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use crate::engine::{ExceptionCauseOptions, RuleOptions};
     use crate::test_harness::{JvmTestHarness, Language, SourceFile};
 
     fn analyze_sources(sources: Vec<SourceFile>) -> Vec<String> {
@@ -542,6 +558,58 @@ public class ClassA {
         assert!(messages.iter().any(|msg| msg.contains("original cause")));
     }
 
+    #[test]
+    fn exception_cause_reports_allocation_site_as_related_location() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassR.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassR {
+    public void methodOne() {
+        try {
+            MethodX();
+        } catch (Exception varOne) {
+            RuntimeException varTwo = new RuntimeException("failed");
+            throw varTwo;
+        }
+    }
+
+    private void MethodX() {
+        throw new IllegalStateException("boom");
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        let result = output
+            .results
+            .iter()
+            .find(|result| result.rule_id.as_deref() == Some("EXCEPTION_CAUSE_NOT_PRESERVED"))
+            .expect("finding");
+        let start_line = |location: &serde_sarif::sarif::Location| {
+            location
+                .physical_location
+                .as_ref()
+                .and_then(|physical| physical.region.as_ref())
+                .and_then(|region| region.start_line)
+        };
+        let primary = result.locations.as_ref().expect("locations");
+        let related = result
+            .related_locations
+            .as_ref()
+            .expect("related locations");
+
+        assert_eq!(related.len(), 1);
+        assert_eq!(start_line(&related[0]), Some(9));
+        assert_eq!(start_line(&primary[0]), Some(10));
+    }
+
     #[test]
     fn exception_cause_allows_constructor_cause() {
         let sources = vec![SourceFile {
@@ -750,4 +818,82 @@ public class ClassH {
         let messages = analyze_sources(sources);
         assert!(messages.iter().any(|msg| msg.contains("original cause")));
     }
+
+    #[test]
+    fn raised_stack_depth_tracks_exceptions_built_from_many_arguments() {
+        let params = (1..=30)
+            .map(|index| format!("String varParam{index}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let args = (1..=30)
+            .map(|index| format!("\"value{index}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sources = vec![
+            SourceFile {
+                path: "com/example/WideException.java".to_string(),
+                contents: format!(
+                    r#"
+package com.example;
+public class WideException extends RuntimeException {{
+    public WideException({params}) {{
+        super(varParam1);
+    }}
+}}
+"#
+                ),
+            },
+            SourceFile {
+                path: "com/example/ClassA.java".to_string(),
+                contents: format!(
+                    r#"
+package com.example;
+public class ClassA {{
+    public void methodX(Runnable varOne) {{
+        try {{
+            varOne.run();
+        }} catch (RuntimeException varTwo) {{
+            throw new WideException({args});
+        }}
+    }}
+}}
+"#
+                ),
+            },
+        ];
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let compiled = harness
+            .compile(Language::Java, &sources, &[])
+            .expect("compile classes");
+        let messages_with = |options: ExceptionCauseOptions| -> Vec<String> {
+            harness
+                .analyze_with_rule_options(
+                    compiled.classes_dir(),
+                    &[],
+                    RuleOptions {
+                        exception_cause: options,
+                        ..RuleOptions::default()
+                    },
+                )
+                .expect("run analysis")
+                .results
+                .iter()
+                .filter(|result| result.rule_id.as_deref() == Some("EXCEPTION_CAUSE_NOT_PRESERVED"))
+                .filter_map(|result| result.message.text.clone())
+                .collect()
+        };
+
+        // 30 constructor arguments push the new exception below the default depth of 24.
+        let default_messages = messages_with(ExceptionCauseOptions::default());
+        let raised_messages = messages_with(ExceptionCauseOptions {
+            max_stack_depth: 64,
+            ..ExceptionCauseOptions::default()
+        });
+
+        assert!(
+            default_messages.is_empty(),
+            "default depth should lose track of the allocation: {default_messages:?}"
+        );
+        assert_eq!(raised_messages.len(), 1, "{raised_messages:?}");
+    }
 }
@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::collections::{BTreeSet, VecDeque};
 
 use anyhow::Result;
 use opentelemetry::KeyValue;
@@ -14,9 +14,9 @@ use crate::dataflow::worklist::{
 };
 use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
 use crate::engine::AnalysisContext;
-use crate::ir::{CallKind, CallSite, Class, EdgeKind, Instruction, InstructionKind, Method};
+use crate::ir::{CallKind, CallSite, EdgeKind, Instruction, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 const MAX_TRACKED_STACK_DEPTH: usize = 32;
 
@@ -32,15 +32,14 @@ impl Rule for ExecutorServiceNotShutdownRule {
             id: "EXECUTOR_SERVICE_NOT_SHUTDOWN",
             name: "ExecutorService not shut down",
             description: "Locally created executor services should be shut down on every exit path",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency"],
         }
     }
 
     fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
         let mut results = Vec::new();
-        let class_map = context
-            .all_classes()
-            .map(|class| (class.name.clone(), class))
-            .collect::<BTreeMap<_, _>>();
 
         for class in context.analysis_target_classes() {
             let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
@@ -56,7 +55,7 @@ impl Rule for ExecutorServiceNotShutdownRule {
                             continue;
                         }
 
-                        for creation_offset in analyze_executor_lifecycle(method, &class_map)? {
+                        for creation_offset in analyze_executor_lifecycle(method, context)? {
                             let message = result_message(format!(
                                 "ExecutorService created in {}.{}{} may exit without shutdown(); call shutdown(), shutdownNow(), or close() before the method returns.",
                                 class.name, method.name, method.descriptor
@@ -133,7 +132,7 @@ impl WorklistState for ExecutionState {
 /// Dataflow callbacks for local executor lifecycle analysis.
 struct ExecutorLifecycleSemantics<'a> {
     entry_block: u32,
-    class_map: &'a BTreeMap<String, &'a Class>,
+    context: &'a AnalysisContext,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -202,7 +201,7 @@ impl WorklistSemantics for ExecutorLifecycleSemantics<'_> {
                 }
             }
             _ => match &instruction.kind {
-                InstructionKind::Invoke(call) => handle_invoke(call, state, self.class_map)?,
+                InstructionKind::Invoke(call) => handle_invoke(call, state, self.context)?,
                 InstructionKind::InvokeDynamic { descriptor, .. } => {
                     handle_invoke_dynamic(descriptor, state)?
                 }
@@ -244,7 +243,7 @@ impl WorklistSemantics for ExecutorLifecycleSemantics<'_> {
 
 fn analyze_executor_lifecycle(
     method: &Method,
-    class_map: &BTreeMap<String, &Class>,
+    context: &AnalysisContext,
 ) -> Result<Vec<u32>> {
     let entry_block = method
         .cfg
@@ -255,7 +254,7 @@ fn analyze_executor_lifecycle(
         .unwrap_or(0);
     let semantics = ExecutorLifecycleSemantics {
         entry_block,
-        class_map,
+        context,
     };
     let findings = analyze_method(method, &semantics)?;
     Ok(findings
@@ -307,7 +306,7 @@ impl SemanticsHooks<Value> for ExecutorSemanticsHook {
 fn handle_invoke(
     call: &CallSite,
     state: &mut ExecutionState,
-    class_map: &BTreeMap<String, &Class>,
+    context: &AnalysisContext,
 ) -> Result<()> {
     let param_count = method_param_count(&call.descriptor)?;
     let mut args = Vec::with_capacity(param_count);
@@ -323,7 +322,7 @@ fn handle_invoke(
 
     if call.name == "<init>" {
         if let Some(Value::Symbol(symbol)) = receiver {
-            if is_executor_constructor(call, class_map) {
+            if is_executor_constructor(call, context) {
                 state.active_executors.insert(symbol);
             } else {
                 state.machine.rewrite_values(|value| {
@@ -482,11 +481,11 @@ fn is_executor_factory_call(call: &CallSite) -> bool {
     )
 }
 
-fn is_executor_constructor(call: &CallSite, class_map: &BTreeMap<String, &Class>) -> bool {
-    call.name == "<init>" && is_executor_service_type(&call.owner, class_map)
+fn is_executor_constructor(call: &CallSite, context: &AnalysisContext) -> bool {
+    call.name == "<init>" && is_executor_service_type(&call.owner, context)
 }
 
-fn is_executor_service_type(name: &str, class_map: &BTreeMap<String, &Class>) -> bool {
+fn is_executor_service_type(name: &str, context: &AnalysisContext) -> bool {
     if is_known_executor_service_name(name) {
         return true;
     }
@@ -500,7 +499,7 @@ fn is_executor_service_type(name: &str, class_map: &BTreeMap<String, &Class>) ->
         if is_known_executor_service_name(&next) {
             return true;
         }
-        let Some(class) = class_map.get(&next) else {
+        let Some(class) = context.class_by_name(&next) else {
             continue;
         };
         if let Some(super_name) = &class.super_name {
@@ -4,7 +4,7 @@ use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
 use crate::ir::CallKind;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects explicit virtual calls to `finalize()` on object instances.
 #[derive(Default)]
@@ -18,6 +18,9 @@ impl Rule for ExplicitFinalizeCallRule {
             id: "EXPLICIT_FINALIZE_CALL",
             name: "Explicit finalize call",
             description: "Direct virtual calls to finalize() bypass GC lifecycle and indicate broken resource cleanup",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
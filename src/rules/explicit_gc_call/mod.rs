@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects explicit garbage collection API calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for ExplicitGcCallRule {
             id: "EXPLICIT_GC_CALL",
             name: "Explicit GC call",
             description: "Direct calls to explicit GC APIs should be avoided",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["performance"],
         }
     }
 
@@ -0,0 +1,202 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, Class, Method};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `finalize()` overrides that never call `super.finalize()`.
+#[derive(Default)]
+pub(crate) struct FinalizeOverrideWithoutSuperRule;
+
+crate::register_rule!(FinalizeOverrideWithoutSuperRule);
+
+impl Rule for FinalizeOverrideWithoutSuperRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "FINALIZE_OVERRIDE_WITHOUT_SUPER",
+            name: "finalize() override without super.finalize()",
+            description: "finalize() overrides that never call super.finalize() skip the superclass cleanup",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    if !extends_other_than_object(class) {
+                        return Ok(class_results);
+                    }
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if !is_finalize_override(method) || calls_super_finalize(class, method) {
+                            continue;
+                        }
+                        let message = result_message(format!(
+                            "{}.finalize() does not call super.finalize(); call it in a finally block so the cleanup of {} still runs.",
+                            class.name,
+                            class.super_name.as_deref().unwrap_or_default()
+                        ));
+                        let location = method_location_with_line(
+                            &class.name,
+                            &method.name,
+                            &method.descriptor,
+                            artifact_uri.as_deref(),
+                            method.line_for_offset(0),
+                        );
+                        class_results.push(
+                            SarifResult::builder()
+                                .message(message)
+                                .locations(vec![location])
+                                .build(),
+                        );
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// `Object.finalize()` does nothing, so only classes with another superclass can lose cleanup.
+fn extends_other_than_object(class: &Class) -> bool {
+    class
+        .super_name
+        .as_deref()
+        .is_some_and(|super_name| super_name != "java/lang/Object")
+}
+
+fn is_finalize_override(method: &Method) -> bool {
+    method.name == "finalize"
+        && method.descriptor == "()V"
+        && !method.access.is_static
+        && !method.access.is_abstract
+        && !method.access.is_synthetic
+        && !method.bytecode.is_empty()
+}
+
+/// Whether `method` invokes a superclass `finalize()` at least once. `super.finalize()`
+/// compiles to `invokespecial` on the direct superclass, so calls owned by the class itself
+/// are not counted.
+fn calls_super_finalize(class: &Class, method: &Method) -> bool {
+    method.calls.iter().any(|call| {
+        call.kind == CallKind::Special
+            && call.name == "finalize"
+            && call.descriptor == "()V"
+            && call.owner != class.name
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::EngineOutput;
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn finalize_messages(output: &EngineOutput) -> Vec<String> {
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("FINALIZE_OVERRIDE_WITHOUT_SUPER"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    fn analyze(sources: &[SourceFile]) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, sources, &[])
+            .expect("run harness analysis");
+        finalize_messages(&output)
+    }
+
+    fn parent_source() -> SourceFile {
+        SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+@SuppressWarnings("deprecation")
+public class ClassA {
+    @Override
+    protected void finalize() throws Throwable {
+        System.out.println("release");
+    }
+}
+"#
+            .to_string(),
+        }
+    }
+
+    #[test]
+    fn reports_finalize_override_without_super_call() {
+        let sources = vec![
+            parent_source(),
+            SourceFile {
+                path: "com/example/ClassB.java".to_string(),
+                contents: r#"
+package com.example;
+@SuppressWarnings("deprecation")
+public class ClassB extends ClassA {
+    @Override
+    protected void finalize() {
+        System.out.println("child");
+    }
+}
+"#
+                .to_string(),
+            },
+        ];
+
+        let messages = analyze(&sources);
+
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].contains("com/example/ClassB.finalize()"));
+        assert!(messages[0].contains("com/example/ClassA"));
+    }
+
+    #[test]
+    fn does_not_report_finalize_override_calling_super() {
+        let sources = vec![
+            parent_source(),
+            SourceFile {
+                path: "com/example/ClassB.java".to_string(),
+                contents: r#"
+package com.example;
+@SuppressWarnings("deprecation")
+public class ClassB extends ClassA {
+    @Override
+    protected void finalize() throws Throwable {
+        try {
+            System.out.println("child");
+        } finally {
+            super.finalize();
+        }
+    }
+}
+"#
+                .to_string(),
+            },
+        ];
+
+        let messages = analyze(&sources);
+
+        assert!(messages.is_empty(), "{messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_direct_subclass_of_object() {
+        let messages = analyze(&[parent_source()]);
+
+        assert!(messages.is_empty(), "{messages:?}");
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::local_guard::{
+    BytecodeInstruction, aload_local_index, astore_local_index, collect_instructions,
+};
+use crate::engine::AnalysisContext;
+use crate::ir::{EdgeKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects throw, break, and continue statements that leave a finally block early.
+#[derive(Default)]
+pub(crate) struct FinallySwallowsControlFlowRule;
+
+crate::register_rule!(FinallySwallowsControlFlowRule);
+
+impl Rule for FinallySwallowsControlFlowRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "FINALLY_SWALLOWS_CONTROL_FLOW",
+            name: "Finally swallows control flow",
+            description: "Throw, break, or continue in finally blocks discards exceptions in flight",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty()
+                            || !method
+                                .exception_handlers
+                                .iter()
+                                .any(|handler| handler.catch_type.is_none())
+                        {
+                            continue;
+                        }
+                        let mut seen_lines = BTreeSet::new();
+                        for (offset, exit) in finally_exits(method)? {
+                            let line = method.line_for_offset(offset);
+                            // One source statement can compile to several exits, such as a
+                            // conditional branch and a goto.
+                            if let Some(line) = line
+                                && !seen_lines.insert(line)
+                            {
+                                continue;
+                            }
+                            let message = result_message(format!(
+                                "{} in a finally block of {}.{}{} discards any exception thrown by the try block. {}",
+                                exit.statement(),
+                                class.name,
+                                method.name,
+                                method.descriptor,
+                                exit.advice()
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                line,
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Abrupt exit from a finally block that replaces the exception in flight.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FinallyExit {
+    Throw,
+    Jump,
+}
+
+impl FinallyExit {
+    fn statement(self) -> &'static str {
+        match self {
+            FinallyExit::Throw => "throw",
+            FinallyExit::Jump => "break or continue",
+        }
+    }
+
+    fn advice(self) -> &'static str {
+        match self {
+            FinallyExit::Throw => {
+                "Handle the failure inside the finally block, or add the original exception with addSuppressed."
+            }
+            FinallyExit::Jump => "Move the break or continue out of the finally block.",
+        }
+    }
+}
+
+/// Returns the offsets of throw and jump instructions that leave the exception-path copy of a
+/// finally block without rethrowing the exception it caught, sorted by offset.
+///
+/// Returns are left to RETURN_IN_FINALLY.
+fn finally_exits(method: &Method) -> Result<BTreeMap<u32, FinallyExit>> {
+    let instructions = collect_instructions(method)?;
+    let index_by_offset: BTreeMap<u32, usize> = instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| (instruction.offset, index))
+        .collect();
+    // javac stores the caught exception of a catch-any handler in a local as its first
+    // instruction and rethrows it with `aload; athrow` when the finally body completes.
+    let finally_locals: BTreeMap<u32, usize> = method
+        .exception_handlers
+        .iter()
+        .filter(|handler| handler.catch_type.is_none())
+        .filter_map(|handler| {
+            let index = *index_by_offset.get(&handler.handler_pc)?;
+            let local = astore_local_index(&method.bytecode, &instructions[index])?;
+            Some((handler.handler_pc, local))
+        })
+        .collect();
+
+    let mut exits = BTreeMap::new();
+    for (&handler_pc, &local) in &finally_locals {
+        let start = index_by_offset[&handler_pc];
+        let end = region_end(method, &instructions, start, local);
+        let region = handler_pc..=end;
+        let rethrown_locals: BTreeSet<usize> = finally_locals
+            .range(region.clone())
+            .map(|(_, local)| *local)
+            .collect();
+        for (index, instruction) in instructions.iter().enumerate().skip(start) {
+            if instruction.offset > end {
+                break;
+            }
+            if instruction.opcode != opcodes::ATHROW {
+                continue;
+            }
+            let rethrow = index
+                .checked_sub(1)
+                .and_then(|previous| aload_local_index(&method.bytecode, &instructions[previous]))
+                .is_some_and(|loaded| rethrown_locals.contains(&loaded));
+            if !rethrow {
+                exits.insert(instruction.offset, FinallyExit::Throw);
+            }
+        }
+        for block in &method.cfg.blocks {
+            if !region.contains(&block.start_offset) {
+                continue;
+            }
+            let Some(last) = block.instructions.last() else {
+                continue;
+            };
+            let leaves_region = method.cfg.edges.iter().any(|edge| {
+                edge.from == block.start_offset
+                    && edge.kind == EdgeKind::Branch
+                    && !region.contains(&edge.to)
+            });
+            if leaves_region {
+                exits.insert(last.offset, FinallyExit::Jump);
+            }
+        }
+    }
+    Ok(exits)
+}
+
+/// Returns the last offset of the finally block copy starting at `instructions[start]`.
+///
+/// The copy ends at the rethrow of `local`. A finally block that never completes normally has
+/// no rethrow; it then ends before the first code after the handler that is reached by a branch
+/// from before the handler, such as the code following the try statement.
+fn region_end(
+    method: &Method,
+    instructions: &[BytecodeInstruction],
+    start: usize,
+    local: usize,
+) -> u32 {
+    let handler_pc = instructions[start].offset;
+    let rethrow = instructions
+        .windows(2)
+        .skip(start)
+        .find(|pair| {
+            pair[1].opcode == opcodes::ATHROW
+                && aload_local_index(&method.bytecode, &pair[0]) == Some(local)
+        })
+        .map(|pair| pair[1].offset);
+    if let Some(offset) = rethrow {
+        return offset;
+    }
+    method
+        .cfg
+        .edges
+        .iter()
+        .filter(|edge| {
+            edge.kind == EdgeKind::Branch && edge.from < handler_pc && edge.to > handler_pc
+        })
+        .map(|edge| edge.to - 1)
+        .min()
+        .unwrap_or(method.bytecode.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("FINALLY_SWALLOWS_CONTROL_FLOW"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_throw_and_break_in_finally() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    void methodX(Runnable varOne) {
+        try {
+            varOne.run();
+        } finally {
+            throw new IllegalStateException("cleanup failed");
+        }
+    }
+
+    void methodY(Runnable[] varOne) {
+        for (Runnable varTwo : varOne) {
+            try {
+                varTwo.run();
+            } finally {
+                if (varTwo == null) {
+                    break;
+                }
+            }
+        }
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "throw in a finally block of com/example/ClassA.methodX(Ljava/lang/Runnable;)V discards any exception thrown by the try block. Handle the failure inside the finally block, or add the original exception with addSuppressed."
+                    .to_string(),
+                "break or continue in a finally block of com/example/ClassA.methodY([Ljava/lang/Runnable;)V discards any exception thrown by the try block. Move the break or continue out of the finally block."
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_clean_finally_blocks() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    private final Object fieldOne = new Object();
+
+    void methodX(Runnable varOne, Runnable varTwo) {
+        try {
+            varOne.run();
+        } finally {
+            if (varTwo != null) {
+                varTwo.run();
+            }
+        }
+    }
+
+    void methodY(Runnable varOne, Runnable varTwo) {
+        try {
+            varOne.run();
+        } finally {
+            try {
+                varTwo.run();
+            } finally {
+                varOne.run();
+            }
+        }
+    }
+
+    void methodZ(Runnable varOne) {
+        synchronized (fieldOne) {
+            varOne.run();
+        }
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
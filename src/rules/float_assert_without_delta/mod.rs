@@ -0,0 +1,175 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Descriptors of the deprecated `Assert.assertEquals` overloads that compare without a delta.
+const NO_DELTA_DESCRIPTORS: &[&str] = &[
+    "(DD)V",
+    "(FF)V",
+    "(Ljava/lang/String;DD)V",
+    "(Ljava/lang/String;FF)V",
+];
+
+/// Rule that flags JUnit 4 floating-point `assertEquals` calls without a delta.
+#[derive(Default)]
+pub(crate) struct FloatAssertWithoutDeltaRule;
+
+crate::register_rule!(FloatAssertWithoutDeltaRule);
+
+impl Rule for FloatAssertWithoutDeltaRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "FLOAT_ASSERT_WITHOUT_DELTA",
+            name: "Floating-point assertEquals without delta",
+            description: "assertEquals on double or float values without a delta relies on exact floating-point equality",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for call in &method.calls {
+                            if !is_no_delta_assert(call) {
+                                continue;
+                            }
+                            let message = result_message(format!(
+                                "assertEquals{} in {}.{}{} compares floating-point values without a delta; use the overload that takes a delta.",
+                                call.descriptor, class.name, method.name, method.descriptor
+                            ));
+                            let line = method.line_for_offset(call.offset);
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                line,
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+fn is_no_delta_assert(call: &CallSite) -> bool {
+    call.kind == CallKind::Static
+        && call.owner == "org/junit/Assert"
+        && call.name == "assertEquals"
+        && NO_DELTA_DESCRIPTORS.contains(&call.descriptor.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![
+            SourceFile {
+                path: "org/junit/Assert.java".to_string(),
+                contents: r#"
+package org.junit;
+public class Assert {
+    @Deprecated
+    public static void assertEquals(double expected, double actual) {}
+    public static void assertEquals(double expected, double actual, double delta) {}
+    public static void assertEquals(String message, float expected, float actual) {}
+    public static void assertEquals(long expected, long actual) {}
+}
+"#
+                .to_string(),
+            },
+            SourceFile {
+                path: "com/example/ClassA.java".to_string(),
+                contents: contents.to_string(),
+            },
+        ];
+        let output = harness
+            .compile_and_analyze_rules(
+                Language::Java,
+                &sources,
+                &[],
+                &["FLOAT_ASSERT_WITHOUT_DELTA"],
+            )
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("FLOAT_ASSERT_WITHOUT_DELTA"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_assert_equals_without_delta() {
+        let messages = analyze(
+            r#"
+package com.example;
+import static org.junit.Assert.assertEquals;
+public class ClassA {
+    void methodX(double varOne, float varTwo) {
+        assertEquals(0.3, varOne);
+        assertEquals("sum", 0.3f, varTwo);
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "assertEquals(DD)V in com/example/ClassA.methodX(DF)V compares floating-point values without a delta; use the overload that takes a delta."
+                    .to_string(),
+                "assertEquals(Ljava/lang/String;FF)V in com/example/ClassA.methodX(DF)V compares floating-point values without a delta; use the overload that takes a delta."
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_delta_or_integral_overloads() {
+        let messages = analyze(
+            r#"
+package com.example;
+import static org.junit.Assert.assertEquals;
+public class ClassA {
+    void methodX(double varOne, long varTwo) {
+        assertEquals(0.3, varOne, 1e-9);
+        assertEquals(3L, varTwo);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "{messages:?}");
+    }
+}
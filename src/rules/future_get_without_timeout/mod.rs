@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects timeout-free blocking Future.get calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for FutureGetWithoutTimeoutRule {
             id: "FUTURE_GET_WITHOUT_TIMEOUT",
             name: "Future.get without timeout",
             description: "Timeout-free Future.get calls can block indefinitely",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency"],
         }
     }
 
@@ -33,8 +36,12 @@ impl Rule for FutureGetWithoutTimeoutRule {
                     let artifact_uri = context.class_artifact_uri(class);
                     for method in &class.methods {
                         for call in &method.calls {
-                            if is_timeout_free_future_get(&call.owner, &call.name, &call.descriptor)
-                            {
+                            if is_timeout_free_future_get(
+                                context,
+                                &call.owner,
+                                &call.name,
+                                &call.descriptor,
+                            ) {
                                 let message = result_message(format!(
                                     "Avoid timeout-free Future.get() in {}.{}{}; prefer get(timeout, unit) or non-blocking composition.",
                                     class.name, method.name, method.descriptor
@@ -65,18 +72,28 @@ impl Rule for FutureGetWithoutTimeoutRule {
     }
 }
 
-fn is_timeout_free_future_get(owner: &str, name: &str, descriptor: &str) -> bool {
+const JDK_FUTURE_TYPES: &[&str] = &[
+    "java/util/concurrent/Future",
+    "java/util/concurrent/CompletableFuture",
+    "java/util/concurrent/FutureTask",
+    "java/util/concurrent/ForkJoinTask",
+];
+
+fn is_timeout_free_future_get(
+    context: &AnalysisContext,
+    owner: &str,
+    name: &str,
+    descriptor: &str,
+) -> bool {
     if name != "get" || !descriptor.starts_with("()") {
         return false;
     }
 
-    matches!(
-        owner,
-        "java/util/concurrent/Future"
-            | "java/util/concurrent/CompletableFuture"
-            | "java/util/concurrent/FutureTask"
-            | "java/util/concurrent/ForkJoinTask"
-    ) || (owner.starts_with("java/util/concurrent/") && owner.ends_with("Future"))
+    // JDK classes are usually not loaded, so fall back to names for their own subtypes.
+    JDK_FUTURE_TYPES
+        .iter()
+        .any(|future_type| context.is_subtype_of(owner, future_type))
+        || (owner.starts_with("java/util/concurrent/") && owner.ends_with("Future"))
 }
 
 #[cfg(test)]
@@ -159,6 +176,52 @@ public class ClassB {
         );
     }
 
+    #[test]
+    fn future_get_without_timeout_reports_get_on_user_future_subtype() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![
+            SourceFile {
+                path: "com/example/ClassE.java".to_string(),
+                contents: r#"
+package com.example;
+import java.util.concurrent.CompletableFuture;
+public class ClassE extends CompletableFuture<Object> {}
+"#
+                .to_string(),
+            },
+            SourceFile {
+                path: "com/example/ClassF.java".to_string(),
+                contents: r#"
+package com.example;
+public class ClassF extends ClassE {}
+"#
+                .to_string(),
+            },
+            SourceFile {
+                path: "com/example/ClassG.java".to_string(),
+                contents: r#"
+package com.example;
+public class ClassG {
+    public Object methodV(ClassF varOne) throws Exception {
+        return varOne.get();
+    }
+}
+"#
+                .to_string(),
+            },
+        ];
+
+        let output = compile_and_analyze(&harness, Language::Java, &sources, &[]);
+        let messages = future_get_messages(&output);
+        assert!(
+            messages
+                .iter()
+                .any(|msg| msg
+                    .contains("Avoid timeout-free Future.get() in com/example/ClassG.methodV")),
+            "expected FUTURE_GET_WITHOUT_TIMEOUT finding for get() on a Future subtype, got {messages:?}"
+        );
+    }
+
     #[test]
     fn future_get_without_timeout_ignores_timed_get_overload() {
         let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
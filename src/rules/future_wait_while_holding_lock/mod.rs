@@ -13,7 +13,7 @@ use crate::descriptor::{ReturnKind, method_return_kind};
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, CallSite, FieldRef, Instruction, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects future waits while the current method still holds a lock.
 #[derive(Default)]
@@ -27,6 +27,9 @@ impl Rule for FutureWaitWhileHoldingLockRule {
             id: "FUTURE_WAIT_WHILE_HOLDING_LOCK",
             name: "Future wait while holding lock",
             description: "Blocking Future waits should not happen while a lock is still held",
+            severity: Severity::Warning,
+            rank: Some(70),
+            tags: &["concurrency"],
         }
     }
 
@@ -0,0 +1,360 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallSite, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+const NEW_VALUE: &str = "it returns a new value and leaves the receiver unchanged";
+const STATUS: &str = "it reports failure through the return value instead of throwing";
+const BYTE_COUNT: &str = "it may read fewer bytes than requested";
+
+/// Methods whose return value must be used, as owner, name, descriptor, and the reason.
+const MUST_USE_METHODS: &[(&str, &str, &str, &str)] = &[
+    (
+        "java/lang/String",
+        "trim",
+        "()Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "strip",
+        "()Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "toLowerCase",
+        "()Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "toLowerCase",
+        "(Ljava/util/Locale;)Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "toUpperCase",
+        "()Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "toUpperCase",
+        "(Ljava/util/Locale;)Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "replace",
+        "(CC)Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "replace",
+        "(Ljava/lang/CharSequence;Ljava/lang/CharSequence;)Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "replaceAll",
+        "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "concat",
+        "(Ljava/lang/String;)Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "substring",
+        "(I)Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/lang/String",
+        "substring",
+        "(II)Ljava/lang/String;",
+        NEW_VALUE,
+    ),
+    (
+        "java/math/BigDecimal",
+        "add",
+        "(Ljava/math/BigDecimal;)Ljava/math/BigDecimal;",
+        NEW_VALUE,
+    ),
+    (
+        "java/math/BigDecimal",
+        "subtract",
+        "(Ljava/math/BigDecimal;)Ljava/math/BigDecimal;",
+        NEW_VALUE,
+    ),
+    (
+        "java/math/BigDecimal",
+        "multiply",
+        "(Ljava/math/BigDecimal;)Ljava/math/BigDecimal;",
+        NEW_VALUE,
+    ),
+    (
+        "java/math/BigDecimal",
+        "negate",
+        "()Ljava/math/BigDecimal;",
+        NEW_VALUE,
+    ),
+    (
+        "java/math/BigDecimal",
+        "setScale",
+        "(ILjava/math/RoundingMode;)Ljava/math/BigDecimal;",
+        NEW_VALUE,
+    ),
+    (
+        "java/math/BigInteger",
+        "add",
+        "(Ljava/math/BigInteger;)Ljava/math/BigInteger;",
+        NEW_VALUE,
+    ),
+    (
+        "java/math/BigInteger",
+        "subtract",
+        "(Ljava/math/BigInteger;)Ljava/math/BigInteger;",
+        NEW_VALUE,
+    ),
+    (
+        "java/math/BigInteger",
+        "multiply",
+        "(Ljava/math/BigInteger;)Ljava/math/BigInteger;",
+        NEW_VALUE,
+    ),
+    (
+        "java/util/stream/Stream",
+        "filter",
+        "(Ljava/util/function/Predicate;)Ljava/util/stream/Stream;",
+        NEW_VALUE,
+    ),
+    (
+        "java/util/stream/Stream",
+        "map",
+        "(Ljava/util/function/Function;)Ljava/util/stream/Stream;",
+        NEW_VALUE,
+    ),
+    (
+        "java/util/stream/Stream",
+        "flatMap",
+        "(Ljava/util/function/Function;)Ljava/util/stream/Stream;",
+        NEW_VALUE,
+    ),
+    (
+        "java/util/stream/Stream",
+        "sorted",
+        "()Ljava/util/stream/Stream;",
+        NEW_VALUE,
+    ),
+    (
+        "java/util/stream/Stream",
+        "distinct",
+        "()Ljava/util/stream/Stream;",
+        NEW_VALUE,
+    ),
+    (
+        "java/util/stream/Stream",
+        "limit",
+        "(J)Ljava/util/stream/Stream;",
+        NEW_VALUE,
+    ),
+    (
+        "java/util/stream/Stream",
+        "skip",
+        "(J)Ljava/util/stream/Stream;",
+        NEW_VALUE,
+    ),
+    ("java/io/File", "delete", "()Z", STATUS),
+    ("java/io/File", "mkdir", "()Z", STATUS),
+    ("java/io/File", "mkdirs", "()Z", STATUS),
+    ("java/io/File", "renameTo", "(Ljava/io/File;)Z", STATUS),
+    ("java/io/InputStream", "read", "([B)I", BYTE_COUNT),
+    ("java/io/InputStream", "read", "([BII)I", BYTE_COUNT),
+];
+
+/// Rule that detects discarded return values of APIs whose result must be used.
+#[derive(Default)]
+pub(crate) struct IgnoredReturnValueRule;
+
+crate::register_rule!(IgnoredReturnValueRule);
+
+impl Rule for IgnoredReturnValueRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "IGNORED_RETURN_VALUE",
+            name: "Ignored return value",
+            description: "Return values of methods such as String.trim or File.delete should not be discarded",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for (call, reason) in ignored_calls(method) {
+                            let message = result_message(format!(
+                                "Return value of {}.{}{} is ignored in {}.{}{}; {reason}, so use or check the result.",
+                                call.owner,
+                                call.name,
+                                call.descriptor,
+                                class.name,
+                                method.name,
+                                method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(call.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns must-use calls whose result is popped by the next instruction of the same block,
+/// with the reason the result matters.
+fn ignored_calls(method: &Method) -> Vec<(&CallSite, &'static str)> {
+    method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.windows(2))
+        .filter_map(|pair| {
+            let InstructionKind::Invoke(call) = &pair[0].kind else {
+                return None;
+            };
+            if !matches!(pair[1].opcode, opcodes::POP | opcodes::POP2) {
+                return None;
+            }
+            must_use_reason(call).map(|reason| (call, reason))
+        })
+        .collect()
+}
+
+fn must_use_reason(call: &CallSite) -> Option<&'static str> {
+    MUST_USE_METHODS
+        .iter()
+        .find(|(owner, name, descriptor, _)| {
+            call.owner == *owner && call.name == *name && call.descriptor == *descriptor
+        })
+        .map(|(_, _, _, reason)| *reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("IGNORED_RETURN_VALUE"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_ignored_string_trim() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    String methodX(String varOne) {
+        varOne.trim();
+        return varOne;
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Return value of java/lang/String.trim()Ljava/lang/String; is ignored in com/example/ClassA.methodX(Ljava/lang/String;)Ljava/lang/String;; it returns a new value and leaves the receiver unchanged, so use or check the result."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_ignored_file_delete_status() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.File;
+public class ClassA {
+    void methodX(File varOne) {
+        varOne.delete();
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].starts_with("Return value of java/io/File.delete()Z is ignored"));
+    }
+
+    #[test]
+    fn does_not_report_used_return_values() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.File;
+public class ClassA {
+    String methodX(String varOne, File varTwo) {
+        String varThree = varOne.trim();
+        if (!varTwo.delete()) {
+            return "";
+        }
+        return varThree.toUpperCase();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, class_location, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
 
 /// Rule that flags classes overriding equals or hashCode alone.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for IneffectiveEqualsRule {
             id: "INEFFECTIVE_EQUALS_HASHCODE",
             name: "Ineffective equals/hashCode",
             description: "Classes with equals without hashCode or vice versa",
+            severity: Severity::Error,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -91,9 +94,11 @@ mod tests {
             signature: None,
             access: MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -123,6 +128,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }
     }
 
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects insecure API usage.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for InsecureApiRule {
             id: "INSECURE_API",
             name: "Insecure API usage",
             description: "Calls to insecure process or reflection APIs",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["security"],
         }
     }
 
@@ -100,9 +103,11 @@ mod tests {
             signature: None,
             access: MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -132,6 +137,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }
     }
 
@@ -152,6 +159,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }
     }
 
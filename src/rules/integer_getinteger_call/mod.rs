@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct Integer.getInteger calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for IntegerGetintegerCallRule {
             id: "INTEGER_GETINTEGER_CALL",
             name: "Integer.getInteger call",
             description: "Integer.getInteger reads system properties, not numeric input strings",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -10,7 +10,7 @@ use crate::dataflow::worklist::{
 use crate::engine::AnalysisContext;
 use crate::ir::{Instruction, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that ensures InterruptedException handlers restore interrupt status.
 #[derive(Default)]
@@ -72,6 +72,9 @@ impl Rule for InterruptedExceptionRule {
             id: "INTERRUPTED_EXCEPTION_NOT_RESTORED",
             name: "InterruptedException not properly handled",
             description: "Restore interrupt status when catching InterruptedException",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency"],
         }
     }
 
@@ -226,6 +229,33 @@ public class ClassAlpha {
         assert!(messages.iter().any(|msg| msg.contains("interrupt status")));
     }
 
+    #[test]
+    fn interrupted_exception_reports_empty_handler() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassLambda.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassLambda {
+    public void methodSeven() {
+        try {
+            Thread.sleep(10);
+        } catch (InterruptedException varOne) {
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+        let messages = analyze_sources(sources);
+        assert!(
+            messages
+                .iter()
+                .any(|msg| msg.contains("com/example/ClassLambda.methodSeven()V")),
+            "expected swallowed InterruptedException to be reported, got {messages:?}"
+        );
+    }
+
     #[test]
     fn interrupted_exception_allows_restore() {
         let sources = vec![SourceFile {
@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::local_guard::{
+    collect_instructions, guarded_call_offsets, receiver_local_index,
+};
+use crate::engine::AnalysisContext;
+use crate::ir::{CallSite, Method};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `Iterator.next()` calls not guarded by `hasNext()`.
+#[derive(Default)]
+pub(crate) struct IteratorNextWithoutHasnextRule;
+
+crate::register_rule!(IteratorNextWithoutHasnextRule);
+
+impl Rule for IteratorNextWithoutHasnextRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "ITERATOR_NEXT_WITHOUT_HASNEXT",
+            name: "Iterator.next without hasNext",
+            description: "Iterator.next() throws NoSuchElementException when called without a hasNext() check",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for offset in unguarded_next_offsets(method)? {
+                            let message = result_message(format!(
+                                "Iterator.next() in {}.{}{} is not guarded by hasNext() on the same iterator; check hasNext() first to avoid NoSuchElementException.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns offsets of `next()` calls on an iterator local that no `hasNext()` check on the
+/// same local guards.
+fn unguarded_next_offsets(method: &Method) -> Result<Vec<u32>> {
+    if !method.calls.iter().any(is_iterator_next_call) {
+        return Ok(Vec::new());
+    }
+    let guarded = guarded_call_offsets(method, is_iterator_next_call, has_next_polarity)?;
+    let instructions = collect_instructions(method)?;
+    let offset_to_instruction_index: BTreeMap<u32, usize> = instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| (instruction.offset, index))
+        .collect();
+    Ok(method
+        .calls
+        .iter()
+        .filter(|call| is_iterator_next_call(call) && !guarded.contains(&call.offset))
+        .filter(|call| {
+            offset_to_instruction_index
+                .get(&call.offset)
+                .and_then(|index| receiver_local_index(method, &instructions, *index))
+                .is_some()
+        })
+        .map(|call| call.offset)
+        .collect())
+}
+
+fn is_iterator_next_call(call: &CallSite) -> bool {
+    is_iterator_owner(&call.owner)
+        && call.name == "next"
+        && call.descriptor == "()Ljava/lang/Object;"
+}
+
+fn has_next_polarity(call: &CallSite) -> Option<bool> {
+    (is_iterator_owner(&call.owner) && call.name == "hasNext" && call.descriptor == "()Z")
+        .then_some(true)
+}
+
+fn is_iterator_owner(owner: &str) -> bool {
+    matches!(owner, "java/util/Iterator" | "java/util/ListIterator")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("ITERATOR_NEXT_WITHOUT_HASNEXT"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_bare_next_call() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Iterator;
+import java.util.List;
+public class ClassA {
+    String methodX(List<String> varOne) {
+        Iterator<String> varTwo = varOne.iterator();
+        return varTwo.next();
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Iterator.next() in com/example/ClassA.methodX(Ljava/util/List;)Ljava/lang/String; is not guarded by hasNext() on the same iterator; check hasNext() first to avoid NoSuchElementException."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_next_guarded_by_has_next() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Iterator;
+import java.util.List;
+public class ClassA {
+    int methodX(List<String> varOne) {
+        int varTwo = 0;
+        Iterator<String> varThree = varOne.iterator();
+        while (varThree.hasNext()) {
+            varTwo += varThree.next().length();
+        }
+        if (varThree.hasNext()) {
+            varThree.next();
+        }
+        for (String varFour : varOne) {
+            varTwo += varFour.length();
+        }
+        return varTwo;
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn reports_next_after_iterator_reassignment() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Iterator;
+import java.util.List;
+public class ClassA {
+    String methodX(List<String> varOne, List<String> varTwo) {
+        Iterator<String> varThree = varOne.iterator();
+        if (varThree.hasNext()) {
+            varThree = varTwo.iterator();
+            return varThree.next();
+        }
+        return "";
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeSet;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
@@ -9,7 +9,7 @@ use serde_sarif::sarif::Result as SarifResult;
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, Class, Instruction, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects Koin singleton definitions that create AutoCloseable resources without closing them via onClose.
 #[derive(Default)]
@@ -23,6 +23,9 @@ impl Rule for KoinAutoCloseableNotClosedRule {
             id: "KOIN_AUTOCLOSEABLE_NOT_CLOSED",
             name: "Koin AutoCloseable not closed",
             description: "Koin singleton definitions that construct AutoCloseable resources should close them via onClose",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["resource"],
         }
     }
 
@@ -31,10 +34,6 @@ impl Rule for KoinAutoCloseableNotClosedRule {
             return Ok(Vec::new());
         }
 
-        let class_index: BTreeMap<&str, &Class> = context
-            .all_classes()
-            .map(|class| (class.name.as_str(), class))
-            .collect();
         let mut findings = Vec::new();
 
         for class in context.analysis_target_classes() {
@@ -53,7 +52,7 @@ impl Rule for KoinAutoCloseableNotClosedRule {
                             class,
                             method,
                             artifact_uri.as_deref(),
-                            &class_index,
+                            context,
                         )?);
                     }
                     Ok(class_findings)
@@ -104,7 +103,7 @@ fn analyze_method(
     class: &Class,
     method: &Method,
     artifact_uri: Option<&str>,
-    class_index: &BTreeMap<&str, &Class>,
+    context: &AnalysisContext,
 ) -> Result<Vec<RuleFinding>> {
     let instructions = collect_instructions(method);
     let mut findings = Vec::new();
@@ -131,7 +130,7 @@ fn analyze_method(
             continue;
         };
         let Some(resource_type) =
-            created_autocloseable_resource_type(definition_lambda, class_index)?
+            created_autocloseable_resource_type(definition_lambda, context)?
         else {
             continue;
         };
@@ -189,6 +188,7 @@ fn last_lambda_impl_in_range(
             InstructionKind::InvokeDynamic {
                 descriptor,
                 impl_method: Some(name),
+                ..
             } if is_lambda_factory_descriptor(descriptor) => Some(name.clone()),
             _ => None,
         })
@@ -266,7 +266,7 @@ fn matching_onclose_callback<'a>(
 
 fn created_autocloseable_resource_type(
     lambda_method: &Method,
-    class_index: &BTreeMap<&str, &Class>,
+    context: &AnalysisContext,
 ) -> Result<Option<String>> {
     let descriptor =
         MethodDescriptor::from_str(&lambda_method.descriptor).context("parse lambda descriptor")?;
@@ -288,10 +288,10 @@ fn created_autocloseable_resource_type(
         if call.kind != CallKind::Special || call.name != "<init>" {
             continue;
         }
-        if !implements_autocloseable(&call.owner, class_index) {
+        if !implements_autocloseable(&call.owner, context) {
             continue;
         }
-        if !matches_return_type(&call.owner, return_type, class_index) {
+        if !matches_return_type(&call.owner, return_type, context) {
             continue;
         }
         if constructed_value_is_returned(lambda_method, &instructions, instruction_index) {
@@ -305,11 +305,11 @@ fn created_autocloseable_resource_type(
 fn matches_return_type(
     constructed_type: &str,
     return_type: &str,
-    class_index: &BTreeMap<&str, &Class>,
+    context: &AnalysisContext,
 ) -> bool {
     return_type == "java/lang/Object"
         || constructed_type == return_type
-        || is_assignable_to(constructed_type, return_type, class_index)
+        || is_assignable_to(constructed_type, return_type, context)
 }
 
 fn constructed_value_is_returned(
@@ -382,15 +382,15 @@ fn lambda_calls_close(lambda_method: &Method) -> bool {
     })
 }
 
-fn implements_autocloseable(type_name: &str, class_index: &BTreeMap<&str, &Class>) -> bool {
-    is_assignable_to(type_name, "java/lang/AutoCloseable", class_index)
-        || is_assignable_to(type_name, "java/io/Closeable", class_index)
+fn implements_autocloseable(type_name: &str, context: &AnalysisContext) -> bool {
+    is_assignable_to(type_name, "java/lang/AutoCloseable", context)
+        || is_assignable_to(type_name, "java/io/Closeable", context)
 }
 
 fn is_assignable_to(
     type_name: &str,
     target_name: &str,
-    class_index: &BTreeMap<&str, &Class>,
+    context: &AnalysisContext,
 ) -> bool {
     let mut pending = vec![type_name.to_string()];
     let mut seen = BTreeSet::new();
@@ -402,7 +402,7 @@ fn is_assignable_to(
         if current == target_name {
             return true;
         }
-        let Some(class) = class_index.get(current.as_str()).copied() else {
+        let Some(class) = context.class_by_name(&current) else {
             continue;
         };
         if let Some(super_name) = &class.super_name {
@@ -509,9 +509,11 @@ infix fun <T> BeanDefinition<T>.onClose(callback: (T?) -> Unit): BeanDefinition<
     fn default_access() -> MethodAccess {
         MethodAccess {
             is_public: true,
+            is_private: false,
             is_static: true,
             is_synchronized: false,
             is_abstract: false,
+            is_final: false,
             is_synthetic: true,
             is_bridge: false,
         }
@@ -568,6 +570,8 @@ infix fun <T> BeanDefinition<T>.onClose(callback: (T?) -> Unit): BeanDefinition<
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }
     }
 
@@ -600,6 +604,7 @@ infix fun <T> BeanDefinition<T>.onClose(callback: (T?) -> Unit): BeanDefinition<
             offset,
             opcode: opcodes::NOP,
             kind: InstructionKind::InvokeDynamic {
+                name: "create".to_string(),
                 descriptor: descriptor.to_string(),
                 impl_method: impl_method.map(ToOwned::to_owned),
             },
@@ -0,0 +1,224 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that flags construction and mutation of java.util.Date and Calendar.
+#[derive(Default)]
+pub(crate) struct LegacyDatetimeApiRule;
+
+crate::register_rule!(LegacyDatetimeApiRule);
+
+impl Rule for LegacyDatetimeApiRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "LEGACY_DATETIME_API",
+            name: "Legacy date-time API",
+            description: "java.util.Date and Calendar are mutable legacy types superseded by java.time",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for call in &method.calls {
+                            let Some(usage) = legacy_usage(call) else {
+                                continue;
+                            };
+                            let message = result_message(format!(
+                                "{usage} in {}.{}{} uses the legacy date-time API; use java.time types such as Instant, LocalDateTime, or ZonedDateTime instead.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let line = method.line_for_offset(call.offset);
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                line,
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+/// Describes `call` when it constructs or mutates a legacy date-time object.
+fn legacy_usage(call: &CallSite) -> Option<String> {
+    let simple_name = match call.owner.as_str() {
+        "java/util/Date" => "Date",
+        "java/util/Calendar" => "Calendar",
+        "java/util/GregorianCalendar" => "GregorianCalendar",
+        _ => return None,
+    };
+    if call.name == "<init>" {
+        return (call.kind == CallKind::Special).then(|| format!("new {simple_name}(...)"));
+    }
+    let is_factory = call.kind == CallKind::Static && call.name == "getInstance";
+    let is_mutator = call.kind != CallKind::Static && is_mutator_name(simple_name, &call.name);
+    (is_factory || is_mutator).then(|| format!("{simple_name}.{}()", call.name))
+}
+
+fn is_mutator_name(simple_name: &str, name: &str) -> bool {
+    if simple_name == "Date" {
+        return matches!(
+            name,
+            "setTime"
+                | "setYear"
+                | "setMonth"
+                | "setDate"
+                | "setHours"
+                | "setMinutes"
+                | "setSeconds"
+        );
+    }
+    matches!(
+        name,
+        "set" | "add" | "roll" | "clear" | "setTime" | "setTimeInMillis" | "setTimeZone"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze_rules(Language::Java, &sources, &[], &["LEGACY_DATETIME_API"])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("LEGACY_DATETIME_API"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_date_construction() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+import java.util.Date;
+
+public class ClassA {
+    public Date methodX() {
+        return new Date();
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].starts_with("new Date(...) in com/example/ClassA.methodX()"));
+    }
+
+    #[test]
+    fn reports_calendar_factory_and_mutation() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+import java.util.Calendar;
+import java.util.Date;
+
+public class ClassA {
+    public long methodX(Date varOne) {
+        Calendar varTwo = Calendar.getInstance();
+        varTwo.add(Calendar.DAY_OF_MONTH, 1);
+        varOne.setTime(varTwo.getTimeInMillis());
+        return varOne.getTime();
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 3, "{messages:?}");
+        assert!(messages[0].starts_with("Calendar.getInstance()"));
+        assert!(messages[1].starts_with("Calendar.add()"));
+        assert!(messages[2].starts_with("Date.setTime()"));
+    }
+
+    #[test]
+    fn does_not_report_java_time() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+import java.time.Instant;
+
+public class ClassA {
+    public Instant methodX() {
+        return Instant.now();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_run_by_default() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassA {
+    public java.util.Date methodX() {
+        return new java.util.Date();
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+
+        assert!(
+            output
+                .results
+                .iter()
+                .all(|result| result.rule_id.as_deref() != Some("LEGACY_DATETIME_API"))
+        );
+    }
+}
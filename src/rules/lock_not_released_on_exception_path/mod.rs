@@ -4,12 +4,16 @@ use anyhow::Result;
 use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
-use crate::dataflow::worklist::{
-    BlockEndStep, InstructionStep, WorklistSemantics, WorklistState, analyze_method,
+use crate::dataflow::acquire_release::{
+    AcquireSite, acquire_sites, always_released_from_entry, code_flow_steps,
+    exit_path_without_release,
 };
 use crate::engine::AnalysisContext;
-use crate::ir::{Instruction, InstructionKind, Method};
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::ir::{BasicBlock, Class, FieldRef, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{
+    Rule, RuleMetadata, Severity, method_code_flow, method_location_with_line, result_message,
+};
 
 /// Rule that detects lock acquisitions without guaranteed unlock on all reachable exits.
 #[derive(Default)]
@@ -17,88 +21,15 @@ pub(crate) struct LockNotReleasedOnExceptionPathRule;
 
 crate::register_rule!(LockNotReleasedOnExceptionPathRule);
 
-/// Lock acquisition site metadata used for path exploration.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
-struct LockSite {
-    block_start: u32,
-    instruction_index: usize,
-    offset: u32,
-}
-
-/// Exploration state for CFG traversal after a lock acquisition.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
-struct ExplorationState {
-    block_start: u32,
-    instruction_index: usize,
-    unlock_seen: bool,
-}
-
-impl WorklistState for ExplorationState {
-    fn block_start(&self) -> u32 {
-        self.block_start
-    }
-
-    fn instruction_index(&self) -> usize {
-        self.instruction_index
-    }
-
-    fn set_position(&mut self, block_start: u32, instruction_index: usize) {
-        self.block_start = block_start;
-        self.instruction_index = instruction_index;
-    }
-}
-
-/// Dataflow callbacks for lock release path exploration.
-struct LockPathSemantics {
-    site: LockSite,
-}
-
-impl WorklistSemantics for LockPathSemantics {
-    type State = ExplorationState;
-    type Finding = ();
-
-    fn initial_states(&self, _method: &Method) -> Vec<Self::State> {
-        vec![ExplorationState {
-            block_start: self.site.block_start,
-            instruction_index: self.site.instruction_index + 1,
-            unlock_seen: false,
-        }]
-    }
-
-    fn transfer_instruction(
-        &self,
-        _method: &Method,
-        instruction: &Instruction,
-        state: &mut Self::State,
-    ) -> Result<InstructionStep<Self::Finding>> {
-        if is_unlock_invocation(instruction) {
-            state.unlock_seen = true;
-        }
-        Ok(InstructionStep::continue_path())
-    }
-
-    fn on_block_end(
-        &self,
-        _method: &Method,
-        state: &Self::State,
-        successors: &[u32],
-    ) -> Result<BlockEndStep<Self::State, Self::Finding>> {
-        if successors.is_empty() {
-            if state.unlock_seen {
-                return Ok(BlockEndStep::terminal());
-            }
-            return Ok(BlockEndStep::terminal().with_finding(()));
-        }
-        Ok(BlockEndStep::follow_all_successors(state, successors))
-    }
-}
-
 impl Rule for LockNotReleasedOnExceptionPathRule {
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata {
             id: "LOCK_NOT_RELEASED_ON_EXCEPTION_PATH",
             name: "Lock acquired without guaranteed release",
             description: "Lock.lock() must be followed by unlock() on every reachable exit path",
+            severity: Severity::Error,
+            rank: Some(90),
+            tags: &["concurrency"],
         }
     }
 
@@ -118,7 +49,7 @@ impl Rule for LockNotReleasedOnExceptionPathRule {
                             continue;
                         }
 
-                        let lock_sites = lock_sites(method);
+                        let lock_sites = acquire_sites(method, is_lock_invocation);
                         if lock_sites.is_empty() {
                             continue;
                         }
@@ -129,7 +60,7 @@ impl Rule for LockNotReleasedOnExceptionPathRule {
                             if !seen_offsets.insert(site.offset) {
                                 continue;
                             }
-                            if has_exit_path_without_unlock(method, site)? {
+                            if let Some(path) = exit_path_without_unlock(class, method, site)? {
                                 let message = result_message(format!(
                                     "Lock acquired in {}.{}{} may exit without unlock(); release it in a finally block.",
                                     class.name, method.name, method.descriptor
@@ -143,10 +74,23 @@ impl Rule for LockNotReleasedOnExceptionPathRule {
                                     artifact_uri.as_deref(),
                                     line,
                                 );
+                                let code_flow = method_code_flow(
+                                    &class.name,
+                                    method,
+                                    artifact_uri.as_deref(),
+                                    &code_flow_steps(
+                                        method,
+                                        site,
+                                        &path,
+                                        "Lock acquired here",
+                                        "Method exits here without unlock()",
+                                    ),
+                                );
                                 class_results.push(
                                     SarifResult::builder()
                                         .message(message)
                                         .locations(vec![location])
+                                        .code_flows(vec![code_flow])
                                         .build(),
                                 );
                             }
@@ -160,27 +104,87 @@ impl Rule for LockNotReleasedOnExceptionPathRule {
     }
 }
 
-fn lock_sites(method: &Method) -> Vec<LockSite> {
-    let mut sites = Vec::new();
-    for block in &method.cfg.blocks {
-        for (instruction_index, instruction) in block.instructions.iter().enumerate() {
-            if is_lock_invocation(instruction) {
-                sites.push(LockSite {
-                    block_start: block.start_offset,
-                    instruction_index,
-                    offset: instruction.offset,
-                });
+/// Returns the block path from the lock site to an exit that skips unlock(), if any.
+fn exit_path_without_unlock(
+    class: &Class,
+    method: &Method,
+    site: AcquireSite,
+) -> Result<Option<Vec<u32>>> {
+    exit_path_without_release(method, site, &release_offsets(class, method, site)?)
+}
+
+/// Offsets that release the lock acquired at `site`: direct unlock() calls, plus calls to
+/// private same-class helpers that unlock the same lock field on every exit.
+fn release_offsets(class: &Class, method: &Method, site: AcquireSite) -> Result<BTreeSet<u32>> {
+    let instructions = || {
+        method
+            .cfg
+            .blocks
+            .iter()
+            .flat_map(|block| block.instructions.iter())
+    };
+    let mut offsets: BTreeSet<u32> = instructions()
+        .filter(|instruction| is_unlock_invocation(instruction))
+        .map(|instruction| instruction.offset)
+        .collect();
+    let Some(lock_field) = method
+        .cfg
+        .blocks
+        .iter()
+        .find(|block| block.start_offset == site.block_start)
+        .and_then(|block| receiver_field(block, site.instruction_index))
+    else {
+        return Ok(offsets);
+    };
+
+    for instruction in instructions() {
+        let InstructionKind::Invoke(call) = &instruction.kind else {
+            continue;
+        };
+        if call.owner != class.name {
+            continue;
+        }
+        let Some(callee) = class.methods.iter().find(|candidate| {
+            candidate.access.is_private
+                && candidate.name == call.name
+                && candidate.descriptor == call.descriptor
+        }) else {
+            continue;
+        };
+        if helper_always_unlocks(callee, lock_field)? {
+            offsets.insert(instruction.offset);
+        }
+    }
+    Ok(offsets)
+}
+
+/// Returns true when every exit of `helper` passes an unlock() on `lock_field`.
+fn helper_always_unlocks(helper: &Method, lock_field: &FieldRef) -> Result<bool> {
+    let mut release_offsets = BTreeSet::new();
+    for block in &helper.cfg.blocks {
+        for (index, instruction) in block.instructions.iter().enumerate() {
+            if is_unlock_invocation(instruction)
+                && receiver_field(block, index).is_some_and(|field| {
+                    field.owner == lock_field.owner && field.name == lock_field.name
+                })
+            {
+                release_offsets.insert(instruction.offset);
             }
         }
     }
-    sites.sort_by_key(|site| site.offset);
-    sites
+    always_released_from_entry(helper, &release_offsets)
 }
 
-fn has_exit_path_without_unlock(method: &Method, site: LockSite) -> Result<bool> {
-    let semantics = LockPathSemantics { site };
-    let findings = analyze_method(method, &semantics)?;
-    Ok(!findings.is_empty())
+/// Field loaded immediately before the call at `instruction_index`, i.e. the call receiver.
+fn receiver_field(block: &BasicBlock, instruction_index: usize) -> Option<&FieldRef> {
+    let previous = block.instructions.get(instruction_index.checked_sub(1)?)?;
+    if previous.opcode != opcodes::GETFIELD && previous.opcode != opcodes::GETSTATIC {
+        return None;
+    }
+    let InstructionKind::FieldAccess(field) = &previous.kind else {
+        return None;
+    };
+    Some(field)
 }
 
 fn is_lock_invocation(instruction: &Instruction) -> bool {
@@ -260,6 +264,134 @@ public class ClassA {
         assert!(messages[0].contains("finally block"));
     }
 
+    #[test]
+    fn code_flow_visits_lock_site_and_exiting_block() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassF.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.util.concurrent.locks.Lock;
+import java.util.concurrent.locks.ReentrantLock;
+
+public class ClassF {
+    private final Lock varOne = new ReentrantLock();
+
+    public void methodX(boolean varTwo) {
+        varOne.lock();
+        if (varTwo) {
+            throw new IllegalStateException("tmpValue");
+        }
+        varOne.unlock();
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        let result = output
+            .results
+            .iter()
+            .find(|result| {
+                result.rule_id.as_deref() == Some("LOCK_NOT_RELEASED_ON_EXCEPTION_PATH")
+            })
+            .expect("finding");
+        let code_flows = result.code_flows.as_ref().expect("code flows");
+        let lines: Vec<i64> = code_flows[0].thread_flows[0]
+            .locations
+            .iter()
+            .filter_map(|step| step.location.as_ref())
+            .filter_map(|location| location.physical_location.as_ref())
+            .filter_map(|physical| physical.region.as_ref())
+            .filter_map(|region| region.start_line)
+            .collect();
+
+        assert_eq!(lines.first(), Some(&11));
+        assert_eq!(lines.last(), Some(&13));
+        let primary_line = result.locations.as_ref().expect("locations")[0]
+            .physical_location
+            .as_ref()
+            .and_then(|physical| physical.region.as_ref())
+            .and_then(|region| region.start_line);
+        assert_eq!(primary_line, Some(11));
+    }
+
+    #[test]
+    fn does_not_report_lock_released_by_private_helper() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassG.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.util.concurrent.locks.Lock;
+import java.util.concurrent.locks.ReentrantLock;
+
+public class ClassG {
+    private final Lock varOne = new ReentrantLock();
+
+    public void methodX(boolean varTwo) {
+        varOne.lock();
+        try {
+            if (varTwo) {
+                System.out.println("tmpValue");
+            }
+        } finally {
+            release();
+        }
+    }
+
+    private void release() {
+        varOne.unlock();
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn reports_lock_when_helper_unlocks_conditionally() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassH.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.util.concurrent.locks.Lock;
+import java.util.concurrent.locks.ReentrantLock;
+
+public class ClassH {
+    private final Lock varOne = new ReentrantLock();
+
+    public void methodX(boolean varTwo) {
+        varOne.lock();
+        try {
+            System.out.println("tmpValue");
+        } finally {
+            release(varTwo);
+        }
+    }
+
+    private void release(boolean varTwo) {
+        if (varTwo) {
+            varOne.unlock();
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert_eq!(messages.len(), 1);
+    }
+
     #[test]
     fn does_not_report_lock_released_in_finally() {
         let sources = vec![SourceFile {
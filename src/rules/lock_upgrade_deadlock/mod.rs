@@ -0,0 +1,327 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::acquire_release::{AcquireSite, acquire_sites, reachable_before_release};
+use crate::engine::AnalysisContext;
+use crate::ir::{BasicBlock, FieldRef, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects write-lock acquisitions while the read lock of the same
+/// ReentrantReadWriteLock may still be held.
+#[derive(Default)]
+pub(crate) struct LockUpgradeDeadlockRule;
+
+crate::register_rule!(LockUpgradeDeadlockRule);
+
+impl Rule for LockUpgradeDeadlockRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "LOCK_UPGRADE_DEADLOCK",
+            name: "Read lock upgraded to write lock",
+            description: "writeLock().lock() while the read lock of the same ReadWriteLock is held deadlocks",
+            severity: Severity::Error,
+            rank: None,
+            tags: &["concurrency"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() {
+                            continue;
+                        }
+                        for offset in upgrade_offsets(method)? {
+                            let message = result_message(format!(
+                                "Write lock acquired in {}.{}{} while the read lock of the same ReadWriteLock may still be held; release the read lock before calling writeLock().lock().",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let line = method.line_for_offset(offset);
+                            let artifact_uri = context.class_artifact_uri(class);
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                line,
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Which half of a ReadWriteLock a lock()/unlock() call targets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum LockMode {
+    Read,
+    Write,
+}
+
+/// A lock()/unlock() call on `field.readLock()` or `field.writeLock()`.
+struct ReadWriteLockCall<'a> {
+    offset: u32,
+    mode: LockMode,
+    lock: bool,
+    field: &'a FieldRef,
+}
+
+/// Offsets of write-lock acquisitions reachable from a read-lock acquisition on the same field
+/// before the read lock is released.
+fn upgrade_offsets(method: &Method) -> Result<BTreeSet<u32>> {
+    let calls: Vec<ReadWriteLockCall<'_>> = method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| {
+            (0..block.instructions.len())
+                .filter_map(move |index| read_write_lock_call(block, index))
+        })
+        .collect();
+    let mut offsets = BTreeSet::new();
+    let read_lock_sites = acquire_sites(method, |instruction| {
+        calls.iter().any(|call| {
+            call.offset == instruction.offset && call.lock && call.mode == LockMode::Read
+        })
+    });
+    for site in read_lock_sites {
+        let Some(field) = call_field(&calls, site) else {
+            continue;
+        };
+        let offsets_for = |mode: LockMode, lock: bool| -> BTreeSet<u32> {
+            calls
+                .iter()
+                .filter(|call| {
+                    call.mode == mode && call.lock == lock && same_field(call.field, field)
+                })
+                .map(|call| call.offset)
+                .collect()
+        };
+        let release_offsets = offsets_for(LockMode::Read, false);
+        let write_lock_offsets = offsets_for(LockMode::Write, true);
+        offsets.extend(reachable_before_release(
+            method,
+            site,
+            &release_offsets,
+            &write_lock_offsets,
+        )?);
+    }
+    Ok(offsets)
+}
+
+fn call_field<'a>(calls: &[ReadWriteLockCall<'a>], site: AcquireSite) -> Option<&'a FieldRef> {
+    calls
+        .iter()
+        .find(|call| call.offset == site.offset)
+        .map(|call| call.field)
+}
+
+fn same_field(left: &FieldRef, right: &FieldRef) -> bool {
+    left.owner == right.owner && left.name == right.name
+}
+
+/// Matches `<field>.readLock().lock()` style chains ending at `instruction_index`.
+fn read_write_lock_call(
+    block: &BasicBlock,
+    instruction_index: usize,
+) -> Option<ReadWriteLockCall<'_>> {
+    let instruction = block.instructions.get(instruction_index)?;
+    let lock = lock_call_kind(instruction)?;
+    let accessor = block.instructions.get(instruction_index.checked_sub(1)?)?;
+    let mode = lock_accessor_mode(accessor)?;
+    let field = block.instructions.get(instruction_index.checked_sub(2)?)?;
+    if field.opcode != opcodes::GETFIELD && field.opcode != opcodes::GETSTATIC {
+        return None;
+    }
+    let InstructionKind::FieldAccess(field) = &field.kind else {
+        return None;
+    };
+    Some(ReadWriteLockCall {
+        offset: instruction.offset,
+        mode,
+        lock,
+        field,
+    })
+}
+
+/// Returns `Some(true)` for `lock()`, `Some(false)` for `unlock()`, and `None` otherwise.
+fn lock_call_kind(instruction: &Instruction) -> Option<bool> {
+    let InstructionKind::Invoke(call) = &instruction.kind else {
+        return None;
+    };
+    if call.descriptor != "()V"
+        || !matches!(
+            call.owner.as_str(),
+            "java/util/concurrent/locks/Lock"
+                | "java/util/concurrent/locks/ReentrantReadWriteLock$ReadLock"
+                | "java/util/concurrent/locks/ReentrantReadWriteLock$WriteLock"
+        )
+    {
+        return None;
+    }
+    match call.name.as_str() {
+        "lock" => Some(true),
+        "unlock" => Some(false),
+        _ => None,
+    }
+}
+
+fn lock_accessor_mode(instruction: &Instruction) -> Option<LockMode> {
+    let InstructionKind::Invoke(call) = &instruction.kind else {
+        return None;
+    };
+    if !matches!(
+        call.owner.as_str(),
+        "java/util/concurrent/locks/ReadWriteLock"
+            | "java/util/concurrent/locks/ReentrantReadWriteLock"
+    ) || !call.descriptor.starts_with("()")
+    {
+        return None;
+    }
+    match call.name.as_str() {
+        "readLock" => Some(LockMode::Read),
+        "writeLock" => Some(LockMode::Write),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze_sources(sources: Vec<SourceFile>) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("LOCK_UPGRADE_DEADLOCK"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_write_lock_acquired_under_read_lock() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.util.concurrent.locks.ReentrantReadWriteLock;
+
+public class ClassA {
+    private final ReentrantReadWriteLock varOne = new ReentrantReadWriteLock();
+
+    public void methodX(boolean varTwo) {
+        varOne.readLock().lock();
+        try {
+            if (varTwo) {
+                varOne.writeLock().lock();
+                varOne.writeLock().unlock();
+            }
+        } finally {
+            varOne.readLock().unlock();
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].contains("ClassA.methodX(Z)V"));
+    }
+
+    #[test]
+    fn does_not_report_write_lock_after_read_lock_released() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassB.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.util.concurrent.locks.ReadWriteLock;
+import java.util.concurrent.locks.ReentrantReadWriteLock;
+
+public class ClassB {
+    private final ReadWriteLock varOne = new ReentrantReadWriteLock();
+
+    public void methodX() {
+        varOne.readLock().lock();
+        try {
+            System.out.println("tmpValue");
+        } finally {
+            varOne.readLock().unlock();
+        }
+        varOne.writeLock().lock();
+        try {
+            System.out.println("tmpValue");
+        } finally {
+            varOne.writeLock().unlock();
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_write_lock_of_other_read_write_lock() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassC.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.util.concurrent.locks.ReentrantReadWriteLock;
+
+public class ClassC {
+    private final ReentrantReadWriteLock varOne = new ReentrantReadWriteLock();
+    private final ReentrantReadWriteLock varTwo = new ReentrantReadWriteLock();
+
+    public void methodX() {
+        varOne.readLock().lock();
+        try {
+            varTwo.writeLock().lock();
+            varTwo.writeLock().unlock();
+        } finally {
+            varOne.readLock().unlock();
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -9,7 +9,7 @@ use serde_sarif::sarif::Result as SarifResult;
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that ensures Log4j2 format strings are compile-time constants.
 #[derive(Default)]
@@ -23,6 +23,9 @@ impl Rule for Log4j2FormatShouldBeConstRule {
             id: "LOG4J2_FORMAT_SHOULD_BE_CONST",
             name: "Log4j2 format should be const",
             description: "Log4j2 format strings should be compile-time constants",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, class_location, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
 
 /// Rule that ensures Log4j2 logger fields are final.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for Log4j2LoggerShouldBeFinalRule {
             id: "LOG4J2_LOGGER_SHOULD_BE_FINAL",
             name: "Log4j2 logger should be final",
             description: "Log4j2 Logger fields should be final",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
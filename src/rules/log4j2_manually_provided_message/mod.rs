@@ -10,7 +10,7 @@ use crate::descriptor::{ReturnKind, method_return_kind};
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects Log4j2 log messages assembled manually instead of placeholders.
 #[derive(Default)]
@@ -24,6 +24,9 @@ impl Rule for Log4j2ManuallyProvidedMessageRule {
             id: "LOG4J2_MANUALLY_PROVIDED_MESSAGE",
             name: "Log4j2 preformatted message",
             description: "Log4j2 messages should use placeholders instead of manual formatting",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
@@ -9,7 +9,7 @@ use serde_sarif::sarif::Result as SarifResult;
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that flags Log4j2 format strings that are only placeholders.
 #[derive(Default)]
@@ -23,6 +23,9 @@ impl Rule for Log4j2SignOnlyFormatRule {
             id: "LOG4J2_SIGN_ONLY_FORMAT",
             name: "Log4j2 placeholder-only format",
             description: "Log4j2 format strings should include descriptive text",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
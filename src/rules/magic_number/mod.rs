@@ -4,10 +4,10 @@ use anyhow::Result;
 use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
-use crate::engine::AnalysisContext;
+use crate::engine::{AnalysisContext, MagicNumberOptions};
 use crate::ir::{AnnotationDefaultNumeric, CallKind, Class, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 const KOTLIN_DEFAULT_BUFFER_SIZE: i64 = 8192;
 
@@ -23,11 +23,14 @@ impl Rule for MagicNumberRule {
             id: "MAGIC_NUMBER",
             name: "Magic number",
             description: "Numeric literals used directly in method bodies reduce readability and maintainability; extract them into named constants",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
         }
     }
 
     fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
-        let allowlist = build_allowlist();
+        let allowlist = build_allowlist(&context.rule_options().magic_number);
         let mut results = Vec::new();
 
         for class in context.analysis_target_classes() {
@@ -106,7 +109,7 @@ fn scan_method_body(
     class_super_name: Option<&str>,
     artifact_uri: Option<&str>,
     is_kotlin_class: bool,
-    allowlist: &HashSet<i64>,
+    allowlist: &Allowlist,
     results: &mut Vec<SarifResult>,
 ) {
     let instructions = collect_instructions(method);
@@ -124,7 +127,7 @@ fn scan_method_body(
                 format_int(*v)
             }
             InstructionKind::ConstFloat(v) => {
-                if is_float_allowlisted(*v) {
+                if is_float_allowlisted(*v, allowlist) {
                     continue;
                 }
                 format_float(*v)
@@ -177,7 +180,7 @@ fn scan_lambda_bodies(
     class: &Class,
     artifact_uri: Option<&str>,
     is_kotlin_class: bool,
-    allowlist: &HashSet<i64>,
+    allowlist: &Allowlist,
     results: &mut Vec<SarifResult>,
 ) {
     let instructions = collect_instructions(method);
@@ -216,7 +219,7 @@ fn scan_lambda_bodies(
                     format_int(*v)
                 }
                 InstructionKind::ConstFloat(v) => {
-                    if is_float_allowlisted(*v) {
+                    if is_float_allowlisted(*v, allowlist) {
                         continue;
                     }
                     format_float(*v)
@@ -261,7 +264,7 @@ fn scan_default_arg_bodies(
     class: &Class,
     artifact_uri: Option<&str>,
     is_kotlin_class: bool,
-    allowlist: &HashSet<i64>,
+    allowlist: &Allowlist,
     results: &mut Vec<SarifResult>,
 ) {
     let default_name = format!("{}$default", method.name);
@@ -288,7 +291,7 @@ fn scan_default_arg_bodies(
                     format_int(*v)
                 }
                 InstructionKind::ConstFloat(v) => {
-                    if is_float_allowlisted(*v) {
+                    if is_float_allowlisted(*v, allowlist) {
                         continue;
                     }
                     format_float(*v)
@@ -329,13 +332,13 @@ fn scan_default_arg_bodies(
 fn scan_annotation_defaults(
     class: &Class,
     artifact_uri: Option<&str>,
-    allowlist: &HashSet<i64>,
+    allowlist: &Allowlist,
     results: &mut Vec<SarifResult>,
 ) {
     for default in &class.annotation_defaults {
         let should_report = match &default.value {
             AnnotationDefaultNumeric::Int(v) => !is_int_allowlisted(*v, allowlist),
-            AnnotationDefaultNumeric::Float(v) => !is_float_allowlisted(*v),
+            AnnotationDefaultNumeric::Float(v) => !is_float_allowlisted(*v, allowlist),
         };
         if !should_report {
             continue;
@@ -379,10 +382,7 @@ fn is_enum_constructor_context(
     let limit = (idx + 9).min(instructions.len());
     for i in (idx + 1)..limit {
         if let InstructionKind::Invoke(call) = &instructions[i].kind {
-            if call.kind == CallKind::Special
-                && call.name == "<init>"
-                && call.owner == class_name
-            {
+            if call.kind == CallKind::Special && call.name == "<init>" && call.owner == class_name {
                 return true;
             }
         }
@@ -502,10 +502,7 @@ fn finalize_kotlin_default_buffer_tracking(
     }
 }
 
-fn is_buffered_constructor_size_load(
-    instructions: &[FlatInstruction],
-    load_idx: usize,
-) -> bool {
+fn is_buffered_constructor_size_load(instructions: &[FlatInstruction], load_idx: usize) -> bool {
     let Some(next) = instructions.get(load_idx + 1) else {
         return false;
     };
@@ -543,34 +540,47 @@ fn int_store_local_index(inst: &FlatInstruction, bytecode: &[u8]) -> Option<u16>
     }
 }
 
-/// Build the integer allowlist: -1, 0, 1, 2, powers of two up to 1024,
-/// and common bit masks.
-fn build_allowlist() -> HashSet<i64> {
-    let mut set = HashSet::new();
+/// Numeric values that are never reported.
+struct Allowlist {
+    ints: HashSet<i64>,
+    floats: Vec<f64>,
+}
+
+/// Build the allowlist: -1, 0, 1, 2, powers of two up to the configured maximum,
+/// common bit masks, 0.0, 1.0, and any user-configured values.
+fn build_allowlist(options: &MagicNumberOptions) -> Allowlist {
+    let mut ints = HashSet::new();
     // Basic values
-    set.insert(-1);
-    set.insert(0);
-    set.insert(1);
-    set.insert(2);
-    // Powers of two up to 1024
+    ints.insert(-1);
+    ints.insert(0);
+    ints.insert(1);
+    ints.insert(2);
+    // Powers of two up to the configured maximum
     let mut p = 4i64;
-    while p <= 1024 {
-        set.insert(p);
-        p *= 2;
+    while p <= options.max_power_of_two {
+        ints.insert(p);
+        let Some(next) = p.checked_mul(2) else {
+            break;
+        };
+        p = next;
     }
     // Common bit masks
-    set.insert(0xFF);
-    set.insert(0xFFFF);
-    set.insert(0xFFFF_FFFF);
-    set
+    ints.insert(0xFF);
+    ints.insert(0xFFFF);
+    ints.insert(0xFFFF_FFFF);
+    ints.extend(options.allowlist.iter().copied());
+
+    let mut floats = vec![0.0, 1.0];
+    floats.extend(options.float_allowlist.iter().copied());
+    Allowlist { ints, floats }
 }
 
-fn is_int_allowlisted(value: i64, allowlist: &HashSet<i64>) -> bool {
-    allowlist.contains(&value)
+fn is_int_allowlisted(value: i64, allowlist: &Allowlist) -> bool {
+    allowlist.ints.contains(&value)
 }
 
-fn is_float_allowlisted(value: f64) -> bool {
-    value == 0.0 || value == 1.0
+fn is_float_allowlisted(value: f64, allowlist: &Allowlist) -> bool {
+    allowlist.floats.contains(&value)
 }
 
 /// Check if the next instruction is an array creation opcode.
@@ -645,6 +655,7 @@ fn format_float(v: f64) -> String {
 mod tests {
     use std::path::PathBuf;
 
+    use crate::engine::{MagicNumberOptions, RuleOptions};
     use crate::test_harness::{JvmTestHarness, Language, SourceFile};
 
     fn magic_number_messages(output: &crate::engine::EngineOutput) -> Vec<String> {
@@ -693,6 +704,51 @@ public class ClassA {
         );
     }
 
+    #[test]
+    fn ignores_configured_allowlist_values() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+public class ClassA {
+    public void methodOne(int varOne, double varTwo) {
+        if (varOne > 3600 || varOne == 4096) {
+            System.out.println("timeout");
+        }
+        if (varTwo > 0.5) {
+            System.out.println("half");
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+        let compiled = harness
+            .compile(Language::Java, &sources, &[])
+            .expect("compile classes");
+
+        let output = harness
+            .analyze_with_rule_options(
+                compiled.classes_dir(),
+                &[],
+                RuleOptions {
+                    magic_number: MagicNumberOptions {
+                        allowlist: vec![3600],
+                        float_allowlist: vec![0.5],
+                        max_power_of_two: 4096,
+                    },
+                    ..RuleOptions::default()
+                },
+            )
+            .expect("run analysis");
+        let messages = magic_number_messages(&output);
+        assert!(
+            messages.is_empty(),
+            "did not expect findings for configured values: {messages:?}"
+        );
+    }
+
     #[test]
     fn reports_non_allowlisted_float() {
         let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
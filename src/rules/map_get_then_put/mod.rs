@@ -0,0 +1,416 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::local_guard::{
+    BytecodeInstruction, aload_local_index, collect_instructions, has_store_to_local_between,
+};
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{BasicBlock, CallKind, CallSite, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `Map.get`, a null check, then `Map.put` on the same map local.
+#[derive(Default)]
+pub(crate) struct MapGetThenPutRule;
+
+crate::register_rule!(MapGetThenPutRule);
+
+impl Rule for MapGetThenPutRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "MAP_GET_THEN_PUT",
+            name: "Map.get then put",
+            description: "Map.get, null check, then put looks the key up twice and is not atomic",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() {
+                            continue;
+                        }
+                        let analysis = analyze_method(context, method)?;
+                        rule_coverage.merge_from(&analysis.coverage);
+                        for offset in analysis.put_offsets {
+                            let message = result_message(format!(
+                                "Map.put in {}.{}{} follows a null check of Map.get on the same map; use computeIfAbsent or putIfAbsent to look the key up once and atomically on concurrent maps.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("MAP_GET_THEN_PUT", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Operand stack or local value tracked within a basic block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    /// Reference loaded from this local.
+    Local(usize),
+    /// Result of `Map.get` on the map held in this local.
+    MapGet(usize),
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct MapValueDomain;
+
+impl ValueDomain<Value> for MapValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Hooks that remember which local a reference came from and keep `Map.get` results through
+/// `checkcast`.
+struct MapGetHooks;
+
+impl SemanticsHooks<Value> for MapGetHooks {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        method: &Method,
+        offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        if opcode == opcodes::CHECKCAST {
+            return ApplyOutcome::Applied;
+        }
+        let instruction = BytecodeInstruction {
+            offset: offset as u32,
+            opcode,
+            length: 0,
+        };
+        let Some(local_index) = aload_local_index(&method.bytecode, &instruction) else {
+            return ApplyOutcome::NotHandled;
+        };
+        let value = match machine.load_local(local_index) {
+            Value::MapGet(map_local) => Value::MapGet(map_local),
+            _ => Value::Local(local_index),
+        };
+        machine.push(value);
+        ApplyOutcome::Applied
+    }
+}
+
+/// Bytecode range where the `Map.get` result of the map in `map_local` is known to be null.
+struct NullGuard {
+    map_local: usize,
+    start_offset: u32,
+    end_offset: u32,
+}
+
+/// Method-level analysis output with coverage summary for debug telemetry events.
+struct MethodAnalysis {
+    put_offsets: Vec<u32>,
+    coverage: SemanticsCoverage,
+}
+
+/// Returns the offsets of `Map.put` calls that run only when a `Map.get` on the same map local
+/// returned null.
+fn analyze_method(context: &AnalysisContext, method: &Method) -> Result<MethodAnalysis> {
+    let mut coverage = SemanticsCoverage::default();
+    let mut guards = Vec::new();
+    let mut puts = Vec::new();
+    for block in &method.cfg.blocks {
+        simulate_block(
+            context,
+            method,
+            block,
+            &mut guards,
+            &mut puts,
+            &mut coverage,
+        )?;
+    }
+    if guards.is_empty() {
+        return Ok(MethodAnalysis {
+            put_offsets: Vec::new(),
+            coverage,
+        });
+    }
+
+    let instructions = collect_instructions(method)?;
+    let put_offsets = puts
+        .into_iter()
+        .filter(|(put_offset, map_local)| {
+            guards.iter().any(|guard| {
+                guard.map_local == *map_local
+                    && *put_offset >= guard.start_offset
+                    && *put_offset < guard.end_offset
+                    && !has_store_to_local_between(
+                        method,
+                        &instructions,
+                        *map_local,
+                        guard.start_offset,
+                        *put_offset,
+                    )
+            })
+        })
+        .map(|(put_offset, _)| put_offset)
+        .collect();
+    Ok(MethodAnalysis {
+        put_offsets,
+        coverage,
+    })
+}
+
+/// Simulates `block` from an unknown state, collecting `ifnonnull` checks of `Map.get`
+/// results and `Map.put` calls whose receiver is a local.
+fn simulate_block(
+    context: &AnalysisContext,
+    method: &Method,
+    block: &BasicBlock,
+    guards: &mut Vec<NullGuard>,
+    puts: &mut Vec<(u32, usize)>,
+    coverage: &mut SemanticsCoverage,
+) -> Result<()> {
+    let mut machine = StackMachine::new(Value::Other);
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "MAP_GET_THEN_PUT",
+    };
+    for (index, instruction) in block.instructions.iter().enumerate() {
+        if instruction.opcode == opcodes::IFNONNULL
+            && let Some(Value::MapGet(map_local)) = machine.peek().copied()
+        {
+            // Only the fall-through branch, where the value is null, is tracked.
+            let start_offset = block
+                .instructions
+                .get(index + 1)
+                .map_or(block.end_offset, |next| next.offset);
+            if let Some(end_offset) = branch_target(&method.bytecode, instruction.offset)?
+                && start_offset < end_offset
+            {
+                guards.push(NullGuard {
+                    map_local,
+                    start_offset,
+                    end_offset,
+                });
+            }
+        }
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &MapValueDomain,
+            &mut MapGetHooks,
+            coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => {
+                handle_invoke(context, call, instruction.offset, &mut machine, puts)?
+            }
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn handle_invoke(
+    context: &AnalysisContext,
+    call: &CallSite,
+    offset: u32,
+    machine: &mut StackMachine<Value>,
+    puts: &mut Vec<(u32, usize)>,
+) -> Result<()> {
+    let param_count = method_param_count(&call.descriptor)?;
+    let receiver = if call.kind == CallKind::Static {
+        None
+    } else {
+        machine
+            .stack_values()
+            .len()
+            .checked_sub(param_count + 1)
+            .and_then(|index| machine.stack_values().get(index).copied())
+    };
+    machine.pop_n(param_count);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+
+    let map_local = match receiver {
+        Some(Value::Local(local_index)) if is_map_type(context, &call.owner) => Some(local_index),
+        _ => None,
+    };
+    let mut returned = Value::Other;
+    if let Some(map_local) = map_local {
+        match (call.name.as_str(), param_count) {
+            ("get", 1) => returned = Value::MapGet(map_local),
+            ("put", 2) => puts.push((offset, map_local)),
+            _ => {}
+        }
+    }
+    if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        machine.push(returned);
+    }
+    Ok(())
+}
+
+/// Whether `owner` is `java/util/Map` or a map implementation. JDK maps are usually not loaded,
+/// so common ones are also matched by name.
+fn is_map_type(context: &AnalysisContext, owner: &str) -> bool {
+    context.is_subtype_of(owner, "java/util/Map")
+        || matches!(
+            owner,
+            "java/util/HashMap"
+                | "java/util/LinkedHashMap"
+                | "java/util/TreeMap"
+                | "java/util/SortedMap"
+                | "java/util/NavigableMap"
+                | "java/util/Hashtable"
+                | "java/util/WeakHashMap"
+                | "java/util/IdentityHashMap"
+                | "java/util/EnumMap"
+                | "java/util/concurrent/ConcurrentMap"
+                | "java/util/concurrent/ConcurrentHashMap"
+                | "java/util/concurrent/ConcurrentNavigableMap"
+                | "java/util/concurrent/ConcurrentSkipListMap"
+        )
+}
+
+fn branch_target(code: &[u8], offset: u32) -> Result<Option<u32>> {
+    let branch = crate::scan::read_u16(code, offset as usize + 1)?;
+    let branch = i16::from_be_bytes(branch.to_be_bytes()) as i32;
+    let target = offset as i32 + branch;
+    if target < 0 {
+        return Ok(None);
+    }
+    Ok(Some(target as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("MAP_GET_THEN_PUT"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_get_null_check_then_put() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+public class ClassA {
+    List<String> methodX(Map<String, List<String>> varOne, String varTwo) {
+        List<String> varThree = varOne.get(varTwo);
+        if (varThree == null) {
+            varThree = new ArrayList<>();
+            varOne.put(varTwo, varThree);
+        }
+        return varThree;
+    }
+
+    void methodY(Map<String, Integer> varOne, String varTwo) {
+        if (varOne.get(varTwo) == null) {
+            varOne.put(varTwo, 1);
+        }
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 2, "unexpected findings: {messages:?}");
+        assert!(messages[0].contains("com/example/ClassA.methodX"));
+        assert!(messages[1].contains("com/example/ClassA.methodY"));
+    }
+
+    #[test]
+    fn does_not_report_put_if_absent_or_put_on_other_map() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Map;
+public class ClassA {
+    void methodX(Map<String, Integer> varOne, String varTwo) {
+        varOne.putIfAbsent(varTwo, 1);
+    }
+
+    void methodY(Map<String, Integer> varOne, Map<String, Integer> varTwo, String varThree) {
+        if (varOne.get(varThree) == null) {
+            varTwo.put(varThree, 1);
+        }
+    }
+
+    void methodZ(Map<String, Integer> varOne, String varTwo) {
+        if (varOne.get(varTwo) != null) {
+            varOne.put(varTwo, 1);
+        }
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
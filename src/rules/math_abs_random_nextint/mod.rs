@@ -0,0 +1,183 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallSite, Method};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Random generators whose `nextInt()` can return `Integer.MIN_VALUE`.
+const RANDOM_OWNERS: &[&str] = &[
+    "java/util/Random",
+    "java/security/SecureRandom",
+    "java/util/concurrent/ThreadLocalRandom",
+];
+
+/// Rule that detects `Math.abs(random.nextInt())`, which can still be negative.
+#[derive(Default)]
+pub(crate) struct MathAbsRandomNextIntRule;
+
+crate::register_rule!(MathAbsRandomNextIntRule);
+
+impl Rule for MathAbsRandomNextIntRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "MATH_ABS_RANDOM_NEXTINT",
+            name: "Math.abs on Random.nextInt",
+            description: "Math.abs(Random.nextInt()) is negative when nextInt() returns Integer.MIN_VALUE",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for offset in abs_of_next_int_offsets(method) {
+                            let message = result_message(format!(
+                                "Math.abs(nextInt()) in {}.{}{} returns a negative value when nextInt() yields Integer.MIN_VALUE; use nextInt(bound) or Math.floorMod instead.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Offsets of `Math.abs(I)I` calls that directly follow a `nextInt()I` call.
+fn abs_of_next_int_offsets(method: &Method) -> Vec<u32> {
+    method
+        .calls
+        .windows(2)
+        .filter_map(|pair| {
+            let [first, second] = pair else {
+                return None;
+            };
+            if !is_next_int_call(first) || !is_math_abs_int_call(second) {
+                return None;
+            }
+            let length =
+                crate::scan::opcode_length(&method.bytecode, first.offset as usize).ok()?;
+            (first.offset + length as u32 == second.offset).then_some(second.offset)
+        })
+        .collect()
+}
+
+fn is_next_int_call(call: &CallSite) -> bool {
+    RANDOM_OWNERS.contains(&call.owner.as_str())
+        && call.name == "nextInt"
+        && call.descriptor == "()I"
+}
+
+fn is_math_abs_int_call(call: &CallSite) -> bool {
+    call.owner == "java/lang/Math" && call.name == "abs" && call.descriptor == "(I)I"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("MATH_ABS_RANDOM_NEXTINT"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_math_abs_of_random_next_int() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Random;
+public class ClassA {
+    private final Random rng = new Random();
+    int methodX(int varOne) {
+        return Math.abs(rng.nextInt()) % varOne;
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Math.abs(nextInt()) in com/example/ClassA.methodX(I)I returns a negative value when nextInt() yields Integer.MIN_VALUE; use nextInt(bound) or Math.floorMod instead."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_math_abs_of_secure_random_next_int() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.security.SecureRandom;
+public class ClassA {
+    int methodX(SecureRandom varOne) {
+        return Math.abs(varOne.nextInt());
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_math_abs_of_other_values() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Random;
+public class ClassA {
+    private int someField;
+    int methodX(Random varOne) {
+        int varTwo = varOne.nextInt();
+        return Math.abs(someField) + Math.abs(varOne.nextInt(10)) + varTwo;
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
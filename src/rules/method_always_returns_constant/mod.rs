@@ -0,0 +1,211 @@
+use std::fmt;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::Method;
+use crate::opcodes;
+use crate::rules::{
+    PrimitiveConstant, Rule, RuleMetadata, Severity, constant_return, method_location_with_line,
+    result_message,
+};
+
+/// Returns of the primitive types this rule checks; `boolean`, `byte`, `char`, and `short`
+/// methods also use `ireturn`.
+const RETURN_OPCODES: &[u8] = &[
+    opcodes::IRETURN,
+    opcodes::LRETURN,
+    opcodes::FRETURN,
+    opcodes::DRETURN,
+];
+
+/// Rule that detects methods whose several return sites all return the same primitive constant.
+#[derive(Default)]
+pub(crate) struct MethodAlwaysReturnsConstantRule;
+
+crate::register_rule!(MethodAlwaysReturnsConstantRule);
+
+impl Rule for MethodAlwaysReturnsConstantRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "METHOD_ALWAYS_RETURNS_CONSTANT",
+            name: "Method always returns constant",
+            description: "Methods whose branches all return the same primitive constant",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if !is_candidate_method(method) {
+                            continue;
+                        }
+                        // A single `return <constant>` is an ordinary constant method, not
+                        // dead branching.
+                        let Some((value, offset)) = constant_return(method, RETURN_OPCODES, 2)
+                        else {
+                            continue;
+                        };
+                        let value = ReturnedConstant {
+                            value,
+                            is_boolean: method.descriptor.ends_with(")Z"),
+                        };
+                        let message = result_message(format!(
+                            "{}.{}{} returns {value} on every path; the branches before its returns do not affect the result. Return the computed value or simplify the method.",
+                            class.name, method.name, method.descriptor
+                        ));
+                        let location = method_location_with_line(
+                            &class.name,
+                            &method.name,
+                            &method.descriptor,
+                            artifact_uri.as_deref(),
+                            method.line_for_offset(offset),
+                        );
+                        class_results.push(
+                            SarifResult::builder()
+                                .message(message)
+                                .locations(vec![location])
+                                .build(),
+                        );
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Constant formatted for the message, as `true`/`false` for `boolean` methods.
+struct ReturnedConstant {
+    value: PrimitiveConstant,
+    is_boolean: bool,
+}
+
+impl fmt::Display for ReturnedConstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            PrimitiveConstant::Int(0) if self.is_boolean => write!(f, "false"),
+            PrimitiveConstant::Int(1) if self.is_boolean => write!(f, "true"),
+            PrimitiveConstant::Int(value) => write!(f, "{value}"),
+            PrimitiveConstant::Float(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
+/// Whether `method` has a body and returns a primitive value. `compare`/`compareTo` are left to
+/// COMPARATOR_RETURNS_CONSTANT.
+fn is_candidate_method(method: &Method) -> bool {
+    if method.bytecode.is_empty()
+        || method.access.is_abstract
+        || method.access.is_synthetic
+        || method.access.is_bridge
+        || matches!(method.name.as_str(), "compare" | "compareTo")
+    {
+        return false;
+    }
+    matches!(
+        method.descriptor.rsplit_once(')').map(|(_, ret)| ret),
+        Some("Z" | "B" | "C" | "S" | "I" | "J" | "F" | "D")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("METHOD_ALWAYS_RETURNS_CONSTANT"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_boolean_method_returning_true_on_every_branch() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public boolean methodX(int varOne) {
+        if (varOne > 10) {
+            return true;
+        }
+        if (varOne < 0) {
+            return true;
+        }
+        return true;
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.methodX(I)Z returns true on every path; the branches before its returns do not affect the result. Return the computed value or simplify the method."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_getters_constants_or_distinct_returns() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    private boolean fieldOne;
+
+    public boolean methodX() {
+        return fieldOne;
+    }
+
+    public boolean methodY() {
+        return true;
+    }
+
+    public long methodZ(int varOne) {
+        if (varOne > 10) {
+            return 1L;
+        }
+        return 2L;
+    }
+
+    public boolean methodW(int varOne) {
+        if (varOne > 10) {
+            return fieldOne;
+        }
+        return true;
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -1,10 +1,12 @@
 use anyhow::Result;
 use serde_sarif::sarif::{
-    ArtifactLocation, Location, LogicalLocation, Message, PhysicalLocation, Region,
-    Result as SarifResult,
+    ArtifactLocation, CodeFlow, Location, LogicalLocation, Message, PhysicalLocation, Region,
+    Result as SarifResult, ThreadFlow, ThreadFlowLocation,
 };
 
 use crate::engine::AnalysisContext;
+use crate::ir::{Instruction, InstructionKind, Method};
+use crate::opcodes;
 
 // Rule modules are auto-discovered by build.rs — do not edit manually.
 include!(concat!(env!("OUT_DIR"), "/rule_modules.rs"));
@@ -15,12 +17,52 @@ pub(crate) struct RuleMetadata {
     pub(crate) id: &'static str,
     pub(crate) name: &'static str,
     pub(crate) description: &'static str,
+    pub(crate) severity: Severity,
+    /// Priority from 0 to 100 copied to each result's SARIF `rank`; `None` leaves it unset.
+    pub(crate) rank: Option<u8>,
+    /// Category tags published in the rule descriptor's property bag.
+    pub(crate) tags: &'static [&'static str],
+}
+
+impl RuleMetadata {
+    /// SARIF `precision` derived from the rule's rank; unranked rules report none.
+    pub(crate) fn precision(&self) -> Option<&'static str> {
+        match self.rank? {
+            80..=100 => Some("high"),
+            50..=79 => Some("medium"),
+            _ => Some("low"),
+        }
+    }
+}
+
+/// Default SARIF level reported for a rule's findings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Severity {
+    Note,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// SARIF `level` value for this severity.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Severity::Note => "note",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
 }
 
 /// Rule interface for analysis execution.
 pub(crate) trait Rule {
     fn metadata(&self) -> RuleMetadata;
     fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>>;
+
+    /// Whether the rule runs without being named in `--rules`. Opt-in rules return false.
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
 }
 
 /// Wrapper struct for rule factory functions to enable inventory collection.
@@ -106,6 +148,38 @@ pub(crate) fn method_location_with_line(
     Location::builder().logical_locations(vec![logical]).build()
 }
 
+/// Builds a single-thread code flow that visits the given bytecode offsets of a method in order.
+///
+/// Each step is an `(offset, message)` pair; the offset is mapped to its source line.
+pub(crate) fn method_code_flow(
+    class_name: &str,
+    method: &Method,
+    artifact_uri: Option<&str>,
+    steps: &[(u32, Option<&str>)],
+) -> CodeFlow {
+    let locations = steps
+        .iter()
+        .enumerate()
+        .map(|(index, (offset, message))| {
+            let mut location = method_location_with_line(
+                class_name,
+                &method.name,
+                &method.descriptor,
+                artifact_uri,
+                method.line_for_offset(*offset),
+            );
+            location.message = message.map(result_message);
+            ThreadFlowLocation::builder()
+                .location(location)
+                .execution_order(index as i64)
+                .build()
+        })
+        .collect::<Vec<_>>();
+    CodeFlow::builder()
+        .thread_flows(vec![ThreadFlow::builder().locations(locations).build()])
+        .build()
+}
+
 fn jar_container_uri(uri: &str) -> Option<String> {
     let rest = uri.strip_prefix("jar:")?;
     let container = rest.split("!/").next()?;
@@ -159,6 +233,106 @@ pub(crate) fn result_message(text: impl Into<String>) -> Message {
     Message::builder().text(text.into()).build()
 }
 
+/// Builds a result with a primary location and secondary `relatedLocations`.
+///
+/// Each related location is paired with a message describing its role and gets a
+/// sequential `id` so the primary message can refer to it.
+pub(crate) fn result_with_related_locations(
+    message: Message,
+    location: Location,
+    related: Vec<(Location, Message)>,
+) -> SarifResult {
+    let related_locations: Vec<Location> = related
+        .into_iter()
+        .enumerate()
+        .map(|(index, (mut location, message))| {
+            location.id = Some(index as i64);
+            location.message = Some(message);
+            location
+        })
+        .collect();
+    if related_locations.is_empty() {
+        return SarifResult::builder()
+            .message(message)
+            .locations(vec![location])
+            .build();
+    }
+    SarifResult::builder()
+        .message(message)
+        .locations(vec![location])
+        .related_locations(related_locations)
+        .build()
+}
+
+/// Primitive constant loaded right before a return instruction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum PrimitiveConstant {
+    Int(i64),
+    Float(f64),
+}
+
+/// Returns the constant every return in `method` with one of `return_opcodes` returns, with
+/// the offset of the first such return, when there are at least `min_return_sites` of them
+/// and all return the same constant loaded right before the return.
+pub(crate) fn constant_return(
+    method: &Method,
+    return_opcodes: &[u8],
+    min_return_sites: usize,
+) -> Option<(PrimitiveConstant, u32)> {
+    let mut returned: Option<(PrimitiveConstant, u32)> = None;
+    let mut return_sites = 0;
+    for block in &method.cfg.blocks {
+        for (index, instruction) in block.instructions.iter().enumerate() {
+            if !return_opcodes.contains(&instruction.opcode) {
+                continue;
+            }
+            // A return at the start of a block merges values from several predecessors.
+            let value = index
+                .checked_sub(1)
+                .and_then(|previous| block.instructions.get(previous))
+                .and_then(primitive_constant)?;
+            return_sites += 1;
+            match returned {
+                Some((constant, _)) if constant != value => return None,
+                Some((constant, offset)) if instruction.offset < offset => {
+                    returned = Some((constant, instruction.offset));
+                }
+                Some(_) => {}
+                None => returned = Some((value, instruction.offset)),
+            }
+        }
+    }
+    if return_sites < min_return_sites {
+        return None;
+    }
+    returned
+}
+
+fn primitive_constant(instruction: &Instruction) -> Option<PrimitiveConstant> {
+    match (instruction.opcode, &instruction.kind) {
+        (opcodes::ICONST_M1..=opcodes::ICONST_5, _) => Some(PrimitiveConstant::Int(
+            i64::from(instruction.opcode) - i64::from(opcodes::ICONST_0),
+        )),
+        (opcodes::LCONST_0 | opcodes::LCONST_1, _) => Some(PrimitiveConstant::Int(i64::from(
+            instruction.opcode - opcodes::LCONST_0,
+        ))),
+        (opcodes::FCONST_0..=opcodes::FCONST_2, _) => Some(PrimitiveConstant::Float(f64::from(
+            instruction.opcode - opcodes::FCONST_0,
+        ))),
+        (opcodes::DCONST_0 | opcodes::DCONST_1, _) => Some(PrimitiveConstant::Float(f64::from(
+            instruction.opcode - opcodes::DCONST_0,
+        ))),
+        (
+            opcodes::BIPUSH | opcodes::SIPUSH | opcodes::LDC | opcodes::LDC_W | opcodes::LDC2_W,
+            InstructionKind::ConstInt(value),
+        ) => Some(PrimitiveConstant::Int(*value)),
+        (opcodes::LDC | opcodes::LDC_W | opcodes::LDC2_W, InstructionKind::ConstFloat(value)) => {
+            Some(PrimitiveConstant::Float(*value))
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
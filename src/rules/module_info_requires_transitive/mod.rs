@@ -0,0 +1,188 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{ModuleDescriptor, ModuleRequires};
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
+
+/// Rule that flags `requires transitive` directives that do not make sense for the module.
+///
+/// Opt-in: it only runs when named in `--rules`.
+#[derive(Default)]
+pub(crate) struct ModuleInfoRequiresTransitiveRule;
+
+crate::register_rule!(ModuleInfoRequiresTransitiveRule);
+
+impl Rule for ModuleInfoRequiresTransitiveRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "MODULE_INFO_REQUIRES_TRANSITIVE",
+            name: "Questionable requires transitive",
+            description: "requires transitive should only re-export mandatory dependencies that the module's exported API exposes",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for module in context.modules() {
+            let mut attributes = vec![KeyValue::new("inspequte.module", module.name.clone())];
+            let artifact_uri = context.module_artifact_uri(module);
+            if let Some(uri) = &artifact_uri {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri.clone()));
+            }
+            let module_results = context.with_span("module", &attributes, || {
+                let mut module_results = Vec::new();
+                for requires in &module.requires {
+                    let Some(text) = finding_message(module, requires) else {
+                        continue;
+                    };
+                    let location = class_location("module-info", artifact_uri.as_deref());
+                    module_results.push(
+                        SarifResult::builder()
+                            .message(result_message(text))
+                            .locations(vec![location])
+                            .build(),
+                    );
+                }
+                module_results
+            });
+            results.extend(module_results);
+        }
+        Ok(results)
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+fn finding_message(module: &ModuleDescriptor, requires: &ModuleRequires) -> Option<String> {
+    if !requires.is_transitive || requires.is_mandated {
+        return None;
+    }
+    if requires.is_static {
+        return Some(format!(
+            "Module {} declares requires static transitive {}; modules reading {} may not find {} at run time. Drop transitive or make the dependency mandatory.",
+            module.name, requires.name, module.name, requires.name
+        ));
+    }
+    if module.exports.is_empty() {
+        return Some(format!(
+            "Module {} declares requires transitive {} but exports no packages; use a plain requires instead.",
+            module.name, requires.name
+        ));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(sources: &[(&str, &str)]) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = sources
+            .iter()
+            .map(|(path, contents)| SourceFile {
+                path: path.to_string(),
+                contents: contents.to_string(),
+            })
+            .collect::<Vec<_>>();
+        let output = harness
+            .compile_and_analyze_rules(
+                Language::Java,
+                &sources,
+                &[],
+                &["MODULE_INFO_REQUIRES_TRANSITIVE"],
+            )
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("MODULE_INFO_REQUIRES_TRANSITIVE"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    const CLASS_A: (&str, &str) = (
+        "com/example/api/ClassA.java",
+        r#"
+package com.example.api;
+public class ClassA {
+    public java.sql.Connection methodX() {
+        return null;
+    }
+}
+"#,
+    );
+
+    #[test]
+    fn reports_transitive_requires_in_module_without_exports() {
+        let messages = analyze(&[
+            (
+                "module-info.java",
+                r#"
+module com.example {
+    requires transitive java.sql;
+}
+"#,
+            ),
+            CLASS_A,
+        ]);
+
+        assert_eq!(
+            messages,
+            vec![
+                "Module com.example declares requires transitive java.sql but exports no packages; use a plain requires instead."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_static_transitive_requires() {
+        let messages = analyze(&[
+            (
+                "module-info.java",
+                r#"
+module com.example {
+    exports com.example.api;
+    requires transitive java.sql;
+    requires static transitive java.compiler;
+}
+"#,
+            ),
+            CLASS_A,
+        ]);
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(
+            messages[0].starts_with(
+                "Module com.example declares requires static transitive java.compiler;"
+            )
+        );
+    }
+
+    #[test]
+    fn does_not_report_transitive_requires_of_exporting_module() {
+        let messages = analyze(&[
+            (
+                "module-info.java",
+                r#"
+module com.example {
+    exports com.example.api;
+    requires transitive java.sql;
+    requires java.logging;
+}
+"#,
+            ),
+            CLASS_A,
+        ]);
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -0,0 +1,179 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
+
+/// Collection types whose instances can be modified through the reference alone.
+const MUTABLE_COLLECTION_TYPES: &[&str] = &[
+    "Ljava/util/ArrayList;",
+    "Ljava/util/Collection;",
+    "Ljava/util/Deque;",
+    "Ljava/util/HashMap;",
+    "Ljava/util/HashSet;",
+    "Ljava/util/Hashtable;",
+    "Ljava/util/LinkedHashMap;",
+    "Ljava/util/LinkedHashSet;",
+    "Ljava/util/LinkedList;",
+    "Ljava/util/List;",
+    "Ljava/util/Map;",
+    "Ljava/util/NavigableMap;",
+    "Ljava/util/NavigableSet;",
+    "Ljava/util/Queue;",
+    "Ljava/util/Set;",
+    "Ljava/util/SortedMap;",
+    "Ljava/util/SortedSet;",
+    "Ljava/util/TreeMap;",
+    "Ljava/util/TreeSet;",
+    "Ljava/util/Vector;",
+];
+
+/// Rule that flags `public static` fields any code can reassign or modify.
+#[derive(Default)]
+pub(crate) struct MutablePublicStaticFieldRule;
+
+crate::register_rule!(MutablePublicStaticFieldRule);
+
+impl Rule for MutablePublicStaticFieldRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "MUTABLE_PUBLIC_STATIC_FIELD",
+            name: "Mutable public static field",
+            description: "Public static fields should be final and hold immutable values",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["security"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for field in &class.fields {
+                        if !field.access.is_public
+                            || !field.access.is_static
+                            || field.access.is_synthetic
+                        {
+                            continue;
+                        }
+                        let problem = if field.descriptor.starts_with('[') {
+                            "holds an array whose elements any caller can change"
+                        } else if MUTABLE_COLLECTION_TYPES.contains(&field.descriptor.as_str()) {
+                            "holds a collection any caller can modify"
+                        } else if !field.access.is_final {
+                            "is not final, so any caller can reassign it"
+                        } else {
+                            continue;
+                        };
+                        let message = result_message(format!(
+                            "Public static field {}.{} {problem}; make it private and expose an accessor or an unmodifiable copy instead.",
+                            class.name, field.name
+                        ));
+                        let location = class_location(&class.name, artifact_uri.as_deref());
+                        class_results.push(
+                            SarifResult::builder()
+                                .message(message)
+                                .locations(vec![location])
+                                .build(),
+                        );
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("MUTABLE_PUBLIC_STATIC_FIELD"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_public_static_final_array() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public static final int[] A = {1, 2, 3};
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Public static field com/example/ClassA.A holds an array whose elements any caller can change; make it private and expose an accessor or an unmodifiable copy instead."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_public_static_final_list_and_non_final_field() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.ArrayList;
+import java.util.List;
+public class ClassA {
+    public static final List<String> C = new ArrayList<>();
+    public static String fieldD = "value";
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 2, "unexpected findings: {messages:?}");
+        assert!(messages.iter().any(|message| message.starts_with(
+            "Public static field com/example/ClassA.C holds a collection any caller can modify;"
+        )));
+        assert!(messages.iter().any(|message| message.starts_with(
+            "Public static field com/example/ClassA.fieldD is not final, so any caller can reassign it;"
+        )));
+    }
+
+    #[test]
+    fn does_not_report_final_immutable_or_non_public_fields() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.List;
+public class ClassA {
+    public static final int B = 1;
+    public static final String fieldE = "value";
+    private static final int[] fieldF = {1};
+    static List<String> fieldG;
+    public final int[] fieldH = {1};
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -10,7 +10,7 @@ use crate::descriptor::{ReturnKind, method_return_kind};
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, CallSite, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects mutations applied to known-unmodifiable collections.
 #[derive(Default)]
@@ -24,6 +24,9 @@ impl Rule for MutateUnmodifiableCollectionRule {
             id: "MUTATE_UNMODIFIABLE_COLLECTION",
             name: "Mutation on unmodifiable collection",
             description: "Mutation calls on known JDK unmodifiable collection values",
+            severity: Severity::Error,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -0,0 +1,198 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{Class, Field};
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
+
+/// Rule that flags instance fields of Serializable classes whose type is not Serializable.
+#[derive(Default)]
+pub(crate) struct NonserializableFieldInSerializableClassRule;
+
+crate::register_rule!(NonserializableFieldInSerializableClassRule);
+
+impl Rule for NonserializableFieldInSerializableClassRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "NONSERIALIZABLE_FIELD_IN_SERIALIZABLE_CLASS",
+            name: "Non-serializable field in Serializable class",
+            description: "Instance fields of Serializable classes should be Serializable or transient",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    if !uses_default_serialization(class, context) {
+                        return Ok(class_results);
+                    }
+                    for field in &class.fields {
+                        let Some(type_name) = nonserializable_field_type(field, context) else {
+                            continue;
+                        };
+                        let message = result_message(format!(
+                            "Field {}.{} of a Serializable class has non-serializable type {}; mark it transient or make {} implement Serializable.",
+                            class.name, field.name, type_name, type_name
+                        ));
+                        let artifact_uri = context.class_artifact_uri(class);
+                        let location = class_location(&class.name, artifact_uri.as_deref());
+                        class_results.push(
+                            SarifResult::builder()
+                                .message(message)
+                                .locations(vec![location])
+                                .build(),
+                        );
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns true for Serializable classes whose instance fields are written by default
+/// serialization, which excludes enums and Externalizable classes.
+fn uses_default_serialization(class: &Class, context: &AnalysisContext) -> bool {
+    class.super_name.as_deref() != Some("java/lang/Enum")
+        && context.is_subtype_of(&class.name, "java/io/Serializable")
+        && !context.is_subtype_of(&class.name, "java/io/Externalizable")
+}
+
+/// Declared type of `field` when it is a concrete scanned class that is not Serializable.
+fn nonserializable_field_type<'a>(
+    field: &'a Field,
+    context: &AnalysisContext,
+) -> Option<&'a str> {
+    if field.access.is_static || field.access.is_transient || field.access.is_synthetic {
+        return None;
+    }
+    let type_name = field.descriptor.strip_prefix('L')?.strip_suffix(';')?;
+    if type_name == "java/lang/Object" {
+        return None;
+    }
+    let declared = context.class_by_name(type_name)?;
+    // Interfaces and abstract classes may still be implemented by serializable classes.
+    let is_concrete = declared
+        .methods
+        .iter()
+        .any(|method| method.name == "<init>")
+        && !declared
+            .methods
+            .iter()
+            .any(|method| method.access.is_abstract);
+    if !is_concrete || context.is_subtype_of(type_name, "java/io/Serializable") {
+        return None;
+    }
+    Some(type_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| {
+                result.rule_id.as_deref() == Some("NONSERIALIZABLE_FIELD_IN_SERIALIZABLE_CLASS")
+            })
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_field_of_nonserializable_scanned_type() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+import java.io.Serializable;
+
+public class ClassA implements Serializable {
+    private ClassB varOne = new ClassB();
+    private String varTwo = "tmpValue";
+}
+
+class ClassB {
+    int varThree;
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].contains("com/example/ClassA.varOne"));
+        assert!(messages[0].contains("com/example/ClassB"));
+    }
+
+    #[test]
+    fn does_not_report_transient_or_static_fields() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+import java.io.Serializable;
+
+public class ClassA implements Serializable {
+    private transient ClassB varOne = new ClassB();
+    private static ClassB varTwo = new ClassB();
+}
+
+class ClassB {
+    int varThree;
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_field_of_serializable_type() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+import java.io.Serializable;
+import java.util.ArrayList;
+
+public class ClassA implements Serializable {
+    private ClassB varOne = new ClassB();
+    private ClassC varTwo = new ClassC();
+}
+
+class ClassB extends ArrayList<String> {
+}
+
+class ClassC extends ClassD {
+}
+
+class ClassD implements Serializable {
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
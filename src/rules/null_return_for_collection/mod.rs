@@ -0,0 +1,401 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::dataflow::worklist::{
+    BlockEndStep, InstructionStep, WorklistConfig, WorklistSemantics, WorklistState,
+    analyze_method_with_config,
+};
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, Instruction, InstructionKind, Method, Nullness};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Collection types for which an empty instance is the conventional "no result".
+const COLLECTION_RETURN_TYPES: &[&str] = &[
+    "java/lang/Iterable",
+    "java/util/Collection",
+    "java/util/Deque",
+    "java/util/List",
+    "java/util/Map",
+    "java/util/NavigableMap",
+    "java/util/NavigableSet",
+    "java/util/Queue",
+    "java/util/Set",
+    "java/util/SortedMap",
+    "java/util/SortedSet",
+];
+
+/// Rule that detects `return null` from methods returning arrays or collections.
+#[derive(Default)]
+pub(crate) struct NullReturnForCollectionRule;
+
+crate::register_rule!(NullReturnForCollectionRule);
+
+impl Rule for NullReturnForCollectionRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "NULL_RETURN_FOR_COLLECTION",
+            name: "Null return for array or collection",
+            description: "Methods returning arrays or collections should return an empty instance instead of null",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        let Some(kind) = empty_return_kind(&method.descriptor) else {
+                            continue;
+                        };
+                        if method.bytecode.is_empty()
+                            || method.access.is_synthetic
+                            || method.nullness.return_nullness == Nullness::Nullable
+                        {
+                            continue;
+                        }
+                        for offset in null_return_offsets(method)? {
+                            let message = result_message(format!(
+                                "{}.{}{} returns null instead of an empty {kind}; return an empty {kind} so callers need no null check.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns "array" or "collection" when the descriptor's return type has an empty form.
+fn empty_return_kind(descriptor: &str) -> Option<&'static str> {
+    let (_, return_type) = descriptor.rsplit_once(')')?;
+    if return_type.starts_with('[') {
+        return Some("array");
+    }
+    let class_name = return_type.strip_prefix('L')?.strip_suffix(';')?;
+    COLLECTION_RETURN_TYPES
+        .contains(&class_name)
+        .then_some("collection")
+}
+
+/// Offsets of `areturn` instructions that return a definite `null` on some path.
+fn null_return_offsets(method: &Method) -> Result<Vec<u32>> {
+    let outcome =
+        analyze_method_with_config(method, &NullReturnSemantics, WorklistConfig::from_env())?;
+    if outcome.aborted {
+        return Ok(Vec::new());
+    }
+    Ok(outcome
+        .findings
+        .into_iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect())
+}
+
+/// Whether a reference is the `null` constant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum Value {
+    Null,
+    Other,
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct NullValueDomain;
+
+impl ValueDomain<Value> for NullValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Symbolic execution state at a specific instruction position.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct ExecutionState {
+    block_start: u32,
+    instruction_index: usize,
+    machine: StackMachine<Value>,
+}
+
+impl WorklistState for ExecutionState {
+    fn block_start(&self) -> u32 {
+        self.block_start
+    }
+
+    fn instruction_index(&self) -> usize {
+        self.instruction_index
+    }
+
+    fn set_position(&mut self, block_start: u32, instruction_index: usize) {
+        self.block_start = block_start;
+        self.instruction_index = instruction_index;
+    }
+}
+
+/// Dataflow callbacks that track `aconst_null` through the stack and locals.
+struct NullReturnSemantics;
+
+impl WorklistSemantics for NullReturnSemantics {
+    type State = ExecutionState;
+    type Finding = u32;
+
+    fn initial_states(&self, _method: &Method) -> Vec<Self::State> {
+        vec![ExecutionState {
+            block_start: 0,
+            instruction_index: 0,
+            machine: StackMachine::new(Value::Other),
+        }]
+    }
+
+    fn transfer_instruction(
+        &self,
+        method: &Method,
+        instruction: &Instruction,
+        state: &mut Self::State,
+    ) -> Result<InstructionStep<Self::Finding>> {
+        match instruction.opcode {
+            opcodes::ARETURN => {
+                let step = InstructionStep::terminate_path();
+                if state.machine.pop() == Value::Null {
+                    return Ok(step.with_finding(instruction.offset));
+                }
+                return Ok(step);
+            }
+            opcodes::IRETURN
+            | opcodes::LRETURN
+            | opcodes::FRETURN
+            | opcodes::DRETURN
+            | opcodes::RETURN
+            | opcodes::ATHROW => return Ok(InstructionStep::terminate_path()),
+            _ => {}
+        }
+
+        let _ = apply_semantics(
+            &mut state.machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &NullValueDomain,
+            &mut NullSemanticsHook,
+            &mut SemanticsCoverage::default(),
+            SemanticsDebugConfig {
+                enabled: false,
+                rule_id: "NULL_RETURN_FOR_COLLECTION",
+            },
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => {
+                state.machine.pop_n(method_param_count(&call.descriptor)?);
+                if call.kind != CallKind::Static {
+                    state.machine.pop();
+                }
+                if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+                    state.machine.push(Value::Other);
+                }
+            }
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                state.machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    state.machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+        Ok(InstructionStep::continue_path())
+    }
+
+    fn on_block_end(
+        &self,
+        _method: &Method,
+        state: &Self::State,
+        successors: &[u32],
+    ) -> Result<BlockEndStep<Self::State, Self::Finding>> {
+        Ok(BlockEndStep::follow_all_successors(state, successors))
+    }
+}
+
+/// Rule-specific hook that marks `aconst_null` results.
+struct NullSemanticsHook;
+
+impl SemanticsHooks<Value> for NullSemanticsHook {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        _method: &Method,
+        _offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        if opcode == opcodes::ACONST_NULL {
+            machine.push(Value::Null);
+            return ApplyOutcome::Applied;
+        }
+        ApplyOutcome::NotHandled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(sources: &[SourceFile]) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("NULL_RETURN_FOR_COLLECTION"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    fn source(contents: &str) -> Vec<SourceFile> {
+        vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }]
+    }
+
+    #[test]
+    fn reports_null_return_from_list_method() {
+        let messages = analyze(&source(
+            r#"
+package com.example;
+import java.util.List;
+public class ClassA {
+    public List<String> methodX() {
+        return null;
+    }
+}
+"#,
+        ));
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.methodX()Ljava/util/List; returns null instead of an empty collection; return an empty collection so callers need no null check."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_null_stored_in_local_on_one_path_of_array_method() {
+        let messages = analyze(&source(
+            r#"
+package com.example;
+public class ClassA {
+    public String[] methodX(boolean varOne) {
+        String[] tmpValue = null;
+        if (varOne) {
+            tmpValue = new String[] {"a"};
+        }
+        return tmpValue;
+    }
+}
+"#,
+        ));
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].contains("returns null instead of an empty array"));
+    }
+
+    #[test]
+    fn does_not_report_empty_collection_return() {
+        let messages = analyze(&source(
+            r#"
+package com.example;
+import java.util.Collections;
+import java.util.List;
+public class ClassA {
+    public List<String> methodX() {
+        return Collections.emptyList();
+    }
+    public Object methodY() {
+        return null;
+    }
+}
+"#,
+        ));
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_nullable_annotated_return() {
+        let sources = vec![
+            SourceFile {
+                path: "org/jspecify/annotations/Nullable.java".to_string(),
+                contents: r#"
+package org.jspecify.annotations;
+import java.lang.annotation.ElementType;
+import java.lang.annotation.Retention;
+import java.lang.annotation.RetentionPolicy;
+import java.lang.annotation.Target;
+@Retention(RetentionPolicy.RUNTIME)
+@Target({ElementType.TYPE_USE, ElementType.TYPE_PARAMETER})
+public @interface Nullable {}
+"#
+                .to_string(),
+            },
+            SourceFile {
+                path: "com/example/ClassA.java".to_string(),
+                contents: r#"
+package com.example;
+import java.util.List;
+import org.jspecify.annotations.Nullable;
+public class ClassA {
+    public @Nullable List<String> methodX() {
+        return null;
+    }
+}
+"#
+                .to_string(),
+            },
+        ];
+
+        let messages = analyze(&sources);
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -10,7 +10,7 @@ use crate::descriptor::{
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, Class, ClassTypeUse, Method, Nullness, TypeUse, TypeUseKind};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 // TODO: refer Checkerframework stubs or something like it to handle nullness of standard APIs
 
@@ -26,19 +26,13 @@ impl Rule for NullnessRule {
             id: "NULLNESS",
             name: "Nullness checks",
             description: "Nullness issues guided by JSpecify annotations",
+            severity: Severity::Error,
+            rank: Some(80),
+            tags: &["correctness"],
         }
     }
 
     fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
-        let preprocess_attributes = [KeyValue::new("inspequte.phase", "preprocess")];
-        let class_map = context.with_span("nullness.preprocess", &preprocess_attributes, || {
-            let mut class_map = BTreeMap::new();
-            for class in context.all_classes() {
-                class_map.insert(class.name.clone(), class);
-            }
-            class_map
-        });
-
         let analyze_attributes = [KeyValue::new("inspequte.phase", "analyze")];
         let results = context.with_span("nullness.analyze", &analyze_attributes, || {
             let mut results = Vec::new();
@@ -53,7 +47,7 @@ impl Rule for NullnessRule {
                         let mut class_results = Vec::new();
                         let override_results =
                             context.with_span("nullness.override_check", &[], || {
-                                check_overrides(class, &class_map)
+                                check_overrides(class, context)
                             });
                         class_results.extend(override_results);
 
@@ -74,7 +68,6 @@ impl Rule for NullnessRule {
                                         context,
                                         class,
                                         method,
-                                        &class_map,
                                         artifact_uri.as_deref(),
                                     )?);
                                 }
@@ -137,9 +130,9 @@ fn result_dedup_key(result: &SarifResult) -> (String, String, i64, String) {
     (message, artifact_uri, line, logical)
 }
 
-fn check_overrides(class: &Class, class_map: &BTreeMap<String, &Class>) -> Vec<SarifResult> {
+fn check_overrides(class: &Class, context: &AnalysisContext) -> Vec<SarifResult> {
     let mut results = Vec::new();
-    let supertypes = collect_supertypes(class, class_map);
+    let supertypes = collect_supertypes(class, context);
     for method in &class.methods {
         for super_class in &supertypes {
             let Some(base_method) = find_method(super_class, &method.name, &method.descriptor)
@@ -358,7 +351,7 @@ fn class_type_use_conflict(
 
 fn collect_supertypes<'a>(
     class: &'a Class,
-    class_map: &'a BTreeMap<String, &'a Class>,
+    context: &'a AnalysisContext,
 ) -> Vec<&'a Class> {
     let mut result = Vec::new();
     let mut seen = BTreeSet::new();
@@ -373,10 +366,10 @@ fn collect_supertypes<'a>(
         if !seen.insert(name.clone()) {
             continue;
         }
-        let Some(super_class) = class_map.get(&name) else {
+        let Some(super_class) = context.class_by_name(&name) else {
             continue;
         };
-        result.push(*super_class);
+        result.push(super_class);
         if let Some(super_name) = &super_class.super_name {
             queue.push_back(super_name.clone());
         }
@@ -398,7 +391,6 @@ fn check_method_flow(
     context: &AnalysisContext,
     class: &Class,
     method: &Method,
-    class_map: &BTreeMap<String, &Class>,
     artifact_uri: Option<&str>,
 ) -> Result<Vec<SarifResult>> {
     let method_attributes = [
@@ -410,7 +402,7 @@ fn check_method_flow(
     ];
     let (call_infos, call_index_by_offset, entry_state, block_map, predecessors, successors) =
         context.with_span("nullness.method_preprocess", &method_attributes, || {
-            let call_infos = build_method_call_infos(method, class_map)?;
+            let call_infos = build_method_call_infos(method, context)?;
             let call_index_by_offset = build_callsite_index_by_offset(method, &call_infos);
 
             let local_count = local_count(method)?;
@@ -625,7 +617,7 @@ impl BranchRefinement {
 
 fn build_method_call_infos<'a>(
     method: &'a Method,
-    class_map: &'a BTreeMap<String, &'a Class>,
+    context: &'a AnalysisContext,
 ) -> Result<Vec<MethodCallInfo<'a>>> {
     let mut infos = Vec::with_capacity(method.calls.len());
     let mut descriptor_cache: HashMap<&str, CallDescriptorInfo> = HashMap::new();
@@ -647,7 +639,7 @@ fn build_method_call_infos<'a>(
         infos.push(MethodCallInfo {
             call,
             descriptor,
-            target: resolve_call_target(class_map, call),
+            target: resolve_call_target(context, call),
         });
     }
     Ok(infos)
@@ -668,10 +660,10 @@ fn build_callsite_index_by_offset(
 }
 
 fn resolve_call_target<'a>(
-    class_map: &'a BTreeMap<String, &'a Class>,
+    context: &'a AnalysisContext,
     call: &crate::ir::CallSite,
 ) -> Option<ResolvedCallTarget<'a>> {
-    let class = class_map.get(&call.owner).copied()?;
+    let class = context.class_by_name(&call.owner)?;
     let method = class
         .methods
         .iter()
@@ -1252,6 +1244,8 @@ mod tests {
             annotation_defaults: Vec::new(),
             artifact_index: 0,
             is_record: false,
+            is_final: false,
+            content_hash: String::new(),
         }
     }
 
@@ -1393,9 +1387,11 @@ public @interface NullnessUnspecified {}
             signature: None,
             access: MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -1422,9 +1418,11 @@ public @interface NullnessUnspecified {}
             signature: None,
             access: MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -1468,9 +1466,11 @@ public @interface NullnessUnspecified {}
             signature: None,
             access: MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -1497,9 +1497,11 @@ public @interface NullnessUnspecified {}
             signature: None,
             access: MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -1542,9 +1544,11 @@ public @interface NullnessUnspecified {}
             "()Ljava/lang/String;",
             MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -1584,9 +1588,11 @@ public @interface NullnessUnspecified {}
             "(Ljava/lang/Object;)V",
             MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: true,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -1643,9 +1649,11 @@ public @interface NullnessUnspecified {}
             "()Ljava/lang/Object;",
             MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -1671,8 +1679,7 @@ public @interface NullnessUnspecified {}
             class_bound: None,
             interface_bounds: Vec::new(),
         }];
-        let mut class_map = BTreeMap::new();
-        class_map.insert(callee_class.name.clone(), &callee_class);
+        let context = build_context(vec![callee_class], &[]);
         let call = CallSite {
             owner: "com/example/ClassB".to_string(),
             name: "methodOne".to_string(),
@@ -1680,7 +1687,7 @@ public @interface NullnessUnspecified {}
             kind: CallKind::Virtual,
             offset: 0,
         };
-        let target = resolve_call_target(&class_map, &call);
+        let target = resolve_call_target(&context, &call);
         let receiver = StackValue {
             nullness: Nullness::NonNull,
             type_use: Some(TypeUse {
@@ -1722,9 +1729,11 @@ public @interface NullnessUnspecified {}
             "()Ljava/lang/Object;",
             MethodAccess {
                 is_public: true,
+                is_private: false,
                 is_static: false,
                 is_synchronized: false,
                 is_abstract: false,
+                is_final: false,
                 is_synthetic: false,
                 is_bridge: false,
             },
@@ -1750,8 +1759,7 @@ public @interface NullnessUnspecified {}
             class_bound: None,
             interface_bounds: Vec::new(),
         }];
-        let mut class_map = BTreeMap::new();
-        class_map.insert(callee_class.name.clone(), &callee_class);
+        let context = build_context(vec![callee_class], &[]);
         let call = CallSite {
             owner: "com/example/ClassB".to_string(),
             name: "methodOne".to_string(),
@@ -1759,7 +1767,7 @@ public @interface NullnessUnspecified {}
             kind: CallKind::Virtual,
             offset: 0,
         };
-        let target = resolve_call_target(&class_map, &call);
+        let target = resolve_call_target(&context, &call);
 
         let (return_nullness, return_type_use) =
             lookup_return_value(target.as_ref(), call.kind, None);
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects timeout-free Object.wait calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for ObjectWaitWithoutTimeoutRule {
             id: "OBJECT_WAIT_WITHOUT_TIMEOUT",
             name: "Object.wait without timeout",
             description: "Timeout-free Object.wait calls can block indefinitely",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency"],
         }
     }
 
@@ -0,0 +1,199 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::descriptor::method_param_class_names;
+use crate::engine::AnalysisContext;
+use crate::rules::{
+    Rule, RuleMetadata, Severity, class_location, method_location_with_line, result_message,
+};
+
+/// Optional types intended only as method return types.
+const OPTIONAL_TYPES: &[&str] = &[
+    "java/util/Optional",
+    "java/util/OptionalDouble",
+    "java/util/OptionalInt",
+    "java/util/OptionalLong",
+];
+
+/// Rule that flags `Optional` used as a method parameter or field type.
+#[derive(Default)]
+pub(crate) struct OptionalAsParameterOrFieldRule;
+
+crate::register_rule!(OptionalAsParameterOrFieldRule);
+
+impl Rule for OptionalAsParameterOrFieldRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "OPTIONAL_AS_PARAMETER_OR_FIELD",
+            name: "Optional as parameter or field",
+            description: "Optional is meant for return types and should not be used for parameters or fields",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for field in &class.fields {
+                        if field.access.is_synthetic {
+                            continue;
+                        }
+                        let Some(optional_type) = optional_type_of(&field.descriptor) else {
+                            continue;
+                        };
+                        let message = result_message(format!(
+                            "Field {}.{} has type {optional_type}; store the plain value and expose an Optional from a getter instead.",
+                            class.name, field.name
+                        ));
+                        let location = class_location(&class.name, artifact_uri.as_deref());
+                        class_results.push(
+                            SarifResult::builder()
+                                .message(message)
+                                .locations(vec![location])
+                                .build(),
+                        );
+                    }
+                    for method in &class.methods {
+                        if method.access.is_synthetic || method.access.is_bridge {
+                            continue;
+                        }
+                        let optional_params = method_param_class_names(&method.descriptor)?
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, name)| {
+                                name.as_deref()
+                                    .is_some_and(|name| OPTIONAL_TYPES.contains(&name))
+                            })
+                            .map(|(index, _)| (index + 1).to_string())
+                            .collect::<Vec<_>>();
+                        if optional_params.is_empty() {
+                            continue;
+                        }
+                        let message = result_message(format!(
+                            "{}.{}{} takes Optional as parameter {}; accept the plain value or add an overload instead.",
+                            class.name,
+                            method.name,
+                            method.descriptor,
+                            optional_params.join(", ")
+                        ));
+                        let location = method_location_with_line(
+                            &class.name,
+                            &method.name,
+                            &method.descriptor,
+                            artifact_uri.as_deref(),
+                            method.line_for_offset(0),
+                        );
+                        class_results.push(
+                            SarifResult::builder()
+                                .message(message)
+                                .locations(vec![location])
+                                .build(),
+                        );
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns the Optional class name when `descriptor` is a field descriptor of an Optional type.
+fn optional_type_of(descriptor: &str) -> Option<&str> {
+    let name = descriptor.strip_prefix('L')?.strip_suffix(';')?;
+    OPTIONAL_TYPES.contains(&name).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("OPTIONAL_AS_PARAMETER_OR_FIELD"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_optional_parameter() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Optional;
+public class ClassA {
+    void methodX(Optional<String> varOne) {
+        varOne.ifPresent(System.out::println);
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.methodX(Ljava/util/Optional;)V takes Optional as parameter 1; accept the plain value or add an overload instead."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_optional_fields_including_primitive_specializations() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Optional;
+import java.util.OptionalInt;
+public class ClassA {
+    private Optional<String> fieldA = Optional.empty();
+    private OptionalInt fieldB = OptionalInt.empty();
+    private String fieldC = "";
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 2, "unexpected findings: {messages:?}");
+        assert!(messages.iter().any(|message| {
+            message.starts_with("Field com/example/ClassA.fieldB has type java/util/OptionalInt;")
+        }));
+    }
+
+    #[test]
+    fn does_not_report_optional_return_type() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Optional;
+public class ClassA {
+    Optional<String> methodX(String varOne) {
+        return Optional.ofNullable(varOne);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
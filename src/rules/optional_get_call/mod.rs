@@ -1,13 +1,13 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeSet;
 
 use anyhow::Result;
 use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
+use crate::dataflow::local_guard::guarded_call_offsets;
 use crate::engine::AnalysisContext;
 use crate::ir::{CallSite, Method};
-use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct getter calls on Optional APIs.
 #[derive(Default)]
@@ -21,6 +21,9 @@ impl Rule for OptionalGetCallRule {
             id: "OPTIONAL_GET_CALL",
             name: "Optional direct getter call",
             description: "Optional.get/getAs* can throw when empty",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -81,142 +84,22 @@ fn is_optional_getter_call(owner: &str, name: &str, descriptor: &str) -> bool {
     )
 }
 
-/// Bytecode instruction metadata needed for local guard tracking.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-struct BytecodeInstruction {
-    offset: u32,
-    opcode: u8,
-    length: usize,
-}
-
-/// Bytecode range where an Optional local is guaranteed non-empty.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-struct NonEmptyGuardRange {
-    start_offset: u32,
-    end_offset: u32,
-    local_index: usize,
-}
-
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum PresenceCheckKind {
-    IsPresent,
-    IsEmpty,
-}
-
 fn guarded_optional_getter_offsets(method: &Method) -> Result<BTreeSet<u32>> {
-    let instructions = collect_instructions(method)?;
-    let offset_to_instruction_index: BTreeMap<u32, usize> = instructions
-        .iter()
-        .enumerate()
-        .map(|(index, instruction)| (instruction.offset, index))
-        .collect();
-    let guard_ranges = collect_non_empty_guard_ranges(method, &instructions)?;
-
-    let mut guarded_offsets = BTreeSet::new();
-    for call in &method.calls {
-        if !is_optional_getter_call(&call.owner, &call.name, &call.descriptor) {
-            continue;
-        }
-        let Some(instruction_index) = offset_to_instruction_index.get(&call.offset).copied() else {
-            continue;
-        };
-        let Some(local_index) = receiver_local_index(method, &instructions, instruction_index)
-        else {
-            continue;
-        };
-        let guarded = guard_ranges.iter().any(|range| {
-            range.local_index == local_index
-                && call.offset >= range.start_offset
-                && call.offset < range.end_offset
-                && !has_store_to_local_between(
-                    method,
-                    &instructions,
-                    local_index,
-                    range.start_offset,
-                    call.offset,
-                )
-        });
-        if guarded {
-            guarded_offsets.insert(call.offset);
-        }
-    }
-
-    Ok(guarded_offsets)
-}
-
-fn collect_instructions(method: &Method) -> Result<Vec<BytecodeInstruction>> {
-    let mut instructions = Vec::new();
-    let mut offset = 0usize;
-    while offset < method.bytecode.len() {
-        let opcode = method.bytecode[offset];
-        let length = crate::scan::opcode_length(&method.bytecode, offset)?;
-        instructions.push(BytecodeInstruction {
-            offset: offset as u32,
-            opcode,
-            length,
-        });
-        offset += length;
-    }
-    Ok(instructions)
-}
-
-fn collect_non_empty_guard_ranges(
-    method: &Method,
-    instructions: &[BytecodeInstruction],
-) -> Result<Vec<NonEmptyGuardRange>> {
-    let calls_by_offset: BTreeMap<u32, &CallSite> = method
-        .calls
-        .iter()
-        .map(|call| (call.offset, call))
-        .collect();
-    let mut ranges = Vec::new();
-    for (index, instruction) in instructions.iter().enumerate() {
-        if !is_invoke_opcode(instruction.opcode) {
-            continue;
-        }
-        let Some(call) = calls_by_offset.get(&instruction.offset).copied() else {
-            continue;
-        };
-        let Some(kind) = optional_presence_check_kind(call) else {
-            continue;
-        };
-        let Some(local_index) = receiver_local_index(method, instructions, index) else {
-            continue;
-        };
-        let Some(branch) = instructions.get(index + 1) else {
-            continue;
-        };
-        let Some(target_offset) = conditional_branch_target(&method.bytecode, branch)? else {
-            continue;
-        };
-        if let Some(range) = fallthrough_non_empty_guard_range(kind, branch, target_offset) {
-            ranges.push(NonEmptyGuardRange {
-                start_offset: range.0,
-                end_offset: range.1,
-                local_index,
-            });
-        }
-    }
-    Ok(ranges)
-}
-
-fn is_invoke_opcode(opcode: u8) -> bool {
-    matches!(
-        opcode,
-        opcodes::INVOKEVIRTUAL
-            | opcodes::INVOKESPECIAL
-            | opcodes::INVOKEINTERFACE
-            | opcodes::INVOKESTATIC
+    guarded_call_offsets(
+        method,
+        |call| is_optional_getter_call(&call.owner, &call.name, &call.descriptor),
+        optional_presence_check_polarity,
     )
 }
 
-fn optional_presence_check_kind(call: &CallSite) -> Option<PresenceCheckKind> {
+/// Returns whether a `true` result of an Optional presence check means the value is present.
+fn optional_presence_check_polarity(call: &CallSite) -> Option<bool> {
     if call.descriptor != "()Z" || !is_optional_owner(&call.owner) {
         return None;
     }
     match call.name.as_str() {
-        "isPresent" => Some(PresenceCheckKind::IsPresent),
-        "isEmpty" => Some(PresenceCheckKind::IsEmpty),
+        "isPresent" => Some(true),
+        "isEmpty" => Some(false),
         _ => None,
     }
 }
@@ -231,107 +114,6 @@ fn is_optional_owner(owner: &str) -> bool {
     )
 }
 
-fn receiver_local_index(
-    method: &Method,
-    instructions: &[BytecodeInstruction],
-    instruction_index: usize,
-) -> Option<usize> {
-    let previous = instructions.get(instruction_index.checked_sub(1)?)?;
-    aload_local_index(&method.bytecode, previous)
-}
-
-fn aload_local_index(code: &[u8], instruction: &BytecodeInstruction) -> Option<usize> {
-    match instruction.opcode {
-        opcodes::ALOAD => code
-            .get(instruction.offset as usize + 1)
-            .copied()
-            .map(usize::from),
-        opcodes::ALOAD_0..=opcodes::ALOAD_3 => {
-            Some((instruction.opcode - opcodes::ALOAD_0) as usize)
-        }
-        0xc4 => {
-            if code.get(instruction.offset as usize + 1).copied() != Some(opcodes::ALOAD) {
-                return None;
-            }
-            crate::scan::read_u16(code, instruction.offset as usize + 2)
-                .ok()
-                .map(usize::from)
-        }
-        _ => None,
-    }
-}
-
-fn astore_local_index(code: &[u8], instruction: &BytecodeInstruction) -> Option<usize> {
-    match instruction.opcode {
-        opcodes::ASTORE => code
-            .get(instruction.offset as usize + 1)
-            .copied()
-            .map(usize::from),
-        opcodes::ASTORE_0..=opcodes::ASTORE_3 => {
-            Some((instruction.opcode - opcodes::ASTORE_0) as usize)
-        }
-        0xc4 => {
-            if code.get(instruction.offset as usize + 1).copied() != Some(opcodes::ASTORE) {
-                return None;
-            }
-            crate::scan::read_u16(code, instruction.offset as usize + 2)
-                .ok()
-                .map(usize::from)
-        }
-        _ => None,
-    }
-}
-
-fn conditional_branch_target(
-    code: &[u8],
-    instruction: &BytecodeInstruction,
-) -> Result<Option<u32>> {
-    if !matches!(instruction.opcode, opcodes::IFEQ | opcodes::IFNE) {
-        return Ok(None);
-    }
-    let branch = crate::scan::read_u16(code, instruction.offset as usize + 1)?;
-    let branch = i16::from_be_bytes(branch.to_be_bytes()) as i32;
-    let target = instruction.offset as i32 + branch;
-    if target < 0 {
-        return Ok(None);
-    }
-    Ok(Some(target as u32))
-}
-
-fn fallthrough_non_empty_guard_range(
-    kind: PresenceCheckKind,
-    branch: &BytecodeInstruction,
-    branch_target: u32,
-) -> Option<(u32, u32)> {
-    let non_empty_on_fallthrough = matches!(
-        (kind, branch.opcode),
-        (PresenceCheckKind::IsPresent, opcodes::IFEQ) | (PresenceCheckKind::IsEmpty, opcodes::IFNE)
-    );
-    if !non_empty_on_fallthrough {
-        return None;
-    }
-
-    let start_offset = branch.offset + branch.length as u32;
-    if start_offset >= branch_target {
-        return None;
-    }
-    Some((start_offset, branch_target))
-}
-
-fn has_store_to_local_between(
-    method: &Method,
-    instructions: &[BytecodeInstruction],
-    local_index: usize,
-    start_offset: u32,
-    end_offset: u32,
-) -> bool {
-    instructions
-        .iter()
-        .filter(|instruction| instruction.offset >= start_offset && instruction.offset < end_offset)
-        .filter_map(|instruction| astore_local_index(&method.bytecode, instruction))
-        .any(|stored| stored == local_index)
-}
-
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -1,12 +1,12 @@
-use std::collections::BTreeSet;
-
 use anyhow::Result;
 use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
 use crate::ir::{Class, Method};
-use crate::rules::{Rule, RuleMetadata, class_location, method_location_with_line, result_message};
+use crate::rules::{
+    Rule, RuleMetadata, Severity, class_location, method_location_with_line, result_message,
+};
 
 const TARGET_COLLECTION_TYPES: [&str; 5] = [
     "java/util/Set",
@@ -28,15 +28,13 @@ impl Rule for PreferEnumSetRule {
             id: "PREFER_ENUMSET",
             name: "Prefer EnumSet for enum collections",
             description: "Using EnumSet for enum types provides better performance than general collections",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["performance"],
         }
     }
 
     fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
-        let enums = identify_enum_types(context);
-        if enums.is_empty() {
-            return Ok(Vec::new());
-        }
-
         let mut results = Vec::new();
         for class in context.analysis_target_classes() {
             let local_variable_entries: usize = class
@@ -71,10 +69,10 @@ impl Rule for PreferEnumSetRule {
                     let mut class_results = Vec::new();
                     class_results.extend(check_fields(
                         class,
-                        &enums,
+                        context,
                         context.class_artifact_uri(class).as_deref(),
                     ));
-                    class_results.extend(check_methods(class, &enums, artifact_uri.as_deref()));
+                    class_results.extend(check_methods(class, context, artifact_uri.as_deref()));
                     Ok(class_results)
                 })?;
             results.extend(class_results);
@@ -330,17 +328,16 @@ impl<'a> SignatureParser<'a> {
     }
 }
 
-fn identify_enum_types(context: &AnalysisContext) -> BTreeSet<String> {
+/// Returns true when `name` is a loaded enum class.
+fn is_enum_type(context: &AnalysisContext, name: &str) -> bool {
     context
-        .all_classes()
-        .filter(|class| class.super_name.as_deref() == Some("java/lang/Enum"))
-        .map(|class| class.name.clone())
-        .collect()
+        .class_by_name(name)
+        .is_some_and(|class| class.super_name.as_deref() == Some("java/lang/Enum"))
 }
 
 fn check_fields(
     class: &Class,
-    enums: &BTreeSet<String>,
+    context: &AnalysisContext,
     artifact_uri: Option<&str>,
 ) -> Vec<SarifResult> {
     let mut results = Vec::new();
@@ -352,7 +349,7 @@ fn check_fields(
         let Some(ty) = parser.parse_type_signature() else {
             continue;
         };
-        let Some(matched) = match_collection_enum(&ty, enums) else {
+        let Some(matched) = match_collection_enum(&ty, context) else {
             continue;
         };
         let message =
@@ -369,13 +366,13 @@ fn check_fields(
 
 fn check_methods(
     class: &Class,
-    enums: &BTreeSet<String>,
+    context: &AnalysisContext,
     artifact_uri: Option<&str>,
 ) -> Vec<SarifResult> {
     let mut results = Vec::new();
     for method in &class.methods {
-        results.extend(check_method_signature(class, method, enums, artifact_uri));
-        results.extend(check_local_variables(class, method, enums, artifact_uri));
+        results.extend(check_method_signature(class, method, context, artifact_uri));
+        results.extend(check_local_variables(class, method, context, artifact_uri));
     }
     results
 }
@@ -383,7 +380,7 @@ fn check_methods(
 fn check_method_signature(
     class: &Class,
     method: &Method,
-    enums: &BTreeSet<String>,
+    context: &AnalysisContext,
     artifact_uri: Option<&str>,
 ) -> Vec<SarifResult> {
     let mut results = Vec::new();
@@ -396,7 +393,7 @@ fn check_method_signature(
     };
     let line = method.line_for_offset(0);
     if let Some(return_type) = method_signature.return_type {
-        if let Some(matched) = match_collection_enum(&return_type, enums) {
+        if let Some(matched) = match_collection_enum(&return_type, context) {
             let message = enum_collection_message(
                 &matched,
                 &format!("return type of {}.{}", class.name, method.name),
@@ -417,7 +414,7 @@ fn check_method_signature(
         }
     }
     for (index, param) in method_signature.params.iter().enumerate() {
-        let Some(matched) = match_collection_enum(param, enums) else {
+        let Some(matched) = match_collection_enum(param, context) else {
             continue;
         };
         let message = enum_collection_message(
@@ -444,7 +441,7 @@ fn check_method_signature(
 fn check_local_variables(
     class: &Class,
     method: &Method,
-    enums: &BTreeSet<String>,
+    context: &AnalysisContext,
     artifact_uri: Option<&str>,
 ) -> Vec<SarifResult> {
     let mut results = Vec::new();
@@ -453,7 +450,7 @@ fn check_local_variables(
         let Some(ty) = parser.parse_type_signature() else {
             continue;
         };
-        let Some(matched) = match_collection_enum(&ty, enums) else {
+        let Some(matched) = match_collection_enum(&ty, context) else {
             continue;
         };
         let line = method.line_for_offset(local.start_pc);
@@ -480,7 +477,7 @@ fn check_local_variables(
 
 fn match_collection_enum(
     ty: &TypeSignature,
-    enums: &BTreeSet<String>,
+    context: &AnalysisContext,
 ) -> Option<EnumCollectionMatch> {
     let TypeSignature::Class(class_type) = ty else {
         return None;
@@ -492,7 +489,7 @@ fn match_collection_enum(
     let TypeSignature::Class(arg_class) = arg else {
         return None;
     };
-    if !enums.contains(&arg_class.name) {
+    if !is_enum_type(context, &arg_class.name) {
         return None;
     }
     Some(EnumCollectionMatch {
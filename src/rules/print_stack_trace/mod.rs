@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct calls to `Throwable.printStackTrace`.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for PrintStackTraceRule {
             id: "PRINT_STACK_TRACE",
             name: "Direct printStackTrace call",
             description: "Throwable.printStackTrace should be replaced with structured logging",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
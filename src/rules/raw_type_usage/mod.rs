@@ -0,0 +1,413 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::descriptor::{
+    ClassType, GenericSignature, GenericType, SignatureKind, TypeArgument, parse_signature,
+};
+use crate::engine::AnalysisContext;
+use crate::ir::{Class, Field, Method};
+use crate::rules::{
+    Rule, RuleMetadata, Severity, class_location, method_location_with_line, result_message,
+};
+
+/// JDK types that declare type parameters; classpath classes are detected from their signatures.
+const JDK_GENERIC_TYPES: &[&str] = &[
+    "java/lang/Iterable",
+    "java/lang/ThreadLocal",
+    "java/util/ArrayList",
+    "java/util/Collection",
+    "java/util/Comparator",
+    "java/util/Deque",
+    "java/util/HashMap",
+    "java/util/HashSet",
+    "java/util/Iterator",
+    "java/util/LinkedHashMap",
+    "java/util/LinkedHashSet",
+    "java/util/LinkedList",
+    "java/util/List",
+    "java/util/Map",
+    "java/util/NavigableMap",
+    "java/util/NavigableSet",
+    "java/util/Optional",
+    "java/util/Queue",
+    "java/util/Set",
+    "java/util/SortedMap",
+    "java/util/SortedSet",
+    "java/util/TreeMap",
+    "java/util/TreeSet",
+    "java/util/concurrent/BlockingQueue",
+    "java/util/concurrent/Callable",
+    "java/util/concurrent/CompletableFuture",
+    "java/util/concurrent/ConcurrentHashMap",
+    "java/util/concurrent/ConcurrentMap",
+    "java/util/concurrent/Future",
+    "java/util/function/BiFunction",
+    "java/util/function/Consumer",
+    "java/util/function/Function",
+    "java/util/function/Predicate",
+    "java/util/function/Supplier",
+    "java/util/stream/Stream",
+];
+
+/// Rule that detects raw uses of generic types in field and method declarations.
+#[derive(Default)]
+pub(crate) struct RawTypeUsageRule;
+
+crate::register_rule!(RawTypeUsageRule);
+
+impl Rule for RawTypeUsageRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "RAW_TYPE_USAGE",
+            name: "Raw type usage",
+            description: "Generic types declared without type arguments bypass compile-time type checks",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for field in &class.fields {
+                        if field.access.is_synthetic {
+                            continue;
+                        }
+                        for raw_type in raw_types_in_field(field, context) {
+                            let message = result_message(format!(
+                                "Raw type {raw_type} in field {}.{}; add type arguments such as {raw_type}<...>.",
+                                class.name, field.name
+                            ));
+                            let location = class_location(&class.name, artifact_uri.as_deref());
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    for method in &class.methods {
+                        if method.access.is_synthetic || method.access.is_bridge {
+                            continue;
+                        }
+                        for raw_type in raw_types_in_method(class, method, context) {
+                            let message = result_message(format!(
+                                "Raw type {raw_type} in method {}.{}{}; add type arguments such as {raw_type}<...>.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(0),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns true for known JDK generic types and loaded classes that declare type parameters.
+fn is_generic_type(context: &AnalysisContext, name: &str) -> bool {
+    JDK_GENERIC_TYPES.contains(&name)
+        || context
+            .class_by_name(name)
+            .is_some_and(|class| !class.type_parameters.is_empty())
+}
+
+/// Raw generic types in a field declaration, in declaration order without duplicates.
+fn raw_types_in_field(field: &Field, context: &AnalysisContext) -> Vec<String> {
+    let Some(signature) = field.signature.as_deref() else {
+        return raw_types_in_descriptor(&field.descriptor, context);
+    };
+    let mut raw_types = Vec::new();
+    if let Ok(GenericSignature::Field(field_type)) =
+        parse_signature(signature, SignatureKind::Field)
+    {
+        collect_raw_types(&field_type, context, &mut raw_types);
+    }
+    raw_types
+}
+
+/// Raw generic types in method parameters and the return type.
+fn raw_types_in_method(
+    class: &Class,
+    method: &Method,
+    context: &AnalysisContext,
+) -> Vec<String> {
+    let Some(signature) = method.signature.as_deref() else {
+        // Nested class constructors take the outer instance and captured values as leading
+        // parameters that never get generic signatures.
+        if method.name == "<init>" && class.name.contains('$') {
+            return Vec::new();
+        }
+        return raw_types_in_descriptor(&method.descriptor, context);
+    };
+    let mut raw_types = Vec::new();
+    if let Ok(GenericSignature::Method {
+        parameters,
+        return_type,
+        ..
+    }) = parse_signature(signature, SignatureKind::Method)
+    {
+        for declared_type in parameters.iter().chain(std::iter::once(&return_type)) {
+            collect_raw_types(declared_type, context, &mut raw_types);
+        }
+    }
+    raw_types
+}
+
+/// Without a `Signature` attribute, every generic class in the erased descriptor is raw.
+fn raw_types_in_descriptor(descriptor: &str, context: &AnalysisContext) -> Vec<String> {
+    let mut raw_types = Vec::new();
+    let mut rest = descriptor;
+    while let Some(start) = rest.find('L') {
+        let Some(end) = rest[start..].find(';') else {
+            break;
+        };
+        let class_name = &rest[start + 1..start + end];
+        if is_generic_type(context, class_name) && !raw_types.iter().any(|name| name == class_name) {
+            raw_types.push(class_name.to_string());
+        }
+        rest = &rest[start + end + 1..];
+    }
+    raw_types
+}
+
+fn collect_raw_types(
+    declared_type: &GenericType,
+    context: &AnalysisContext,
+    raw_types: &mut Vec<String>,
+) {
+    match declared_type {
+        GenericType::Class(class_type) => {
+            collect_raw_class_types(class_type, context, raw_types)
+        }
+        GenericType::Array(component) => collect_raw_types(component, context, raw_types),
+        GenericType::Base | GenericType::Void | GenericType::TypeVariable => {}
+    }
+}
+
+fn collect_raw_class_types(
+    class_type: &ClassType,
+    context: &AnalysisContext,
+    raw_types: &mut Vec<String>,
+) {
+    if class_type.type_arguments.is_empty()
+        && is_generic_type(context, &class_type.name)
+        && !raw_types.contains(&class_type.name)
+    {
+        raw_types.push(class_type.name.clone());
+    }
+    for argument in &class_type.type_arguments {
+        match argument {
+            TypeArgument::Extends(bound)
+            | TypeArgument::Super(bound)
+            | TypeArgument::Exact(bound) => collect_raw_types(bound, context, raw_types),
+            TypeArgument::Any => {}
+        }
+    }
+    if let Some(outer) = class_type.outer.as_deref() {
+        collect_raw_class_types(outer, context, raw_types);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(sources: &[SourceFile]) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("RAW_TYPE_USAGE"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_raw_list_field() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+import java.util.List;
+public class ClassA {
+    private List fieldA;
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze(&sources);
+
+        assert_eq!(
+            messages,
+            vec![
+                "Raw type java/util/List in field com/example/ClassA.fieldA; add type arguments such as java/util/List<...>."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_parameterized_list_field() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassB.java".to_string(),
+            contents: r#"
+package com.example;
+import java.util.List;
+import java.util.Map;
+public class ClassB {
+    private List<String> fieldA;
+    private Map<String, List<? extends Number>> fieldB;
+    private List<?>[] fieldC;
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze(&sources);
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn reports_raw_type_nested_in_type_argument() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassC.java".to_string(),
+            contents: r#"
+package com.example;
+import java.util.List;
+import java.util.Map;
+public class ClassC {
+    private Map<String, List> fieldA;
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze(&sources);
+
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("Raw type java/util/List in field com/example/ClassC.fieldA"));
+    }
+
+    #[test]
+    fn reports_raw_method_parameter_and_return_type() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassD.java".to_string(),
+            contents: r#"
+package com.example;
+import java.util.List;
+import java.util.Map;
+public class ClassD {
+    public Map methodOne(List<String> varOne) {
+        return null;
+    }
+    public <T> void methodTwo(List varOne, T varTwo) {
+    }
+    public List<String> methodThree(Map<String, Integer> varOne) {
+        return null;
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze(&sources);
+
+        assert_eq!(messages.len(), 2, "unexpected findings: {messages:?}");
+        assert!(messages.iter().any(|message| message.starts_with(
+            "Raw type java/util/Map in method com/example/ClassD.methodOne(Ljava/util/List;)Ljava/util/Map;"
+        )));
+        assert!(messages.iter().any(|message| message.starts_with(
+            "Raw type java/util/List in method com/example/ClassD.methodTwo(Ljava/util/List;Ljava/lang/Object;)V"
+        )));
+    }
+
+    #[test]
+    fn reports_raw_use_of_generic_class_from_analyzed_code() {
+        let sources = vec![
+            SourceFile {
+                path: "com/example/Box.java".to_string(),
+                contents: r#"
+package com.example;
+public class Box<T> {
+    T value;
+}
+"#
+                .to_string(),
+            },
+            SourceFile {
+                path: "com/example/ClassE.java".to_string(),
+                contents: r#"
+package com.example;
+public class ClassE {
+    private Box fieldA;
+    private Box<String> fieldB;
+}
+"#
+                .to_string(),
+            },
+        ];
+
+        let messages = analyze(&sources);
+
+        assert_eq!(
+            messages,
+            vec![
+                "Raw type com/example/Box in field com/example/ClassE.fieldA; add type arguments such as com/example/Box<...>."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_outer_instance_of_generic_class() {
+        let sources = vec![SourceFile {
+            path: "com/example/Outer.java".to_string(),
+            contents: r#"
+package com.example;
+public class Outer<T> {
+    class Inner {
+        void methodX() {
+            System.out.println(Outer.this.hashCode());
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze(&sources);
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
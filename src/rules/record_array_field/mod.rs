@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, class_location, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
 
 /// Rule that flags record components that use array types.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for RecordArrayFieldRule {
             id: "RECORD_ARRAY_FIELD",
             name: "Record array field",
             description: "Records should not use array-typed components",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -0,0 +1,256 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::allocation::NewAllocationHook;
+use crate::dataflow::opcode_semantics::{
+    SemanticsCoverage, SemanticsDebugConfig, ValueDomain, apply_semantics,
+    emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{BasicBlock, CallKind, CallSite, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects null checks of a reference created by `new` in the same basic block.
+#[derive(Default)]
+pub(crate) struct RedundantNullCheckAfterNewRule;
+
+crate::register_rule!(RedundantNullCheckAfterNewRule);
+
+impl Rule for RedundantNullCheckAfterNewRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "REDUNDANT_NULL_CHECK_AFTER_NEW",
+            name: "Redundant null check after new",
+            description: "A reference created by new is never null, so checking it for null is dead code",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() {
+                            continue;
+                        }
+                        for block in &method.cfg.blocks {
+                            let analysis = analyze_block(method, block)?;
+                            rule_coverage.merge_from(&analysis.coverage);
+                            for offset in analysis.offsets {
+                                let message = result_message(format!(
+                                    "Null check in {}.{}{} tests a reference just created with new, which is never null; remove the check.",
+                                    class.name, method.name, method.descriptor
+                                ));
+                                let location = method_location_with_line(
+                                    &class.name,
+                                    &method.name,
+                                    &method.descriptor,
+                                    artifact_uri.as_deref(),
+                                    method.line_for_offset(offset),
+                                );
+                                class_results.push(
+                                    SarifResult::builder()
+                                        .message(message)
+                                        .locations(vec![location])
+                                        .build(),
+                                );
+                            }
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("REDUNDANT_NULL_CHECK_AFTER_NEW", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Whether an operand stack or local value is the result of a `new` in the current block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    New,
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct AllocationValueDomain;
+
+impl ValueDomain<Value> for AllocationValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Block-level analysis output with coverage summary for debug telemetry events.
+struct BlockAnalysis {
+    offsets: Vec<u32>,
+    coverage: SemanticsCoverage,
+}
+
+/// Simulates `block` from an unknown state and returns the offsets of `ifnull`/`ifnonnull`
+/// branches whose operand was allocated earlier in the block.
+fn analyze_block(method: &Method, block: &BasicBlock) -> Result<BlockAnalysis> {
+    let mut machine = StackMachine::new(Value::Other);
+    let mut coverage = SemanticsCoverage::default();
+    let mut offsets = Vec::new();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "REDUNDANT_NULL_CHECK_AFTER_NEW",
+    };
+    for instruction in &block.instructions {
+        if matches!(instruction.opcode, opcodes::IFNULL | opcodes::IFNONNULL)
+            && machine.peek() == Some(&Value::New)
+        {
+            offsets.push(instruction.offset);
+        }
+        let mut hooks = NewAllocationHook::new(instruction.offset, |_| Value::New);
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &AllocationValueDomain,
+            &mut hooks,
+            &mut coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => handle_invoke(call, &mut machine)?,
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(BlockAnalysis { offsets, coverage })
+}
+
+fn handle_invoke(call: &CallSite, machine: &mut StackMachine<Value>) -> Result<()> {
+    machine.pop_n(method_param_count(&call.descriptor)?);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+    if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        machine.push(Value::Other);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("REDUNDANT_NULL_CHECK_AFTER_NEW"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_null_check_of_new_local() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.ArrayList;
+import java.util.List;
+public class ClassA {
+    int methodX() {
+        List<String> varOne = new ArrayList<>();
+        if (varOne != null) {
+            return varOne.size();
+        }
+        return -1;
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Null check in com/example/ClassA.methodX()I tests a reference just created with new, which is never null; remove the check."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_null_check_of_method_result() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Map;
+public class ClassA {
+    int methodX(Map<String, String> varOne) {
+        String varTwo = varOne.get("a");
+        if (varTwo != null) {
+            return varTwo.length();
+        }
+        return -1;
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "{messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_local_reassigned_before_check() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Map;
+public class ClassA {
+    int methodX(Map<String, StringBuilder> varOne) {
+        StringBuilder varTwo = new StringBuilder();
+        varTwo = varOne.get("a");
+        if (varTwo == null) {
+            return -1;
+        }
+        return varTwo.length();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "{messages:?}");
+    }
+}
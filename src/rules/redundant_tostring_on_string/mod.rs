@@ -0,0 +1,149 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::CallSite;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `toString()` calls on values that are already strings.
+#[derive(Default)]
+pub(crate) struct RedundantTostringOnStringRule;
+
+crate::register_rule!(RedundantTostringOnStringRule);
+
+impl Rule for RedundantTostringOnStringRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "REDUNDANT_TOSTRING_ON_STRING",
+            name: "Redundant toString on String",
+            description: "String.toString() returns its receiver and has no effect",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        // javac uses the static type of the receiver as the call owner, so the
+                        // owner is String exactly when the receiver is declared as a String.
+                        for call in method.calls.iter().filter(|call| is_string_to_string(call)) {
+                            let message = result_message(format!(
+                                "{}.{}{} calls toString() on a String, which returns the same String; remove the call.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(call.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+fn is_string_to_string(call: &CallSite) -> bool {
+    call.owner == "java/lang/String"
+        && call.name == "toString"
+        && call.descriptor == "()Ljava/lang/String;"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("REDUNDANT_TOSTRING_ON_STRING"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_to_string_on_string_values() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    private String fieldOne = "value";
+
+    String methodX() {
+        return fieldOne.toString();
+    }
+
+    String methodY(Object varOne) {
+        return (varOne + "").toString();
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "com/example/ClassA.methodX()Ljava/lang/String; calls toString() on a String, which returns the same String; remove the call."
+                    .to_string(),
+                "com/example/ClassA.methodY(Ljava/lang/Object;)Ljava/lang/String; calls toString() on a String, which returns the same String; remove the call."
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_to_string_on_other_types() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    String methodX(Object varOne) {
+        return varOne.toString();
+    }
+
+    String methodY(StringBuilder varOne) {
+        return varOne.toString();
+    }
+
+    String methodZ(CharSequence varOne) {
+        return varOne.toString();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -0,0 +1,237 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallSite, InstructionKind};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects constant resource paths whose leading slash does not match the lookup API.
+#[derive(Default)]
+pub(crate) struct ResourcePathSlashMismatchRule;
+
+crate::register_rule!(ResourcePathSlashMismatchRule);
+
+impl Rule for ResourcePathSlashMismatchRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "RESOURCE_PATH_SLASH_MISMATCH",
+            name: "Resource path slash mismatch",
+            description: "Constant resource paths whose leading slash does not match Class or ClassLoader lookup rules",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for block in &method.cfg.blocks {
+                            for pair in block.instructions.windows(2) {
+                                let (
+                                    InstructionKind::ConstString(path),
+                                    InstructionKind::Invoke(call),
+                                ) = (&pair[0].kind, &pair[1].kind)
+                                else {
+                                    continue;
+                                };
+                                let Some(api) = resource_api(call) else {
+                                    continue;
+                                };
+                                let Some(problem) = api.mismatch(path) else {
+                                    continue;
+                                };
+                                let message = result_message(format!(
+                                    "{}.{} in {}.{}{} is called with \"{path}\", {problem}",
+                                    api.simple_owner(),
+                                    call.name,
+                                    class.name,
+                                    method.name,
+                                    method.descriptor
+                                ));
+                                let location = method_location_with_line(
+                                    &class.name,
+                                    &method.name,
+                                    &method.descriptor,
+                                    artifact_uri.as_deref(),
+                                    method.line_for_offset(pair[1].offset),
+                                );
+                                class_results.push(
+                                    SarifResult::builder()
+                                        .message(message)
+                                        .locations(vec![location])
+                                        .build(),
+                                );
+                            }
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Resource lookup API, which decides how a leading `/` in the path is treated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ResourceApi {
+    /// `Class.getResource*`: paths are relative to the class's package unless they start with `/`.
+    Class,
+    /// `ClassLoader.getResource*`: paths are always absolute and must not start with `/`.
+    ClassLoader,
+}
+
+impl ResourceApi {
+    fn simple_owner(self) -> &'static str {
+        match self {
+            ResourceApi::Class => "Class",
+            ResourceApi::ClassLoader => "ClassLoader",
+        }
+    }
+
+    /// Describes why `path` will not resolve as intended with this API, if it will not.
+    fn mismatch(self, path: &str) -> Option<&'static str> {
+        match self {
+            // A bare file name next to the class is a legitimate relative lookup; a nested
+            // path is almost always meant from the classpath root.
+            ResourceApi::Class if !path.starts_with('/') && path.contains('/') => Some(
+                "which is resolved relative to the class's package; add a leading '/' to look it up from the classpath root.",
+            ),
+            ResourceApi::ClassLoader if path.starts_with('/') => Some(
+                "but ClassLoader paths never start with '/' and this lookup always returns null; remove the leading '/'.",
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the lookup API of `call` when it takes a single resource name.
+fn resource_api(call: &CallSite) -> Option<ResourceApi> {
+    let api = match call.owner.as_str() {
+        "java/lang/Class" => ResourceApi::Class,
+        "java/lang/ClassLoader" => ResourceApi::ClassLoader,
+        _ => return None,
+    };
+    let expected = match (api, call.name.as_str()) {
+        (_, "getResource") => "(Ljava/lang/String;)Ljava/net/URL;",
+        (_, "getResourceAsStream") => "(Ljava/lang/String;)Ljava/io/InputStream;",
+        (ResourceApi::ClassLoader, "getResources") => "(Ljava/lang/String;)Ljava/util/Enumeration;",
+        (ResourceApi::ClassLoader, "getSystemResource") => "(Ljava/lang/String;)Ljava/net/URL;",
+        (ResourceApi::ClassLoader, "getSystemResourceAsStream") => {
+            "(Ljava/lang/String;)Ljava/io/InputStream;"
+        }
+        _ => return None,
+    };
+    (call.descriptor == expected).then_some(api)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("RESOURCE_PATH_SLASH_MISMATCH"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_class_relative_nested_path() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.InputStream;
+public class ClassA {
+    InputStream methodX() {
+        return getClass().getResourceAsStream("config/app.properties");
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Class.getResourceAsStream in com/example/ClassA.methodX()Ljava/io/InputStream; is called with \"config/app.properties\", which is resolved relative to the class's package; add a leading '/' to look it up from the classpath root."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_class_loader_path_with_leading_slash() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.net.URL;
+public class ClassA {
+    URL methodX() {
+        return ClassA.class.getClassLoader().getResource("/config/app.properties");
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "ClassLoader.getResource in com/example/ClassA.methodX()Ljava/net/URL; is called with \"/config/app.properties\", but ClassLoader paths never start with '/' and this lookup always returns null; remove the leading '/'."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_matching_paths() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.InputStream;
+import java.net.URL;
+public class ClassA {
+    InputStream methodX() {
+        return getClass().getResourceAsStream("/config/app.properties");
+    }
+
+    URL methodY() {
+        return ClassA.class.getResource("sibling.txt");
+    }
+
+    InputStream methodZ() {
+        return Thread.currentThread()
+            .getContextClassLoader()
+            .getResourceAsStream("config/app.properties");
+    }
+
+    URL methodW(String varOne) {
+        return ClassA.class.getClassLoader().getResource(varOne);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -10,7 +10,7 @@ use crate::dataflow::worklist::{
 use crate::engine::AnalysisContext;
 use crate::ir::{Instruction, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects return statements executed inside finally blocks.
 #[derive(Default)]
@@ -75,6 +75,9 @@ impl Rule for ReturnInFinallyRule {
             id: "RETURN_IN_FINALLY",
             name: "Return in finally",
             description: "Return statements in finally blocks override exceptions or prior returns",
+            severity: Severity::Error,
+            rank: Some(80),
+            tags: &["correctness"],
         }
     }
 
@@ -100,16 +103,24 @@ impl Rule for ReturnInFinallyRule {
                         }
 
                         let mut seen_offsets = BTreeSet::new();
+                        let mut seen_lines = BTreeSet::new();
 
                         for handler_pc in handler_offsets {
                             for instruction_offset in return_offsets_in_handler(method, handler_pc)? {
                                 if !seen_offsets.insert(instruction_offset) {
                                     continue;
                                 }
+                                let line = method.line_for_offset(instruction_offset);
+                                // javac copies finally blocks into every exit path, so one source
+                                // return can appear at several offsets.
+                                if let Some(line) = line
+                                    && !seen_lines.insert(line)
+                                {
+                                    continue;
+                                }
                                 let message = result_message(
                                     "Return in finally overrides exceptions or prior returns. Move the return outside the finally block or return after the try/finally.",
                                 );
-                                let line = method.line_for_offset(instruction_offset);
                                 let artifact_uri = context.class_artifact_uri(class);
                                 let location = method_location_with_line(
                                     &class.name,
@@ -276,4 +287,39 @@ public class ClassC {
         let messages = return_messages(&analysis);
         assert_eq!(messages.len(), 1);
     }
+
+    #[test]
+    fn return_in_finally_reports_duplicated_finally_copies_once_per_line() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "example/ClassD.java".to_string(),
+            contents: r#"
+package example;
+
+public class ClassD {
+    int MethodW() {
+        try {
+            MethodV();
+        } finally {
+            try {
+                MethodV();
+            } finally {
+                return 1;
+            }
+        }
+    }
+
+    void MethodV() {}
+}
+"#
+            .to_string(),
+        }];
+
+        let analysis = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("compile and analyze");
+
+        let messages = return_messages(&analysis);
+        assert_eq!(messages.len(), 1, "expected one finding, got {messages:?}");
+    }
 }
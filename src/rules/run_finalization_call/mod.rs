@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects explicit finalization trigger APIs.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for RunFinalizationCallRule {
             id: "RUN_FINALIZATION_CALL",
             name: "Explicit finalization trigger call",
             description: "System/Runtime runFinalization calls are unpredictable",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["performance"],
         }
     }
 
@@ -0,0 +1,189 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, Class, Method};
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
+
+/// Rule that flags Thread subclasses that override run() and also pass a Runnable to super.
+#[derive(Default)]
+pub(crate) struct RunnableAndRunConflictRule;
+
+crate::register_rule!(RunnableAndRunConflictRule);
+
+impl Rule for RunnableAndRunConflictRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "RUNNABLE_AND_RUN_CONFLICT",
+            name: "Thread subclass ignores its Runnable",
+            description: "Thread subclasses that override run() while passing a Runnable to the Thread constructor",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness", "concurrency"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    if class.super_name.as_deref() != Some("java/lang/Thread") {
+                        return Ok(class_results);
+                    }
+                    let Some(run) = run_override(class) else {
+                        return Ok(class_results);
+                    };
+                    if run.calls.iter().any(is_super_run_call) || !passes_runnable_to_super(class)
+                    {
+                        return Ok(class_results);
+                    }
+                    let message = result_message(format!(
+                        "Class {} overrides run() and passes a Runnable to the Thread constructor; the overriding run() never executes the Runnable.",
+                        class.name
+                    ));
+                    let artifact_uri = context.class_artifact_uri(class);
+                    let location = class_location(&class.name, artifact_uri.as_deref());
+                    class_results.push(
+                        SarifResult::builder()
+                            .message(message)
+                            .locations(vec![location])
+                            .build(),
+                    );
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+fn run_override(class: &Class) -> Option<&Method> {
+    class.methods.iter().find(|method| {
+        method.name == "run"
+            && method.descriptor == "()V"
+            && !method.access.is_static
+            && !method.access.is_abstract
+    })
+}
+
+/// Whether a constructor of `class` calls a `Thread` constructor that takes a `Runnable`.
+fn passes_runnable_to_super(class: &Class) -> bool {
+    class
+        .methods
+        .iter()
+        .filter(|method| method.name == "<init>")
+        .flat_map(|method| &method.calls)
+        .any(|call| {
+            call.kind == CallKind::Special
+                && call.owner == "java/lang/Thread"
+                && call.name == "<init>"
+                && call.descriptor.contains("Ljava/lang/Runnable;")
+        })
+}
+
+/// `super.run()` runs the Runnable passed to the constructor, so the override keeps using it.
+fn is_super_run_call(call: &CallSite) -> bool {
+    call.kind == CallKind::Special
+        && call.owner == "java/lang/Thread"
+        && call.name == "run"
+        && call.descriptor == "()V"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("RUNNABLE_AND_RUN_CONFLICT"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_thread_subclass_overriding_run_with_runnable_constructor() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA extends Thread {
+    public ClassA(Runnable varOne) {
+        super(varOne, "worker");
+    }
+
+    @Override
+    public void run() {
+        System.out.println("ignored");
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Class com/example/ClassA overrides run() and passes a Runnable to the Thread constructor; the overriding run() never executes the Runnable."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_plain_thread_subclass() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA extends Thread {
+    public ClassA() {
+        super("worker");
+    }
+
+    @Override
+    public void run() {
+        System.out.println("work");
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_run_override_calling_super_run() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA extends Thread {
+    public ClassA(Runnable varOne) {
+        super(varOne);
+    }
+
+    @Override
+    public void run() {
+        System.out.println("before");
+        super.run();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -0,0 +1,144 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::CallSite;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that flags `Runtime.exec` overloads taking the command as a single string.
+#[derive(Default)]
+pub(crate) struct RuntimeExecStringRule;
+
+crate::register_rule!(RuntimeExecStringRule);
+
+impl Rule for RuntimeExecStringRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "RUNTIME_EXEC_STRING",
+            name: "Runtime.exec with a command string",
+            description: "Runtime.exec(String) splits the command on whitespace, so arguments built from input can inject extra arguments",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["security"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for call in &method.calls {
+                            if !is_exec_string_call(call) {
+                                continue;
+                            }
+                            let message = result_message(format!(
+                                "Runtime.exec{} in {}.{}{} splits the command string on whitespace; pass the command and its arguments as a String[] or use ProcessBuilder.",
+                                call.descriptor, class.name, method.name, method.descriptor
+                            ));
+                            let line = method.line_for_offset(call.offset);
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                line,
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns whether `call` is a `Runtime.exec` overload whose first parameter is the command
+/// string, e.g. `exec(String)` or `exec(String, String[], File)`.
+fn is_exec_string_call(call: &CallSite) -> bool {
+    call.owner == "java/lang/Runtime"
+        && call.name == "exec"
+        && call.descriptor.starts_with("(Ljava/lang/String;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("RUNTIME_EXEC_STRING"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_exec_with_command_string() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.File;
+import java.io.IOException;
+public class ClassA {
+    void methodX(String varOne) throws IOException {
+        Runtime.getRuntime().exec("rm -rf " + varOne);
+        Runtime.getRuntime().exec("ls", null, new File("."));
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "Runtime.exec(Ljava/lang/String;)Ljava/lang/Process; in com/example/ClassA.methodX(Ljava/lang/String;)V splits the command string on whitespace; pass the command and its arguments as a String[] or use ProcessBuilder."
+                    .to_string(),
+                "Runtime.exec(Ljava/lang/String;[Ljava/lang/String;Ljava/io/File;)Ljava/lang/Process; in com/example/ClassA.methodX(Ljava/lang/String;)V splits the command string on whitespace; pass the command and its arguments as a String[] or use ProcessBuilder."
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_exec_with_command_array() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.IOException;
+public class ClassA {
+    void methodX(String varOne) throws IOException {
+        Runtime.getRuntime().exec(new String[] {"rm", "-rf", varOne});
+        new ProcessBuilder("ls", varOne).start();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "{messages:?}");
+    }
+}
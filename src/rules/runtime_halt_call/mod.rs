@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct calls to `Runtime.halt(int)`.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for RuntimeHaltCallRule {
             id: "RUNTIME_HALT_CALL",
             name: "Runtime.halt call",
             description: "Direct Runtime.halt(int) calls bypass graceful JVM shutdown",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
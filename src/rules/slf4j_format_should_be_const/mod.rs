@@ -9,7 +9,7 @@ use serde_sarif::sarif::Result as SarifResult;
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that ensures SLF4J format strings are compile-time constants.
 #[derive(Default)]
@@ -23,6 +23,9 @@ impl Rule for Slf4jFormatShouldBeConstRule {
             id: "SLF4J_FORMAT_SHOULD_BE_CONST",
             name: "SLF4J format should be const",
             description: "SLF4J format strings should be compile-time constants",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
@@ -10,7 +10,7 @@ use crate::descriptor::{ReturnKind, method_return_kind};
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that checks illegal classes passed to LoggerFactory.getLogger(Class).
 #[derive(Default)]
@@ -24,6 +24,9 @@ impl Rule for Slf4jIllegalPassedClassRule {
             id: "SLF4J_ILLEGAL_PASSED_CLASS",
             name: "SLF4J illegal passed class",
             description: "LoggerFactory.getLogger should be called with the caller class",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, class_location, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
 
 /// Rule that ensures SLF4J logger fields are final.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for Slf4jLoggerShouldBeFinalRule {
             id: "SLF4J_LOGGER_SHOULD_BE_FINAL",
             name: "SLF4J logger should be final",
             description: "SLF4J Logger fields should be final",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
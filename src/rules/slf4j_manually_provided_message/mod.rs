@@ -10,7 +10,7 @@ use crate::descriptor::{ReturnKind, method_return_kind};
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects SLF4J log messages assembled manually instead of placeholders.
 #[derive(Default)]
@@ -24,6 +24,9 @@ impl Rule for Slf4jManuallyProvidedMessageRule {
             id: "SLF4J_MANUALLY_PROVIDED_MESSAGE",
             name: "SLF4J preformatted message",
             description: "SLF4J messages should use placeholders instead of manual formatting",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
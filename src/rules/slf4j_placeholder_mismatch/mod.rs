@@ -10,7 +10,7 @@ use crate::descriptor::{ReturnKind, method_return_kind};
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects SLF4J placeholder and argument count mismatches.
 #[derive(Default)]
@@ -24,6 +24,9 @@ impl Rule for Slf4jPlaceholderMismatchRule {
             id: "SLF4J_PLACEHOLDER_MISMATCH",
             name: "SLF4J placeholder mismatch",
             description: "SLF4J placeholder count does not match arguments",
+            severity: Severity::Error,
+            rank: Some(70),
+            tags: &["logging"],
         }
     }
 
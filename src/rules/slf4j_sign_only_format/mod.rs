@@ -9,7 +9,7 @@ use serde_sarif::sarif::Result as SarifResult;
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that reports SLF4J format strings made only of placeholders or whitespace.
 #[derive(Default)]
@@ -23,6 +23,9 @@ impl Rule for Slf4jSignOnlyFormatRule {
             id: "SLF4J_SIGN_ONLY_FORMAT",
             name: "SLF4J placeholder-only format",
             description: "SLF4J format strings should include descriptive text",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
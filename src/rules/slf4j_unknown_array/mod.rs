@@ -10,7 +10,7 @@ use crate::descriptor::{ReturnKind, method_return_kind};
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that reports SLF4J varargs calls with unknown argument array length.
 #[derive(Default)]
@@ -24,6 +24,9 @@ impl Rule for Slf4jUnknownArrayRule {
             id: "SLF4J_UNKNOWN_ARRAY",
             name: "SLF4J unknown array",
             description: "SLF4J varargs calls with unknown argument arrays",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["logging"],
         }
     }
 
@@ -0,0 +1,254 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::acquire_release::{acquire_sites, code_flow_steps, exit_path_without_release};
+use crate::engine::AnalysisContext;
+use crate::ir::{Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{
+    Rule, RuleMetadata, Severity, method_code_flow, method_location_with_line, result_message,
+};
+
+/// File stream classes whose constructors open a file handle.
+const FILE_STREAM_CLASSES: &[&str] = &[
+    "java/io/FileInputStream",
+    "java/io/FileOutputStream",
+    "java/io/FileReader",
+    "java/io/FileWriter",
+    "java/io/RandomAccessFile",
+];
+
+/// `java.nio.file.Files` factories that open a file handle.
+const FILES_STREAM_FACTORIES: &[&str] = &[
+    "newBufferedReader",
+    "newBufferedWriter",
+    "newInputStream",
+    "newOutputStream",
+];
+
+/// Rule that detects file streams that can reach a method exit without close().
+#[derive(Default)]
+pub(crate) struct StreamNotClosedRule;
+
+crate::register_rule!(StreamNotClosedRule);
+
+impl Rule for StreamNotClosedRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "STREAM_NOT_CLOSED",
+            name: "File stream not closed on all paths",
+            description: "File streams opened in a method must be closed on every reachable exit path",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["resource"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() {
+                            continue;
+                        }
+                        let sites = acquire_sites(method, is_stream_acquisition);
+                        if sites.is_empty() {
+                            continue;
+                        }
+                        let release_offsets = release_offsets(method);
+                        for site in sites {
+                            let Some(path) =
+                                exit_path_without_release(method, site, &release_offsets)?
+                            else {
+                                continue;
+                            };
+                            let message = result_message(format!(
+                                "File stream opened in {}.{}{} may not be closed on every path; use try-with-resources.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let artifact_uri = context.class_artifact_uri(class);
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(site.offset),
+                            );
+                            let code_flow = method_code_flow(
+                                &class.name,
+                                method,
+                                artifact_uri.as_deref(),
+                                &code_flow_steps(
+                                    method,
+                                    site,
+                                    &path,
+                                    "Stream opened here",
+                                    "Method exits here without close()",
+                                ),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .code_flows(vec![code_flow])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Offsets that end the method's responsibility for a stream: close() calls (including the
+/// ones try-with-resources generates), returning a resource, or storing one in a field.
+fn release_offsets(method: &Method) -> BTreeSet<u32> {
+    let returns_resource = method
+        .descriptor
+        .rsplit_once(')')
+        .is_some_and(|(_, return_type)| is_resource_type(return_type));
+    method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .filter(|instruction| match &instruction.kind {
+            InstructionKind::Invoke(call) => call.name == "close" && call.descriptor == "()V",
+            InstructionKind::FieldAccess(field) => {
+                matches!(instruction.opcode, opcodes::PUTFIELD | opcodes::PUTSTATIC)
+                    && is_resource_type(&field.descriptor)
+            }
+            _ => instruction.opcode == opcodes::ARETURN && returns_resource,
+        })
+        .map(|instruction| instruction.offset)
+        .collect()
+}
+
+/// Returns true for field or return descriptors that can hold an open stream.
+fn is_resource_type(descriptor: &str) -> bool {
+    descriptor.starts_with("Ljava/io/")
+        || descriptor == "Ljava/lang/AutoCloseable;"
+        || descriptor.starts_with("Ljava/nio/channels/")
+}
+
+fn is_stream_acquisition(instruction: &Instruction) -> bool {
+    let InstructionKind::Invoke(call) = &instruction.kind else {
+        return false;
+    };
+    match call.name.as_str() {
+        "<init>" => FILE_STREAM_CLASSES.contains(&call.owner.as_str()),
+        name => call.owner == "java/nio/file/Files" && FILES_STREAM_FACTORIES.contains(&name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("STREAM_NOT_CLOSED"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_unclosed_file_input_stream() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.FileInputStream;
+import java.io.IOException;
+import java.io.InputStream;
+public class ClassA {
+    int methodX(String varOne) throws IOException {
+        InputStream varTwo = new FileInputStream(varOne);
+        return varTwo.read();
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "File stream opened in com/example/ClassA.methodX(Ljava/lang/String;)I may not be closed on every path; use try-with-resources."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_stream_closed_only_on_normal_path() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.IOException;
+import java.io.InputStream;
+import java.nio.file.Files;
+import java.nio.file.Path;
+public class ClassA {
+    int methodX(Path varOne, boolean varTwo) throws IOException {
+        InputStream varThree = Files.newInputStream(varOne);
+        if (varTwo) {
+            throw new IllegalStateException("tmpValue");
+        }
+        int varFour = varThree.read();
+        varThree.close();
+        return varFour;
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_try_with_resources_or_returned_stream() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.io.FileInputStream;
+import java.io.IOException;
+import java.io.InputStream;
+public class ClassA {
+    int methodX(String varOne) throws IOException {
+        try (InputStream varTwo = new FileInputStream(varOne)) {
+            return varTwo.read();
+        }
+    }
+    InputStream methodY(String varOne) throws IOException {
+        return new FileInputStream(varOne);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects String case conversion calls without an explicit Locale.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for StringCaseWithoutLocaleRule {
             id: "STRING_CASE_WITHOUT_LOCALE",
             name: "String case conversion without explicit locale",
             description: "String.toLowerCase()/toUpperCase() calls without Locale argument",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
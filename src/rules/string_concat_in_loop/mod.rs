@@ -0,0 +1,308 @@
+use std::ops::Range;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::cfg::loop_ranges;
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects strings accumulated by concatenation inside loops.
+#[derive(Default)]
+pub(crate) struct StringConcatInLoopRule;
+
+crate::register_rule!(StringConcatInLoopRule);
+
+impl Rule for StringConcatInLoopRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "STRING_CONCAT_IN_LOOP",
+            name: "String concatenation in loop",
+            description: "Accumulating a String with concatenation in a loop copies it on every iteration",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["performance"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for offset in accumulating_concat_offsets(method) {
+                            let message = result_message(format!(
+                                "String concatenation in a loop in {}.{}{} copies the accumulated string on every iteration; append to a StringBuilder created before the loop instead.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Offsets of concatenations inside loops whose result overwrites a local they read.
+///
+/// Covers `s += x` compiled to `makeConcatWithConstants`, to a fresh `StringBuilder`
+/// (`new StringBuilder().append(s)...toString()`), and `s = s.concat(x)`.
+fn accumulating_concat_offsets(method: &Method) -> Vec<u32> {
+    let loops = loop_ranges(&method.cfg);
+    if loops.is_empty() {
+        return Vec::new();
+    }
+    let mut offsets = Vec::new();
+    for block in &method.cfg.blocks {
+        let instructions = &block.instructions;
+        for (index, instruction) in instructions.iter().enumerate() {
+            if !in_loop(&loops, instruction.offset) {
+                continue;
+            }
+            // The instructions that build the concatenated value, and the index producing it.
+            let (operands, result_index) = if is_string_builder_init(instruction) {
+                let Some(to_string_index) = instructions[index..]
+                    .iter()
+                    .position(is_string_builder_to_string)
+                    .map(|position| index + position)
+                else {
+                    continue;
+                };
+                (&instructions[index..to_string_index], to_string_index)
+            } else if is_string_concat(instruction) {
+                (&instructions[..index], index)
+            } else {
+                continue;
+            };
+            let Some(stored_local) = instructions
+                .get(result_index + 1)
+                .and_then(|next| astore_local_index(&method.bytecode, next))
+            else {
+                continue;
+            };
+            if operands
+                .iter()
+                .any(|operand| aload_local_index(&method.bytecode, operand) == Some(stored_local))
+            {
+                offsets.push(instruction.offset);
+            }
+        }
+    }
+    offsets
+}
+
+fn in_loop(loops: &[Range<u32>], offset: u32) -> bool {
+    loops.iter().any(|range| range.contains(&offset))
+}
+
+fn is_string_builder_init(instruction: &Instruction) -> bool {
+    matches!(
+        &instruction.kind,
+        InstructionKind::Invoke(call)
+            if call.kind == CallKind::Special
+                && call.name == "<init>"
+                && is_string_builder_owner(&call.owner)
+    )
+}
+
+fn is_string_builder_to_string(instruction: &Instruction) -> bool {
+    matches!(
+        &instruction.kind,
+        InstructionKind::Invoke(call)
+            if call.name == "toString" && is_string_builder_owner(&call.owner)
+    )
+}
+
+fn is_string_builder_owner(owner: &str) -> bool {
+    matches!(owner, "java/lang/StringBuilder" | "java/lang/StringBuffer")
+}
+
+fn is_string_concat(instruction: &Instruction) -> bool {
+    match &instruction.kind {
+        InstructionKind::InvokeDynamic { name, .. } => {
+            matches!(name.as_str(), "makeConcatWithConstants" | "makeConcat")
+        }
+        InstructionKind::Invoke(call) => {
+            call.owner == "java/lang/String"
+                && call.name == "concat"
+                && call.descriptor == "(Ljava/lang/String;)Ljava/lang/String;"
+        }
+        _ => false,
+    }
+}
+
+fn aload_local_index(code: &[u8], instruction: &Instruction) -> Option<usize> {
+    match instruction.opcode {
+        opcodes::ALOAD => code
+            .get(instruction.offset as usize + 1)
+            .copied()
+            .map(usize::from),
+        opcodes::ALOAD_0..=opcodes::ALOAD_3 => {
+            Some((instruction.opcode - opcodes::ALOAD_0) as usize)
+        }
+        _ => None,
+    }
+}
+
+fn astore_local_index(code: &[u8], instruction: &Instruction) -> Option<usize> {
+    match instruction.opcode {
+        opcodes::ASTORE => code
+            .get(instruction.offset as usize + 1)
+            .copied()
+            .map(usize::from),
+        opcodes::ASTORE_0..=opcodes::ASTORE_3 => {
+            Some((instruction.opcode - opcodes::ASTORE_0) as usize)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(source: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: source.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("STRING_CONCAT_IN_LOOP"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_concat_assignment_in_for_loop() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public String methodX(int varOne) {
+        String tmpValue = "";
+        for (int i = 0; i < varOne; i++) {
+            tmpValue += i;
+        }
+        return tmpValue;
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].contains("com/example/ClassA.methodX(I)Ljava/lang/String;"));
+    }
+
+    #[test]
+    fn reports_string_builder_rebuilt_in_while_loop() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public String methodX(java.util.Iterator<String> varOne) {
+        String tmpValue = "";
+        while (varOne.hasNext()) {
+            tmpValue = new StringBuilder().append(tmpValue).append(varOne.next()).toString();
+        }
+        return tmpValue;
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn reports_string_concat_method_in_loop() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public String methodX(String[] varOne) {
+        String tmpValue = "";
+        for (String item : varOne) {
+            tmpValue = tmpValue.concat(item);
+        }
+        return tmpValue;
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_hoisted_string_builder() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public String methodX(int varOne) {
+        StringBuilder builder = new StringBuilder();
+        for (int i = 0; i < varOne; i++) {
+            builder.append(i);
+        }
+        return builder.toString();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_per_iteration_value_or_concat_outside_loop() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public String methodX(int varOne, java.util.List<String> varTwo) {
+        for (int i = 0; i < varOne; i++) {
+            String line = "item-" + i;
+            varTwo.add(line);
+        }
+        String tmpValue = "a";
+        tmpValue += varOne;
+        return tmpValue;
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
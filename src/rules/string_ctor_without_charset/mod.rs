@@ -0,0 +1,180 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::CallSite;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects String/byte[] conversions relying on the platform default charset.
+#[derive(Default)]
+pub(crate) struct StringCtorWithoutCharsetRule;
+
+crate::register_rule!(StringCtorWithoutCharsetRule);
+
+impl Rule for StringCtorWithoutCharsetRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "STRING_CTOR_WITHOUT_CHARSET",
+            name: "String/byte[] conversion without explicit charset",
+            description: "new String(byte[]) and String.getBytes() calls without a Charset argument",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    for method in &class.methods {
+                        let artifact_uri = context.class_artifact_uri(class);
+                        for call in &method.calls {
+                            let Some(conversion) = default_charset_conversion(call) else {
+                                continue;
+                            };
+                            let message = result_message(format!(
+                                "{conversion} in {}.{}{} uses the platform default charset; pass StandardCharsets.UTF_8 (or an explicit Charset) to make behavior deterministic.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let line = method.line_for_offset(call.offset);
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                line,
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns the Java form of `call` when it converts between String and byte[] with the
+/// default charset.
+fn default_charset_conversion(call: &CallSite) -> Option<&'static str> {
+    if call.owner != "java/lang/String" {
+        return None;
+    }
+    match (call.name.as_str(), call.descriptor.as_str()) {
+        ("<init>", "([B)V") => Some("new String(byte[])"),
+        ("<init>", "([BII)V") => Some("new String(byte[], int, int)"),
+        ("getBytes", "()[B") => Some("String.getBytes()"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze_sources(sources: Vec<SourceFile>) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("STRING_CTOR_WITHOUT_CHARSET"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_string_constructors_without_charset() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+class ClassA {
+    String methodX(byte[] varOne) {
+        return new String(varOne) + new String(varOne, 0, 1);
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+
+        assert_eq!(
+            messages.len(),
+            2,
+            "expected two findings, got: {messages:?}"
+        );
+        assert!(messages.iter().any(|message| message.starts_with(
+            "new String(byte[]) in com/example/ClassA.methodX([B)Ljava/lang/String;"
+        )));
+    }
+
+    #[test]
+    fn reports_get_bytes_without_charset() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+class ClassA {
+    byte[] methodX(String varOne) {
+        return varOne.getBytes();
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+
+        assert_eq!(messages.len(), 1, "expected one finding, got: {messages:?}");
+        assert!(messages[0].starts_with("String.getBytes() in com/example/ClassA.methodX"));
+    }
+
+    #[test]
+    fn does_not_report_charset_aware_overloads() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+import java.nio.charset.StandardCharsets;
+
+class ClassA {
+    byte[] methodX(byte[] varOne) throws Exception {
+        String varTwo = new String(varOne, StandardCharsets.UTF_8);
+        String varThree = new String(varOne, 0, 1, "UTF-8");
+        return (varTwo + varThree).getBytes(StandardCharsets.UTF_8);
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+
+        assert!(
+            messages.is_empty(),
+            "expected no findings, got: {messages:?}"
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects `String.format` calls whose constant format string consumes a different
+/// number of arguments than are passed.
+#[derive(Default)]
+pub(crate) struct StringFormatArgMismatchRule;
+
+crate::register_rule!(StringFormatArgMismatchRule);
+
+impl Rule for StringFormatArgMismatchRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "STRING_FORMAT_ARG_MISMATCH",
+            name: "String.format argument count mismatch",
+            description: "String.format format specifiers do not match the number of arguments",
+            severity: Severity::Error,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if !method.calls.iter().any(is_string_format_call) {
+                            continue;
+                        }
+                        let analysis = analyze_method(method)?;
+                        rule_coverage.merge_from(&analysis.coverage);
+                        for mismatch in analysis.mismatches {
+                            let message = result_message(format!(
+                                "String.format in {}.{}{} uses a format string that expects {} argument(s) but {} are passed; make the format specifiers match the arguments.",
+                                class.name,
+                                method.name,
+                                method.descriptor,
+                                mismatch.expected,
+                                mismatch.found
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(mismatch.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("STRING_FORMAT_ARG_MISMATCH", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Operand stack value relevant to counting format arguments.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    /// Constant format string and the number of arguments it consumes, if it parses.
+    FormatString(Option<usize>),
+    IntConstant(usize),
+    /// Array created with a constant length, such as the `Object[]` built for varargs.
+    Array(usize),
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct FormatValueDomain;
+
+impl ValueDomain<Value> for FormatValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Rule-specific hook that tags format strings, small int constants, and sized arrays.
+struct FormatSemanticsHook<'a> {
+    instruction: &'a Instruction,
+}
+
+impl SemanticsHooks<Value> for FormatSemanticsHook<'_> {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        method: &Method,
+        offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        match opcode {
+            opcodes::LDC | opcodes::LDC_W => {
+                let InstructionKind::ConstString(value) = &self.instruction.kind else {
+                    return ApplyOutcome::NotHandled;
+                };
+                machine.push(Value::FormatString(required_argument_count(value)));
+                ApplyOutcome::Applied
+            }
+            opcodes::ICONST_0..=opcodes::ICONST_5 => {
+                machine.push(Value::IntConstant((opcode - opcodes::ICONST_0) as usize));
+                ApplyOutcome::Applied
+            }
+            opcodes::BIPUSH => {
+                let value = method.bytecode.get(offset + 1).copied().unwrap_or(0) as i8;
+                machine.push(usize::try_from(value).map_or(Value::Other, Value::IntConstant));
+                ApplyOutcome::Applied
+            }
+            opcodes::ANEWARRAY => {
+                let value = match machine.pop() {
+                    Value::IntConstant(len) => Value::Array(len),
+                    _ => Value::Other,
+                };
+                machine.push(value);
+                ApplyOutcome::Applied
+            }
+            _ => ApplyOutcome::NotHandled,
+        }
+    }
+}
+
+/// Format string whose consumed argument count differs from the passed argument count.
+struct Mismatch {
+    offset: u32,
+    expected: usize,
+    found: usize,
+}
+
+/// Method-level analysis output with coverage summary for debug telemetry events.
+struct MethodAnalysis {
+    mismatches: Vec<Mismatch>,
+    coverage: SemanticsCoverage,
+}
+
+fn analyze_method(method: &Method) -> Result<MethodAnalysis> {
+    let mut instructions: Vec<&Instruction> = method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .collect();
+    instructions.sort_by_key(|instruction| instruction.offset);
+
+    let mut machine = StackMachine::new(Value::Other);
+    let mut coverage = SemanticsCoverage::default();
+    let mut mismatches = Vec::new();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "STRING_FORMAT_ARG_MISMATCH",
+    };
+    for instruction in instructions {
+        let mut hooks = FormatSemanticsHook { instruction };
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &FormatValueDomain,
+            &mut hooks,
+            &mut coverage,
+            debug,
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => {
+                if is_string_format_call(call)
+                    && let Some(mismatch) = format_mismatch(call, &machine)
+                {
+                    mismatches.push(mismatch);
+                }
+                handle_invoke(call, &mut machine)?;
+            }
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(MethodAnalysis {
+        mismatches,
+        coverage,
+    })
+}
+
+/// Compares the format string and varargs array operands on top of the stack at `call`.
+fn format_mismatch(call: &CallSite, machine: &StackMachine<Value>) -> Option<Mismatch> {
+    let stack = machine.stack_values();
+    let [.., format, arguments] = stack else {
+        return None;
+    };
+    let (Value::FormatString(Some(expected)), Value::Array(found)) = (*format, *arguments) else {
+        return None;
+    };
+    (expected != found).then_some(Mismatch {
+        offset: call.offset,
+        expected,
+        found,
+    })
+}
+
+fn handle_invoke(call: &CallSite, machine: &mut StackMachine<Value>) -> Result<()> {
+    machine.pop_n(method_param_count(&call.descriptor)?);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+    if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+        machine.push(Value::Other);
+    }
+    Ok(())
+}
+
+fn is_string_format_call(call: &CallSite) -> bool {
+    call.kind == CallKind::Static
+        && call.owner == "java/lang/String"
+        && call.name == "format"
+        && matches!(
+            call.descriptor.as_str(),
+            "(Ljava/lang/String;[Ljava/lang/Object;)Ljava/lang/String;"
+                | "(Ljava/util/Locale;Ljava/lang/String;[Ljava/lang/Object;)Ljava/lang/String;"
+        )
+}
+
+/// Returns how many arguments `format` consumes under `java.util.Formatter` syntax, or `None`
+/// when a specifier does not parse.
+///
+/// Ordinary specifiers take the next argument, `%2$s` requires at least two arguments, and
+/// `%<s`, `%n`, and `%%` take none.
+fn required_argument_count(format: &str) -> Option<usize> {
+    let chars: Vec<char> = format.chars().collect();
+    let mut index = 0;
+    let mut ordinary = 0;
+    let mut max_explicit = 0;
+    while index < chars.len() {
+        if chars[index] != '%' {
+            index += 1;
+            continue;
+        }
+        index += 1;
+        let digits_start = index;
+        while chars.get(index).is_some_and(char::is_ascii_digit) {
+            index += 1;
+        }
+        let mut explicit = None;
+        let mut relative = false;
+        if index > digits_start && chars.get(index) == Some(&'$') {
+            let digits: String = chars[digits_start..index].iter().collect();
+            explicit = Some(digits.parse::<usize>().ok()?);
+            index += 1;
+        } else if index == digits_start && chars.get(index) == Some(&'<') {
+            relative = true;
+            index += 1;
+        } else {
+            // The digits were a width or a `0` flag, which the loops below skip again.
+            index = digits_start;
+        }
+        while chars
+            .get(index)
+            .is_some_and(|c| matches!(c, '-' | '#' | '+' | ' ' | '0' | ',' | '('))
+        {
+            index += 1;
+        }
+        while chars.get(index).is_some_and(char::is_ascii_digit) {
+            index += 1;
+        }
+        if chars.get(index) == Some(&'.') {
+            index += 1;
+            while chars.get(index).is_some_and(char::is_ascii_digit) {
+                index += 1;
+            }
+        }
+        let conversion = *chars.get(index)?;
+        index += 1;
+        match conversion {
+            '%' | 'n' => continue,
+            't' | 'T' => {
+                chars.get(index)?;
+                index += 1;
+            }
+            'b' | 'B' | 'h' | 'H' | 's' | 'S' | 'c' | 'C' | 'd' | 'o' | 'x' | 'X' | 'e' | 'E'
+            | 'f' | 'g' | 'G' | 'a' | 'A' => {}
+            _ => return None,
+        }
+        match explicit {
+            Some(0) => return None,
+            Some(position) => max_explicit = max_explicit.max(position),
+            None if relative => {}
+            None => ordinary += 1,
+        }
+    }
+    Some(ordinary.max(max_explicit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::required_argument_count;
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("STRING_FORMAT_ARG_MISMATCH"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn required_argument_count_follows_formatter_syntax() {
+        assert_eq!(required_argument_count("%s %s"), Some(2));
+        assert_eq!(required_argument_count("%-10s|%05d|%.2f%%%n"), Some(3));
+        assert_eq!(required_argument_count("%2$s %1$s %<s"), Some(2));
+        assert_eq!(required_argument_count("%tY-%<tm"), Some(1));
+        assert_eq!(required_argument_count("100%"), None);
+        assert_eq!(required_argument_count("%q"), None);
+    }
+
+    #[test]
+    fn reports_missing_format_argument() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    String methodX(String varOne) {
+        return String.format("%s %s", varOne);
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "String.format in com/example/ClassA.methodX(Ljava/lang/String;)Ljava/lang/String; uses a format string that expects 2 argument(s) but 1 are passed; make the format specifiers match the arguments."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_extra_format_argument_with_locale() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Locale;
+public class ClassA {
+    String methodX(String varOne, int varTwo) {
+        return String.format(Locale.ROOT, "%s", varOne, varTwo);
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].contains("expects 1 argument(s) but 2 are passed"));
+    }
+
+    #[test]
+    fn does_not_report_matching_or_dynamic_formats() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    String methodX(String varOne, int varTwo, String varThree, Object[] varFour) {
+        String varFive = String.format("%s=%d%n", varOne, varTwo);
+        String varSix = String.format("%1$s %1$s", varOne);
+        String varSeven = String.format(varThree, varOne, varTwo);
+        return varFive + varSix + varSeven + String.format("%s %s", varFour);
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
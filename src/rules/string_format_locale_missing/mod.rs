@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects locale-dependent string formatting calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for StringFormatLocaleMissingRule {
             id: "STRING_FORMAT_LOCALE_MISSING",
             name: "String/Formatter formatting without explicit locale",
             description: "String.format(...) and Formatter usage without Locale can vary by runtime locale",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct String.intern calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for StringInternCallRule {
             id: "STRING_INTERN_CALL",
             name: "String intern call",
             description: "String.intern can increase global pool pressure and contention",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["performance"],
         }
     }
 
@@ -4,7 +4,7 @@ use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
 use crate::ir::{CallSite, Method};
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct `String.trim().isEmpty()` call chains.
 #[derive(Default)]
@@ -18,6 +18,9 @@ impl Rule for StringTrimIsEmptyRule {
             id: "STRING_TRIM_IS_EMPTY",
             name: "String trim followed by isEmpty",
             description: "String.trim().isEmpty() can be ambiguous; prefer String.isBlank()",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["performance"],
         }
     }
 
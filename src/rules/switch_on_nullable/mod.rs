@@ -0,0 +1,401 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::acquire_release::{acquire_sites, reachable_before_release};
+use crate::dataflow::local_guard::{
+    BytecodeInstruction, aload_local_index, astore_local_index, collect_instructions,
+};
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Map implementations whose `get` returns null for a missing key.
+const MAP_OWNERS: &[&str] = &[
+    "java/util/Map",
+    "java/util/HashMap",
+    "java/util/LinkedHashMap",
+    "java/util/TreeMap",
+    "java/util/concurrent/ConcurrentMap",
+    "java/util/concurrent/ConcurrentHashMap",
+];
+
+/// Rule that detects switches whose boxed or enum selector may be null.
+#[derive(Default)]
+pub(crate) struct SwitchOnNullableRule;
+
+crate::register_rule!(SwitchOnNullableRule);
+
+impl Rule for SwitchOnNullableRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "SWITCH_ON_NULLABLE",
+            name: "switch on nullable boxed or enum selector",
+            description: "switch on a boxed or enum value that may be null throws NullPointerException",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() || !method.calls.iter().any(is_unboxing) {
+                            continue;
+                        }
+                        for selector in nullable_switch_selectors(method)? {
+                            let message = result_message(format!(
+                                "switch in {}.{}{} unboxes a selector that may be null with {}(); check it for null before the switch, which otherwise throws NullPointerException.",
+                                class.name,
+                                method.name,
+                                method.descriptor,
+                                selector.unboxing
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(selector.switch_offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Switch whose selector is unboxed from a value that may be null.
+struct NullableSelector {
+    switch_offset: u32,
+    /// Unboxing method as `owner.name`, such as `java/lang/Integer.intValue`.
+    unboxing: String,
+}
+
+/// Unboxing call feeding a switch, with where its receiver comes from.
+struct SelectorUnboxing<'a> {
+    call: &'a CallSite,
+    switch_offset: u32,
+    receiver: Receiver,
+}
+
+enum Receiver {
+    /// `Map.get` result used directly, which is null for a missing key.
+    MapGet,
+    Local(usize),
+    Other,
+}
+
+/// Returns the switches whose selector is unboxed either straight from `Map.get` or from a
+/// local that holds `null` or a `Map.get` result on some path reaching the switch without a
+/// null check or another store to that local.
+fn nullable_switch_selectors(method: &Method) -> Result<Vec<NullableSelector>> {
+    let instructions = collect_instructions(method)?;
+    let calls: BTreeMap<u32, &CallSite> = method
+        .calls
+        .iter()
+        .map(|call| (call.offset, call))
+        .collect();
+    let mut selectors = Vec::new();
+    for unboxing in selector_unboxings(method, &instructions, &calls) {
+        let nullable = match unboxing.receiver {
+            Receiver::MapGet => true,
+            Receiver::Local(local) => {
+                local_may_be_null_at(method, &instructions, &calls, local, unboxing.call.offset)?
+            }
+            Receiver::Other => false,
+        };
+        if nullable {
+            selectors.push(NullableSelector {
+                switch_offset: unboxing.switch_offset,
+                unboxing: format!("{}.{}", unboxing.call.owner, unboxing.call.name),
+            });
+        }
+    }
+    Ok(selectors)
+}
+
+/// Finds `intValue()`-style calls followed by a switch, and `ordinal()` calls followed by the
+/// `$SwitchMap` array load and a switch, as javac compiles enum switches.
+fn selector_unboxings<'a>(
+    method: &Method,
+    instructions: &[BytecodeInstruction],
+    calls: &BTreeMap<u32, &'a CallSite>,
+) -> Vec<SelectorUnboxing<'a>> {
+    let mut unboxings = Vec::new();
+    for (index, instruction) in instructions.iter().enumerate() {
+        let Some(call) = calls.get(&instruction.offset).copied() else {
+            continue;
+        };
+        if !is_unboxing(call) {
+            continue;
+        }
+        let switch_index = if call.name == "ordinal" {
+            if instructions.get(index + 1).map(|next| next.opcode) != Some(opcodes::IALOAD) {
+                continue;
+            }
+            index + 2
+        } else {
+            index + 1
+        };
+        let Some(switch) = instructions
+            .get(switch_index)
+            .filter(|next| matches!(next.opcode, opcodes::TABLESWITCH | opcodes::LOOKUPSWITCH))
+        else {
+            continue;
+        };
+        unboxings.push(SelectorUnboxing {
+            call,
+            switch_offset: switch.offset,
+            receiver: receiver(method, instructions, calls, index),
+        });
+    }
+    unboxings
+}
+
+fn receiver(
+    method: &Method,
+    instructions: &[BytecodeInstruction],
+    calls: &BTreeMap<u32, &CallSite>,
+    call_index: usize,
+) -> Receiver {
+    let Some(previous) = call_index
+        .checked_sub(1)
+        .and_then(|index| instructions.get(index))
+    else {
+        return Receiver::Other;
+    };
+    if let Some(local) = aload_local_index(&method.bytecode, previous) {
+        return Receiver::Local(local);
+    }
+    if produces_map_get(instructions, calls, call_index) {
+        return Receiver::MapGet;
+    }
+    Receiver::Other
+}
+
+/// Whether the value consumed at `index` is a `Map.get` result, possibly after a checkcast.
+fn produces_map_get(
+    instructions: &[BytecodeInstruction],
+    calls: &BTreeMap<u32, &CallSite>,
+    index: usize,
+) -> bool {
+    let mut producer = index.checked_sub(1);
+    if let Some(previous) = producer.and_then(|index| instructions.get(index))
+        && previous.opcode == opcodes::CHECKCAST
+    {
+        producer = producer.and_then(|index| index.checked_sub(1));
+    }
+    producer
+        .and_then(|index| instructions.get(index))
+        .and_then(|instruction| calls.get(&instruction.offset))
+        .is_some_and(|call| is_map_get(call))
+}
+
+/// Whether a `null` or `Map.get` value stored to `local` reaches `target_offset` along a path
+/// with no other store to `local` and no `ifnull`/`ifnonnull` test of it.
+fn local_may_be_null_at(
+    method: &Method,
+    instructions: &[BytecodeInstruction],
+    calls: &BTreeMap<u32, &CallSite>,
+    local: usize,
+    target_offset: u32,
+) -> Result<bool> {
+    let mut nullable_stores = BTreeSet::new();
+    let mut kills = BTreeSet::new();
+    for (index, instruction) in instructions.iter().enumerate() {
+        if astore_local_index(&method.bytecode, instruction) == Some(local) {
+            kills.insert(instruction.offset);
+            let stores_null = index
+                .checked_sub(1)
+                .and_then(|previous| instructions.get(previous))
+                .is_some_and(|previous| previous.opcode == opcodes::ACONST_NULL);
+            if stores_null || produces_map_get(instructions, calls, index) {
+                nullable_stores.insert(instruction.offset);
+            }
+        }
+        if matches!(instruction.opcode, opcodes::IFNULL | opcodes::IFNONNULL)
+            && index
+                .checked_sub(1)
+                .and_then(|previous| instructions.get(previous))
+                .and_then(|previous| aload_local_index(&method.bytecode, previous))
+                == Some(local)
+        {
+            kills.insert(instruction.offset);
+        }
+    }
+    if nullable_stores.is_empty() {
+        return Ok(false);
+    }
+    let targets = BTreeSet::from([target_offset]);
+    for site in acquire_sites(method, |instruction| {
+        nullable_stores.contains(&instruction.offset)
+    }) {
+        if !reachable_before_release(method, site, &kills, &targets)?.is_empty() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn is_unboxing(call: &CallSite) -> bool {
+    if call.kind != CallKind::Virtual {
+        return false;
+    }
+    matches!(
+        (
+            call.owner.as_str(),
+            call.name.as_str(),
+            call.descriptor.as_str()
+        ),
+        ("java/lang/Integer", "intValue", "()I")
+            | ("java/lang/Short", "shortValue", "()S")
+            | ("java/lang/Byte", "byteValue", "()B")
+            | ("java/lang/Character", "charValue", "()C")
+            | (_, "ordinal", "()I")
+    )
+}
+
+fn is_map_get(call: &CallSite) -> bool {
+    call.name == "get"
+        && call.descriptor == "(Ljava/lang/Object;)Ljava/lang/Object;"
+        && MAP_OWNERS.contains(&call.owner.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("SWITCH_ON_NULLABLE"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_switch_on_unchecked_map_value() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Map;
+public class ClassA {
+    int methodX(Map<String, Integer> varOne) {
+        Integer varTwo = varOne.get("a");
+        switch (varTwo) {
+            case 1: return 10;
+            case 2: return 20;
+            default: return 0;
+        }
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "switch in com/example/ClassA.methodX(Ljava/util/Map;)I unboxes a selector that may be null with java/lang/Integer.intValue(); check it for null before the switch, which otherwise throws NullPointerException."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_enum_switch_on_map_get_and_conditionally_assigned_local() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Map;
+public class ClassA {
+    enum Color { RED, GREEN }
+    int methodX(Map<String, Color> varOne) {
+        switch (varOne.get("a")) {
+            case RED: return 1;
+            default: return 0;
+        }
+    }
+    int methodY(boolean varOne) {
+        Integer varTwo = null;
+        if (varOne) {
+            varTwo = 3;
+        }
+        switch (varTwo) {
+            case 3: return 1;
+            default: return 0;
+        }
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 2, "unexpected findings: {messages:?}");
+        assert!(
+            messages[0].contains("com/example/ClassA$Color.ordinal()"),
+            "{messages:?}"
+        );
+        assert!(messages[1].contains("methodY(Z)I"), "{messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_null_checked_or_unknown_selectors() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Map;
+public class ClassA {
+    int methodX(Map<String, Integer> varOne) {
+        Integer varTwo = varOne.get("a");
+        if (varTwo == null) {
+            return -1;
+        }
+        switch (varTwo) {
+            case 1: return 10;
+            default: return 0;
+        }
+    }
+    int methodY(Integer varOne) {
+        switch (varOne) {
+            case 1: return 10;
+            default: return 0;
+        }
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -0,0 +1,344 @@
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics, emit_opcode_semantics_summary_event, opcode_semantics_debug_enabled,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, FieldRef, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects synchronization on interned strings and cached boxed primitives.
+#[derive(Default)]
+pub(crate) struct SynchronizedOnPooledObjectRule;
+
+crate::register_rule!(SynchronizedOnPooledObjectRule);
+
+impl Rule for SynchronizedOnPooledObjectRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "SYNCHRONIZED_ON_POOLED_OBJECT",
+            name: "Synchronized on pooled object",
+            description: "Synchronizing on String literals or boxed primitives shares the lock with unrelated code",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        let debug_enabled = opcode_semantics_debug_enabled();
+        let mut rule_coverage = SemanticsCoverage::default();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("rule.class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() {
+                            continue;
+                        }
+                        let analysis = analyze_method(method)?;
+                        rule_coverage.merge_from(&analysis.coverage);
+                        for site in analysis.sites {
+                            let message = result_message(format!(
+                                "Synchronizing on {} in {}.{}{}; the object may be shared JVM-wide, so lock on a private final Object instead.",
+                                site.kind.describe(),
+                                class.name,
+                                method.name,
+                                method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(site.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        if debug_enabled && rule_coverage.fallback_not_handled > 0 {
+            emit_opcode_semantics_summary_event("SYNCHRONIZED_ON_POOLED_OBJECT", &rule_coverage);
+        }
+        Ok(results)
+    }
+}
+
+/// Provenance of a reference on the operand stack.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    Other,
+    StringLiteral,
+    Boxed,
+}
+
+impl Value {
+    fn describe(self) -> &'static str {
+        match self {
+            Value::StringLiteral => "a String literal",
+            Value::Boxed => "a boxed primitive",
+            Value::Other => "an object",
+        }
+    }
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct PooledValueDomain;
+
+impl ValueDomain<Value> for PooledValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// `monitorenter` whose operand is a pooled object.
+struct MonitorSite {
+    offset: u32,
+    kind: Value,
+}
+
+/// Rule-specific hook that tags pooled constants and inspects `monitorenter` operands.
+struct PooledSemanticsHook<'a> {
+    instruction: &'a Instruction,
+    sites: Vec<MonitorSite>,
+}
+
+impl SemanticsHooks<Value> for PooledSemanticsHook<'_> {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        _method: &Method,
+        _offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        match opcode {
+            opcodes::LDC | opcodes::LDC_W
+                if matches!(self.instruction.kind, InstructionKind::ConstString(_)) =>
+            {
+                machine.push(Value::StringLiteral);
+                ApplyOutcome::Applied
+            }
+            opcodes::GETSTATIC if is_boolean_constant(&self.instruction.kind) => {
+                machine.push(Value::Boxed);
+                ApplyOutcome::Applied
+            }
+            opcodes::MONITORENTER => {
+                let operand = machine.pop();
+                if operand != Value::Other {
+                    self.sites.push(MonitorSite {
+                        offset: self.instruction.offset,
+                        kind: operand,
+                    });
+                }
+                ApplyOutcome::Applied
+            }
+            _ => ApplyOutcome::NotHandled,
+        }
+    }
+}
+
+/// Method-level analysis output with coverage summary for debug telemetry events.
+struct MethodAnalysis {
+    sites: Vec<MonitorSite>,
+    coverage: SemanticsCoverage,
+}
+
+fn analyze_method(method: &Method) -> Result<MethodAnalysis> {
+    let mut instructions: Vec<&Instruction> = method
+        .cfg
+        .blocks
+        .iter()
+        .flat_map(|block| block.instructions.iter())
+        .collect();
+    instructions.sort_by_key(|instruction| instruction.offset);
+
+    let mut machine = StackMachine::new(Value::Other);
+    let mut coverage = SemanticsCoverage::default();
+    let mut sites = Vec::new();
+    let debug = SemanticsDebugConfig {
+        enabled: opcode_semantics_debug_enabled(),
+        rule_id: "SYNCHRONIZED_ON_POOLED_OBJECT",
+    };
+    for instruction in instructions {
+        let mut hooks = PooledSemanticsHook {
+            instruction,
+            sites: Vec::new(),
+        };
+        apply_semantics(
+            &mut machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &PooledValueDomain,
+            &mut hooks,
+            &mut coverage,
+            debug,
+        );
+        sites.append(&mut hooks.sites);
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => handle_invoke(call, &mut machine)?,
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(MethodAnalysis { sites, coverage })
+}
+
+fn handle_invoke(call: &CallSite, machine: &mut StackMachine<Value>) -> Result<()> {
+    machine.pop_n(method_param_count(&call.descriptor)?);
+    if call.kind != CallKind::Static {
+        machine.pop();
+    }
+    if method_return_kind(&call.descriptor)? == ReturnKind::Void {
+        return Ok(());
+    }
+    if is_boxing_call(call) {
+        machine.push(Value::Boxed);
+    } else {
+        machine.push(Value::Other);
+    }
+    Ok(())
+}
+
+fn is_boxing_call(call: &CallSite) -> bool {
+    call.kind == CallKind::Static
+        && call.name == "valueOf"
+        && matches!(
+            call.owner.as_str(),
+            "java/lang/Integer"
+                | "java/lang/Long"
+                | "java/lang/Short"
+                | "java/lang/Byte"
+                | "java/lang/Character"
+                | "java/lang/Boolean"
+        )
+        && call.descriptor.ends_with(&format!(")L{};", call.owner))
+}
+
+fn is_boolean_constant(kind: &InstructionKind) -> bool {
+    let InstructionKind::FieldAccess(FieldRef { owner, name, .. }) = kind else {
+        return false;
+    };
+    owner == "java/lang/Boolean" && matches!(name.as_str(), "TRUE" | "FALSE")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze_sources(sources: Vec<SourceFile>) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("SYNCHRONIZED_ON_POOLED_OBJECT"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_synchronized_on_string_literal() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassA {
+    public void methodX() {
+        synchronized ("x") {
+            System.out.println("tmpValue");
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("String literal"));
+    }
+
+    #[test]
+    fn reports_synchronized_on_boxed_primitive() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassB.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassB {
+    public void methodY() {
+        Integer varTwo = Integer.valueOf(42);
+        synchronized (varTwo) {
+            System.out.println("tmpValue");
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("boxed primitive"));
+    }
+
+    #[test]
+    fn does_not_report_synchronized_on_new_object() {
+        let sources = vec![SourceFile {
+            path: "com/example/ClassC.java".to_string(),
+            contents: r#"
+package com.example;
+
+public class ClassC {
+    private final Object varOne = new Object();
+
+    public void methodZ() {
+        synchronized (new Object()) {
+            System.out.println("tmpValue");
+        }
+        synchronized (varOne) {
+            System.out.println("tmpValue");
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let messages = analyze_sources(sources);
+        assert!(messages.is_empty());
+    }
+}
@@ -3,9 +3,9 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
-/// Rule that detects direct JVM termination via `System.exit(int)`.
+/// Rule that detects direct JVM termination via `System.exit(int)` or `Runtime.exit(int)`.
 #[derive(Default)]
 pub(crate) struct SystemExitRule;
 
@@ -17,10 +17,14 @@ impl Rule for SystemExitRule {
             id: "SYSTEM_EXIT",
             name: "System.exit call",
             description: "Direct calls to System.exit(int) terminate the JVM abruptly",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
     fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let allow_exit_in_main = context.rule_options().allow_exit_in_main;
         let mut results = Vec::new();
         for class in context.analysis_target_classes() {
             let has_main_with_args = class
@@ -36,14 +40,19 @@ impl Rule for SystemExitRule {
                     let mut class_results = Vec::new();
                     let artifact_uri = context.class_artifact_uri(class);
                     for method in &class.methods {
-                        if is_allowed_main_method(method, has_main_with_args) {
+                        if allow_exit_in_main
+                            && is_allowed_main_method(method, has_main_with_args)
+                        {
                             continue;
                         }
                         for call in &method.calls {
-                            if is_process_termination_call(&call.owner, &call.name, &call.descriptor)
-                            {
+                            if let Some(call_label) = process_termination_call_label(
+                                &call.owner,
+                                &call.name,
+                                &call.descriptor,
+                            ) {
                                 let message = result_message(format!(
-                                    "Avoid System.exit() in {}.{}{}; return an error or throw an exception instead.",
+                                    "Avoid {call_label} in {}.{}{}; return an error or throw an exception instead.",
                                     class.name, method.name, method.descriptor
                                 ));
                                 let line = method.line_for_offset(call.offset);
@@ -71,13 +80,21 @@ impl Rule for SystemExitRule {
     }
 }
 
-fn is_process_termination_call(owner: &str, name: &str, descriptor: &str) -> bool {
-    if owner == "java/lang/System" && name == "exit" && descriptor == "(I)V" {
-        return true;
+/// Returns the name used in messages when the call terminates the JVM.
+/// `Runtime.halt(int)` is covered separately by RUNTIME_HALT_CALL.
+fn process_termination_call_label(
+    owner: &str,
+    name: &str,
+    descriptor: &str,
+) -> Option<&'static str> {
+    match (owner, name, descriptor) {
+        ("java/lang/System", "exit", "(I)V") => Some("System.exit()"),
+        ("java/lang/Runtime", "exit", "(I)V") => Some("Runtime.exit()"),
+        ("kotlin/system/ProcessKt", "exitProcess", "(I)Ljava/lang/Void;" | "(I)V") => {
+            Some("System.exit()")
+        }
+        _ => None,
     }
-    owner == "kotlin/system/ProcessKt"
-        && name == "exitProcess"
-        && matches!(descriptor, "(I)Ljava/lang/Void;" | "(I)V")
 }
 
 fn is_java_entrypoint_main_with_args(method: &crate::ir::Method) -> bool {
@@ -103,6 +120,7 @@ fn is_allowed_main_method(method: &crate::ir::Method, has_main_with_args: bool)
 mod tests {
     use std::path::PathBuf;
 
+    use crate::engine::RuleOptions;
     use crate::test_harness::{JvmTestHarness, Language, SourceFile};
 
     fn system_exit_messages(output: &crate::engine::EngineOutput) -> Vec<String> {
@@ -204,6 +222,74 @@ public class ClassC {
         );
     }
 
+    #[test]
+    fn system_exit_reports_call_in_main_method_when_not_allowed() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassC.java".to_string(),
+            contents: r#"
+package com.example;
+public class ClassC {
+    public static void main(String[] varOne) {
+        System.exit(0);
+    }
+}
+"#
+            .to_string(),
+        }];
+        let compiled = harness
+            .compile(Language::Java, &sources, &[])
+            .expect("compile classes");
+
+        let output = harness
+            .analyze_with_rule_options(
+                compiled.classes_dir(),
+                &[],
+                RuleOptions {
+                    allow_exit_in_main: false,
+                    ..RuleOptions::default()
+                },
+            )
+            .expect("run harness analysis");
+        let messages = system_exit_messages(&output);
+
+        assert_eq!(
+            messages,
+            vec![
+                "Avoid System.exit() in com/example/ClassC.main([Ljava/lang/String;)V; return an error or throw an exception instead."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn system_exit_reports_runtime_exit_call() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+public class ClassA {
+    public void methodX() {
+        Runtime.getRuntime().exit(1);
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let output = compile_and_analyze(&harness, Language::Java, &sources, &[]);
+        let messages = system_exit_messages(&output);
+
+        assert_eq!(
+            messages,
+            vec![
+                "Avoid Runtime.exit() in com/example/ClassA.methodX()V; return an error or throw an exception instead."
+                    .to_string()
+            ]
+        );
+    }
+
     #[test]
     fn system_exit_reports_kotlin_exit_process_outside_main() {
         let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
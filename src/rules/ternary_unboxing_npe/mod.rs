@@ -0,0 +1,459 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::dataflow::opcode_semantics::{
+    ApplyOutcome, SemanticsCoverage, SemanticsDebugConfig, SemanticsHooks, ValueDomain,
+    apply_semantics,
+};
+use crate::dataflow::stack_machine::StackMachine;
+use crate::dataflow::worklist::{
+    BlockEndStep, InstructionStep, WorklistConfig, WorklistSemantics, WorklistState,
+    analyze_method_with_config,
+};
+use crate::descriptor::{ReturnKind, method_param_count, method_return_kind};
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, Instruction, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Boxed types checked by this rule with their unboxing method and primitive descriptor.
+const BOXED_TYPES: &[(&str, &str, &str)] = &[
+    ("java/lang/Integer", "intValue", "I"),
+    ("java/lang/Long", "longValue", "J"),
+    ("java/lang/Boolean", "booleanValue", "Z"),
+];
+
+/// Rule that detects auto-unboxing of possibly null operands in conditional expressions.
+#[derive(Default)]
+pub(crate) struct TernaryUnboxingNpeRule;
+
+crate::register_rule!(TernaryUnboxingNpeRule);
+
+impl Rule for TernaryUnboxingNpeRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "TERNARY_UNBOXING_NPE",
+            name: "Unboxing in conditional expression",
+            description: "Conditional expressions mixing boxed and primitive operands unbox the boxed operand and throw NullPointerException when it is null",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.bytecode.is_empty() || method.access.is_synthetic {
+                            continue;
+                        }
+                        for finding in unboxing_findings(method)? {
+                            let detail = match finding.kind {
+                                FindingKind::Reboxed => {
+                                    "unboxes a boxed operand of a conditional expression whose other operand is primitive, then boxes the result again"
+                                }
+                                FindingKind::NullOperand => {
+                                    "unboxes the result of a conditional expression with a null operand"
+                                }
+                            };
+                            let message = result_message(format!(
+                                "{}.{}{} {detail}; a null operand throws NullPointerException. Make both operands the boxed type, e.g. Integer.valueOf(0).",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(finding.offset),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Shape of a reported conditional expression.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum FindingKind {
+    /// `cond ? boxed : 0` assigned to a boxed type; javac unboxes `boxed` inside its branch.
+    Reboxed,
+    /// `cond ? null : 0` assigned to a primitive; the merged result is unboxed.
+    NullOperand,
+}
+
+/// Unboxing call that may throw, identified by its offset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct Finding {
+    offset: u32,
+    kind: FindingKind,
+}
+
+fn unboxing_findings(method: &Method) -> Result<Vec<Finding>> {
+    let outcome =
+        analyze_method_with_config(method, &UnboxingSemantics, WorklistConfig::from_env())?;
+    if outcome.aborted {
+        return Ok(Vec::new());
+    }
+    Ok(outcome
+        .findings
+        .into_iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect())
+}
+
+/// Provenance of a stack or local value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum Value {
+    /// The `null` constant.
+    Null,
+    /// A reference loaded from a local whose content is otherwise unknown.
+    Local(usize),
+    /// A primitive produced by an unboxing call at this offset on an unchecked reference.
+    Unboxed(u32),
+    Other,
+}
+
+/// Value-domain adapter used by shared default opcode semantics.
+struct UnboxingValueDomain;
+
+impl ValueDomain<Value> for UnboxingValueDomain {
+    fn unknown_value(&self) -> Value {
+        Value::Other
+    }
+
+    fn scalar_value(&self) -> Value {
+        Value::Other
+    }
+}
+
+/// Symbolic execution state at a specific instruction position.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct ExecutionState {
+    block_start: u32,
+    instruction_index: usize,
+    machine: StackMachine<Value>,
+    /// Locals compared against null on this path; unboxing them is guarded.
+    null_checked_locals: BTreeSet<usize>,
+}
+
+impl WorklistState for ExecutionState {
+    fn block_start(&self) -> u32 {
+        self.block_start
+    }
+
+    fn instruction_index(&self) -> usize {
+        self.instruction_index
+    }
+
+    fn set_position(&mut self, block_start: u32, instruction_index: usize) {
+        self.block_start = block_start;
+        self.instruction_index = instruction_index;
+    }
+}
+
+/// Dataflow callbacks that follow unboxed values and `null` into branch merges.
+struct UnboxingSemantics;
+
+impl WorklistSemantics for UnboxingSemantics {
+    type State = ExecutionState;
+    type Finding = Finding;
+
+    fn initial_states(&self, _method: &Method) -> Vec<Self::State> {
+        vec![ExecutionState {
+            block_start: 0,
+            instruction_index: 0,
+            machine: StackMachine::new(Value::Other),
+            null_checked_locals: BTreeSet::new(),
+        }]
+    }
+
+    fn transfer_instruction(
+        &self,
+        method: &Method,
+        instruction: &Instruction,
+        state: &mut Self::State,
+    ) -> Result<InstructionStep<Self::Finding>> {
+        // javac places the merged operand's first use at the start of the join block.
+        let at_merge = instruction.offset == state.block_start;
+        match instruction.opcode {
+            opcodes::IRETURN
+            | opcodes::LRETURN
+            | opcodes::FRETURN
+            | opcodes::DRETURN
+            | opcodes::ARETURN
+            | opcodes::RETURN
+            | opcodes::ATHROW => return Ok(InstructionStep::terminate_path()),
+            opcodes::IFNULL | opcodes::IFNONNULL => {
+                if let Some(Value::Local(index)) = state.machine.peek() {
+                    state.null_checked_locals.insert(*index);
+                }
+            }
+            _ => {
+                if let Some(index) = astore_local_index(method, instruction) {
+                    state.null_checked_locals.remove(&index);
+                }
+            }
+        }
+
+        match &instruction.kind {
+            InstructionKind::Invoke(call) if is_unboxing_call(call) => {
+                let receiver = state.machine.pop();
+                let step = InstructionStep::continue_path();
+                if at_merge && receiver == Value::Null {
+                    state.machine.push(Value::Other);
+                    return Ok(step.with_finding(Finding {
+                        offset: instruction.offset,
+                        kind: FindingKind::NullOperand,
+                    }));
+                }
+                let guarded = matches!(
+                    receiver,
+                    Value::Local(index) if state.null_checked_locals.contains(&index)
+                );
+                state.machine.push(if guarded {
+                    Value::Other
+                } else {
+                    Value::Unboxed(instruction.offset)
+                });
+                return Ok(step);
+            }
+            InstructionKind::Invoke(call) if is_boxing_call(call) => {
+                let argument = state.machine.pop();
+                state.machine.push(Value::Other);
+                let step = InstructionStep::continue_path();
+                if at_merge && let Value::Unboxed(offset) = argument {
+                    return Ok(step.with_finding(Finding {
+                        offset,
+                        kind: FindingKind::Reboxed,
+                    }));
+                }
+                return Ok(step);
+            }
+            _ => {}
+        }
+
+        let _ = apply_semantics(
+            &mut state.machine,
+            method,
+            instruction.offset as usize,
+            instruction.opcode,
+            &UnboxingValueDomain,
+            &mut UnboxingSemanticsHook,
+            &mut SemanticsCoverage::default(),
+            SemanticsDebugConfig {
+                enabled: false,
+                rule_id: "TERNARY_UNBOXING_NPE",
+            },
+        );
+        match &instruction.kind {
+            InstructionKind::Invoke(call) => {
+                state.machine.pop_n(method_param_count(&call.descriptor)?);
+                if call.kind != CallKind::Static {
+                    state.machine.pop();
+                }
+                if method_return_kind(&call.descriptor)? != ReturnKind::Void {
+                    state.machine.push(Value::Other);
+                }
+            }
+            InstructionKind::InvokeDynamic { descriptor, .. } => {
+                state.machine.pop_n(method_param_count(descriptor)?);
+                if method_return_kind(descriptor)? != ReturnKind::Void {
+                    state.machine.push(Value::Other);
+                }
+            }
+            _ => {}
+        }
+        Ok(InstructionStep::continue_path())
+    }
+
+    fn on_block_end(
+        &self,
+        _method: &Method,
+        state: &Self::State,
+        successors: &[u32],
+    ) -> Result<BlockEndStep<Self::State, Self::Finding>> {
+        Ok(BlockEndStep::follow_all_successors(state, successors))
+    }
+}
+
+/// Rule-specific hook that marks `aconst_null` results and loads of unknown locals.
+struct UnboxingSemanticsHook;
+
+impl SemanticsHooks<Value> for UnboxingSemanticsHook {
+    fn pre_apply(
+        &mut self,
+        machine: &mut StackMachine<Value>,
+        method: &Method,
+        offset: usize,
+        opcode: u8,
+    ) -> ApplyOutcome {
+        let index = match opcode {
+            opcodes::ACONST_NULL => {
+                machine.push(Value::Null);
+                return ApplyOutcome::Applied;
+            }
+            opcodes::ALOAD => match method.bytecode.get(offset + 1) {
+                Some(index) => usize::from(*index),
+                None => return ApplyOutcome::NotHandled,
+            },
+            opcodes::ALOAD_0..=opcodes::ALOAD_3 => usize::from(opcode - opcodes::ALOAD_0),
+            _ => return ApplyOutcome::NotHandled,
+        };
+        let value = match machine.load_local(index) {
+            Value::Other => Value::Local(index),
+            value => value,
+        };
+        machine.push(value);
+        ApplyOutcome::Applied
+    }
+}
+
+fn astore_local_index(method: &Method, instruction: &Instruction) -> Option<usize> {
+    match instruction.opcode {
+        opcodes::ASTORE => method
+            .bytecode
+            .get(instruction.offset as usize + 1)
+            .copied()
+            .map(usize::from),
+        opcodes::ASTORE_0..=opcodes::ASTORE_3 => {
+            Some(usize::from(instruction.opcode - opcodes::ASTORE_0))
+        }
+        _ => None,
+    }
+}
+
+fn is_unboxing_call(call: &CallSite) -> bool {
+    call.kind == CallKind::Virtual
+        && BOXED_TYPES.iter().any(|(owner, name, primitive)| {
+            call.owner == *owner
+                && call.name == *name
+                && call.descriptor.strip_prefix("()") == Some(*primitive)
+        })
+}
+
+fn is_boxing_call(call: &CallSite) -> bool {
+    call.kind == CallKind::Static
+        && call.name == "valueOf"
+        && BOXED_TYPES.iter().any(|(owner, _, primitive)| {
+            call.owner == *owner && call.descriptor == format!("({primitive})L{owner};")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("TERNARY_UNBOXING_NPE"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_boxed_operand_unboxed_and_reboxed() {
+        let messages = analyze(
+            r#"
+package com.example;
+import java.util.Map;
+public class ClassA {
+    public Integer methodX(Map<String, Integer> varOne, boolean varTwo) {
+        return varTwo ? varOne.get("key") : 0;
+    }
+    public Long methodY(Long varOne, boolean varTwo) {
+        Long tmpValue = varTwo ? varOne : 1L;
+        return tmpValue;
+    }
+    public Boolean methodZ(Boolean varOne, boolean varTwo) {
+        return varTwo ? varOne : false;
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 3, "unexpected findings: {messages:?}");
+        assert!(messages.contains(
+            &"com/example/ClassA.methodX(Ljava/util/Map;Z)Ljava/lang/Integer; unboxes a boxed operand of a conditional expression whose other operand is primitive, then boxes the result again; a null operand throws NullPointerException. Make both operands the boxed type, e.g. Integer.valueOf(0)."
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn reports_null_operand_unboxed_after_merge() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public int methodX(boolean varOne) {
+        return varOne ? null : 0;
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(
+            messages[0]
+                .contains("unboxes the result of a conditional expression with a null operand")
+        );
+    }
+
+    #[test]
+    fn does_not_report_boxed_operands_or_null_guarded_unboxing() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    public Integer methodX(Integer varOne, boolean varTwo) {
+        return varTwo ? varOne : Integer.valueOf(0);
+    }
+    public Integer methodY(Integer varOne) {
+        return varOne == null ? 0 : varOne;
+    }
+    public int methodZ(Integer varOne, boolean varTwo) {
+        return varTwo ? varOne : 0;
+    }
+    public Integer methodW(Integer varOne) {
+        return Integer.valueOf(varOne.intValue());
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
@@ -4,7 +4,7 @@ use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
 use crate::ir::{CallKind, CallSite, Method};
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct calls to `Thread.run()`.
 #[derive(Default)]
@@ -18,6 +18,9 @@ impl Rule for ThreadRunDirectCallRule {
             id: "THREAD_RUN_DIRECT_CALL",
             name: "Thread.run direct call",
             description: "Direct Thread.run() calls execute synchronously on the current thread",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency"],
         }
     }
 
@@ -34,10 +37,10 @@ impl Rule for ThreadRunDirectCallRule {
                     let artifact_uri = context.class_artifact_uri(class);
                     for method in &class.methods {
                         for call in &method.calls {
-                            if !is_thread_run_call(call) {
+                            if !is_thread_run_call(call, context) {
                                 continue;
                             }
-                            if is_allowed_super_run_call(method, call) {
+                            if is_allowed_super_run_call(method, call, context) {
                                 continue;
                             }
 
@@ -69,15 +72,22 @@ impl Rule for ThreadRunDirectCallRule {
     }
 }
 
-fn is_thread_run_call(call: &CallSite) -> bool {
-    call.owner == "java/lang/Thread" && call.name == "run" && call.descriptor == "()V"
+/// Returns true for `run()V` calls on `java/lang/Thread` or one of its subclasses.
+fn is_thread_run_call(call: &CallSite, context: &AnalysisContext) -> bool {
+    call.name == "run"
+        && call.descriptor == "()V"
+        && context.is_subtype_of(&call.owner, "java/lang/Thread")
 }
 
-fn is_allowed_super_run_call(method: &Method, call: &CallSite) -> bool {
+fn is_allowed_super_run_call(
+    method: &Method,
+    call: &CallSite,
+    context: &AnalysisContext,
+) -> bool {
     method.name == "run"
         && method.descriptor == "()V"
         && call.kind == CallKind::Special
-        && is_thread_run_call(call)
+        && is_thread_run_call(call, context)
 }
 
 #[cfg(test)]
@@ -182,6 +192,57 @@ public class ClassC extends Thread {
         );
     }
 
+    #[test]
+    fn thread_run_direct_call_reports_run_call_on_thread_subclass() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![
+            SourceFile {
+                path: "com/example/ClassB.java".to_string(),
+                contents: r#"
+package com.example;
+public class ClassB extends Thread {}
+"#
+                .to_string(),
+            },
+            SourceFile {
+                path: "com/example/ClassC.java".to_string(),
+                contents: r#"
+package com.example;
+public class ClassC extends ClassB {
+    @Override
+    public void run() {
+        super.run();
+    }
+}
+"#
+                .to_string(),
+            },
+            SourceFile {
+                path: "com/example/ClassA.java".to_string(),
+                contents: r#"
+package com.example;
+public class ClassA {
+    public void methodX(ClassC varOne, Runnable varTwo) {
+        varOne.run();
+        varTwo.run();
+    }
+}
+"#
+                .to_string(),
+            },
+        ];
+
+        let output = compile_and_analyze(&harness, &sources, &[]);
+        let messages = thread_run_messages(&output);
+        assert_eq!(
+            messages,
+            vec![
+                "Avoid direct Thread.run() in com/example/ClassA.methodX(Lcom/example/ClassC;Ljava/lang/Runnable;)V; call start() for asynchronous execution."
+                    .to_string()
+            ]
+        );
+    }
+
     #[test]
     fn thread_run_direct_call_ignores_classpath_calls() {
         let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct Thread.sleep calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for ThreadSleepCallRule {
             id: "THREAD_SLEEP_CALL",
             name: "Thread.sleep call",
             description: "Thread.sleep introduces timing-coupled blocking",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency"],
         }
     }
 
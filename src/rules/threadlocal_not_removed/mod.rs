@@ -0,0 +1,255 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{Class, Instruction, InstructionKind};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, class_location, result_message};
+
+const THREAD_LOCAL_TYPES: &[&str] = &["java/lang/ThreadLocal", "java/lang/InheritableThreadLocal"];
+
+/// Rule that flags `ThreadLocal` fields that are set or read but never removed.
+#[derive(Default)]
+pub(crate) struct ThreadlocalNotRemovedRule;
+
+crate::register_rule!(ThreadlocalNotRemovedRule);
+
+impl Rule for ThreadlocalNotRemovedRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "THREADLOCAL_NOT_REMOVED",
+            name: "ThreadLocal never removed",
+            description: "ThreadLocal values that are never removed outlive the work that set them on pooled threads",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["resource"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for field_name in leaking_fields(class) {
+                        let message = result_message(format!(
+                            "ThreadLocal field {}.{field_name} is set or read but never removed; call remove() in a finally block when the work is done, or the value leaks on pooled threads.",
+                            class.name
+                        ));
+                        let location = class_location(&class.name, artifact_uri.as_deref());
+                        class_results.push(
+                            SarifResult::builder()
+                                .message(message)
+                                .locations(vec![location])
+                                .build(),
+                        );
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+/// Returns the sorted names of `ThreadLocal` fields of `class` used with `get` or `set` but
+/// never with `remove`.
+///
+/// A call is attributed to the field whose value was last loaded in the method. A `remove`
+/// whose receiver is not a field load counts for every field, and a `get` or `set` on an
+/// unknown receiver counts only when the class has a single `ThreadLocal` field.
+fn leaking_fields(class: &Class) -> Vec<String> {
+    let fields: BTreeSet<&str> = class
+        .fields
+        .iter()
+        .filter(|field| {
+            field
+                .descriptor
+                .strip_prefix('L')
+                .and_then(|name| name.strip_suffix(';'))
+                .is_some_and(|name| THREAD_LOCAL_TYPES.contains(&name))
+        })
+        .map(|field| field.name.as_str())
+        .collect();
+    if fields.is_empty() {
+        return Vec::new();
+    }
+
+    let mut used = BTreeSet::new();
+    let mut removed = BTreeSet::new();
+    for method in &class.methods {
+        let mut instructions: Vec<&Instruction> = method
+            .cfg
+            .blocks
+            .iter()
+            .flat_map(|block| block.instructions.iter())
+            .collect();
+        instructions.sort_by_key(|instruction| instruction.offset);
+
+        let mut last_loaded = None;
+        for instruction in instructions {
+            match &instruction.kind {
+                InstructionKind::FieldAccess(field)
+                    if matches!(instruction.opcode, opcodes::GETFIELD | opcodes::GETSTATIC) =>
+                {
+                    last_loaded = (field.owner == class.name)
+                        .then(|| fields.get(field.name.as_str()).copied())
+                        .flatten();
+                }
+                InstructionKind::Invoke(call)
+                    if THREAD_LOCAL_TYPES.contains(&call.owner.as_str()) =>
+                {
+                    let targets: Vec<&str> = match last_loaded.take() {
+                        Some(field) => vec![field],
+                        None if call.name == "remove" || fields.len() == 1 => {
+                            fields.iter().copied().collect()
+                        }
+                        None => Vec::new(),
+                    };
+                    match call.name.as_str() {
+                        "remove" => removed.extend(targets),
+                        "get" | "set" => used.extend(targets),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    used.difference(&removed)
+        .map(|field| field.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze_rules(Language::Java, &sources, &[], &["THREADLOCAL_NOT_REMOVED"])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("THREADLOCAL_NOT_REMOVED"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_thread_local_set_without_remove() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    private static final ThreadLocal<String> CONTEXT = new ThreadLocal<>();
+    void methodX(String varOne) {
+        CONTEXT.set(varOne);
+    }
+    String methodY() {
+        return CONTEXT.get();
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "ThreadLocal field com/example/ClassA.CONTEXT is set or read but never removed; call remove() in a finally block when the work is done, or the value leaks on pooled threads."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_thread_local_removed_in_finally() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    private static final ThreadLocal<String> CONTEXT = new ThreadLocal<>();
+    void methodX(String varOne, Runnable varTwo) {
+        CONTEXT.set(varOne);
+        try {
+            varTwo.run();
+        } finally {
+            CONTEXT.remove();
+        }
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "{messages:?}");
+    }
+
+    #[test]
+    fn reports_only_the_field_that_is_not_removed() {
+        let messages = analyze(
+            r#"
+package com.example;
+public class ClassA {
+    private final ThreadLocal<String> fieldOne = new ThreadLocal<>();
+    private final ThreadLocal<Integer> fieldTwo = new ThreadLocal<>();
+    void methodX(String varOne) {
+        fieldOne.set(varOne);
+        fieldTwo.set(1);
+        fieldTwo.remove();
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].contains("ClassA.fieldOne"), "{messages:?}");
+    }
+
+    #[test]
+    fn does_not_run_by_default() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+public class ClassA {
+    private static final ThreadLocal<String> CONTEXT = new ThreadLocal<>();
+    void methodX(String varOne) {
+        CONTEXT.set(varOne);
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+
+        assert!(
+            output
+                .results
+                .iter()
+                .all(|result| result.rule_id.as_deref() != Some("THREADLOCAL_NOT_REMOVED"))
+        );
+    }
+}
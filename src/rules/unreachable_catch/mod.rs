@@ -0,0 +1,283 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{ExceptionHandler, Method};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Rule that detects catch handlers shadowed by an earlier handler for a supertype.
+#[derive(Default)]
+pub(crate) struct UnreachableCatchRule;
+
+crate::register_rule!(UnreachableCatchRule);
+
+impl Rule for UnreachableCatchRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "UNREACHABLE_CATCH",
+            name: "Unreachable catch block",
+            description: "Catch blocks for a subtype placed after a catch block for its supertype never run",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        for (handler, shadowing_type) in shadowed_handlers(method, context) {
+                            let catch_type = handler.catch_type.as_deref().unwrap_or_default();
+                            let message = result_message(format!(
+                                "Catch of {catch_type} in {}.{}{} is unreachable because an earlier catch of {shadowing_type} already handles it; move it before that catch or remove it.",
+                                class.name, method.name, method.descriptor
+                            ));
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                method.line_for_offset(handler.handler_pc),
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Returns each handler whose whole range is covered by an earlier handler for the same type or
+/// a supertype, with that earlier handler's catch type. The JVM picks the first matching entry
+/// of the exception table, so such a handler never runs.
+fn shadowed_handlers<'a>(
+    method: &'a Method,
+    context: &AnalysisContext,
+) -> Vec<(&'a ExceptionHandler, &'a str)> {
+    let mut shadowed = Vec::new();
+    let mut reported = BTreeSet::new();
+    for (index, handler) in method.exception_handlers.iter().enumerate() {
+        let Some(catch_type) = handler.catch_type.as_deref() else {
+            continue;
+        };
+        let shadowing_type = method.exception_handlers[..index]
+            .iter()
+            .filter(|earlier| {
+                earlier.handler_pc != handler.handler_pc
+                    && earlier.start_pc <= handler.start_pc
+                    && handler.end_pc <= earlier.end_pc
+            })
+            .filter_map(|earlier| earlier.catch_type.as_deref())
+            .find(|earlier_type| context.is_subtype_of(catch_type, earlier_type));
+        if let Some(shadowing_type) = shadowing_type
+            && reported.insert((handler.handler_pc, catch_type))
+        {
+            shadowed.push((handler, shadowing_type));
+        }
+    }
+    shadowed
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::descriptor::method_param_count;
+    use crate::engine::build_context;
+    use crate::ir::{
+        Class, ControlFlowGraph, ExceptionHandler, Method, MethodAccess, MethodNullness,
+    };
+    use crate::rules::Rule;
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    use super::UnreachableCatchRule;
+
+    fn handler(start_pc: u32, end_pc: u32, handler_pc: u32, catch_type: &str) -> ExceptionHandler {
+        ExceptionHandler {
+            start_pc,
+            end_pc,
+            handler_pc,
+            catch_type: Some(catch_type.to_string()),
+        }
+    }
+
+    fn method_with_handlers(handlers: Vec<ExceptionHandler>) -> Method {
+        Method {
+            name: "methodX".to_string(),
+            descriptor: "()V".to_string(),
+            signature: None,
+            access: MethodAccess {
+                is_public: true,
+                is_private: false,
+                is_static: false,
+                is_synchronized: false,
+                is_abstract: false,
+                is_final: false,
+                is_synthetic: false,
+                is_bridge: false,
+            },
+            nullness: MethodNullness::unknown(method_param_count("()V").expect("param count")),
+            type_use: None,
+            bytecode: vec![0],
+            line_numbers: Vec::new(),
+            cfg: ControlFlowGraph {
+                blocks: Vec::new(),
+                edges: Vec::new(),
+            },
+            calls: Vec::new(),
+            string_literals: Vec::new(),
+            exception_handlers: handlers,
+            local_variables: Vec::new(),
+            local_variable_types: Vec::new(),
+        }
+    }
+
+    fn class(name: &str, super_name: &str, methods: Vec<Method>) -> Class {
+        Class {
+            name: name.to_string(),
+            source_file: None,
+            super_name: Some(super_name.to_string()),
+            interfaces: Vec::new(),
+            type_parameters: Vec::new(),
+            referenced_classes: Vec::new(),
+            fields: Vec::new(),
+            methods,
+            annotation_defaults: Vec::new(),
+            artifact_index: 0,
+            is_record: false,
+            is_final: false,
+            content_hash: String::new(),
+        }
+    }
+
+    fn run(classes: Vec<Class>) -> Vec<String> {
+        let context = build_context(classes, &[]);
+        UnreachableCatchRule
+            .run(&context)
+            .expect("run rule")
+            .into_iter()
+            .filter_map(|result| result.message.text)
+            .collect()
+    }
+
+    #[test]
+    fn reports_io_exception_catch_after_exception_catch() {
+        let method = method_with_handlers(vec![
+            handler(0, 8, 11, "java/lang/Exception"),
+            handler(0, 8, 20, "java/io/IOException"),
+        ]);
+
+        let messages = run(vec![class(
+            "com/example/ClassA",
+            "java/lang/Object",
+            vec![method],
+        )]);
+
+        assert_eq!(
+            messages,
+            vec![
+                "Catch of java/io/IOException in com/example/ClassA.methodX()V is unreachable because an earlier catch of java/lang/Exception already handles it; move it before that catch or remove it."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_application_exception_hierarchy() {
+        let method = method_with_handlers(vec![
+            handler(0, 8, 11, "java/lang/RuntimeException"),
+            handler(0, 8, 20, "com/example/ChildException"),
+        ]);
+
+        let messages = run(vec![
+            class("com/example/ClassA", "java/lang/Object", vec![method]),
+            class(
+                "com/example/ParentException",
+                "java/lang/IllegalStateException",
+                Vec::new(),
+            ),
+            class(
+                "com/example/ChildException",
+                "com/example/ParentException",
+                Vec::new(),
+            ),
+        ]);
+
+        assert_eq!(messages.len(), 1, "unexpected findings: {messages:?}");
+        assert!(messages[0].starts_with("Catch of com/example/ChildException"));
+    }
+
+    #[test]
+    fn does_not_report_handlers_for_disjoint_ranges() {
+        let method = method_with_handlers(vec![
+            handler(0, 8, 11, "java/lang/Exception"),
+            handler(14, 20, 23, "java/io/IOException"),
+        ]);
+
+        let messages = run(vec![class(
+            "com/example/ClassA",
+            "java/lang/Object",
+            vec![method],
+        )]);
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn does_not_report_narrow_catch_before_broad_catch() {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: r#"
+package com.example;
+import java.io.IOException;
+import java.io.InputStream;
+public class ClassA {
+    int methodX(InputStream varOne) {
+        try {
+            return varOne.read();
+        } catch (IOException varTwo) {
+            return -1;
+        } catch (Exception varThree) {
+            return -2;
+        } finally {
+            System.out.println("done");
+        }
+    }
+}
+"#
+            .to_string(),
+        }];
+
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+
+        let messages = output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("UNREACHABLE_CATCH"))
+            .filter_map(|result| result.message.text.clone())
+            .collect::<Vec<_>>();
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+}
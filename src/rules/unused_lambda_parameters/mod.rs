@@ -8,7 +8,7 @@ use crate::descriptor::{method_param_slots, method_param_start_slots};
 use crate::engine::AnalysisContext;
 use crate::ir::{Class, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects unused lambda parameters in Java and Kotlin lambda expressions.
 #[derive(Default)]
@@ -22,6 +22,9 @@ impl Rule for UnusedLambdaParametersRule {
             id: "UNUSED_LAMBDA_PARAMETERS",
             name: "Unused lambda parameter",
             description: "Reports lambda parameters that are never referenced in the lambda body",
+            severity: Severity::Note,
+            rank: None,
+            tags: &["maintainability"],
         }
     }
 
@@ -100,6 +103,7 @@ fn check_java_lambdas(class: &Class, artifact_uri: Option<&str>) -> Result<Vec<S
                 if let InstructionKind::InvokeDynamic {
                     descriptor,
                     impl_method: Some(impl_name),
+                    ..
                 } = &instr.kind
                 {
                     let captured_slots = method_param_slots(descriptor).unwrap_or(0);
@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct `URL.equals(Object)` calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for UrlEqualsCallRule {
             id: "URL_EQUALS_CALL",
             name: "URL equals call",
             description: "URL.equals may trigger host resolution and surprising equality semantics",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["performance"],
         }
     }
 
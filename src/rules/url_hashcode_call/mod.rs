@@ -3,7 +3,7 @@ use opentelemetry::KeyValue;
 use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct `URL.hashCode()` calls.
 #[derive(Default)]
@@ -17,6 +17,9 @@ impl Rule for UrlHashcodeCallRule {
             id: "URL_HASHCODE_CALL",
             name: "URL hashCode call",
             description: "URL.hashCode may trigger host resolution and surprising hash semantics",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["performance"],
         }
     }
 
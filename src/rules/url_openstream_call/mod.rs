@@ -4,7 +4,7 @@ use serde_sarif::sarif::Result as SarifResult;
 
 use crate::engine::AnalysisContext;
 use crate::ir::Method;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects direct URL.openStream calls.
 #[derive(Default)]
@@ -18,6 +18,9 @@ impl Rule for UrlOpenstreamCallRule {
             id: "URL_OPENSTREAM_CALL",
             name: "URL.openStream call",
             description: "URL.openStream can hide timeout and connection configuration",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["correctness"],
         }
     }
 
@@ -7,7 +7,7 @@ use serde_sarif::sarif::Result as SarifResult;
 use crate::engine::AnalysisContext;
 use crate::ir::{Class, FieldRef, Instruction, InstructionKind, Method};
 use crate::opcodes;
-use crate::rules::{Rule, RuleMetadata, method_location_with_line, result_message};
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
 
 /// Rule that detects non-atomic read-modify-write updates on volatile fields.
 #[derive(Default)]
@@ -37,6 +37,9 @@ impl Rule for VolatileIncrementNonAtomicRule {
             id: "VOLATILE_INCREMENT_NON_ATOMIC",
             name: "Non-atomic update on volatile field",
             description: "Read-modify-write updates on volatile fields can lose concurrent updates",
+            severity: Severity::Error,
+            rank: Some(80),
+            tags: &["concurrency"],
         }
     }
 
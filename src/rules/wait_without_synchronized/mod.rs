@@ -0,0 +1,257 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use anyhow::Result;
+use opentelemetry::KeyValue;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::engine::AnalysisContext;
+use crate::ir::{CallKind, CallSite, InstructionKind, Method};
+use crate::opcodes;
+use crate::rules::{Rule, RuleMetadata, Severity, method_location_with_line, result_message};
+
+/// Upper bound for tracked monitor depth so unbalanced bytecode in loops still converges.
+const MAX_MONITOR_DEPTH: u32 = 16;
+
+/// Rule that detects wait/notify calls made outside any synchronized region of the method.
+#[derive(Default)]
+pub(crate) struct WaitWithoutSynchronizedRule;
+
+crate::register_rule!(WaitWithoutSynchronizedRule);
+
+impl Rule for WaitWithoutSynchronizedRule {
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata {
+            id: "WAIT_WITHOUT_SYNCHRONIZED",
+            name: "wait/notify without holding a monitor",
+            description: "Object.wait/notify/notifyAll outside synchronized code throw IllegalMonitorStateException",
+            severity: Severity::Warning,
+            rank: None,
+            tags: &["concurrency", "correctness"],
+        }
+    }
+
+    fn run(&self, context: &AnalysisContext) -> Result<Vec<SarifResult>> {
+        let mut results = Vec::new();
+        for class in context.analysis_target_classes() {
+            let mut attributes = vec![KeyValue::new("inspequte.class", class.name.clone())];
+            if let Some(uri) = context.class_artifact_uri(class) {
+                attributes.push(KeyValue::new("inspequte.artifact_uri", uri));
+            }
+            let class_results =
+                context.with_span("class", &attributes, || -> Result<Vec<SarifResult>> {
+                    let mut class_results = Vec::new();
+                    let artifact_uri = context.class_artifact_uri(class);
+                    for method in &class.methods {
+                        if method.access.is_synchronized || method.bytecode.is_empty() {
+                            continue;
+                        }
+                        for call in unguarded_monitor_calls(method) {
+                            let message = result_message(format!(
+                                "{}() is called in {}.{}{} outside a synchronized block; the caller must hold the object's monitor or the call throws IllegalMonitorStateException.",
+                                call.name, class.name, method.name, method.descriptor
+                            ));
+                            let line = method.line_for_offset(call.offset);
+                            let location = method_location_with_line(
+                                &class.name,
+                                &method.name,
+                                &method.descriptor,
+                                artifact_uri.as_deref(),
+                                line,
+                            );
+                            class_results.push(
+                                SarifResult::builder()
+                                    .message(message)
+                                    .locations(vec![location])
+                                    .build(),
+                            );
+                        }
+                    }
+                    Ok(class_results)
+                })?;
+            results.extend(class_results);
+        }
+        Ok(results)
+    }
+}
+
+/// Wait-family calls reached with no MONITORENTER held on any path from the method entry.
+///
+/// Blocks not reachable through normal control flow, such as exception handlers, are skipped
+/// because their monitor depth is unknown.
+fn unguarded_monitor_calls(method: &Method) -> Vec<&CallSite> {
+    let entry_depths = block_entry_depths(method);
+    let mut calls = Vec::new();
+    for block in &method.cfg.blocks {
+        let Some(mut depth) = entry_depths.get(&block.start_offset).copied() else {
+            continue;
+        };
+        for instruction in &block.instructions {
+            depth = monitor_depth_after(depth, instruction.opcode);
+            if let InstructionKind::Invoke(call) = &instruction.kind
+                && depth == 0
+                && is_monitor_method(call)
+            {
+                calls.push(call);
+            }
+        }
+    }
+    calls.sort_by_key(|call| call.offset);
+    calls
+}
+
+/// Highest monitor depth on entry to each block reachable from the method entry.
+fn block_entry_depths(method: &Method) -> BTreeMap<u32, u32> {
+    let mut successors: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for edge in &method.cfg.edges {
+        successors.entry(edge.from).or_default().push(edge.to);
+    }
+    let mut depths = BTreeMap::new();
+    let Some(entry) = method
+        .cfg
+        .blocks
+        .iter()
+        .map(|block| block.start_offset)
+        .min()
+    else {
+        return depths;
+    };
+    depths.insert(entry, 0);
+    let mut queue = VecDeque::from([entry]);
+    while let Some(block_start) = queue.pop_front() {
+        let Some(block) = method
+            .cfg
+            .blocks
+            .iter()
+            .find(|block| block.start_offset == block_start)
+        else {
+            continue;
+        };
+        let mut depth = depths[&block_start];
+        for instruction in &block.instructions {
+            depth = monitor_depth_after(depth, instruction.opcode);
+        }
+        for successor in successors.get(&block_start).into_iter().flatten() {
+            let known = depths.get(successor).copied();
+            if known.is_none_or(|known| depth > known) {
+                depths.insert(*successor, depth);
+                queue.push_back(*successor);
+            }
+        }
+    }
+    depths
+}
+
+fn monitor_depth_after(depth: u32, opcode: u8) -> u32 {
+    match opcode {
+        opcodes::MONITORENTER => (depth + 1).min(MAX_MONITOR_DEPTH),
+        opcodes::MONITOREXIT => depth.saturating_sub(1),
+        _ => depth,
+    }
+}
+
+fn is_monitor_method(call: &CallSite) -> bool {
+    call.kind == CallKind::Virtual
+        && matches!(
+            (call.name.as_str(), call.descriptor.as_str()),
+            ("wait", "()V" | "(J)V" | "(JI)V") | ("notify" | "notifyAll", "()V")
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_harness::{JvmTestHarness, Language, SourceFile};
+
+    fn analyze(contents: &str) -> Vec<String> {
+        let harness = JvmTestHarness::new().expect("JAVA_HOME must be set for harness tests");
+        let sources = vec![SourceFile {
+            path: "com/example/ClassA.java".to_string(),
+            contents: contents.to_string(),
+        }];
+        let output = harness
+            .compile_and_analyze(Language::Java, &sources, &[])
+            .expect("run harness analysis");
+        output
+            .results
+            .iter()
+            .filter(|result| result.rule_id.as_deref() == Some("WAIT_WITHOUT_SYNCHRONIZED"))
+            .filter_map(|result| result.message.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn reports_bare_wait_and_notify() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+public class ClassA {
+    private final Object varOne = new Object();
+
+    public void methodX() throws InterruptedException {
+        varOne.wait();
+    }
+
+    public void methodY() {
+        varOne.notifyAll();
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 2, "{messages:?}");
+        assert!(messages[0].starts_with("wait() is called in com/example/ClassA.methodX()V"));
+        assert!(messages[1].starts_with("notifyAll() is called in com/example/ClassA.methodY()V"));
+    }
+
+    #[test]
+    fn does_not_report_calls_inside_synchronized_code() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+public class ClassA {
+    private final Object varOne = new Object();
+    private final Object varTwo = new Object();
+
+    public void methodX() throws InterruptedException {
+        synchronized (varOne) {
+            synchronized (varTwo) {
+                varTwo.notify();
+            }
+            varOne.wait(10L);
+        }
+    }
+
+    public synchronized void methodY() {
+        notifyAll();
+    }
+}
+"#,
+        );
+
+        assert!(messages.is_empty(), "unexpected findings: {messages:?}");
+    }
+
+    #[test]
+    fn reports_wait_after_synchronized_block_ends() {
+        let messages = analyze(
+            r#"
+package com.example;
+
+public class ClassA {
+    private final Object varOne = new Object();
+
+    public void methodX() throws InterruptedException {
+        synchronized (varOne) {
+            varOne.notify();
+        }
+        varOne.wait();
+    }
+}
+"#,
+        );
+
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].starts_with("wait() is called"));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::{BTreeSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fs;
 use std::io::{Cursor, Read, Seek};
 use std::path::{Path, PathBuf};
@@ -10,8 +10,11 @@ use jclassfile::constant_pool::ConstantPool;
 use jclassfile::fields::FieldFlags;
 use jclassfile::methods::MethodFlags;
 use jdescriptor::{MethodDescriptor, TypeDescriptor};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_sarif::sarif::{Artifact, ArtifactLocation, ArtifactRoles};
+use sha2::{Digest, Sha256};
+use tracing::warn;
 use zip::ZipArchive;
 
 use opentelemetry::Context as OtelContext;
@@ -24,34 +27,69 @@ use crate::ir::{
     AnnotationDefaultNumeric, AnnotationDefaultValue, CallKind, CallSite, Class, ClassTypeUse,
     ExceptionHandler, Field, FieldAccess, FieldRef, Instruction, InstructionKind, LineNumber,
     LocalVariable, LocalVariableType, Method, MethodAccess, MethodNullness, MethodTypeUse,
-    Nullness, TypeParameterUse, TypeUse, TypeUseKind,
+    ModuleDescriptor, ModuleRequires, Nullness, TypeParameterUse, TypeUse, TypeUseKind,
 };
 use crate::opcodes;
 use crate::telemetry::Telemetry;
 
 /// Snapshot of parsed artifacts, classes, and counts for a scan.
+#[derive(Default)]
 pub(crate) struct ScanOutput {
     pub(crate) artifacts: Vec<Artifact>,
     pub(crate) class_count: usize,
     pub(crate) classes: Vec<Class>,
+    /// Module declarations from `module-info.class` files among the inputs. They are not
+    /// counted or returned as classes.
+    pub(crate) modules: Vec<ModuleDescriptor>,
 }
 
+/// Options that change how inputs are discovered and parsed.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ScanOptions {
+    /// Java release used to select `META-INF/versions/N/` entries in multi-release JARs.
+    /// When unset, only base (unversioned) entries are scanned.
+    pub(crate) release: Option<u32>,
+    /// Add JARs listed in the `Class-Path` attribute of JAR manifests to the classpath.
+    pub(crate) follow_manifest_classpath: bool,
+    /// Treat `BOOT-INF/classes/` of Spring Boot fat JARs as analysis targets and
+    /// `BOOT-INF/lib/` JARs as classpath.
+    pub(crate) spring_boot: bool,
+    /// Record the SHA-256 of each artifact's bytes in SARIF `artifacts[].hashes`.
+    pub(crate) emit_artifact_hashes: bool,
+    /// Scan Android `.dex` files and the `classes*.dex` entries of `.apk` files.
+    pub(crate) dex: bool,
+}
+
+const SPRING_BOOT_CLASSES_PREFIX: &str = "BOOT-INF/classes/";
+/// Directory holding class files inside a `.jmod` archive.
+const JMOD_CLASSES_PREFIX: &str = "classes/";
+/// Magic number and version that precede the zip data of a `.jmod` file.
+const JMOD_HEADER: &[u8] = b"JM\x01\x00";
+
 pub(crate) fn scan_inputs(
     input: &[PathBuf],
     classpath: &[PathBuf],
+    options: &ScanOptions,
     telemetry: Option<&Telemetry>,
 ) -> Result<ScanOutput> {
     // Keep deterministic ordering by sorting classpath entries and directory listings.
     let mut classpath_entries = classpath.to_vec();
     classpath_entries.sort_by_key(|a| path_key(a));
 
-    for entry in input {
-        if is_jar_path(entry) {
-            classpath_entries.extend(manifest_classpath(entry)?);
+    let mut manifest_entries = Vec::new();
+    if options.follow_manifest_classpath {
+        for entry in input {
+            if is_jar_path(entry) {
+                manifest_entries.extend(manifest_classpath(entry)?);
+            }
         }
     }
 
-    let expanded = expand_classpath(classpath_entries)?;
+    let expanded = expand_classpath(
+        classpath_entries,
+        manifest_entries,
+        options.follow_manifest_classpath,
+    )?;
     let mut targets = Vec::with_capacity(expanded.len() + input.len());
     for (index, entry) in input.iter().enumerate() {
         targets.push(ScanTarget {
@@ -77,54 +115,50 @@ pub(crate) fn scan_inputs(
         .par_iter()
         .map(|target| {
             let _guard = telemetry.map(|_| parent_cx.clone().attach());
-            let mut artifacts = Vec::new();
-            let mut class_count = 0;
-            let mut classes = Vec::new();
+            let mut output = ScanOutput::default();
             scan_path(
                 &target.path,
                 target.is_input,
                 true,
+                options,
                 telemetry,
-                &mut artifacts,
-                &mut class_count,
-                &mut classes,
+                &mut output,
             )?;
-            Ok((
-                target.index,
-                ScanOutput {
-                    artifacts,
-                    class_count,
-                    classes,
-                },
-            ))
+            Ok((target.index, output))
         })
         .collect::<Result<Vec<_>>>()?;
 
     results.sort_by_key(|(index, _)| *index);
 
-    let mut artifacts = Vec::new();
-    let mut class_count = 0;
-    let mut classes = Vec::new();
-    for (_, mut output) in results {
-        let offset = artifacts.len() as i64;
-        for mut artifact in output.artifacts.drain(..) {
-            artifact.parent_index = artifact.parent_index.map(|parent| parent + offset);
-            artifacts.push(artifact);
-        }
-        for mut class in output.classes.drain(..) {
-            if class.artifact_index >= 0 {
-                class.artifact_index += offset;
-            }
-            classes.push(class);
-        }
-        class_count += output.class_count;
+    let mut merged = ScanOutput::default();
+    for (_, output) in results {
+        append_scan_output(output, &mut merged);
     }
 
-    Ok(ScanOutput {
-        artifacts,
-        class_count,
-        classes,
-    })
+    Ok(merged)
+}
+
+/// Appends `output`, scanned into its own vectors, to `merged`, shifting its artifact indices
+/// past the artifacts already collected.
+fn append_scan_output(output: ScanOutput, merged: &mut ScanOutput) {
+    let offset = merged.artifacts.len() as i64;
+    for mut artifact in output.artifacts {
+        artifact.parent_index = artifact.parent_index.map(|parent| parent + offset);
+        merged.artifacts.push(artifact);
+    }
+    for mut class in output.classes {
+        if class.artifact_index >= 0 {
+            class.artifact_index += offset;
+        }
+        merged.classes.push(class);
+    }
+    for mut module in output.modules {
+        if module.artifact_index >= 0 {
+            module.artifact_index += offset;
+        }
+        merged.modules.push(module);
+    }
+    merged.class_count += output.class_count;
 }
 
 struct ScanTarget {
@@ -137,13 +171,12 @@ fn scan_path(
     path: &Path,
     is_input: bool,
     strict: bool,
+    options: &ScanOptions,
     telemetry: Option<&Telemetry>,
-    artifacts: &mut Vec<Artifact>,
-    class_count: &mut usize,
-    classes: &mut Vec<Class>,
+    output: &mut ScanOutput,
 ) -> Result<()> {
     if path.is_dir() {
-        scan_dir(path, is_input, telemetry, artifacts, class_count, classes)?;
+        scan_dir(path, is_input, options, telemetry, output)?;
         return Ok(());
     }
 
@@ -157,8 +190,47 @@ fn scan_path(
     };
 
     match extension {
-        "class" => scan_class_file(path, roles, telemetry, artifacts, class_count, classes),
-        "jar" => scan_jar_file(path, roles, telemetry, artifacts, class_count, classes),
+        "class" if is_module_info_path(path) => scan_module_info_file(path, roles, options, output),
+        "class" => scan_class_file(
+            path,
+            roles,
+            options,
+            telemetry,
+            &mut output.artifacts,
+            &mut output.class_count,
+            &mut output.classes,
+        ),
+        "jar" => scan_jar_file(
+            path,
+            roles,
+            options,
+            telemetry,
+            &mut output.artifacts,
+            &mut output.class_count,
+            &mut output.classes,
+        ),
+        "jmod" => scan_jmod_file(
+            path,
+            roles,
+            options,
+            telemetry,
+            &mut output.artifacts,
+            &mut output.class_count,
+            &mut output.classes,
+        ),
+        "dex" | "apk" if options.dex => scan_dex_file(
+            path,
+            extension == "apk",
+            roles,
+            options,
+            &mut output.artifacts,
+            &mut output.class_count,
+            &mut output.classes,
+        ),
+        "dex" | "apk" if strict => anyhow::bail!(
+            "{} is an Android input; pass --dex to analyze it",
+            path.display()
+        ),
         _ => {
             if strict {
                 anyhow::bail!("unsupported input file: {}", path.display())
@@ -172,11 +244,34 @@ fn scan_path(
 fn scan_dir(
     path: &Path,
     is_input: bool,
+    options: &ScanOptions,
     telemetry: Option<&Telemetry>,
-    artifacts: &mut Vec<Artifact>,
-    class_count: &mut usize,
-    classes: &mut Vec<Class>,
+    output: &mut ScanOutput,
 ) -> Result<()> {
+    let mut files = Vec::new();
+    collect_dir_files(path, &mut files)?;
+
+    // Parse files in parallel, then append them in walk order so artifact indices and class
+    // order do not depend on thread scheduling.
+    let parent_cx = OtelContext::current();
+    let outputs = files
+        .par_iter()
+        .map(|file| {
+            let _guard = telemetry.map(|_| parent_cx.clone().attach());
+            let mut file_output = ScanOutput::default();
+            scan_path(file, is_input, false, options, telemetry, &mut file_output)?;
+            Ok(file_output)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    for file_output in outputs {
+        append_scan_output(file_output, output);
+    }
+
+    Ok(())
+}
+
+/// Collects the files under `path` depth-first, visiting each directory's entries sorted by path.
+fn collect_dir_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
     let mut entries = Vec::new();
     for entry in fs::read_dir(path)
         .with_context(|| format!("failed to read directory {}", path.display()))?
@@ -190,26 +285,53 @@ fn scan_dir(
 
     for entry in entries {
         if entry.is_dir() {
-            scan_dir(&entry, is_input, telemetry, artifacts, class_count, classes)?;
+            collect_dir_files(&entry, files)?;
         } else {
-            scan_path(
-                &entry,
-                is_input,
-                false,
-                telemetry,
-                artifacts,
-                class_count,
-                classes,
-            )?;
+            files.push(entry);
         }
     }
 
     Ok(())
 }
 
+fn is_module_info_path(path: &Path) -> bool {
+    path.file_name().and_then(|name| name.to_str()) == Some("module-info.class")
+}
+
+/// Records the module declaration of an input `module-info.class`. It has no superclass,
+/// fields, or methods to analyze, so it never becomes a class; on the classpath it is skipped.
+fn scan_module_info_file(
+    path: &Path,
+    roles: Option<Vec<Value>>,
+    options: &ScanOptions,
+    output: &mut ScanOutput,
+) -> Result<()> {
+    if roles.is_none() {
+        return Ok(());
+    }
+    let data = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let parsed = parse_module_info_bytes(&data)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    let hash = options.emit_artifact_hashes.then(|| content_sha256(&data));
+    let artifact_index = push_path_artifact(
+        path,
+        roles,
+        data.len() as u64,
+        hash,
+        None,
+        &mut output.artifacts,
+    )?;
+    output.modules.push(ModuleDescriptor {
+        artifact_index,
+        ..parsed
+    });
+    Ok(())
+}
+
 fn scan_class_file(
     path: &Path,
     roles: Option<Vec<Value>>,
+    options: &ScanOptions,
     telemetry: Option<&Telemetry>,
     artifacts: &mut Vec<Artifact>,
     class_count: &mut usize,
@@ -244,7 +366,8 @@ fn scan_class_file(
     *class_count += 1;
 
     let artifact_index = if roles.is_some() {
-        push_path_artifact(path, roles, data.len() as u64, None, artifacts)?
+        let hash = options.emit_artifact_hashes.then(|| content_sha256(&data));
+        push_path_artifact(path, roles, data.len() as u64, hash, None, artifacts)?
     } else {
         -1
     };
@@ -260,6 +383,8 @@ fn scan_class_file(
         annotation_defaults: parsed.annotation_defaults,
         artifact_index,
         is_record: parsed.is_record,
+        is_final: parsed.is_final,
+        content_hash: parsed.content_hash,
     });
     Ok(())
 }
@@ -267,6 +392,7 @@ fn scan_class_file(
 fn scan_jar_file(
     path: &Path,
     roles: Option<Vec<Value>>,
+    options: &ScanOptions,
     telemetry: Option<&Telemetry>,
     artifacts: &mut Vec<Artifact>,
     class_count: &mut usize,
@@ -281,13 +407,14 @@ fn scan_jar_file(
             scan_jar_file_inner(
                 path,
                 roles,
+                options,
                 Some(telemetry),
                 artifacts,
                 class_count,
                 classes,
             )
         }),
-        None => scan_jar_file_inner(path, roles, None, artifacts, class_count, classes),
+        None => scan_jar_file_inner(path, roles, options, None, artifacts, class_count, classes),
     };
     result
 }
@@ -295,6 +422,7 @@ fn scan_jar_file(
 fn scan_jar_file_inner(
     path: &Path,
     roles: Option<Vec<Value>>,
+    options: &ScanOptions,
     telemetry: Option<&Telemetry>,
     artifacts: &mut Vec<Artifact>,
     class_count: &mut usize,
@@ -310,11 +438,29 @@ fn scan_jar_file_inner(
     let jar_len = fs::metadata(path)
         .with_context(|| format!("failed to read {}", path.display()))?
         .len();
-    let jar_index = push_path_artifact(path, roles, jar_len, None, artifacts)?;
+    let spring_boot = options.spring_boot
+        && read_manifest_content(&mut archive, &jar_path)?
+            .as_deref()
+            .is_some_and(is_spring_boot_manifest);
+    // Spring Boot loader classes and BOOT-INF/lib JARs are dependencies, so only the
+    // BOOT-INF/classes entries below carry the analysis target role.
+    let jar_roles = if spring_boot { None } else { roles.clone() };
+    let jar_hash = options
+        .emit_artifact_hashes
+        .then(|| file_sha256(path))
+        .transpose()?;
+    let jar_index = push_path_artifact(path, jar_roles, jar_len, jar_hash, None, artifacts)?;
     let jar_uri = path_to_uri(path);
-    let entries = jar_entries(&jar_path, &mut archive)?;
-    let class_entry_bytes =
-        read_jar_entries_bytes(&mut archive, &entries.class_entries, &jar_path)?;
+    let entries = jar_entries(&jar_path, &mut archive, options.release)?;
+    let (boot_class_entries, class_entries): (Vec<String>, Vec<String>) = if spring_boot {
+        entries
+            .class_entries
+            .into_iter()
+            .partition(|name| name.starts_with(SPRING_BOOT_CLASSES_PREFIX))
+    } else {
+        (Vec::new(), entries.class_entries)
+    };
+    let class_entry_bytes = read_jar_entries_bytes(&mut archive, &class_entries, &jar_path)?;
     parse_jar_classes(
         &jar_path,
         &jar_path,
@@ -325,19 +471,150 @@ fn scan_jar_file_inner(
         class_count,
         classes,
     )?;
+    if !boot_class_entries.is_empty() {
+        let boot_class_bytes =
+            read_jar_entries_bytes(&mut archive, &boot_class_entries, &jar_path)?;
+        scan_nested_class_entries(
+            &jar_path,
+            &jar_uri,
+            jar_index,
+            boot_class_bytes,
+            roles,
+            options,
+            telemetry,
+            &parent_cx,
+            artifacts,
+            class_count,
+            classes,
+        )?;
+    }
     scan_nested_jars(
         &mut archive,
         &jar_path,
         &jar_uri,
         jar_index,
         entries.jar_entries,
+        options,
+        telemetry,
+        &parent_cx,
+        artifacts,
+        class_count,
+        classes,
+    )?;
+
+    Ok(())
+}
+
+/// Scan a `.jmod` archive: a zip behind a short header, with class files under `classes/`.
+/// Each class gets a nested artifact such as `jar:file:///java.base.jmod!/classes/Foo.class`.
+fn scan_jmod_file(
+    path: &Path,
+    roles: Option<Vec<Value>>,
+    options: &ScanOptions,
+    telemetry: Option<&Telemetry>,
+    artifacts: &mut Vec<Artifact>,
+    class_count: &mut usize,
+    classes: &mut Vec<Class>,
+) -> Result<()> {
+    let parent_cx = OtelContext::current();
+    let jmod_path = path.display().to_string();
+    let data = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let zip_data = data.strip_prefix(JMOD_HEADER).unwrap_or(&data);
+    let mut archive = ZipArchive::new(Cursor::new(zip_data))
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    let jmod_hash = options.emit_artifact_hashes.then(|| content_sha256(&data));
+    let jmod_index = push_path_artifact(
+        path,
+        roles.clone(),
+        data.len() as u64,
+        jmod_hash,
+        None,
+        artifacts,
+    )?;
+    let class_entries = jar_entries(&jmod_path, &mut archive, None)?
+        .class_entries
+        .into_iter()
+        .filter(|name| name.starts_with(JMOD_CLASSES_PREFIX))
+        .collect::<Vec<_>>();
+    let class_entry_bytes = read_jar_entries_bytes(&mut archive, &class_entries, &jmod_path)?;
+    scan_nested_class_entries(
+        &jmod_path,
+        &path_to_uri(path),
+        jmod_index,
+        class_entry_bytes,
+        roles,
+        options,
         telemetry,
         &parent_cx,
         artifacts,
         class_count,
         classes,
+    )
+}
+
+/// Scan a `.dex` file, or the `classes*.dex` entries at the root of an `.apk` file.
+///
+/// Each DEX file becomes one artifact, nested under the APK artifact for APK entries, and
+/// every class it defines points at that artifact.
+fn scan_dex_file(
+    path: &Path,
+    is_apk: bool,
+    roles: Option<Vec<Value>>,
+    options: &ScanOptions,
+    artifacts: &mut Vec<Artifact>,
+    class_count: &mut usize,
+    classes: &mut Vec<Class>,
+) -> Result<()> {
+    let data = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let hash = options.emit_artifact_hashes.then(|| content_sha256(&data));
+    let file_index = push_path_artifact(
+        path,
+        roles.clone(),
+        data.len() as u64,
+        hash,
+        None,
+        artifacts,
     )?;
+    let dex_files = if is_apk {
+        let mut archive = ZipArchive::new(Cursor::new(data.as_slice()))
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let mut names = archive
+            .file_names()
+            .filter(|name| crate::dex::is_apk_dex_entry(name))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        names.sort();
+        let entries = read_jar_entries_bytes(&mut archive, &names, &path.display().to_string())?;
+        let apk_uri = path_to_uri(path);
+        entries
+            .into_iter()
+            .map(|(name, bytes)| {
+                let hash = options.emit_artifact_hashes.then(|| content_sha256(&bytes));
+                let index = push_artifact(
+                    jar_entry_uri(&apk_uri, &name),
+                    bytes.len() as u64,
+                    hash,
+                    Some(file_index),
+                    roles.clone(),
+                    artifacts,
+                );
+                (format!("{}!/{name}", path.display()), index, bytes)
+            })
+            .collect()
+    } else {
+        vec![(path.display().to_string(), file_index, data)]
+    };
 
+    for (display, artifact_index, bytes) in dex_files {
+        let parsed = crate::dex::parse_dex_classes(&bytes)
+            .with_context(|| format!("failed to parse {display}"))?;
+        *class_count += parsed.len();
+        classes.extend(parsed.into_iter().map(|class| Class {
+            artifact_index,
+            ..class
+        }));
+    }
     Ok(())
 }
 
@@ -350,6 +627,7 @@ struct JarEntries {
 fn jar_entries<R: Read + Seek>(
     jar_display: &str,
     archive: &mut ZipArchive<R>,
+    release: Option<u32>,
 ) -> Result<JarEntries> {
     let mut class_entries = Vec::new();
     let mut jar_entries = Vec::new();
@@ -361,10 +639,7 @@ fn jar_entries<R: Read + Seek>(
             continue;
         }
         let name = entry.name().to_string();
-        // TODO: Handle multi-release entries under META-INF/versions/ in a future release.
-        let is_class = name.ends_with(".class")
-            && !name.ends_with("module-info.class")
-            && !name.starts_with("META-INF/versions/");
+        let is_class = name.ends_with(".class") && !name.ends_with("module-info.class");
         let is_jar = name.ends_with(".jar");
         if is_class {
             class_entries.push(name.clone());
@@ -374,6 +649,14 @@ fn jar_entries<R: Read + Seek>(
         }
     }
 
+    // Versioned entries only apply to JARs that declare themselves multi-release.
+    let release = match release {
+        Some(release) => read_manifest_content(archive, jar_display)?
+            .filter(|content| is_multi_release_manifest(content))
+            .map(|_| release),
+        None => None,
+    };
+    let mut class_entries = select_release_class_entries(class_entries, release);
     class_entries.sort();
     jar_entries.sort();
     Ok(JarEntries {
@@ -382,6 +665,42 @@ fn jar_entries<R: Read + Seek>(
     })
 }
 
+/// Pick one entry per class from a multi-release JAR.
+///
+/// Entries under `META-INF/versions/N/` override the base entry when `N <= release`;
+/// the highest such `N` wins. Without a release, versioned entries are ignored, so callers
+/// pass `None` for JARs whose manifest lacks `Multi-Release: true`.
+fn select_release_class_entries(entries: Vec<String>, release: Option<u32>) -> Vec<String> {
+    let mut selected: BTreeMap<String, (u32, String)> = BTreeMap::new();
+    for name in entries {
+        let (version, logical_name) = match name.strip_prefix("META-INF/versions/") {
+            Some(rest) => {
+                let Some(release) = release else {
+                    continue;
+                };
+                let Some((version, logical_name)) = rest.split_once('/') else {
+                    continue;
+                };
+                let Ok(version) = version.parse::<u32>() else {
+                    continue;
+                };
+                if version > release {
+                    continue;
+                }
+                (version, logical_name.to_string())
+            }
+            None => (0, name.clone()),
+        };
+        match selected.get(&logical_name) {
+            Some((current, _)) if *current >= version => {}
+            _ => {
+                selected.insert(logical_name, (version, name));
+            }
+        }
+    }
+    selected.into_values().map(|(_, name)| name).collect()
+}
+
 fn read_jar_entries_bytes<R: Read + Seek>(
     archive: &mut ZipArchive<R>,
     entry_names: &[String],
@@ -456,18 +775,86 @@ fn parse_jar_classes(
             annotation_defaults: parsed.annotation_defaults,
             artifact_index: jar_index,
             is_record: parsed.is_record,
+            is_final: parsed.is_final,
+            content_hash: parsed.content_hash,
         });
     }
 
     Ok(())
 }
 
+/// Parse class entries kept under a prefix (Spring Boot `BOOT-INF/classes/`, jmod `classes/`),
+/// giving each class its own nested artifact so the artifact URI points at the real entry.
+fn scan_nested_class_entries(
+    jar_display: &str,
+    jar_uri: &str,
+    jar_index: i64,
+    entries: Vec<(String, Vec<u8>)>,
+    roles: Option<Vec<Value>>,
+    options: &ScanOptions,
+    telemetry: Option<&Telemetry>,
+    parent_cx: &OtelContext,
+    artifacts: &mut Vec<Artifact>,
+    class_count: &mut usize,
+    classes: &mut Vec<Class>,
+) -> Result<()> {
+    let mut entries = entries;
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    let artifact_indices = entries
+        .iter()
+        .map(|(name, data)| {
+            push_artifact(
+                jar_entry_uri(jar_uri, name),
+                data.len() as u64,
+                options.emit_artifact_hashes.then(|| content_sha256(data)),
+                Some(jar_index),
+                roles.clone(),
+                artifacts,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // parse_jar_classes keeps entry-name order, which matches artifact_indices.
+    let mut parsed = Vec::new();
+    parse_jar_classes(
+        jar_display,
+        jar_display,
+        entries,
+        jar_index,
+        telemetry,
+        Some(parent_cx),
+        class_count,
+        &mut parsed,
+    )?;
+    for (mut class, artifact_index) in parsed.into_iter().zip(artifact_indices) {
+        class.artifact_index = artifact_index;
+        classes.push(class);
+    }
+    Ok(())
+}
+
+fn is_multi_release_manifest(content: &str) -> bool {
+    content.lines().any(|line| {
+        line.trim_end_matches('\r')
+            .split_once(':')
+            .is_some_and(|(key, value)| {
+                key.trim().eq_ignore_ascii_case("Multi-Release")
+                    && value.trim().eq_ignore_ascii_case("true")
+            })
+    })
+}
+
+fn is_spring_boot_manifest(content: &str) -> bool {
+    content.lines().any(|line| line.starts_with("Spring-Boot-"))
+}
+
 fn scan_nested_jars(
     archive: &mut ZipArchive<fs::File>,
     jar_display: &str,
     jar_uri: &str,
     parent_index: i64,
     jar_entries: Vec<String>,
+    options: &ScanOptions,
     telemetry: Option<&Telemetry>,
     parent_cx: &OtelContext,
     artifacts: &mut Vec<Artifact>,
@@ -493,6 +880,7 @@ fn scan_nested_jars(
             jar_display,
             jar_uri,
             parent_index,
+            options,
             telemetry,
             parent_cx,
             artifacts,
@@ -515,6 +903,7 @@ fn scan_nested_jar_entry(
     parent_jar_display: &str,
     parent_jar_uri: &str,
     parent_index: i64,
+    options: &ScanOptions,
     telemetry: Option<&Telemetry>,
     parent_cx: &OtelContext,
     artifacts: &mut Vec<Artifact>,
@@ -524,9 +913,13 @@ fn scan_nested_jar_entry(
     let jar_display = format!("{parent_jar_display}!/{entry_name}");
     let jar_uri = jar_entry_uri(parent_jar_uri, entry_name);
     let jar_len = jar_bytes.len() as u64;
+    let jar_hash = options
+        .emit_artifact_hashes
+        .then(|| content_sha256(jar_bytes));
     let jar_index = push_artifact(
         jar_uri.clone(),
         jar_len,
+        jar_hash,
         Some(parent_index),
         None,
         artifacts,
@@ -534,7 +927,7 @@ fn scan_nested_jar_entry(
 
     let mut archive = ZipArchive::new(Cursor::new(jar_bytes))
         .with_context(|| format!("failed to read {}", jar_display))?;
-    let entries = jar_entries(&jar_display, &mut archive)?;
+    let entries = jar_entries(&jar_display, &mut archive, options.release)?;
     let class_entry_bytes =
         read_jar_entries_bytes(&mut archive, &entries.class_entries, &jar_display)?;
     parse_jar_classes(
@@ -582,6 +975,15 @@ fn manifest_classpath_entries_from_archive<R: Read + Seek>(
     archive: &mut ZipArchive<R>,
     jar_display: &str,
 ) -> Result<Vec<String>> {
+    Ok(read_manifest_content(archive, jar_display)?
+        .map(|content| parse_manifest_classpath_entries(&content))
+        .unwrap_or_default())
+}
+
+fn read_manifest_content<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    jar_display: &str,
+) -> Result<Option<String>> {
     for index in 0..archive.len() {
         let mut entry = archive
             .by_index(index)
@@ -593,9 +995,9 @@ fn manifest_classpath_entries_from_archive<R: Read + Seek>(
         entry
             .read_to_string(&mut content)
             .with_context(|| format!("failed to read {}:{}", jar_display, entry.name()))?;
-        return Ok(parse_manifest_classpath_entries(&content));
+        return Ok(Some(content));
     }
-    Ok(Vec::new())
+    Ok(None)
 }
 
 fn parse_manifest_classpath_entries(content: &str) -> Vec<String> {
@@ -655,22 +1057,32 @@ fn push_path_artifact(
     path: &Path,
     roles: Option<Vec<Value>>,
     len: u64,
+    hash: Option<String>,
     parent_index: Option<i64>,
     artifacts: &mut Vec<Artifact>,
 ) -> Result<i64> {
     let uri = path_to_uri(path);
-    Ok(push_artifact(uri, len, parent_index, roles, artifacts))
+    Ok(push_artifact(
+        uri,
+        len,
+        hash,
+        parent_index,
+        roles,
+        artifacts,
+    ))
 }
 
+/// Push an artifact; `hash` is the SHA-256 of its own bytes, for JAR entries the entry bytes.
 fn push_artifact(
     uri: String,
     len: u64,
+    hash: Option<String>,
     parent_index: Option<i64>,
     roles: Option<Vec<Value>>,
     artifacts: &mut Vec<Artifact>,
 ) -> i64 {
     let location = ArtifactLocation::builder().uri(uri).build();
-    let artifact = match (parent_index, roles) {
+    let mut artifact = match (parent_index, roles) {
         (Some(parent_index), Some(roles)) => Artifact::builder()
             .location(location)
             .length(len as i64)
@@ -692,6 +1104,9 @@ fn push_artifact(
             .length(len as i64)
             .build(),
     };
+    if let Some(hash) = hash {
+        artifact.hashes = Some(BTreeMap::from([("sha-256".to_string(), hash)]));
+    }
     let index = artifacts.len() as i64;
     artifacts.push(artifact);
     index
@@ -712,30 +1127,48 @@ fn path_key(path: &Path) -> String {
     path.to_string_lossy().to_string()
 }
 
-fn expand_classpath(initial: Vec<PathBuf>) -> Result<Vec<PathBuf>> {
+/// Resolve classpath entries in breadth-first order, optionally following manifest `Class-Path`.
+///
+/// Explicit entries must exist; entries discovered through a manifest are skipped with a
+/// warning when missing, since stale manifests are common in packaged applications.
+fn expand_classpath(
+    initial: Vec<PathBuf>,
+    from_manifest: Vec<PathBuf>,
+    follow_manifest_classpath: bool,
+) -> Result<Vec<PathBuf>> {
     let mut queue = VecDeque::new();
     let mut initial_sorted = initial;
     initial_sorted.sort_by_key(|a| path_key(a));
     for entry in initial_sorted {
-        queue.push_back(entry);
+        queue.push_back((entry, false));
+    }
+    for entry in from_manifest {
+        queue.push_back((entry, true));
     }
 
     let mut seen = BTreeSet::new();
     let mut result = Vec::new();
-    while let Some(entry) = queue.pop_front() {
+    while let Some((entry, is_manifest_entry)) = queue.pop_front() {
         let key = path_key(&entry);
         if !seen.insert(key) {
             continue;
         }
         if !entry.exists() {
+            if is_manifest_entry {
+                warn!(
+                    "skipping manifest Class-Path entry that does not exist: {}",
+                    entry.display()
+                );
+                continue;
+            }
             anyhow::bail!("classpath entry not found: {}", entry.display());
         }
         result.push(entry.clone());
-        if is_jar_path(&entry) {
+        if follow_manifest_classpath && is_jar_path(&entry) {
             let mut referenced = manifest_classpath(&entry)?;
             referenced.sort_by_key(|a| path_key(a));
             for item in referenced {
-                queue.push_back(item);
+                queue.push_back((item, true));
             }
         }
     }
@@ -787,6 +1220,220 @@ fn is_jar_path(path: &Path) -> bool {
         .unwrap_or(false)
 }
 
+const INPUT_MANIFEST_VERSION: u32 = 2;
+
+/// Record of the exact files a scan consumed and the options they were read with, used to
+/// replay a prior run.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct InputManifest {
+    version: u32,
+    options: InputManifestOptions,
+    entries: Vec<InputManifestEntry>,
+}
+
+/// Scan options that change which classes are read from the recorded files.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InputManifestOptions {
+    release: Option<u32>,
+    follow_manifest_classpath: bool,
+    spring_boot: bool,
+    dex: bool,
+}
+
+/// Single class or JAR file captured in an input manifest.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InputManifestEntry {
+    path: PathBuf,
+    /// Input or classpath entry the file was found under, as passed to the scan.
+    root: PathBuf,
+    role: InputManifestRole,
+    sha256: String,
+}
+
+/// Whether a manifest entry was an analysis target or a classpath entry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum InputManifestRole {
+    Input,
+    Classpath,
+}
+
+/// Inputs and scan options re-resolved from a manifest.
+#[derive(Debug)]
+pub(crate) struct ReplayedInputs {
+    pub(crate) input: Vec<PathBuf>,
+    pub(crate) classpath: Vec<PathBuf>,
+    /// Options recorded with the manifest. `emit_artifact_hashes` is left unset because it
+    /// only affects the report.
+    pub(crate) scan_options: ScanOptions,
+}
+
+/// Capture every class and JAR file reachable from the given inputs and classpath, along with
+/// the options that decide which classes a scan reads from them.
+pub(crate) fn build_input_manifest(
+    input: &[PathBuf],
+    classpath: &[PathBuf],
+    options: &ScanOptions,
+) -> Result<InputManifest> {
+    let mut entries = Vec::new();
+    for (paths, role) in [
+        (input, InputManifestRole::Input),
+        (classpath, InputManifestRole::Classpath),
+    ] {
+        for root in paths {
+            for path in collect_manifest_files(root)? {
+                let sha256 = file_sha256(&path)?;
+                entries.push(InputManifestEntry {
+                    path,
+                    root: root.clone(),
+                    role,
+                    sha256,
+                });
+            }
+        }
+    }
+    Ok(InputManifest {
+        version: INPUT_MANIFEST_VERSION,
+        options: InputManifestOptions {
+            release: options.release,
+            follow_manifest_classpath: options.follow_manifest_classpath,
+            spring_boot: options.spring_boot,
+            dex: options.dex,
+        },
+        entries,
+    })
+}
+
+pub(crate) fn write_input_manifest(path: &Path, manifest: &InputManifest) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create manifest directory {}", parent.display()))?;
+    }
+    let content =
+        serde_json::to_string_pretty(manifest).context("failed to serialize input manifest")?;
+    fs::write(path, format!("{content}\n"))
+        .with_context(|| format!("failed to write input manifest {}", path.display()))
+}
+
+pub(crate) fn read_input_manifest(path: &Path) -> Result<InputManifest> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read input manifest {}", path.display()))?;
+    let manifest: InputManifest = serde_json::from_str(&content)
+        .with_context(|| format!("failed to parse input manifest {}", path.display()))?;
+    if manifest.version != INPUT_MANIFEST_VERSION {
+        anyhow::bail!(
+            "unsupported input manifest version {} in {}",
+            manifest.version,
+            path.display()
+        );
+    }
+    Ok(manifest)
+}
+
+/// Re-resolve the roots and options recorded in a manifest, warning when a recorded file
+/// changed since the manifest was written.
+///
+/// Replay fails when a recorded file is missing or a directory root holds a class or archive
+/// the manifest does not list, so the scan reads exactly the recorded files.
+pub(crate) fn replay_input_manifest(path: &Path) -> Result<ReplayedInputs> {
+    let manifest = read_input_manifest(path)?;
+    let options = manifest.options;
+    let mut replayed = ReplayedInputs {
+        input: Vec::new(),
+        classpath: Vec::new(),
+        scan_options: ScanOptions {
+            release: options.release,
+            follow_manifest_classpath: options.follow_manifest_classpath,
+            spring_boot: options.spring_boot,
+            emit_artifact_hashes: false,
+            dex: options.dex,
+        },
+    };
+    let mut recorded: BTreeMap<PathBuf, BTreeSet<PathBuf>> = BTreeMap::new();
+    for entry in manifest.entries {
+        if !entry.path.is_file() {
+            anyhow::bail!(
+                "manifest entry not found: {} (recorded in {})",
+                entry.path.display(),
+                path.display()
+            );
+        }
+        let sha256 = file_sha256(&entry.path)?;
+        if sha256 != entry.sha256 {
+            warn!(
+                "content of {} changed since the manifest was written (expected sha256 {}, found {})",
+                entry.path.display(),
+                entry.sha256,
+                sha256
+            );
+        }
+        let roots = match entry.role {
+            InputManifestRole::Input => &mut replayed.input,
+            InputManifestRole::Classpath => &mut replayed.classpath,
+        };
+        if !roots.contains(&entry.root) {
+            roots.push(entry.root.clone());
+        }
+        recorded.entry(entry.root).or_default().insert(entry.path);
+    }
+    if replayed.input.is_empty() {
+        anyhow::bail!("input manifest {} has no input entries", path.display());
+    }
+    for (root, files) in &recorded {
+        for file in collect_manifest_files(root)? {
+            if !files.contains(&file) {
+                anyhow::bail!(
+                    "{} was added under {} after {} was written; remove it or record a new manifest",
+                    file.display(),
+                    root.display(),
+                    path.display()
+                );
+            }
+        }
+    }
+    Ok(replayed)
+}
+
+fn collect_manifest_files(root: &Path) -> Result<Vec<PathBuf>> {
+    if !root.is_dir() {
+        return Ok(vec![root.to_path_buf()]);
+    }
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(root)
+        .with_context(|| format!("failed to read directory {}", root.display()))?
+    {
+        let entry =
+            entry.with_context(|| format!("failed to read entry under {}", root.display()))?;
+        entries.push(entry.path());
+    }
+    entries.sort_by_key(|a| path_key(a));
+
+    let mut files = Vec::new();
+    for entry in entries {
+        if entry.is_dir() {
+            files.extend(collect_manifest_files(&entry)?);
+            continue;
+        }
+        let extension = entry.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+        if matches!(extension, "class" | "jar" | "jmod" | "dex" | "apk") {
+            files.push(entry);
+        }
+    }
+    Ok(files)
+}
+
+fn content_sha256(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+fn file_sha256(path: &Path) -> Result<String> {
+    let data = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    Ok(content_sha256(&data))
+}
+
 /// Parsed class data extracted from class file bytes.
 struct ParsedClass {
     name: String,
@@ -799,6 +1446,8 @@ struct ParsedClass {
     methods: Vec<Method>,
     annotation_defaults: Vec<AnnotationDefaultValue>,
     is_record: bool,
+    is_final: bool,
+    content_hash: String,
 }
 
 fn parse_class_bytes(data: &[u8]) -> Result<ParsedClass> {
@@ -847,6 +1496,9 @@ fn parse_class_bytes(data: &[u8]) -> Result<ParsedClass> {
         .attributes()
         .iter()
         .any(|attr| matches!(attr, jclassfile::attributes::Attribute::Record { .. }));
+    let is_final = class_file
+        .access_flags()
+        .contains(class_file::ClassFlags::ACC_FINAL);
     let default_nullness = parse_default_nullness(class_file.attributes(), constant_pool)
         .context("parse class nullness")?;
     let class_signature =
@@ -887,6 +1539,8 @@ fn parse_class_bytes(data: &[u8]) -> Result<ParsedClass> {
         methods,
         annotation_defaults,
         is_record,
+        is_final,
+        content_hash: content_sha256(data),
     })
 }
 
@@ -924,6 +1578,8 @@ fn normalize_class_name(raw: &str) -> Option<String> {
     None
 }
 
+const CLASS_ACC_FINAL: u16 = 0x0010;
+
 fn parse_class_bytes_minimal(data: &[u8]) -> Result<ParsedClass> {
     let mut offset = 0usize;
     let magic = read_u32_class(data, &mut offset)?;
@@ -933,7 +1589,7 @@ fn parse_class_bytes_minimal(data: &[u8]) -> Result<ParsedClass> {
     let _minor = read_u16_class(data, &mut offset)?;
     let _major = read_u16_class(data, &mut offset)?;
     let (cp_entries, class_entries) = parse_constant_pool_minimal(data, &mut offset)?;
-    let _access_flags = read_u16_class(data, &mut offset)?;
+    let access_flags = read_u16_class(data, &mut offset)?;
     let this_class = read_u16_class(data, &mut offset)?;
     let super_class = read_u16_class(data, &mut offset)?;
 
@@ -976,12 +1632,16 @@ fn parse_class_bytes_minimal(data: &[u8]) -> Result<ParsedClass> {
         methods: Vec::new(),
         annotation_defaults: Vec::new(),
         is_record: false,
+        is_final: access_flags & CLASS_ACC_FINAL != 0,
+        content_hash: content_sha256(data),
     })
 }
 
 #[derive(Clone)]
 enum CpEntryMin {
     Utf8(String),
+    /// CONSTANT_Module or CONSTANT_Package, holding the index of its name.
+    Named(u16),
     Other,
 }
 
@@ -1030,10 +1690,14 @@ fn parse_constant_pool_minimal(
                 skip_class_bytes(data, offset, 3)?;
                 entries.push(CpEntryMin::Other);
             }
-            16 | 19 | 20 => {
+            16 => {
                 skip_class_bytes(data, offset, 2)?;
                 entries.push(CpEntryMin::Other);
             }
+            19 | 20 => {
+                let name_index = read_u16_class(data, offset)?;
+                entries.push(CpEntryMin::Named(name_index));
+            }
             17 => {
                 skip_class_bytes(data, offset, 4)?;
                 entries.push(CpEntryMin::Other);
@@ -1060,16 +1724,101 @@ fn resolve_class_name_minimal(
     }
 }
 
-fn parse_interfaces_minimal(
-    data: &[u8],
-    offset: &mut usize,
-    entries: &[CpEntryMin],
-    class_entries: &[Option<u16>],
-) -> Result<Vec<String>> {
-    let count = read_u16_class(data, offset)? as usize;
-    let mut interfaces = Vec::with_capacity(count);
-    for _ in 0..count {
-        let index = read_u16_class(data, offset)?;
+const MODULE_ACC_TRANSITIVE: u16 = 0x0020;
+const MODULE_ACC_STATIC_PHASE: u16 = 0x0040;
+const MODULE_ACC_MANDATED: u16 = 0x8000;
+
+/// Parses the Module attribute of a `module-info.class`; the artifact index is left unset.
+fn parse_module_info_bytes(data: &[u8]) -> Result<ModuleDescriptor> {
+    let mut offset = 0usize;
+    let magic = read_u32_class(data, &mut offset)?;
+    if magic != 0xCAFEBABE {
+        anyhow::bail!("invalid class file magic");
+    }
+    let _minor = read_u16_class(data, &mut offset)?;
+    let _major = read_u16_class(data, &mut offset)?;
+    let (cp_entries, _) = parse_constant_pool_minimal(data, &mut offset)?;
+    let _access_flags = read_u16_class(data, &mut offset)?;
+    let _this_class = read_u16_class(data, &mut offset)?;
+    let _super_class = read_u16_class(data, &mut offset)?;
+    let interface_count = read_u16_class(data, &mut offset)? as usize;
+    skip_class_bytes(data, &mut offset, interface_count * 2)?;
+    skip_fields(data, &mut offset)?;
+    skip_methods(data, &mut offset)?;
+
+    let attribute_count = read_u16_class(data, &mut offset)?;
+    for _ in 0..attribute_count {
+        let name_index = read_u16_class(data, &mut offset)?;
+        let length = read_u32_class(data, &mut offset)? as usize;
+        let body = read_bytes_class(data, &mut offset, length)?;
+        if matches!(cp_entries.get(name_index as usize), Some(CpEntryMin::Utf8(name)) if name == "Module")
+        {
+            return parse_module_attribute(body, &cp_entries).context("parse Module attribute");
+        }
+    }
+    anyhow::bail!("module-info.class has no Module attribute")
+}
+
+fn parse_module_attribute(data: &[u8], entries: &[CpEntryMin]) -> Result<ModuleDescriptor> {
+    let mut offset = 0usize;
+    let name = resolve_named_minimal(entries, read_u16_class(data, &mut offset)?)?;
+    let _flags = read_u16_class(data, &mut offset)?;
+    let _version = read_u16_class(data, &mut offset)?;
+
+    let requires_count = read_u16_class(data, &mut offset)?;
+    let mut requires = Vec::with_capacity(requires_count as usize);
+    for _ in 0..requires_count {
+        let name = resolve_named_minimal(entries, read_u16_class(data, &mut offset)?)?;
+        let flags = read_u16_class(data, &mut offset)?;
+        let _version = read_u16_class(data, &mut offset)?;
+        requires.push(ModuleRequires {
+            name,
+            is_transitive: flags & MODULE_ACC_TRANSITIVE != 0,
+            is_static: flags & MODULE_ACC_STATIC_PHASE != 0,
+            is_mandated: flags & MODULE_ACC_MANDATED != 0,
+        });
+    }
+
+    let exports_count = read_u16_class(data, &mut offset)?;
+    let mut exports = Vec::with_capacity(exports_count as usize);
+    for _ in 0..exports_count {
+        exports.push(resolve_named_minimal(
+            entries,
+            read_u16_class(data, &mut offset)?,
+        )?);
+        let _flags = read_u16_class(data, &mut offset)?;
+        let to_count = read_u16_class(data, &mut offset)? as usize;
+        skip_class_bytes(data, &mut offset, to_count * 2)?;
+    }
+
+    Ok(ModuleDescriptor {
+        name,
+        requires,
+        exports,
+        artifact_index: -1,
+    })
+}
+
+fn resolve_named_minimal(entries: &[CpEntryMin], index: u16) -> Result<String> {
+    let Some(CpEntryMin::Named(name_index)) = entries.get(index as usize) else {
+        anyhow::bail!("unexpected module or package entry");
+    };
+    match entries.get(*name_index as usize) {
+        Some(CpEntryMin::Utf8(value)) => Ok(value.clone()),
+        _ => anyhow::bail!("unexpected utf8 entry"),
+    }
+}
+
+fn parse_interfaces_minimal(
+    data: &[u8],
+    offset: &mut usize,
+    entries: &[CpEntryMin],
+    class_entries: &[Option<u16>],
+) -> Result<Vec<String>> {
+    let count = read_u16_class(data, offset)? as usize;
+    let mut interfaces = Vec::with_capacity(count);
+    for _ in 0..count {
+        let index = read_u16_class(data, offset)?;
         interfaces.push(resolve_class_name_minimal(entries, class_entries, index)?);
     }
     Ok(interfaces)
@@ -1154,10 +1903,13 @@ fn parse_fields(
         .context("parse field type-use")?;
         let access_flags = field.access_flags();
         let access = FieldAccess {
+            is_public: access_flags.contains(FieldFlags::ACC_PUBLIC),
             is_static: access_flags.contains(FieldFlags::ACC_STATIC),
             is_private: access_flags.contains(FieldFlags::ACC_PRIVATE),
             is_final: access_flags.contains(FieldFlags::ACC_FINAL),
             is_volatile: access_flags.contains(FieldFlags::ACC_VOLATILE),
+            is_transient: access_flags.contains(FieldFlags::ACC_TRANSIENT),
+            is_synthetic: access_flags.contains(FieldFlags::ACC_SYNTHETIC),
         };
         parsed.push(Field {
             name,
@@ -1187,9 +1939,11 @@ fn parse_methods(
         let access_flags = method.access_flags();
         let access = MethodAccess {
             is_public: access_flags.contains(MethodFlags::ACC_PUBLIC),
+            is_private: access_flags.contains(MethodFlags::ACC_PRIVATE),
             is_static: access_flags.contains(MethodFlags::ACC_STATIC),
             is_synchronized: access_flags.contains(MethodFlags::ACC_SYNCHRONIZED),
             is_abstract: access_flags.contains(MethodFlags::ACC_ABSTRACT),
+            is_final: access_flags.contains(MethodFlags::ACC_FINAL),
             is_synthetic: access_flags.contains(MethodFlags::ACC_SYNTHETIC),
             is_bridge: access_flags.contains(MethodFlags::ACC_BRIDGE),
         };
@@ -2263,16 +3017,39 @@ fn parse_bytecode(
                     InstructionKind::Other(opcode)
                 }
             }
+            opcodes::CHECKCAST => {
+                let index = read_u16(code, offset + 1)?;
+                match resolve_class_literal(constant_pool, index)? {
+                    Some(value) => InstructionKind::Checkcast(value),
+                    None => InstructionKind::Other(opcode),
+                }
+            }
+            opcodes::INSTANCEOF => {
+                let index = read_u16(code, offset + 1)?;
+                match resolve_class_literal(constant_pool, index)? {
+                    Some(value) => InstructionKind::InstanceOf(value),
+                    None => InstructionKind::Other(opcode),
+                }
+            }
+            opcodes::ANEWARRAY => {
+                let index = read_u16(code, offset + 1)?;
+                match resolve_class_literal(constant_pool, index)? {
+                    Some(value) => InstructionKind::ANewArray(value),
+                    None => InstructionKind::Other(opcode),
+                }
+            }
             opcodes::INVOKEDYNAMIC => {
                 let call_site_index = read_u16(code, offset + 1)?;
-                let descriptor = resolve_invoke_dynamic_descriptor(constant_pool, call_site_index)
-                    .context("resolve invoke dynamic descriptor")?;
+                let (name, descriptor) =
+                    resolve_invoke_dynamic_name_and_type(constant_pool, call_site_index)
+                        .context("resolve invoke dynamic name and type")?;
                 let impl_method = resolve_invoke_dynamic_impl_method(
                     constant_pool,
                     call_site_index,
                     bootstrap_methods,
                 );
                 InstructionKind::InvokeDynamic {
+                    name,
                     descriptor,
                     impl_method,
                 }
@@ -2354,7 +3131,10 @@ fn resolve_method_ref(constant_pool: &[ConstantPool], index: u16) -> Result<Meth
     })
 }
 
-fn resolve_invoke_dynamic_descriptor(constant_pool: &[ConstantPool], index: u16) -> Result<String> {
+fn resolve_invoke_dynamic_name_and_type(
+    constant_pool: &[ConstantPool],
+    index: u16,
+) -> Result<(String, String)> {
     let entry = constant_pool
         .get(index as usize)
         .context("missing invoke dynamic entry")?;
@@ -2365,8 +3145,11 @@ fn resolve_invoke_dynamic_descriptor(constant_pool: &[ConstantPool], index: u16)
         } => *name_and_type_index,
         _ => anyhow::bail!("unexpected invoke dynamic entry"),
     };
-    let (_, descriptor_index) = resolve_name_and_type(constant_pool, name_and_type_index)?;
-    resolve_utf8(constant_pool, descriptor_index).context("resolve invoke dynamic descriptor")
+    let (name_index, descriptor_index) = resolve_name_and_type(constant_pool, name_and_type_index)?;
+    let name = resolve_utf8(constant_pool, name_index).context("resolve invoke dynamic name")?;
+    let descriptor = resolve_utf8(constant_pool, descriptor_index)
+        .context("resolve invoke dynamic descriptor")?;
+    Ok((name, descriptor))
 }
 
 /// Resolve the implementation method name from an invokedynamic's bootstrap method.
@@ -2649,6 +3432,8 @@ mod tests {
     use zip::ZipArchive;
     use zip::write::SimpleFileOptions;
 
+    const MULTI_RELEASE_MANIFEST: &[u8] = b"Manifest-Version: 1.0\nMulti-Release: true\n\n";
+
     #[test]
     fn scan_inputs_rejects_invalid_class_file() {
         let temp_dir = std::env::temp_dir().join(format!(
@@ -2662,7 +3447,7 @@ mod tests {
         let class_path = temp_dir.join("bad.class");
         fs::write(&class_path, b"nope").expect("write test class");
 
-        let result = scan_inputs(&[class_path.clone()], &[], None);
+        let result = scan_inputs(&[class_path.clone()], &[], &ScanOptions::default(), None);
 
         assert!(result.is_err());
         fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
@@ -2671,7 +3456,8 @@ mod tests {
     #[test]
     fn scan_inputs_accepts_valid_jar() {
         let jar_path = jspecify_jar_path().expect("download jar");
-        let result = scan_inputs(&[jar_path.clone()], &[], None).expect("scan jar");
+        let result =
+            scan_inputs(&[jar_path.clone()], &[], &ScanOptions::default(), None).expect("scan jar");
 
         assert!(result.class_count > 0);
         assert_eq!(result.artifacts.len(), 1);
@@ -2701,13 +3487,46 @@ mod tests {
         let class_path = temp_dir.join("Sample.class");
         fs::write(&class_path, class_bytes).expect("write class file");
 
-        let result = scan_inputs(&[class_path.clone()], &[], None).expect("scan class");
+        let result = scan_inputs(&[class_path.clone()], &[], &ScanOptions::default(), None)
+            .expect("scan class");
 
         assert_eq!(result.class_count, 1);
         assert_eq!(result.artifacts.len(), 1);
         fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
     }
 
+    #[test]
+    fn scan_inputs_emits_stable_artifact_hashes_when_requested() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let class_path = temp_dir.join("FakeClass.class");
+        fs::write(&class_path, build_class_with_unknown_attribute()).expect("write class file");
+        let options = ScanOptions {
+            emit_artifact_hashes: true,
+            ..ScanOptions::default()
+        };
+
+        let hashed = scan_inputs(&[class_path.clone()], &[], &options, None).expect("scan class");
+        let plain = scan_inputs(&[class_path.clone()], &[], &ScanOptions::default(), None)
+            .expect("scan class");
+
+        assert_eq!(
+            hashed.artifacts[0].hashes,
+            Some(BTreeMap::from([(
+                "sha-256".to_string(),
+                "e6b3872d019e9705598f931fa58cd0abb87284e64615b542b43468ef3e5eae4d".to_string()
+            )]))
+        );
+        assert_eq!(plain.artifacts[0].hashes, None);
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
     #[test]
     fn scan_inputs_marks_directory_entries_as_targets() {
         let jar_path = jspecify_jar_path().expect("download jar");
@@ -2724,7 +3543,8 @@ mod tests {
         let class_path = temp_dir.join("Sample.class");
         fs::write(&class_path, class_bytes).expect("write class file");
 
-        let result = scan_inputs(&[temp_dir.clone()], &[], None).expect("scan directory");
+        let result = scan_inputs(&[temp_dir.clone()], &[], &ScanOptions::default(), None)
+            .expect("scan directory");
 
         assert_eq!(result.class_count, 1);
         assert_eq!(result.artifacts.len(), 1);
@@ -2742,6 +3562,134 @@ mod tests {
         fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
     }
 
+    #[test]
+    fn scan_inputs_records_module_info_without_treating_it_as_a_class() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        let package_dir = temp_dir.join("com").join("example");
+        fs::create_dir_all(&package_dir).expect("create package dir");
+        fs::write(
+            temp_dir.join("module-info.class"),
+            build_module_info_class(),
+        )
+        .expect("write module-info");
+        fs::write(
+            package_dir.join("ClassA.class"),
+            build_class_with_unknown_attribute_named(b"com/example/ClassA"),
+        )
+        .expect("write class file");
+
+        let result = scan_inputs(
+            std::slice::from_ref(&temp_dir),
+            &[],
+            &ScanOptions::default(),
+            None,
+        )
+        .expect("scan directory");
+
+        assert_eq!(result.class_count, 1);
+        let class_names = result
+            .classes
+            .iter()
+            .map(|class| class.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(class_names, vec!["com/example/ClassA"]);
+        assert_eq!(result.modules.len(), 1);
+        let module = &result.modules[0];
+        assert_eq!(module.name, "com.example");
+        assert!(module.exports.is_empty());
+        let requires = module
+            .requires
+            .iter()
+            .map(|requires| {
+                (
+                    requires.name.as_str(),
+                    requires.is_transitive,
+                    requires.is_mandated,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            requires,
+            vec![("java.base", false, true), ("java.sql", true, false)]
+        );
+        let uri = result.artifacts[module.artifact_index as usize]
+            .location
+            .as_ref()
+            .and_then(|location| location.uri.clone())
+            .expect("artifact uri");
+        assert!(uri.ends_with("/module-info.class"), "uri: {uri}");
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn scan_inputs_orders_directory_classes_independently_of_thread_count() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        for index in 0..24 {
+            let package_dir = temp_dir.join(format!("pkg{}", index % 3));
+            fs::create_dir_all(&package_dir).expect("create package dir");
+            let class_name = format!("pkg{}/Class{index:02}", index % 3);
+            fs::write(
+                package_dir.join(format!("Class{index:02}.class")),
+                build_class_with_unknown_attribute_named(class_name.as_bytes()),
+            )
+            .expect("write class file");
+        }
+        let scan_with_threads = |num_threads: usize| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .expect("thread pool");
+            let result = pool
+                .install(|| {
+                    scan_inputs(
+                        std::slice::from_ref(&temp_dir),
+                        &[],
+                        &ScanOptions::default(),
+                        None,
+                    )
+                })
+                .expect("scan directory");
+            let classes = result
+                .classes
+                .iter()
+                .map(|class| {
+                    (
+                        class.name.clone(),
+                        class.artifact_index,
+                        class.content_hash.clone(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let uris = result
+                .artifacts
+                .iter()
+                .map(|artifact| artifact.location.as_ref().and_then(|loc| loc.uri.clone()))
+                .collect::<Vec<_>>();
+            (result.class_count, classes, uris)
+        };
+
+        let sequential = scan_with_threads(1);
+        let parallel = scan_with_threads(8);
+
+        assert_eq!(sequential.0, 24);
+        assert_eq!(sequential.1[0].0, "pkg0/Class00");
+        assert_eq!(sequential.1[8].0, "pkg1/Class01");
+        assert_eq!(sequential, parallel);
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
     #[test]
     fn scan_inputs_resolves_manifest_classpath() {
         let temp_dir = std::env::temp_dir().join(format!(
@@ -2758,14 +3706,41 @@ mod tests {
         let jar_path = temp_dir.join("main.jar");
         create_manifest_jar(&jar_path, Some("dep.jar")).expect("create main jar");
 
-        let result = scan_inputs(&[jar_path.clone()], &[], None);
+        let options = ScanOptions {
+            follow_manifest_classpath: true,
+            ..ScanOptions::default()
+        };
+        let result = scan_inputs(&[jar_path.clone()], &[], &options, None).expect("scan jar");
 
-        assert!(result.is_ok());
+        assert_eq!(result.artifacts.len(), 2);
         fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
     }
 
     #[test]
-    fn scan_inputs_errors_on_missing_manifest_classpath_entry() {
+    fn scan_inputs_ignores_manifest_classpath_by_default() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+
+        let dep_path = temp_dir.join("dep.jar");
+        create_manifest_jar(&dep_path, None).expect("create dep jar");
+        let jar_path = temp_dir.join("main.jar");
+        create_manifest_jar(&jar_path, Some("dep.jar")).expect("create main jar");
+
+        let result =
+            scan_inputs(&[jar_path.clone()], &[], &ScanOptions::default(), None).expect("scan jar");
+
+        assert_eq!(result.artifacts.len(), 1);
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn scan_inputs_skips_missing_manifest_classpath_entry() {
         let temp_dir = std::env::temp_dir().join(format!(
             "inspequte-test-{}",
             SystemTime::now()
@@ -2778,9 +3753,13 @@ mod tests {
         let jar_path = temp_dir.join("main.jar");
         create_manifest_jar(&jar_path, Some("missing.jar")).expect("create main jar");
 
-        let result = scan_inputs(&[jar_path.clone()], &[], None);
+        let options = ScanOptions {
+            follow_manifest_classpath: true,
+            ..ScanOptions::default()
+        };
+        let result = scan_inputs(&[jar_path.clone()], &[], &options, None).expect("scan jar");
 
-        assert!(result.is_err());
+        assert_eq!(result.artifacts.len(), 1);
         fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
     }
 
@@ -2813,13 +3792,385 @@ mod tests {
         )
         .expect("create outer jar");
 
-        let result = scan_inputs(&[outer_path.clone()], &[], None).expect("scan outer jar");
+        let result = scan_inputs(&[outer_path.clone()], &[], &ScanOptions::default(), None)
+            .expect("scan outer jar");
 
         assert_eq!(result.class_count, 2);
         assert_eq!(result.artifacts.len(), 3);
         fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
     }
 
+    #[test]
+    fn replay_input_manifest_reuses_recorded_files_and_rejects_changed_file_sets() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        let classes_dir = temp_dir.join("classes").join("com").join("example");
+        fs::create_dir_all(&classes_dir).expect("create classes dir");
+        let class_path = classes_dir.join("FakeClass.class");
+        fs::write(&class_path, build_class_with_unknown_attribute()).expect("write class file");
+        fs::write(classes_dir.join("notes.txt"), "ignored").expect("write text file");
+
+        let input = vec![temp_dir.join("classes")];
+        let original = scan_inputs(&input, &[], &ScanOptions::default(), None).expect("scan");
+        let manifest =
+            build_input_manifest(&input, &[], &ScanOptions::default()).expect("build manifest");
+        let manifest_path = temp_dir.join("manifest.json");
+        write_input_manifest(&manifest_path, &manifest).expect("write manifest");
+
+        let replayed = replay_input_manifest(&manifest_path).expect("replay manifest");
+        assert_eq!(replayed.input, input);
+        assert!(replayed.classpath.is_empty());
+        let replayed_scan = scan_inputs(
+            &replayed.input,
+            &replayed.classpath,
+            &ScanOptions::default(),
+            None,
+        )
+        .expect("scan replayed inputs");
+        let class_names = |output: &ScanOutput| {
+            output
+                .classes
+                .iter()
+                .map(|class| class.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(class_names(&replayed_scan), class_names(&original));
+
+        let mut changed = build_class_with_unknown_attribute();
+        changed.push(0);
+        fs::write(&class_path, changed).expect("modify class file");
+        let drifted = replay_input_manifest(&manifest_path).expect("replay drifted manifest");
+        assert_eq!(drifted.input, input);
+
+        let added_path = classes_dir.join("Added.class");
+        fs::write(&added_path, build_class_with_unknown_attribute()).expect("add class file");
+        let error = replay_input_manifest(&manifest_path).expect_err("added file");
+        assert!(
+            error
+                .to_string()
+                .contains(&format!("{} was added under", added_path.display())),
+            "{error}"
+        );
+        fs::remove_file(&class_path).expect("remove class file");
+        let error = replay_input_manifest(&manifest_path).expect_err("removed file");
+        assert!(
+            error.to_string().contains("manifest entry not found"),
+            "{error}"
+        );
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn replay_input_manifest_restores_release_selection() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let base_bytes = build_class_with_unknown_attribute();
+        let mut versioned_bytes = base_bytes.clone();
+        // Mark the versioned copy ACC_FINAL so the two entries hash differently.
+        let access_flags = versioned_bytes.len() - 20;
+        versioned_bytes[access_flags..access_flags + 2].copy_from_slice(&0x0031u16.to_be_bytes());
+        let jar_path = temp_dir.join("multi-release.jar");
+        create_outer_jar_with_entries(
+            &jar_path,
+            &[
+                ("META-INF/MANIFEST.MF", MULTI_RELEASE_MANIFEST.to_vec()),
+                ("com/example/FakeClass.class", base_bytes),
+                (
+                    "META-INF/versions/17/com/example/FakeClass.class",
+                    versioned_bytes.clone(),
+                ),
+            ],
+        )
+        .expect("create multi-release jar");
+        let input = vec![jar_path];
+        let options = ScanOptions {
+            release: Some(21),
+            ..ScanOptions::default()
+        };
+        let manifest = build_input_manifest(&input, &[], &options).expect("build manifest");
+        let manifest_path = temp_dir.join("manifest.json");
+        write_input_manifest(&manifest_path, &manifest).expect("write manifest");
+
+        let replayed = replay_input_manifest(&manifest_path).expect("replay manifest");
+        assert_eq!(replayed.scan_options.release, Some(21));
+        let scan = scan_inputs(
+            &replayed.input,
+            &replayed.classpath,
+            &replayed.scan_options,
+            None,
+        )
+        .expect("scan replayed inputs");
+
+        assert_eq!(scan.classes.len(), 1);
+        assert_eq!(
+            scan.classes[0].content_hash,
+            content_sha256(&versioned_bytes)
+        );
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn scan_inputs_splits_spring_boot_fat_jar() {
+        let app_class = build_class_with_unknown_attribute_named(b"com/example/ClassA");
+        let lib_class = build_class_with_unknown_attribute_named(b"com/example/ClassB");
+        let lib_jar = build_jar_bytes_with_class(None, "com/example/ClassB.class", &lib_class)
+            .expect("build lib jar");
+        let manifest = b"Manifest-Version: 1.0\nSpring-Boot-Version: 3.2.0\nSpring-Boot-Classes: BOOT-INF/classes/\nSpring-Boot-Lib: BOOT-INF/lib/\n\n".to_vec();
+
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let jar_path = temp_dir.join("app.jar");
+        create_outer_jar_with_entries(
+            &jar_path,
+            &[
+                ("META-INF/MANIFEST.MF", manifest),
+                ("BOOT-INF/classes/com/example/ClassA.class", app_class),
+                ("BOOT-INF/lib/dep.jar", lib_jar),
+            ],
+        )
+        .expect("create fat jar");
+
+        let options = ScanOptions {
+            spring_boot: true,
+            ..ScanOptions::default()
+        };
+        let result = scan_inputs(&[jar_path.clone()], &[], &options, None).expect("scan fat jar");
+
+        assert_eq!(result.class_count, 2);
+        let uri = |index: i64| {
+            result.artifacts[index as usize]
+                .location
+                .as_ref()
+                .and_then(|location| location.uri.clone())
+                .expect("artifact uri")
+        };
+        let has_roles = |index: i64| result.artifacts[index as usize].roles.is_some();
+        assert!(!has_roles(0));
+
+        let app = result
+            .classes
+            .iter()
+            .find(|class| class.name == "com/example/ClassA")
+            .expect("app class");
+        assert!(
+            uri(app.artifact_index).ends_with("app.jar!/BOOT-INF/classes/com/example/ClassA.class")
+        );
+        assert!(uri(app.artifact_index).starts_with("jar:file://"));
+        assert!(has_roles(app.artifact_index));
+
+        let lib = result
+            .classes
+            .iter()
+            .find(|class| class.name == "com/example/ClassB")
+            .expect("lib class");
+        assert!(uri(lib.artifact_index).ends_with("app.jar!/BOOT-INF/lib/dep.jar"));
+        assert!(!has_roles(lib.artifact_index));
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn scan_inputs_reads_classes_from_jmod() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let zip_path = temp_dir.join("entries.zip");
+        create_outer_jar_with_entries(
+            &zip_path,
+            &[
+                (
+                    "classes/com/example/ClassA.class",
+                    build_class_with_unknown_attribute_named(b"com/example/ClassA"),
+                ),
+                (
+                    "classes/module-info.class",
+                    build_class_with_unknown_attribute_named(b"module-info"),
+                ),
+                ("bin/tool", b"#!/bin/sh\n".to_vec()),
+            ],
+        )
+        .expect("create jmod entries");
+        let jmod_path = temp_dir.join("app.jmod");
+        let mut jmod_bytes = JMOD_HEADER.to_vec();
+        jmod_bytes.extend(fs::read(&zip_path).expect("read zip"));
+        fs::write(&jmod_path, jmod_bytes).expect("write jmod");
+
+        let result = scan_inputs(&[jmod_path.clone()], &[], &ScanOptions::default(), None)
+            .expect("scan jmod");
+
+        assert_eq!(result.class_count, 1);
+        assert_eq!(result.classes.len(), 1);
+        let class = &result.classes[0];
+        assert_eq!(class.name, "com/example/ClassA");
+        let uri = result.artifacts[class.artifact_index as usize]
+            .location
+            .as_ref()
+            .and_then(|location| location.uri.clone())
+            .expect("artifact uri");
+        assert!(uri.starts_with("jar:file://"), "uri: {uri}");
+        assert!(
+            uri.ends_with("app.jmod!/classes/com/example/ClassA.class"),
+            "uri: {uri}"
+        );
+        let context = crate::engine::build_context(result.classes, &result.artifacts);
+        assert_eq!(context.analysis_target_classes().len(), 1);
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn select_release_class_entries_prefers_highest_applicable_version() {
+        let entries = vec![
+            "com/example/ClassA.class".to_string(),
+            "com/example/ClassB.class".to_string(),
+            "META-INF/versions/11/com/example/ClassA.class".to_string(),
+            "META-INF/versions/17/com/example/ClassA.class".to_string(),
+            "META-INF/versions/21/com/example/ClassA.class".to_string(),
+            "META-INF/versions/11/com/example/ClassC.class".to_string(),
+        ];
+
+        let base = select_release_class_entries(entries.clone(), None);
+        assert_eq!(
+            base,
+            vec![
+                "com/example/ClassA.class".to_string(),
+                "com/example/ClassB.class".to_string(),
+            ]
+        );
+
+        let release_17 = select_release_class_entries(entries.clone(), Some(17));
+        assert_eq!(
+            release_17,
+            vec![
+                "META-INF/versions/17/com/example/ClassA.class".to_string(),
+                "com/example/ClassB.class".to_string(),
+                "META-INF/versions/11/com/example/ClassC.class".to_string(),
+            ]
+        );
+
+        let release_8 = select_release_class_entries(entries, Some(8));
+        assert_eq!(
+            release_8,
+            vec![
+                "com/example/ClassA.class".to_string(),
+                "com/example/ClassB.class".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_inputs_selects_single_multi_release_entry() {
+        let class_bytes = build_class_with_unknown_attribute();
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let jar_path = temp_dir.join("multi-release.jar");
+        create_outer_jar_with_entries(
+            &jar_path,
+            &[
+                ("META-INF/MANIFEST.MF", MULTI_RELEASE_MANIFEST.to_vec()),
+                ("com/example/FakeClass.class", class_bytes.clone()),
+                (
+                    "META-INF/versions/11/com/example/FakeClass.class",
+                    class_bytes,
+                ),
+            ],
+        )
+        .expect("create multi-release jar");
+
+        let base = scan_inputs(&[jar_path.clone()], &[], &ScanOptions::default(), None)
+            .expect("scan without release");
+        assert_eq!(base.class_count, 1);
+
+        let options = ScanOptions {
+            release: Some(17),
+            ..ScanOptions::default()
+        };
+        let versioned =
+            scan_inputs(&[jar_path.clone()], &[], &options, None).expect("scan with release");
+        assert_eq!(versioned.class_count, 1);
+        assert_eq!(versioned.classes.len(), 1);
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn scan_inputs_ignores_versioned_entries_without_multi_release_manifest() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "inspequte-test-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&temp_dir).expect("create temp dir");
+        let base_bytes = build_class_with_unknown_attribute();
+        let mut versioned_bytes = base_bytes.clone();
+        // Mark the versioned copy ACC_FINAL so the two entries hash differently.
+        let access_flags = versioned_bytes.len() - 20;
+        versioned_bytes[access_flags..access_flags + 2].copy_from_slice(&0x0031u16.to_be_bytes());
+        let jar_path = temp_dir.join("plain.jar");
+        create_outer_jar_with_entries(
+            &jar_path,
+            &[
+                (
+                    "META-INF/MANIFEST.MF",
+                    b"Manifest-Version: 1.0\n\n".to_vec(),
+                ),
+                ("com/example/FakeClass.class", base_bytes.clone()),
+                (
+                    "META-INF/versions/17/com/example/FakeClass.class",
+                    versioned_bytes,
+                ),
+            ],
+        )
+        .expect("create jar");
+
+        let options = ScanOptions {
+            release: Some(21),
+            ..ScanOptions::default()
+        };
+        let result = scan_inputs(&[jar_path], &[], &options, None).expect("scan jar");
+
+        assert_eq!(result.classes.len(), 1);
+        assert_eq!(result.classes[0].content_hash, content_sha256(&base_bytes));
+        fs::remove_dir_all(&temp_dir).expect("cleanup temp dir");
+    }
+
+    #[test]
+    fn is_multi_release_manifest_reads_attribute() {
+        assert!(is_multi_release_manifest(
+            "Manifest-Version: 1.0\r\nMulti-Release: true\r\n\r\n"
+        ));
+        assert!(is_multi_release_manifest("multi-release: TRUE\n"));
+        assert!(!is_multi_release_manifest("Multi-Release: false\n"));
+        assert!(!is_multi_release_manifest("Manifest-Version: 1.0\n"));
+    }
+
     #[test]
     fn default_nullness_parses_marked_and_unmarked() {
         let constant_pool = vec![
@@ -3003,6 +4354,10 @@ mod tests {
     /// "unmatched attribute", causing `parse_class_bytes` to fall back to the
     /// minimal parser.
     fn build_class_with_unknown_attribute() -> Vec<u8> {
+        build_class_with_unknown_attribute_named(b"com/example/FakeClass")
+    }
+
+    fn build_class_with_unknown_attribute_named(class_name: &[u8]) -> Vec<u8> {
         let mut b = Vec::new();
         // magic
         b.extend_from_slice(&[0xCA, 0xFE, 0xBA, 0xBE]);
@@ -3011,8 +4366,7 @@ mod tests {
         b.extend_from_slice(&65u16.to_be_bytes());
         // constant_pool_count = 6 (entries #1..#5)
         b.extend_from_slice(&6u16.to_be_bytes());
-        // #1 Utf8 "com/example/FakeClass"
-        let class_name = b"com/example/FakeClass";
+        // #1 Utf8 class name
         b.push(1);
         b.extend_from_slice(&(class_name.len() as u16).to_be_bytes());
         b.extend_from_slice(class_name);
@@ -3049,6 +4403,64 @@ mod tests {
         b
     }
 
+    /// Builds `module-info.class` for `module com.example { requires transitive java.sql; }`.
+    fn build_module_info_class() -> Vec<u8> {
+        let mut b = Vec::new();
+        b.extend_from_slice(&[0xCA, 0xFE, 0xBA, 0xBE]);
+        b.extend_from_slice(&0u16.to_be_bytes());
+        b.extend_from_slice(&65u16.to_be_bytes());
+        // constant_pool_count = 10 (entries #1..#9)
+        b.extend_from_slice(&10u16.to_be_bytes());
+        let utf8 = |b: &mut Vec<u8>, value: &[u8]| {
+            b.push(1);
+            b.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            b.extend_from_slice(value);
+        };
+        // #1 Utf8 "module-info", #2 Class -> #1, #3 Utf8 "Module"
+        utf8(&mut b, b"module-info");
+        b.push(7);
+        b.extend_from_slice(&1u16.to_be_bytes());
+        utf8(&mut b, b"Module");
+        // #4 Utf8 "com.example", #5 Module -> #4
+        utf8(&mut b, b"com.example");
+        b.push(19);
+        b.extend_from_slice(&4u16.to_be_bytes());
+        // #6 Utf8 "java.base", #7 Module -> #6
+        utf8(&mut b, b"java.base");
+        b.push(19);
+        b.extend_from_slice(&6u16.to_be_bytes());
+        // #8 Utf8 "java.sql", #9 Module -> #8
+        utf8(&mut b, b"java.sql");
+        b.push(19);
+        b.extend_from_slice(&8u16.to_be_bytes());
+        // access_flags (ACC_MODULE), this_class = #2, super_class = 0
+        b.extend_from_slice(&0x8000u16.to_be_bytes());
+        b.extend_from_slice(&2u16.to_be_bytes());
+        b.extend_from_slice(&0u16.to_be_bytes());
+        // interfaces_count = 0, fields_count = 0, methods_count = 0
+        b.extend_from_slice(&[0; 6]);
+        // attributes_count = 1
+        b.extend_from_slice(&1u16.to_be_bytes());
+        let mut module = Vec::new();
+        // module_name_index = #5, module_flags = 0, module_version_index = 0
+        module.extend_from_slice(&5u16.to_be_bytes());
+        module.extend_from_slice(&[0; 4]);
+        // requires_count = 2: java.base (ACC_MANDATED), java.sql (ACC_TRANSITIVE)
+        module.extend_from_slice(&2u16.to_be_bytes());
+        module.extend_from_slice(&7u16.to_be_bytes());
+        module.extend_from_slice(&0x8000u16.to_be_bytes());
+        module.extend_from_slice(&0u16.to_be_bytes());
+        module.extend_from_slice(&9u16.to_be_bytes());
+        module.extend_from_slice(&0x0020u16.to_be_bytes());
+        module.extend_from_slice(&0u16.to_be_bytes());
+        // exports, opens, uses, provides counts = 0
+        module.extend_from_slice(&[0; 8]);
+        b.extend_from_slice(&3u16.to_be_bytes());
+        b.extend_from_slice(&(module.len() as u32).to_be_bytes());
+        b.extend_from_slice(&module);
+        b
+    }
+
     fn jspecify_jar_path() -> Result<PathBuf> {
         static JAR_PATH: OnceLock<PathBuf> = OnceLock::new();
         if let Some(path) = JAR_PATH.get() {
@@ -0,0 +1,352 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::engine::{
+    DEFAULT_EXCEPTION_CAUSE_MAX_ALLOCATIONS, DEFAULT_EXCEPTION_CAUSE_MAX_STACK_DEPTH,
+    DEFAULT_MAGIC_NUMBER_MAX_POWER_OF_TWO, ExceptionCauseOptions, MagicNumberOptions, RuleOptions,
+};
+use crate::remote::is_remote_input;
+
+/// Scan configuration read from a `--config` JSON or TOML file.
+///
+/// Relative paths, including `@file` references, are resolved against the directory of the
+/// config file.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ScanConfig {
+    #[serde(default)]
+    pub(crate) inputs: Vec<String>,
+    #[serde(default)]
+    pub(crate) classpath: Vec<String>,
+    #[serde(default)]
+    pub(crate) rules: Vec<String>,
+    #[serde(default)]
+    pub(crate) exclude_rules: Vec<String>,
+    pub(crate) baseline: Option<String>,
+    pub(crate) release: Option<u32>,
+    #[serde(default)]
+    pub(crate) rule_options: RuleOptionsConfig,
+}
+
+/// Options for individual rules, keyed by rule ID, such as `[rule_options.MAGIC_NUMBER]` in
+/// TOML. Rules without options are rejected as unknown fields.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RuleOptionsConfig {
+    #[serde(rename = "MAGIC_NUMBER")]
+    pub(crate) magic_number: Option<MagicNumberConfig>,
+    #[serde(rename = "EXCEPTION_CAUSE_NOT_PRESERVED")]
+    pub(crate) exception_cause: Option<ExceptionCauseConfig>,
+}
+
+/// `MAGIC_NUMBER` options. Allowlisted values extend the rule's built-in allowlist.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct MagicNumberConfig {
+    #[serde(default)]
+    pub(crate) allowlist: Vec<i64>,
+    #[serde(default)]
+    pub(crate) float_allowlist: Vec<f64>,
+    pub(crate) max_power_of_two: Option<i64>,
+}
+
+/// `EXCEPTION_CAUSE_NOT_PRESERVED` simulation budgets. Larger values increase analysis time.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ExceptionCauseConfig {
+    pub(crate) max_stack_depth: Option<usize>,
+    pub(crate) max_allocations: Option<usize>,
+}
+
+impl RuleOptionsConfig {
+    /// Returns the rule options with every value missing from this table set to its default.
+    pub(crate) fn to_rule_options(&self, allow_exit_in_main: bool) -> RuleOptions {
+        let magic_number = self.magic_number.clone().unwrap_or_default();
+        let exception_cause = self.exception_cause.clone().unwrap_or_default();
+        RuleOptions {
+            allow_exit_in_main,
+            magic_number: MagicNumberOptions {
+                allowlist: magic_number.allowlist,
+                float_allowlist: magic_number.float_allowlist,
+                max_power_of_two: magic_number
+                    .max_power_of_two
+                    .unwrap_or(DEFAULT_MAGIC_NUMBER_MAX_POWER_OF_TWO),
+            },
+            exception_cause: ExceptionCauseOptions {
+                max_stack_depth: exception_cause
+                    .max_stack_depth
+                    .unwrap_or(DEFAULT_EXCEPTION_CAUSE_MAX_STACK_DEPTH),
+                max_allocations: exception_cause
+                    .max_allocations
+                    .unwrap_or(DEFAULT_EXCEPTION_CAUSE_MAX_ALLOCATIONS),
+            },
+        }
+    }
+}
+
+/// Reads and validates the scan configuration at `path`. Files ending in `.toml` are parsed as
+/// TOML and everything else as JSON.
+pub(crate) fn load_scan_config(path: &Path) -> Result<ScanConfig> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read --config {}", path.display()))?;
+    let is_toml = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"));
+    let config = if is_toml {
+        toml::from_str::<ScanConfig>(&content)
+            .map_err(|error| anyhow::anyhow!("invalid --config {}: {error}", path.display()))?
+    } else {
+        parse_json_scan_config(&content)
+            .with_context(|| format!("invalid --config {}", path.display()))?
+    };
+    validate_scan_config(&config)
+        .with_context(|| format!("invalid --config {}", path.display()))?;
+    let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+    Ok(resolve_relative_paths(config, base_dir))
+}
+
+fn parse_json_scan_config(content: &str) -> Result<ScanConfig> {
+    let mut deserializer = serde_json::Deserializer::from_str(content);
+    serde_path_to_error::deserialize::<_, ScanConfig>(&mut deserializer).map_err(|error| {
+        let path = error.path().to_string();
+        let inner = error.into_inner();
+        if path == "." {
+            anyhow::anyhow!("{inner}")
+        } else {
+            anyhow::anyhow!("at {path}: {inner}")
+        }
+    })
+}
+
+fn validate_scan_config(config: &ScanConfig) -> Result<()> {
+    let lists = [
+        ("inputs", &config.inputs),
+        ("classpath", &config.classpath),
+        ("rules", &config.rules),
+        ("exclude_rules", &config.exclude_rules),
+    ];
+    for (name, entries) in lists {
+        if let Some(index) = entries.iter().position(|entry| entry.trim().is_empty()) {
+            anyhow::bail!("at {name}[{index}]: expected a non-empty string");
+        }
+    }
+    if config
+        .baseline
+        .as_deref()
+        .is_some_and(|baseline| baseline.trim().is_empty())
+    {
+        anyhow::bail!("at baseline: expected a non-empty path");
+    }
+    if let Some(rule_id) = config
+        .rules
+        .iter()
+        .find(|rule_id| config.exclude_rules.contains(rule_id))
+    {
+        anyhow::bail!("rule {rule_id} is listed in both rules and exclude_rules");
+    }
+    if let Some(exception_cause) = &config.rule_options.exception_cause {
+        let budgets = [
+            ("max_stack_depth", exception_cause.max_stack_depth),
+            ("max_allocations", exception_cause.max_allocations),
+        ];
+        for (name, value) in budgets {
+            if value == Some(0) {
+                anyhow::bail!(
+                    "at rule_options.EXCEPTION_CAUSE_NOT_PRESERVED.{name}: expected a positive integer"
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn resolve_relative_paths(config: ScanConfig, base_dir: &Path) -> ScanConfig {
+    let resolve_all = |entries: Vec<String>| {
+        entries
+            .into_iter()
+            .map(|entry| resolve_path_entry(&entry, base_dir))
+            .collect()
+    };
+    let resolve_at_files = |entries: Vec<String>| {
+        entries
+            .into_iter()
+            .map(|entry| match entry.strip_prefix('@') {
+                Some(path) => format!("@{}", resolve_path(path, base_dir)),
+                None => entry,
+            })
+            .collect()
+    };
+    ScanConfig {
+        inputs: resolve_all(config.inputs),
+        classpath: resolve_all(config.classpath),
+        rules: resolve_at_files(config.rules),
+        exclude_rules: resolve_at_files(config.exclude_rules),
+        baseline: config
+            .baseline
+            .map(|baseline| resolve_path(&baseline, base_dir)),
+        release: config.release,
+        rule_options: config.rule_options,
+    }
+}
+
+/// Resolves an `--input`/`--classpath` style entry, keeping remote inputs unchanged.
+fn resolve_path_entry(entry: &str, base_dir: &Path) -> String {
+    if is_remote_input(entry) {
+        return entry.to_string();
+    }
+    match entry.strip_prefix('@') {
+        Some(path) => format!("@{}", resolve_path(path, base_dir)),
+        None => resolve_path(entry, base_dir),
+    }
+}
+
+fn resolve_path(path: &str, base_dir: &Path) -> String {
+    let path = Path::new(path);
+    if path.is_absolute() {
+        return path.display().to_string();
+    }
+    base_dir.join(path).display().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn load_scan_config_resolves_json_paths_against_config_directory() {
+        let dir = tempdir().expect("temp dir");
+        let path = dir.path().join("inspequte.json");
+        fs::write(
+            &path,
+            r#"{
+  "inputs": ["build/classes", "@inputs.txt", "mvn:org.jspecify:jspecify:1.0.0"],
+  "classpath": ["/opt/lib/a.jar"],
+  "rules": ["SYSTEM_EXIT", "@rules.txt"],
+  "exclude_rules": ["MAGIC_NUMBER"],
+  "baseline": "baseline.json",
+  "release": 21
+}"#,
+        )
+        .expect("write config");
+
+        let config = load_scan_config(&path).expect("load config");
+
+        let base = dir.path().display();
+        assert_eq!(
+            config,
+            ScanConfig {
+                inputs: vec![
+                    format!("{base}/build/classes"),
+                    format!("@{base}/inputs.txt"),
+                    "mvn:org.jspecify:jspecify:1.0.0".to_string(),
+                ],
+                classpath: vec!["/opt/lib/a.jar".to_string()],
+                rules: vec!["SYSTEM_EXIT".to_string(), format!("@{base}/rules.txt")],
+                exclude_rules: vec!["MAGIC_NUMBER".to_string()],
+                baseline: Some(format!("{base}/baseline.json")),
+                release: Some(21),
+                rule_options: RuleOptionsConfig::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn load_scan_config_reads_toml() {
+        let dir = tempdir().expect("temp dir");
+        let path = dir.path().join("inspequte.toml");
+        fs::write(
+            &path,
+            "inputs = [\"app.jar\"]\nexclude_rules = [\"MAGIC_NUMBER\"]\nrelease = 17\n",
+        )
+        .expect("write config");
+
+        let config = load_scan_config(&path).expect("load config");
+
+        assert_eq!(
+            config.inputs,
+            vec![format!("{}/app.jar", dir.path().display())]
+        );
+        assert_eq!(config.exclude_rules, vec!["MAGIC_NUMBER".to_string()]);
+        assert_eq!(config.release, Some(17));
+        assert!(config.rules.is_empty());
+    }
+
+    #[test]
+    fn load_scan_config_reads_rule_options() {
+        let dir = tempdir().expect("temp dir");
+        let path = dir.path().join("inspequte.toml");
+        fs::write(
+            &path,
+            r#"inputs = ["app.jar"]
+
+[rule_options.MAGIC_NUMBER]
+allowlist = [3600, -60]
+float_allowlist = [0.5]
+
+[rule_options.EXCEPTION_CAUSE_NOT_PRESERVED]
+max_stack_depth = 64
+"#,
+        )
+        .expect("write config");
+
+        let options = load_scan_config(&path)
+            .expect("load config")
+            .rule_options
+            .to_rule_options(true);
+
+        assert_eq!(options.magic_number.allowlist, vec![3600, -60]);
+        assert_eq!(options.magic_number.float_allowlist, vec![0.5]);
+        assert_eq!(
+            options.magic_number.max_power_of_two,
+            DEFAULT_MAGIC_NUMBER_MAX_POWER_OF_TWO
+        );
+        assert_eq!(options.exception_cause.max_stack_depth, 64);
+        assert_eq!(
+            options.exception_cause.max_allocations,
+            DEFAULT_EXCEPTION_CAUSE_MAX_ALLOCATIONS
+        );
+        assert_eq!(
+            RuleOptionsConfig::default().to_rule_options(true),
+            RuleOptions::default()
+        );
+    }
+
+    #[test]
+    fn load_scan_config_rejects_invalid_configs() {
+        let dir = tempdir().expect("temp dir");
+        let path = dir.path().join("inspequte.json");
+        let cases = [
+            (r#"{"input": ["app.jar"]}"#, "unknown field `input`"),
+            (r#"{"release": "21"}"#, "at release: invalid type"),
+            (r#"{"inputs": ["app.jar", " "]}"#, "at inputs[1]"),
+            (
+                r#"{"rule_options": {"SYSTEM_EXIT": {}}}"#,
+                "unknown field `SYSTEM_EXIT`",
+            ),
+            (
+                r#"{"rule_options": {"MAGIC_NUMBER": {"allow": [1]}}}"#,
+                "at rule_options.MAGIC_NUMBER: unknown field `allow`",
+            ),
+            (
+                r#"{"rule_options": {"EXCEPTION_CAUSE_NOT_PRESERVED": {"max_allocations": 0}}}"#,
+                "at rule_options.EXCEPTION_CAUSE_NOT_PRESERVED.max_allocations",
+            ),
+            (
+                r#"{"rules": ["SYSTEM_EXIT"], "exclude_rules": ["SYSTEM_EXIT"]}"#,
+                "rule SYSTEM_EXIT is listed in both rules and exclude_rules",
+            ),
+        ];
+        for (content, expected) in cases {
+            fs::write(&path, content).expect("write config");
+
+            let error = load_scan_config(&path).expect_err("invalid config");
+
+            let message = format!("{error:#}");
+            assert!(message.contains(expected), "{content}: {message}");
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_sarif::sarif::Result as SarifResult;
+
+use crate::InvocationStats;
+
+const SUMMARY_VERSION: u32 = 1;
+
+/// Finding and scan counts written by `--summary`, for CI scripts that do not want to parse
+/// SARIF. Finding counts are taken after baseline filtering.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub(crate) struct ScanSummary {
+    version: u32,
+    finding_count: usize,
+    findings_by_rule: BTreeMap<String, usize>,
+    baseline_suppressed_count: usize,
+    class_count: usize,
+    artifact_count: usize,
+    /// Trace ID of the scan when `--otel` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace_id: Option<String>,
+}
+
+impl ScanSummary {
+    pub(crate) fn new(trace_id: Option<String>) -> Self {
+        Self {
+            version: SUMMARY_VERSION,
+            finding_count: 0,
+            findings_by_rule: BTreeMap::new(),
+            baseline_suppressed_count: 0,
+            class_count: 0,
+            artifact_count: 0,
+            trace_id,
+        }
+    }
+
+    /// Takes the scanned class and artifact counts from `stats`.
+    pub(crate) fn record_scan(&mut self, stats: &InvocationStats) {
+        self.class_count = stats.class_count;
+        self.artifact_count = stats.artifact_count;
+    }
+
+    /// Counts a finding that was reported.
+    pub(crate) fn record_finding(&mut self, result: &SarifResult) {
+        self.finding_count += 1;
+        let rule_id = result.rule_id.as_deref().unwrap_or_default();
+        *self
+            .findings_by_rule
+            .entry(rule_id.to_string())
+            .or_default() += 1;
+    }
+
+    /// Counts findings dropped because the baseline already records them.
+    pub(crate) fn record_suppressed(&mut self, count: usize) {
+        self.baseline_suppressed_count += count;
+    }
+}
+
+pub(crate) fn write_scan_summary(path: &Path, summary: &ScanSummary) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create summary directory {}", parent.display()))?;
+    }
+    let content =
+        serde_json::to_string_pretty(summary).context("failed to serialize scan summary")?;
+    fs::write(path, format!("{content}\n"))
+        .with_context(|| format!("failed to write summary {}", path.display()))
+}
@@ -1,12 +1,16 @@
 use anyhow::{Context, Result, anyhow};
+use opentelemetry::metrics::{Counter, Histogram, MeterProvider as OtelMeterProvider};
 use opentelemetry::trace::{Span, TraceContextExt, Tracer, TracerProvider as OtelTracerProvider};
 use opentelemetry::{Context as OtelContext, KeyValue};
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::metrics::exporter::PushMetricExporter;
+use opentelemetry_sdk::metrics::{PeriodicReader, SdkMeterProvider};
 use opentelemetry_sdk::trace::{
     BatchConfigBuilder, BatchSpanProcessor, SdkTracerProvider, SpanExporter,
 };
 use std::time::Duration;
+use tokio::runtime::Runtime;
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
@@ -15,18 +19,64 @@ use tracing_subscriber::util::SubscriberInitExt;
 pub(crate) struct Telemetry {
     tracer: opentelemetry_sdk::trace::SdkTracer,
     provider: SdkTracerProvider,
+    metrics: Option<RuleMetrics>,
+    // Keeps the gRPC transport alive; dropped after the provider has been shut down.
+    _runtime: Option<Runtime>,
+}
+
+/// OpenTelemetry instruments for per-rule cost.
+struct RuleMetrics {
+    provider: SdkMeterProvider,
+    duration: Histogram<f64>,
+    findings: Counter<u64>,
+}
+
+/// Transport used to send spans to the OTLP collector.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OtlpProtocol {
+    Http,
+    Grpc,
+}
+
+/// OTLP collector endpoint resolved from the `--otel` URL.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct OtlpEndpoint {
+    protocol: OtlpProtocol,
+    url: String,
 }
 
 impl Telemetry {
-    /// Initialize telemetry with an OTLP HTTP exporter.
+    /// Initialize telemetry with an OTLP exporter.
+    ///
+    /// `http://` and `https://` URLs use OTLP/HTTP; `grpc://` URLs use plaintext OTLP/gRPC.
     pub(crate) fn new(endpoint: String) -> Result<Self> {
-        let endpoint = normalize_otlp_http_trace_endpoint(&endpoint)?;
-        let exporter = opentelemetry_otlp::SpanExporter::builder()
-            .with_http()
-            .with_endpoint(endpoint)
-            .build()
-            .context("build OTLP span exporter")?;
-        Self::from_exporter(exporter)
+        let endpoint = resolve_otlp_endpoint(&endpoint)?;
+        let runtime = match endpoint.protocol {
+            OtlpProtocol::Http => None,
+            OtlpProtocol::Grpc => Some(build_grpc_runtime()?),
+        };
+        let exporter = build_span_exporter(&endpoint, runtime.as_ref())?;
+        let metric_exporter = build_metric_exporter(&endpoint, runtime.as_ref())?;
+        let mut telemetry = Self::from_exporter(exporter)?.with_metric_exporter(metric_exporter);
+        telemetry._runtime = runtime;
+        Ok(telemetry)
+    }
+
+    /// Record how long a rule ran and how many findings it reported.
+    pub(crate) fn record_rule_metrics(
+        &self,
+        rule_id: &str,
+        duration: Duration,
+        finding_count: usize,
+    ) {
+        let Some(metrics) = &self.metrics else {
+            return;
+        };
+        let attributes = [KeyValue::new("inspequte.rule", rule_id.to_string())];
+        metrics
+            .duration
+            .record(duration.as_secs_f64() * 1000.0, &attributes);
+        metrics.findings.add(finding_count as u64, &attributes);
     }
 
     /// Run a closure inside a span when telemetry is enabled.
@@ -63,16 +113,45 @@ impl Telemetry {
         f()
     }
 
-    /// Flush spans and shut down the tracer provider.
+    /// Flush spans and metrics, then shut down the providers.
     pub(crate) fn shutdown(&self) -> Result<()> {
+        if let Some(metrics) = &self.metrics
+            && let Err(err) = metrics.provider.shutdown()
+        {
+            return Err(anyhow!("failed to shutdown meter provider: {err}"));
+        }
         if let Err(err) = self.provider.shutdown() {
             return Err(anyhow!("failed to shutdown tracer provider: {err}"));
         }
         Ok(())
     }
 
+    fn with_metric_exporter<E: PushMetricExporter>(mut self, exporter: E) -> Self {
+        let reader = PeriodicReader::builder(exporter).build();
+        let provider = SdkMeterProvider::builder()
+            .with_resource(service_resource())
+            .with_reader(reader)
+            .build();
+        let meter = provider.meter("inspequte");
+        let duration = meter
+            .f64_histogram("inspequte.rule.duration")
+            .with_unit("ms")
+            .with_description("Time spent running a single rule.")
+            .build();
+        let findings = meter
+            .u64_counter("inspequte.rule.findings")
+            .with_description("Number of findings reported by a single rule.")
+            .build();
+        self.metrics = Some(RuleMetrics {
+            provider,
+            duration,
+            findings,
+        });
+        self
+    }
+
     fn from_exporter<E: SpanExporter + 'static>(exporter: E) -> Result<Self> {
-        let resource = Resource::builder().with_service_name("inspequte").build();
+        let resource = service_resource();
         // BatchSpanProcessor in opentelemetry-sdk 0.31 uses std::thread::spawn
         // and std::sync::mpsc channels internally, so on_end() is a plain
         // channel send that works safely from rayon worker threads. The
@@ -92,10 +171,101 @@ impl Telemetry {
             .build();
         let tracer = provider.tracer("inspequte");
         opentelemetry::global::set_tracer_provider(provider.clone());
-        Ok(Self { tracer, provider })
+        Ok(Self {
+            tracer,
+            provider,
+            metrics: None,
+            _runtime: None,
+        })
+    }
+}
+
+fn service_resource() -> Resource {
+    Resource::builder().with_service_name("inspequte").build()
+}
+
+fn resolve_otlp_endpoint(endpoint: &str) -> Result<OtlpEndpoint> {
+    let url = reqwest::Url::parse(endpoint).context("parse OTLP endpoint")?;
+    match url.scheme() {
+        "http" | "https" => Ok(OtlpEndpoint {
+            protocol: OtlpProtocol::Http,
+            url: normalize_otlp_http_trace_endpoint(endpoint)?,
+        }),
+        "grpc" => {
+            let host = url
+                .host_str()
+                .ok_or_else(|| anyhow!("OTLP gRPC endpoint has no host: {endpoint}"))?;
+            let port = url.port().unwrap_or(4317);
+            Ok(OtlpEndpoint {
+                protocol: OtlpProtocol::Grpc,
+                url: format!("http://{host}:{port}"),
+            })
+        }
+        other => Err(anyhow!(
+            "unsupported OTLP endpoint scheme '{other}' (expected http, https, or grpc)"
+        )),
+    }
+}
+
+fn build_span_exporter(
+    endpoint: &OtlpEndpoint,
+    runtime: Option<&Runtime>,
+) -> Result<opentelemetry_otlp::SpanExporter> {
+    match endpoint.protocol {
+        OtlpProtocol::Http => opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint.url.clone())
+            .build()
+            .context("build OTLP HTTP span exporter"),
+        OtlpProtocol::Grpc => {
+            // The tonic channel spawns its worker on the ambient Tokio runtime.
+            let _guard = runtime.map(Runtime::enter);
+            opentelemetry_otlp::SpanExporter::builder()
+                .with_tonic()
+                .with_endpoint(endpoint.url.clone())
+                .build()
+                .context("build OTLP gRPC span exporter")
+        }
+    }
+}
+
+fn build_metric_exporter(
+    endpoint: &OtlpEndpoint,
+    runtime: Option<&Runtime>,
+) -> Result<opentelemetry_otlp::MetricExporter> {
+    match endpoint.protocol {
+        OtlpProtocol::Http => opentelemetry_otlp::MetricExporter::builder()
+            .with_http()
+            .with_endpoint(otlp_http_metrics_endpoint(&endpoint.url))
+            .build()
+            .context("build OTLP HTTP metric exporter"),
+        OtlpProtocol::Grpc => {
+            let _guard = runtime.map(Runtime::enter);
+            opentelemetry_otlp::MetricExporter::builder()
+                .with_tonic()
+                .with_endpoint(endpoint.url.clone())
+                .build()
+                .context("build OTLP gRPC metric exporter")
+        }
+    }
+}
+
+fn otlp_http_metrics_endpoint(trace_endpoint: &str) -> String {
+    match trace_endpoint.strip_suffix("/v1/traces") {
+        Some(prefix) => format!("{prefix}/v1/metrics"),
+        None => trace_endpoint.to_string(),
     }
 }
 
+fn build_grpc_runtime() -> Result<Runtime> {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("inspequte-otlp")
+        .enable_all()
+        .build()
+        .context("build OTLP gRPC runtime")
+}
+
 fn normalize_otlp_http_trace_endpoint(endpoint: &str) -> Result<String> {
     let mut url = reqwest::Url::parse(endpoint).context("parse OTLP endpoint")?;
     let path = url.path().to_string();
@@ -108,17 +278,29 @@ fn normalize_otlp_http_trace_endpoint(endpoint: &str) -> Result<String> {
 }
 
 /// Initialize logging facade with stderr output.
-pub(crate) fn init_logging() {
+///
+/// An explicit `level` takes precedence over `RUST_LOG`; without either, only warnings
+/// and errors are printed.
+pub(crate) fn init_logging(level: Option<&str>) {
+    let filter = match level {
+        Some(level) => EnvFilter::new(logging_directive(level)),
+        None => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+    };
     let init_result = tracing_subscriber::registry()
-        .with(
-            EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| EnvFilter::new("inspequte=info,warn")),
-        )
+        .with(filter)
         .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
         .try_init();
     let _ = init_result;
 }
 
+fn logging_directive(level: &str) -> String {
+    match level {
+        "error" | "warn" => level.to_string(),
+        // Keep dependencies at warn so verbose output focuses on inspequte itself.
+        _ => format!("inspequte={level},warn"),
+    }
+}
+
 /// Add an OpenTelemetry event to the currently active span.
 pub(crate) fn add_current_span_event(name: &str, attributes: &[KeyValue]) {
     let cx = OtelContext::current();
@@ -192,6 +374,58 @@ mod tests {
         assert_eq!(endpoint, "http://localhost:4318/v1/traces");
     }
 
+    #[test]
+    fn resolve_http_endpoint_uses_http_protocol() {
+        let endpoint = resolve_otlp_endpoint("http://localhost:4318/").expect("endpoint");
+        assert_eq!(endpoint.protocol, OtlpProtocol::Http);
+        assert_eq!(endpoint.url, "http://localhost:4318/v1/traces");
+    }
+
+    #[test]
+    fn resolve_grpc_endpoint_uses_grpc_protocol() {
+        let endpoint = resolve_otlp_endpoint("grpc://localhost:4317").expect("endpoint");
+        assert_eq!(endpoint.protocol, OtlpProtocol::Grpc);
+        assert_eq!(endpoint.url, "http://localhost:4317");
+    }
+
+    #[test]
+    fn metrics_endpoint_replaces_trace_path() {
+        assert_eq!(
+            otlp_http_metrics_endpoint("http://localhost:4318/v1/traces"),
+            "http://localhost:4318/v1/metrics"
+        );
+    }
+
+    #[test]
+    fn record_rule_metrics_is_noop_without_meter() {
+        let telemetry = Telemetry::from_exporter(NoopExporter).expect("telemetry");
+        telemetry.record_rule_metrics("RULE_A", Duration::from_millis(3), 2);
+        telemetry.shutdown().expect("shutdown");
+    }
+
+    #[test]
+    fn resolve_endpoint_rejects_unknown_scheme() {
+        assert!(resolve_otlp_endpoint("ftp://localhost:4317").is_err());
+        assert!(resolve_otlp_endpoint("grpcs://collector.example.com").is_err());
+    }
+
+    #[test]
+    fn build_span_exporter_supports_http_and_grpc() {
+        let runtime = build_grpc_runtime().expect("runtime");
+        for url in ["http://localhost:4318/", "grpc://localhost:4317"] {
+            let endpoint = resolve_otlp_endpoint(url).expect("endpoint");
+            let runtime = (endpoint.protocol == OtlpProtocol::Grpc).then_some(&runtime);
+            build_span_exporter(&endpoint, runtime).expect("exporter");
+        }
+    }
+
+    #[test]
+    fn logging_directive_keeps_dependencies_at_warn() {
+        assert_eq!(logging_directive("error"), "error");
+        assert_eq!(logging_directive("warn"), "warn");
+        assert_eq!(logging_directive("debug"), "inspequte=debug,warn");
+    }
+
     #[test]
     fn current_trace_id_is_available_inside_span() {
         let telemetry = Telemetry::from_exporter(NoopExporter).expect("telemetry");
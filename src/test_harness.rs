@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -5,8 +6,9 @@ use std::process::Command;
 use anyhow::{Context, Result};
 use tempfile::TempDir;
 
-use crate::engine::{Engine, EngineOutput, build_context};
-use crate::scan::scan_inputs;
+use crate::class_filter::ClassFilter;
+use crate::engine::{Engine, EngineOutput, RuleOptions, build_context_with_timings};
+use crate::scan::{ScanOptions, scan_inputs};
 
 /// Supported JVM source languages for the harness.
 #[allow(dead_code)]
@@ -110,11 +112,16 @@ impl JvmTestHarness {
         classes_dir: &Path,
         classpath: &[PathBuf],
     ) -> Result<EngineOutput> {
-        let inputs = vec![classes_dir.to_path_buf()];
-        let scan = scan_inputs(&inputs, classpath, None).context("scan classes")?;
-        let context = build_context(scan.classes, &scan.artifacts);
-        let engine = Engine::new_with_allowed_rule_ids(None).expect("build engine");
-        engine.analyze(context).context("run analysis")
+        self.analyze_with_rule_options(classes_dir, classpath, RuleOptions::default())
+    }
+
+    pub(crate) fn analyze_with_rule_options(
+        &self,
+        classes_dir: &Path,
+        classpath: &[PathBuf],
+        rule_options: RuleOptions,
+    ) -> Result<EngineOutput> {
+        run_analysis(classes_dir, classpath, rule_options, None)
     }
 
     pub(crate) fn compile_and_analyze(
@@ -126,6 +133,45 @@ impl JvmTestHarness {
         let output = self.compile(language, sources, classpath)?;
         self.analyze(output.classes_dir(), classpath)
     }
+
+    /// Like `compile_and_analyze`, but runs only `rule_ids`, including opt-in rules.
+    pub(crate) fn compile_and_analyze_rules(
+        &self,
+        language: Language,
+        sources: &[SourceFile],
+        classpath: &[PathBuf],
+        rule_ids: &[&str],
+    ) -> Result<EngineOutput> {
+        let output = self.compile(language, sources, classpath)?;
+        let rule_ids = rule_ids.iter().map(|id| id.to_string()).collect();
+        run_analysis(
+            output.classes_dir(),
+            classpath,
+            RuleOptions::default(),
+            Some(&rule_ids),
+        )
+    }
+}
+
+fn run_analysis(
+    classes_dir: &Path,
+    classpath: &[PathBuf],
+    rule_options: RuleOptions,
+    rule_ids: Option<&BTreeSet<String>>,
+) -> Result<EngineOutput> {
+    let inputs = vec![classes_dir.to_path_buf()];
+    let scan =
+        scan_inputs(&inputs, classpath, &ScanOptions::default(), None).context("scan classes")?;
+    let (context, _) = build_context_with_timings(
+        scan.classes,
+        scan.modules,
+        &scan.artifacts,
+        &ClassFilter::default(),
+        None,
+    );
+    let context = context.with_rule_options(rule_options);
+    let engine = Engine::new_with_allowed_rule_ids(rule_ids).expect("build engine");
+    engine.analyze(context).context("run analysis")
 }
 
 fn javac_path() -> Result<PathBuf> {
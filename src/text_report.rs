@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use serde_sarif::sarif::{Result as SarifResult, Sarif};
+
+/// Writes one line per finding in `sarif`, followed by the finding count, for `--format text`.
+///
+/// Lines read `<location>: <level>: <message> [<rule ID>]`, where the location is the artifact
+/// URI with the line when known, otherwise the class and method of the finding. Results without
+/// an explicit level use their rule's default level, then `warning`.
+pub(crate) fn write_text_report(writer: &mut dyn Write, sarif: &Sarif) -> Result<()> {
+    let mut finding_count = 0;
+    for run in &sarif.runs {
+        let default_levels: BTreeMap<&str, &str> = run
+            .tool
+            .driver
+            .rules
+            .iter()
+            .flatten()
+            .filter_map(|rule| {
+                let level = rule
+                    .default_configuration
+                    .as_ref()?
+                    .level
+                    .as_ref()?
+                    .as_str()?;
+                Some((rule.id.as_str(), level))
+            })
+            .collect();
+        for result in run.results.iter().flatten() {
+            let rule_id = result.rule_id.as_deref().unwrap_or_default();
+            let level = result
+                .level
+                .as_ref()
+                .and_then(|level| level.as_str())
+                .or_else(|| default_levels.get(rule_id).copied())
+                .unwrap_or("warning");
+            writeln!(
+                writer,
+                "{}: {}: {} [{}]",
+                result_location(result),
+                level,
+                result.message.text.as_deref().unwrap_or_default(),
+                rule_id
+            )
+            .context("failed to write text output")?;
+            finding_count += 1;
+        }
+    }
+    let noun = if finding_count == 1 {
+        "finding"
+    } else {
+        "findings"
+    };
+    writeln!(writer, "{finding_count} {noun}").context("failed to write text output")
+}
+
+fn result_location(result: &SarifResult) -> String {
+    let Some(location) = result.locations.iter().flatten().next() else {
+        return "<unknown>".to_string();
+    };
+    let physical = location.physical_location.as_ref();
+    let uri = physical
+        .and_then(|physical| physical.artifact_location.as_ref())
+        .and_then(|artifact| artifact.uri.as_deref());
+    let line = physical
+        .and_then(|physical| physical.region.as_ref())
+        .and_then(|region| region.start_line);
+    let logical = location
+        .logical_locations
+        .iter()
+        .flatten()
+        .find_map(|logical| logical.name.as_deref());
+    match (uri, line, logical) {
+        (Some(uri), Some(line), _) => format!("{uri}:{line}"),
+        (Some(uri), None, Some(logical)) => format!("{uri} ({logical})"),
+        (Some(uri), None, None) => uri.to_string(),
+        (None, _, Some(logical)) => logical.to_string(),
+        (None, _, None) => "<unknown>".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use serde_sarif::sarif::{
+        ReportingConfiguration, ReportingDescriptor, Run, Tool, ToolComponent,
+    };
+
+    use crate::rules::{method_location_with_line, result_message};
+
+    use super::*;
+
+    #[test]
+    fn write_text_report_prints_one_line_per_finding() {
+        let rule = ReportingDescriptor::builder()
+            .id("SYSTEM_EXIT")
+            .default_configuration(
+                ReportingConfiguration::builder()
+                    .level(json!("error"))
+                    .build(),
+            )
+            .build();
+        let with_line = SarifResult::builder()
+            .rule_id("SYSTEM_EXIT")
+            .message(result_message("Avoid System.exit"))
+            .locations(vec![method_location_with_line(
+                "com/example/ClassA",
+                "methodX",
+                "()V",
+                Some("file:///tmp/classes/com/example/ClassA.class"),
+                Some(3),
+            )])
+            .build();
+        let mut without_line = SarifResult::builder()
+            .rule_id("OTHER_RULE")
+            .message(result_message("Other finding"))
+            .locations(vec![method_location_with_line(
+                "com/example/ClassB",
+                "methodY",
+                "()V",
+                None,
+                None,
+            )])
+            .build();
+        without_line.level = Some(json!("note"));
+        let run = Run::builder()
+            .tool(
+                Tool::builder()
+                    .driver(
+                        ToolComponent::builder()
+                            .name("inspequte")
+                            .rules(vec![rule])
+                            .build(),
+                    )
+                    .build(),
+            )
+            .results(vec![with_line, without_line])
+            .build();
+        let sarif = Sarif::builder()
+            .version(json!("2.1.0"))
+            .runs(vec![run])
+            .build();
+
+        let mut output = Vec::new();
+        write_text_report(&mut output, &sarif).expect("write text report");
+
+        assert_eq!(
+            String::from_utf8(output).expect("utf-8"),
+            "file:///tmp/classes/com/example/ClassA.class:3: error: Avoid System.exit [SYSTEM_EXIT]\n\
+             com/example/ClassB.methodY()V: note: Other finding [OTHER_RULE]\n\
+             2 findings\n"
+        );
+    }
+}
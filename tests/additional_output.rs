@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::Value;
+use tempfile::tempdir;
+
+#[test]
+fn additional_output_writes_sarif_and_text_from_one_scan() {
+    let temp_dir = tempdir().expect("temp dir");
+    let classes_dir = temp_dir.path().join("classes");
+    compile_class(
+        temp_dir.path(),
+        &classes_dir,
+        "ClassA",
+        "public class ClassA { void methodX() { System.exit(1); } }\n",
+    );
+    let sarif_path = temp_dir.path().join("result.sarif");
+    let text_path = temp_dir.path().join("result.txt");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .current_dir(temp_dir.path())
+        .arg("--input")
+        .arg(&classes_dir)
+        .arg("--rules")
+        .arg("SYSTEM_EXIT")
+        .arg("--no-cache")
+        .arg("--output")
+        .arg(&sarif_path)
+        .arg("--additional-output")
+        .arg(format!("text={}", text_path.display()))
+        .output()
+        .expect("run inspequte");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let sarif: Value = serde_json::from_str(&fs::read_to_string(&sarif_path).expect("read SARIF"))
+        .expect("SARIF JSON");
+    let results = sarif["runs"][0]["results"].as_array().expect("results");
+    assert_eq!(results.len(), 1, "{results:?}");
+    let text = fs::read_to_string(&text_path).expect("read text output");
+    let lines = text.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2, "{text}");
+    assert!(lines[0].contains("[SYSTEM_EXIT]"), "{text}");
+    assert!(lines[0].contains("ClassA.class"), "{text}");
+    assert_eq!(lines[1], "1 finding");
+}
+
+fn compile_class(dir: &Path, classes_dir: &Path, name: &str, source: &str) {
+    let java_home = std::env::var("JAVA_HOME").expect("JAVA_HOME not set");
+    let mut javac = PathBuf::from(java_home);
+    javac.push("bin");
+    javac.push("javac");
+    if cfg!(windows) {
+        javac.set_extension("exe");
+    }
+    let source_path = dir.join(format!("{name}.java"));
+    fs::write(&source_path, source).expect("write source");
+    let status = Command::new(javac)
+        .arg("-d")
+        .arg(classes_dir)
+        .arg(&source_path)
+        .status()
+        .expect("run javac");
+    assert!(status.success(), "javac failed");
+}
@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::Value;
+use tempfile::tempdir;
+
+#[test]
+fn dedupe_findings_reports_duplicated_class_once() {
+    let temp_dir = tempdir().expect("temp dir");
+    let first_dir = temp_dir.path().join("first");
+    let second_dir = temp_dir.path().join("second");
+    compile_class(
+        temp_dir.path(),
+        &first_dir,
+        "ClassA",
+        "public class ClassA { void methodX() { System.exit(1); } }\n",
+    );
+    fs::create_dir_all(&second_dir).expect("create second dir");
+    fs::copy(
+        first_dir.join("ClassA.class"),
+        second_dir.join("ClassA.class"),
+    )
+    .expect("copy class");
+
+    let without_flag = scan(temp_dir.path(), &first_dir, &second_dir, &[]);
+    let with_flag = scan(
+        temp_dir.path(),
+        &first_dir,
+        &second_dir,
+        &["--dedupe-findings"],
+    );
+
+    assert_eq!(without_flag.len(), 2, "{without_flag:?}");
+    assert_eq!(with_flag.len(), 1, "{with_flag:?}");
+    let uri = with_flag[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+        .as_str()
+        .expect("artifact uri");
+    assert!(uri.contains("/first/"), "{uri}");
+}
+
+#[test]
+fn dedupe_findings_is_rejected_with_ndjson() {
+    let temp_dir = tempdir().expect("temp dir");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .current_dir(temp_dir.path())
+        .arg("--input")
+        .arg(temp_dir.path())
+        .arg("--format")
+        .arg("ndjson")
+        .arg("--dedupe-findings")
+        .output()
+        .expect("run inspequte");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--dedupe-findings cannot be combined with --format ndjson"),
+        "{stderr}"
+    );
+}
+
+/// Scans both class directories with SYSTEM_EXIT and returns the SARIF results.
+fn scan(working_dir: &Path, first_dir: &Path, second_dir: &Path, extra: &[&str]) -> Vec<Value> {
+    let output_path = working_dir.join("result.sarif");
+    let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .current_dir(working_dir)
+        .arg("--input")
+        .arg(first_dir)
+        .arg("--input")
+        .arg(second_dir)
+        .arg("--allow-duplicate-classes")
+        .arg("--rules")
+        .arg("SYSTEM_EXIT")
+        .arg("--no-cache")
+        .arg("--output")
+        .arg(&output_path)
+        .args(extra)
+        .output()
+        .expect("run inspequte");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let sarif: Value = serde_json::from_str(&fs::read_to_string(&output_path).expect("read SARIF"))
+        .expect("SARIF JSON");
+    sarif["runs"][0]["results"]
+        .as_array()
+        .expect("results")
+        .clone()
+}
+
+fn compile_class(dir: &Path, classes_dir: &Path, name: &str, source: &str) {
+    let java_home = std::env::var("JAVA_HOME").expect("JAVA_HOME not set");
+    let mut javac = PathBuf::from(java_home);
+    javac.push("bin");
+    javac.push("javac");
+    if cfg!(windows) {
+        javac.set_extension("exe");
+    }
+    let source_path = dir.join(format!("{name}.java"));
+    fs::write(&source_path, source).expect("write source");
+    let status = Command::new(javac)
+        .arg("-d")
+        .arg(classes_dir)
+        .arg(&source_path)
+        .status()
+        .expect("run javac");
+    assert!(status.success(), "javac failed");
+}
@@ -1,5 +1,8 @@
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use tempfile::tempdir;
+
 #[test]
 fn inspequte_exits_non_zero_on_error() {
     let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
@@ -10,3 +13,74 @@ fn inspequte_exits_non_zero_on_error() {
 
     assert!(!output.status.success());
 }
+
+#[test]
+fn fail_on_controls_exit_code_by_finding_level() {
+    let temp_dir = tempdir().expect("temp dir");
+    let classes_dir = compile_system_exit_class(temp_dir.path());
+    let baseline = temp_dir.path().join("missing-baseline.json");
+
+    // SYSTEM_EXIT reports at the warning level.
+    for (fail_on, expected) in [("none", 0), ("note", 2), ("warning", 2), ("error", 0)] {
+        let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+            .arg("--input")
+            .arg(&classes_dir)
+            .arg("--rules")
+            .arg("SYSTEM_EXIT")
+            .arg("--baseline")
+            .arg(&baseline)
+            .arg("--fail-on")
+            .arg(fail_on)
+            .output()
+            .expect("run inspequte");
+
+        assert_eq!(
+            output.status.code(),
+            Some(expected),
+            "--fail-on {fail_on}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn progress_conflicts_with_quiet() {
+    let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .arg("--input")
+        .arg("missing.class")
+        .arg("--progress")
+        .arg("--quiet")
+        .output()
+        .expect("run inspequte");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--progress"), "{stderr}");
+    assert!(stderr.contains("cannot be used with"), "{stderr}");
+    assert!(!stderr.contains("rule-class pairs"), "{stderr}");
+}
+
+fn compile_system_exit_class(dir: &Path) -> PathBuf {
+    let java_home = std::env::var("JAVA_HOME").expect("JAVA_HOME not set");
+    let mut javac = PathBuf::from(java_home);
+    javac.push("bin");
+    javac.push("javac");
+    if cfg!(windows) {
+        javac.set_extension("exe");
+    }
+    let source = dir.join("Exit.java");
+    std::fs::write(
+        &source,
+        "public class Exit { void stop() { System.exit(1); } }\n",
+    )
+    .expect("write source");
+    let classes_dir = dir.join("classes");
+    let status = Command::new(javac)
+        .arg("-d")
+        .arg(&classes_dir)
+        .arg(&source)
+        .status()
+        .expect("run javac");
+    assert!(status.success(), "javac failed");
+    classes_dir
+}
@@ -0,0 +1,117 @@
+use std::fs;
+use std::process::Command;
+
+use tempfile::tempdir;
+
+fn sarif_with_run(tool: &str, rule_id: &str, command_line: &str) -> String {
+    format!(
+        r#"{{
+  "version": "2.1.0",
+  "runs": [
+    {{
+      "tool": {{ "driver": {{ "name": "{tool}", "rules": [{{ "id": "{rule_id}" }}] }} }},
+      "invocations": [
+        {{ "executionSuccessful": true, "commandLine": "{command_line}" }}
+      ],
+      "results": [
+        {{ "ruleId": "{rule_id}", "message": {{ "text": "finding from {tool}" }} }}
+      ]
+    }}
+  ]
+}}
+"#
+    )
+}
+
+#[test]
+fn merge_keeps_every_run_and_produces_valid_sarif() {
+    let temp_dir = tempdir().expect("temp dir");
+    let first = temp_dir.path().join("module-a.sarif");
+    let second = temp_dir.path().join("module-b.sarif");
+    let merged = temp_dir.path().join("merged.sarif");
+    fs::write(
+        &first,
+        sarif_with_run("inspequte", "RULE_A", "inspequte --input a.jar"),
+    )
+    .expect("write first SARIF");
+    fs::write(
+        &second,
+        sarif_with_run("third-party-tool", "RULE_B", "tool --input b.jar"),
+    )
+    .expect("write second SARIF");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .arg("merge")
+        .arg("--input")
+        .arg(&first)
+        .arg("--input")
+        .arg(&second)
+        .arg("--output")
+        .arg(&merged)
+        .output()
+        .expect("run inspequte merge");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let value: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&merged).expect("read merged SARIF"))
+            .expect("parse merged SARIF");
+    let runs = value["runs"].as_array().expect("runs array");
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0]["tool"]["driver"]["name"], "inspequte");
+    assert_eq!(runs[0]["results"][0]["ruleId"], "RULE_A");
+    assert_eq!(
+        runs[0]["invocations"][0]["commandLine"],
+        "inspequte --input a.jar"
+    );
+    assert_eq!(runs[1]["tool"]["driver"]["name"], "third-party-tool");
+    assert_eq!(runs[1]["results"][0]["ruleId"], "RULE_B");
+    assert_eq!(
+        runs[1]["invocations"][0]["commandLine"],
+        "tool --input b.jar"
+    );
+
+    let verify = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .arg("verify")
+        .arg("--input")
+        .arg(&merged)
+        .output()
+        .expect("run inspequte verify");
+    assert!(
+        verify.status.success(),
+        "{}",
+        String::from_utf8_lossy(&verify.stderr)
+    );
+}
+
+#[test]
+fn merge_rejects_invalid_input() {
+    let temp_dir = tempdir().expect("temp dir");
+    let valid = temp_dir.path().join("valid.sarif");
+    let invalid = temp_dir.path().join("invalid.sarif");
+    fs::write(
+        &valid,
+        sarif_with_run("inspequte", "RULE_A", "inspequte --input a.jar"),
+    )
+    .expect("write valid SARIF");
+    fs::write(&invalid, r#"{ "version": "2.1.0", "runs": [ {} ] }"#).expect("write invalid SARIF");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .arg("merge")
+        .arg("--input")
+        .arg(&valid)
+        .arg("--input")
+        .arg(&invalid)
+        .output()
+        .expect("run inspequte merge");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("invalid.sarif is not valid SARIF 2.1.0"),
+        "{stderr}"
+    );
+}
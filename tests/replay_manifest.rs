@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use tempfile::tempdir;
+
+#[test]
+fn replay_manifest_warns_on_changed_files_and_rejects_added_files() {
+    let temp_dir = tempdir().expect("temp dir");
+    let classes_dir = temp_dir.path().join("classes");
+    compile_class(
+        temp_dir.path(),
+        &classes_dir,
+        "ClassA",
+        "public class ClassA { void methodX() { System.exit(1); } }\n",
+    );
+    let manifest = temp_dir.path().join("inspequte.manifest.json");
+
+    let recorded = inspequte(
+        temp_dir.path(),
+        &[
+            "--input".as_ref(),
+            classes_dir.as_os_str(),
+            "--manifest".as_ref(),
+            manifest.as_os_str(),
+        ],
+    );
+    assert!(
+        recorded.status.success(),
+        "{}",
+        String::from_utf8_lossy(&recorded.stderr)
+    );
+
+    compile_class(
+        temp_dir.path(),
+        &classes_dir,
+        "ClassA",
+        "public class ClassA { void methodX() { System.exit(2); } }\n",
+    );
+    let replayed = inspequte(
+        temp_dir.path(),
+        &["--replay-manifest".as_ref(), manifest.as_os_str()],
+    );
+    let stderr = String::from_utf8_lossy(&replayed.stderr);
+    assert!(replayed.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("changed since the manifest was written"),
+        "{stderr}"
+    );
+
+    compile_class(
+        temp_dir.path(),
+        &classes_dir,
+        "ClassB",
+        "public class ClassB {}\n",
+    );
+    let rejected = inspequte(
+        temp_dir.path(),
+        &["--replay-manifest".as_ref(), manifest.as_os_str()],
+    );
+    let stderr = String::from_utf8_lossy(&rejected.stderr);
+    assert!(!rejected.status.success());
+    assert!(stderr.contains("ClassB.class was added under"), "{stderr}");
+}
+
+/// Runs inspequte with SYSTEM_EXIT and no result cache, writing SARIF into `working_dir`.
+fn inspequte(working_dir: &Path, args: &[&std::ffi::OsStr]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .current_dir(working_dir)
+        .arg("--rules")
+        .arg("SYSTEM_EXIT")
+        .arg("--no-cache")
+        .arg("--output")
+        .arg(working_dir.join("result.sarif"))
+        .args(args)
+        .output()
+        .expect("run inspequte")
+}
+
+fn compile_class(dir: &Path, classes_dir: &Path, name: &str, source: &str) {
+    let java_home = std::env::var("JAVA_HOME").expect("JAVA_HOME not set");
+    let mut javac = PathBuf::from(java_home);
+    javac.push("bin");
+    javac.push("javac");
+    if cfg!(windows) {
+        javac.set_extension("exe");
+    }
+    let source_path = dir.join(format!("{name}.java"));
+    fs::write(&source_path, source).expect("write source");
+    let status = Command::new(javac)
+        .arg("-d")
+        .arg(classes_dir)
+        .arg(&source_path)
+        .status()
+        .expect("run javac");
+    assert!(status.success(), "javac failed");
+}
@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::Value;
+use tempfile::tempdir;
+
+#[test]
+fn summary_counts_findings_left_after_baseline_filtering() {
+    let temp_dir = tempdir().expect("temp dir");
+    let classes_dir = temp_dir.path().join("classes");
+    compile_class(
+        temp_dir.path(),
+        &classes_dir,
+        "ClassA",
+        "public class ClassA { void methodX() { System.exit(1); } }\n",
+    );
+    let baseline = temp_dir.path().join("baseline.json");
+    let output = inspequte(temp_dir.path())
+        .arg("baseline")
+        .arg("--input")
+        .arg(&classes_dir)
+        .arg("--output")
+        .arg(&baseline)
+        .output()
+        .expect("run inspequte baseline");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    compile_class(
+        temp_dir.path(),
+        &classes_dir,
+        "ClassB",
+        "public class ClassB { void methodY() { System.exit(2); } }\n",
+    );
+
+    let summary_path = temp_dir.path().join("reports").join("summary.json");
+    let output = inspequte(temp_dir.path())
+        .arg("--input")
+        .arg(&classes_dir)
+        .arg("--rules")
+        .arg("SYSTEM_EXIT")
+        .arg("--baseline")
+        .arg(&baseline)
+        .arg("--output")
+        .arg(temp_dir.path().join("result.sarif"))
+        .arg("--summary")
+        .arg(&summary_path)
+        .output()
+        .expect("run inspequte");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let summary: Value =
+        serde_json::from_str(&fs::read_to_string(&summary_path).expect("read summary"))
+            .expect("summary JSON");
+    let mut keys: Vec<&str> = summary
+        .as_object()
+        .expect("summary object")
+        .keys()
+        .map(String::as_str)
+        .collect();
+    keys.sort_unstable();
+    assert_eq!(
+        keys,
+        vec![
+            "artifact_count",
+            "baseline_suppressed_count",
+            "class_count",
+            "finding_count",
+            "findings_by_rule",
+            "version",
+        ]
+    );
+    assert_eq!(summary["version"], 1);
+    assert_eq!(summary["finding_count"], 1);
+    assert_eq!(
+        summary["findings_by_rule"],
+        serde_json::json!({"SYSTEM_EXIT": 1})
+    );
+    assert_eq!(summary["baseline_suppressed_count"], 1);
+    assert_eq!(summary["class_count"], 2);
+    assert!(summary["artifact_count"].as_u64().expect("artifact count") >= 1);
+}
+
+fn inspequte(working_dir: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_inspequte"));
+    command.current_dir(working_dir);
+    command
+}
+
+fn compile_class(dir: &Path, classes_dir: &Path, name: &str, source: &str) {
+    let java_home = std::env::var("JAVA_HOME").expect("JAVA_HOME not set");
+    let mut javac = PathBuf::from(java_home);
+    javac.push("bin");
+    javac.push("javac");
+    if cfg!(windows) {
+        javac.set_extension("exe");
+    }
+    let source_path = dir.join(format!("{name}.java"));
+    fs::write(&source_path, source).expect("write source");
+    let status = Command::new(javac)
+        .arg("-d")
+        .arg(classes_dir)
+        .arg(&source_path)
+        .status()
+        .expect("run javac");
+    assert!(status.success(), "javac failed");
+}
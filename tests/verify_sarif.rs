@@ -0,0 +1,74 @@
+use std::fs;
+use std::process::Command;
+
+use tempfile::tempdir;
+
+#[test]
+fn verify_accepts_valid_sarif() {
+    let temp_dir = tempdir().expect("temp dir");
+    let sarif_path = temp_dir.path().join("valid.sarif");
+    fs::write(
+        &sarif_path,
+        r#"{
+  "version": "2.1.0",
+  "runs": [
+    {
+      "tool": { "driver": { "name": "third-party-tool" } },
+      "results": [
+        { "ruleId": "RULE_A", "message": { "text": "finding" } }
+      ]
+    }
+  ]
+}
+"#,
+    )
+    .expect("write SARIF");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .arg("verify")
+        .arg("--input")
+        .arg(&sarif_path)
+        .output()
+        .expect("run inspequte");
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn verify_reports_every_schema_error_for_malformed_sarif() {
+    let temp_dir = tempdir().expect("temp dir");
+    let sarif_path = temp_dir.path().join("malformed.sarif");
+    // The run lacks its required tool, and the result message is not an object.
+    fs::write(
+        &sarif_path,
+        r#"{
+  "version": "2.1.0",
+  "runs": [
+    {
+      "results": [
+        { "ruleId": "RULE_A", "message": "finding" }
+      ]
+    }
+  ]
+}
+"#,
+    )
+    .expect("write SARIF");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_inspequte"))
+        .arg("verify")
+        .arg("--input")
+        .arg(&sarif_path)
+        .output()
+        .expect("run inspequte");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("is not valid SARIF 2.1.0"), "{stderr}");
+    assert!(stderr.contains("\"tool\""), "{stderr}");
+    assert!(stderr.contains("\"finding\""), "{stderr}");
+}